@@ -0,0 +1,156 @@
+use anchor_lang::Discriminator;
+use solana_program::{program::invoke, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyCompressedArgs {
+    pub asset_id: Pubkey,
+    pub price: u64,
+    pub expiry: i64,
+    pub buyer_creator_royalty_bp: u16,
+}
+
+// BuyCompressed places (or updates) a bid on a compressed (Bubblegum) NFT or an MPL Core asset.
+// The bid is a regular buyer trade state with the asset id in place of the mint, so cancel_buy and
+// the escrow work the same as for other bids. Collection checks wait for execute_compressed_sale,
+// where the notary attests the asset's collection, or core_execute_sale.
+#[derive(Accounts)]
+#[instruction(args: BuyCompressedArgs)]
+pub struct BuyCompressed<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: seeds check + discriminator check
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            args.asset_id.as_ref(),
+        ],
+        bump)]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: buyer_referral
+    buyer_referral: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the buyer is paying in a token, this is the mint of that token
+    // 1. payment_source_token_account (optional) - if the buyer is paying in a token, this is the source token account, we need to verify sufficient balance
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyCompressed<'info>>,
+    args: BuyCompressedArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let remaining_accounts = ctx.remaining_accounts;
+    let is_spl = remaining_accounts.len() == 2;
+
+    if buyer_trade_state.data_len() > 0
+        && buyer_trade_state.try_borrow_data()?[0..8] != BuyerTradeStateV2::discriminator()
+    {
+        return Err(ErrorCode::InvalidDiscriminator.into());
+    }
+    if args.buyer_creator_royalty_bp > 10_000 {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    if remaining_accounts.is_empty() {
+        // SOL
+        if escrow_payment_account.lamports() < args.price {
+            let diff = args
+                .price
+                .checked_sub(escrow_payment_account.lamports())
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            invoke(
+                &system_instruction::transfer(wallet.key, &escrow_payment_account.key(), diff),
+                &[
+                    wallet.to_account_info(),
+                    escrow_payment_account.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else if is_spl {
+        // SPL
+        assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.price, index_ra!(remaining_accounts, 0))?;
+        let payment_token_account_parsed = assert_is_ata(
+            index_ra!(remaining_accounts, 1),
+            escrow_payment_account.key,
+            index_ra!(remaining_accounts, 0).key,
+            escrow_payment_account.key,
+        )?;
+        if payment_token_account_parsed.amount < args.price {
+            return Err(ErrorCode::InvalidTokenAmount.into());
+        }
+    } else {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    let bts_bump = ctx.bumps.buyer_trade_state;
+    create_or_realloc_buyer_trade_state(
+        buyer_trade_state,
+        wallet,
+        &[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            args.asset_id.as_ref(),
+            &[bts_bump],
+        ],
+    )?;
+    let bts_v2 = BuyerTradeStateV2 {
+        auction_house_key: auction_house.key(),
+        buyer: wallet.key(),
+        buyer_referral: ctx.accounts.buyer_referral.key(),
+        buyer_price: args.price,
+        token_mint: args.asset_id,
+        token_size: 1,
+        bump: bts_bump,
+        buyer_creator_royalty_bp: args.buyer_creator_royalty_bp,
+        expiry: get_default_buyer_state_expiry(args.expiry),
+        payment_mint: if is_spl {
+            index_ra!(remaining_accounts, 0).key()
+        } else {
+            Pubkey::default()
+        },
+        escrowless: false,
+        take_profit_price: 0,
+        target_seller: Pubkey::default(),
+    };
+    let bts_v2_serialized = bts_v2.try_to_vec()?;
+    buyer_trade_state.try_borrow_mut_data()?[8..8 + bts_v2_serialized.len()]
+        .copy_from_slice(&bts_v2_serialized);
+
+    msg!(
+        "buy_compressed: {{\"buyer_trade_state\":\"{}\",\"asset_id\":\"{}\",\"price\":{},\"buyer_expiry\":{}}}",
+        buyer_trade_state.key(),
+        args.asset_id,
+        bts_v2.buyer_price,
+        bts_v2.expiry,
+    );
+    Ok(())
+}
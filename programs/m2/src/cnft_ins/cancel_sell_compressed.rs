@@ -0,0 +1,104 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelSellCompressedArgs {
+    pub root: Option<[u8; 32]>, // None only closes the listing, e.g. after the asset was transferred away
+}
+
+// CancelSellCompressed cancels a sell_compressed listing and hands the leaf delegate back to the
+// seller, which needs the current root of the tree and the proof of the leaf. Without a root the
+// listing is only closed.
+#[derive(Accounts)]
+pub struct CancelSellCompressed<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        mut,
+        close=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            COMPRESSED_LISTING.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            seller_trade_state.asset_id.as_ref(),
+        ],
+        bump=seller_trade_state.bump,
+        constraint = seller_trade_state.auction_house_key == auction_house.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, CompressedSellerTradeState>>,
+    /// CHECK: tree_authority
+    #[account(seeds=[merkle_tree.key().as_ref()], bump, seeds::program = BUBBLEGUM_PROGRAM_ID)]
+    tree_authority: UncheckedAccount<'info>,
+    /// CHECK: merkle_tree, checked against seller_trade_state and by bubblegum
+    #[account(mut, address = seller_trade_state.merkle_tree)]
+    merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: log_wrapper
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: compression_program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    compression_program: UncheckedAccount<'info>,
+    /// CHECK: bubblegum_program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ... proof nodes of the leaf, if root is Some
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelSellCompressed<'info>>,
+    args: CancelSellCompressedArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    assert_valid_notary(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.notary,
+        100u8, // 100% enforced cosign
+    )?;
+
+    if let Some(root) = args.root {
+        delegate_compressed(
+            &BubblegumAccounts {
+                bubblegum_program: &ctx.accounts.bubblegum_program,
+                tree_authority: &ctx.accounts.tree_authority,
+                merkle_tree: &ctx.accounts.merkle_tree,
+                log_wrapper: &ctx.accounts.log_wrapper,
+                compression_program: &ctx.accounts.compression_program,
+                system_program: &ctx.accounts.system_program,
+                proof: ctx.remaining_accounts,
+            },
+            wallet,
+            &ctx.accounts.program_as_signer,
+            wallet,
+            &CompressedLeafArgs {
+                root,
+                data_hash: seller_trade_state.data_hash,
+                creator_hash: seller_trade_state.creator_hash,
+                nonce: seller_trade_state.nonce,
+                index: seller_trade_state.index,
+            },
+        )?;
+    }
+
+    msg!(
+        "cancel_sell_compressed: {{\"seller_trade_state\":\"{}\",\"asset_id\":\"{}\"}}",
+        seller_trade_state.key(),
+        seller_trade_state.asset_id,
+    );
+    Ok(())
+}
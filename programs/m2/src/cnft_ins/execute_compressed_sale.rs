@@ -0,0 +1,344 @@
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::Token,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteCompressedSaleArgs {
+    pub price: u64,
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub root: [u8; 32], // current root of the tree, the proof nodes are the last proof_len remaining accounts
+    pub proof_len: u8,
+    // attested by the notary, compressed NFTs don't have an on-chain metadata account
+    pub seller_fee_basis_points: u16,
+    pub collection: Option<Pubkey>,
+    pub creators: Vec<CompressedCreator>,
+}
+
+// ExecuteCompressedSale fills a sell_compressed listing with a buy_compressed bid and transfers
+// the asset to the buyer through Bubblegum, signed by program_as_signer as the leaf delegate.
+// Royalty, collection and creators of the asset come from the args and are attested by the
+// notary, who has to sign. The creators are also checked against the creator hash of the leaf.
+#[derive(Accounts)]
+#[instruction(args: ExecuteCompressedSaleArgs)]
+pub struct ExecuteCompressedSale<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: buyer
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: seller
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    notary: Signer<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    /// CHECK: check seeds and check bid_args
+    #[account(
+        mut,
+        seeds=[
+          PREFIX.as_bytes(),
+          buyer.key().as_ref(),
+          auction_house.key().as_ref(),
+          seller_trade_state.asset_id.as_ref(),
+        ],
+        bump
+    )]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: must match the buyer_referral stored in buyer_trade_state
+    buyer_referral: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            COMPRESSED_LISTING.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            seller_trade_state.asset_id.as_ref(),
+        ],
+        bump=seller_trade_state.bump,
+        constraint = seller_trade_state.auction_house_key == auction_house.key() @ ErrorCode::InvalidAccountState,
+        constraint = seller_trade_state.seller_referral == seller_referral.key() @ ErrorCode::InvalidReferral,
+    )]
+    seller_trade_state: Box<Account<'info, CompressedSellerTradeState>>,
+    /// CHECK: must match the seller_referral stored in seller_trade_state
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: tree_authority
+    #[account(seeds=[merkle_tree.key().as_ref()], bump, seeds::program = BUBBLEGUM_PROGRAM_ID)]
+    tree_authority: UncheckedAccount<'info>,
+    /// CHECK: merkle_tree, checked against seller_trade_state and by bubblegum
+    #[account(mut, address = seller_trade_state.merkle_tree)]
+    merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: log_wrapper
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: compression_program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    compression_program: UncheckedAccount<'info>,
+    /// CHECK: bubblegum_program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the listing is paid in a SPL token, this is the mint of that token
+    // 1. payment_source_token_account (optional) - the buyer escrow's token account
+    // 2. payment_seller_token_account (optional) - the seller's token account
+    // 3. payment_treasury_token_account (optional) - the treasury's token account
+    // ... creator accounts, same as for execute_sale_v2
+    // ... proof nodes of the leaf, the last proof_len accounts
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteCompressedSale<'info>>,
+    args: ExecuteCompressedSaleArgs,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let notary = &ctx.accounts.notary;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    if payer.key() != buyer.key() && payer.key() != seller.key() {
+        return Err(ErrorCode::NoValidSignerPresent.into());
+    }
+    let proof_start = ctx
+        .remaining_accounts
+        .len()
+        .checked_sub(args.proof_len as usize)
+        .ok_or(ErrorCode::MissingRemainingAccount)?;
+    let (remaining_accounts, proof) = ctx.remaining_accounts.split_at(proof_start);
+
+    let now = Clock::get()?.unix_timestamp;
+    if seller_trade_state.buyer_price != args.price
+        || is_trade_state_expired(seller_trade_state.expiry, now)
+    {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if buyer_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+    let is_spl = seller_trade_state.payment_mint != Pubkey::default();
+    if bid_args.auction_house_key != auction_house.key() || bid_args.escrowless {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    bid_args.check_args(
+        ctx.accounts.buyer_referral.key,
+        args.price,
+        &seller_trade_state.asset_id,
+        1,
+        &seller_trade_state.payment_mint,
+    )?;
+    if is_spl {
+        assert_keys_equal(
+            index_ra!(remaining_accounts, 0).key,
+            &seller_trade_state.payment_mint,
+        )?;
+    }
+    bid_args.assert_target_seller(seller.key)?;
+    if is_trade_state_expired(bid_args.expiry, now) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    if hash_compressed_creators(&args.creators) != seller_trade_state.creator_hash {
+        return Err(ErrorCode::InvalidCompressedCreators.into());
+    }
+    let metadata_parsed = get_compressed_metadata(
+        &seller_trade_state.asset_id,
+        args.seller_fee_basis_points,
+        args.collection,
+        &args.creators,
+    );
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let auction_house_key = auction_house.key();
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    let royalty = if bid_args.buyer_creator_royalty_bp == 0 {
+        0
+    } else {
+        pay_creator_fees(
+            &mut (if is_spl {
+                remaining_accounts[4..].iter()
+            } else {
+                remaining_accounts.iter()
+            }),
+            None,
+            &metadata_parsed,
+            escrow_payment_account,
+            escrow_signer_seeds,
+            args.price,
+            bid_args.buyer_creator_royalty_bp,
+            if is_spl {
+                Some(TransferCreatorSplArgs {
+                    buyer,
+                    payer,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+        )?
+    };
+
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+    transfer_listing_payment(
+        args.price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        payer,
+        seller,
+        escrow_payment_account,
+        &ctx.accounts.auction_house_treasury,
+        if is_spl {
+            Some(TransferListingPaymentSplArgs {
+                payer,
+                buyer,
+                mint: index_ra!(remaining_accounts, 0),
+                payment_source_token_account: index_ra!(remaining_accounts, 1),
+                payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                system_program,
+                token_program,
+            })
+        } else {
+            None
+        },
+        escrow_signer_seeds,
+    )?;
+
+    transfer_compressed(
+        &BubblegumAccounts {
+            bubblegum_program: &ctx.accounts.bubblegum_program,
+            tree_authority: &ctx.accounts.tree_authority,
+            merkle_tree: &ctx.accounts.merkle_tree,
+            log_wrapper: &ctx.accounts.log_wrapper,
+            compression_program: &ctx.accounts.compression_program,
+            system_program,
+            proof,
+        },
+        seller,
+        program_as_signer,
+        buyer,
+        &CompressedLeafArgs {
+            root: args.root,
+            data_hash: seller_trade_state.data_hash,
+            creator_hash: seller_trade_state.creator_hash,
+            nonce: seller_trade_state.nonce,
+            index: seller_trade_state.index,
+        },
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+
+    record_sale(
+        &ctx.accounts.collection_stats,
+        &ctx.accounts.last_sale,
+        &ctx.accounts.trade_sequence,
+        payer,
+        &auction_house_key,
+        &metadata_parsed,
+        &seller_trade_state.payment_mint,
+        buyer.key,
+        seller.key,
+        args.price,
+        get_payment_decimals(if is_spl {
+            Some(index_ra!(remaining_accounts, 0))
+        } else {
+            None
+        })?,
+    )?;
+
+    msg!(
+        "execute_compressed_sale: {{\"asset_id\":\"{}\",\"price\":{},\"royalty\":{},\"seller_expiry\":{},\"buyer_expiry\":{}}}",
+        seller_trade_state.asset_id,
+        args.price,
+        royalty,
+        seller_trade_state.expiry,
+        bid_args.expiry,
+    );
+
+    close_account_anchor(buyer_trade_state, buyer)?;
+    try_close_buyer_escrow(
+        escrow_payment_account,
+        buyer,
+        system_program,
+        escrow_signer_seeds,
+    )
+}
@@ -0,0 +1,11 @@
+pub mod sell_compressed;
+pub use sell_compressed::*;
+
+pub mod cancel_sell_compressed;
+pub use cancel_sell_compressed::*;
+
+pub mod buy_compressed;
+pub use buy_compressed::*;
+
+pub mod execute_compressed_sale;
+pub use execute_compressed_sale::*;
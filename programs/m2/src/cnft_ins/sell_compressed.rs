@@ -0,0 +1,146 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellCompressedArgs {
+    pub price: u64,
+    pub expiry: i64,
+    pub leaf: CompressedLeafArgs,
+}
+
+// SellCompressed lists a compressed (Bubblegum) NFT. The seller stays the leaf owner and makes
+// program_as_signer the leaf delegate, Bubblegum checks the proof of the leaf against the tree's
+// root on the way, so a listing can only be made by the owner of the asset. Metadata of
+// compressed NFTs isn't an on-chain account, so collection pauses, hold periods and price ticks
+// don't apply to them. Listing again changes the price, but needs a fresh root and proof.
+#[derive(Accounts)]
+#[instruction(args: SellCompressedArgs)]
+pub struct SellCompressed<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: optional
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+        bump=auction_house.bump,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            COMPRESSED_LISTING.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            get_compressed_asset_id(&merkle_tree.key(), args.leaf.nonce).as_ref(),
+        ],
+        space=CompressedSellerTradeState::LEN,
+        bump,
+    )]
+    seller_trade_state: Box<Account<'info, CompressedSellerTradeState>>,
+    /// CHECK: current leaf delegate, the wallet itself if there is none, checked by bubblegum
+    previous_leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: tree_authority
+    #[account(seeds=[merkle_tree.key().as_ref()], bump, seeds::program = BUBBLEGUM_PROGRAM_ID)]
+    tree_authority: UncheckedAccount<'info>,
+    /// CHECK: merkle_tree, checked by bubblegum
+    #[account(mut, owner = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: payment_mint, Pubkey::default() for SOL
+    payment_mint: UncheckedAccount<'info>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: log_wrapper
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: compression_program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    compression_program: UncheckedAccount<'info>,
+    /// CHECK: bubblegum_program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ... proof nodes of the leaf
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellCompressed<'info>>,
+    args: SellCompressedArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let auction_house = &ctx.accounts.auction_house;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let payment_mint = &ctx.accounts.payment_mint;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.expiry >= 0 {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if seller_trade_state.bump != 0 && seller_trade_state.auction_house_key != auction_house.key() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if payment_mint.key() != Pubkey::default() {
+        assert_payment_mint(payment_mint)?;
+        assert_price_precision(args.price, payment_mint)?;
+    }
+
+    delegate_compressed(
+        &BubblegumAccounts {
+            bubblegum_program: &ctx.accounts.bubblegum_program,
+            tree_authority: &ctx.accounts.tree_authority,
+            merkle_tree,
+            log_wrapper: &ctx.accounts.log_wrapper,
+            compression_program: &ctx.accounts.compression_program,
+            system_program: &ctx.accounts.system_program,
+            proof: ctx.remaining_accounts,
+        },
+        wallet,
+        &ctx.accounts.previous_leaf_delegate,
+        &ctx.accounts.program_as_signer,
+        &args.leaf,
+    )?;
+
+    seller_trade_state.auction_house_key = auction_house.key();
+    seller_trade_state.seller = wallet.key();
+    seller_trade_state.seller_referral = ctx.accounts.seller_referral.key();
+    seller_trade_state.asset_id = get_compressed_asset_id(&merkle_tree.key(), args.leaf.nonce);
+    seller_trade_state.merkle_tree = merkle_tree.key();
+    seller_trade_state.nonce = args.leaf.nonce;
+    seller_trade_state.index = args.leaf.index;
+    seller_trade_state.data_hash = args.leaf.data_hash;
+    seller_trade_state.creator_hash = args.leaf.creator_hash;
+    seller_trade_state.buyer_price = args.price;
+    seller_trade_state.payment_mint = payment_mint.key();
+    seller_trade_state.expiry = args.expiry;
+    seller_trade_state.bump = ctx.bumps.seller_trade_state;
+
+    let payment_decimals = get_payment_decimals(if payment_mint.key() != Pubkey::default() {
+        Some(payment_mint)
+    } else {
+        None
+    })?;
+    msg!(
+        "sell_compressed: {{\"seller_trade_state\":\"{}\",\"asset_id\":\"{}\",\"price\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
+        seller_trade_state.key(),
+        seller_trade_state.asset_id,
+        seller_trade_state.buyer_price,
+        seller_trade_state.expiry,
+        payment_decimals,
+        get_ui_price(seller_trade_state.buyer_price, payment_decimals),
+    );
+    Ok(())
+}
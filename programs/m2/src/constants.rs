@@ -3,13 +3,133 @@ use solana_program::{pubkey, pubkey::Pubkey};
 pub const PREFIX: &str = "m2";
 pub const TREASURY: &str = "treasury";
 pub const SIGNER: &str = "signer";
+pub const MINT_LISTING_INDEX: &str = "mint_listing_index";
+pub const COLLECTION_CONFIG: &str = "collection_config";
+pub const COLLECTION_PAUSE: &str = "collection_pause"; // seed of CollectionConfigs before the rename
+pub const COLLECTION_STATS: &str = "collection_stats";
+pub const LAST_SALE: &str = "last_sale";
+pub const TRADE_SEQUENCE: &str = "trade_sequence";
+pub const SELLER_STATUS: &str = "seller_status";
+pub const BUYER_STATUS: &str = "buyer_status";
+pub const CUSTODY_VAULT: &str = "custody_vault";
+pub const FRONTEND_CONFIG: &str = "frontend_config";
+pub const LISTING_OPERATOR: &str = "listing_operator";
+pub const COLLECTION_BID: &str = "collection_bid";
+pub const ROYALTY_FALLBACK: &str = "royalty_fallback";
+pub const AUCTION: &str = "auction";
+pub const AUCTION_ESCROW: &str = "auction_escrow";
+pub const SEALED_BID: &str = "sealed_bid";
+pub const BUNDLE: &str = "bundle";
+pub const SWAP_OFFER: &str = "swap_offer";
+pub const RENTAL_LISTING: &str = "rental_listing";
+pub const ESCROW_DEPOSITOR: &str = "escrow_depositor";
+pub const ROYALTY_SUBSIDY: &str = "royalty_subsidy";
+pub const PORTFOLIO_BID: &str = "portfolio_bid";
+pub const COMPRESSED_LISTING: &str = "compressed_listing";
+pub const REBATE_CAMPAIGN: &str = "rebate_campaign";
+pub const REBATE_EPOCH: &str = "rebate_epoch";
+pub const CORE_LISTING: &str = "core_listing";
+pub const CUSTODIAL_PLATFORM: &str = "custodial_platform";
+pub const FILL_PROOF: &str = "fill_proof";
+pub const ORDER_BOOK: &str = "order_book";
+pub const ORDER_BOOK_LEVEL: &str = "order_book_level";
+pub const EDITION_SALE: &str = "edition_sale";
+pub const INSURANCE_FUND: &str = "insurance_fund";
+pub const STAKED_LISTING: &str = "staked_listing";
+pub const PERMANENT_DELEGATE_APPROVAL: &str = "permanent_delegate_approval";
+pub const PERMANENT_DELEGATE_CONFIG: &str = "permanent_delegate_config";
+pub const CANCELLATION_SNAPSHOT: &str = "cancellation_snapshot";
+pub const ORDER_LIMIT_CONFIG: &str = "order_limit_config";
+pub const WALLET_ORDER_COUNTER: &str = "wallet_order_counter";
+pub const COUNTED_ORDER: &str = "counted_order";
+pub const CRANK_BOND: &str = "crank_bond";
+pub const MAX_RENTAL_TERM_SECONDS: i64 = 60 * 60 * 24 * 365; // 1 year
+pub const MAX_HOLD_PERIOD_SECONDS: i64 = 60 * 60 * 24 * 30; // 30 days
+pub const MAX_AUTO_RENEW_PERIOD_SECONDS: i64 = 60 * 60 * 24 * 30; // 30 days
+pub const MAX_AUTO_RENEW_BUMP_BP: u16 = 10000;
+pub const MAX_AUCTION_REVEAL_SECONDS: i64 = 60 * 60 * 24 * 7; // 7 days
+pub const MAX_AUCTION_EXTENSION_SECONDS: i64 = 60 * 60; // 1 hour
+pub const TAKER_FEE_CONFIG: &str = "taker_fee_config";
+pub const BUYBACK_CONFIG: &str = "buyback_config";
+pub const BID_EXPIRY_ALARM: &str = "bid_expiry_alarm";
+pub const PRICE_BAND_CONFIG: &str = "price_band_config";
+pub const TREASURY_YIELD_CONFIG: &str = "treasury_yield_config";
+pub const PRICE_ATTESTATION: &str = "price_attestation";
+pub const BID_EXPIRY_BUCKET_SECONDS: i64 = 60 * 60; // 1 hour
+pub const MAX_BID_EXPIRY_ALARM_ENTRIES: usize = 48;
+pub const MAX_BUNDLE_ITEMS: usize = 16;
+pub const MAX_PORTFOLIO_BID_MINTS: usize = 16;
+pub const MAX_SELL_BATCH_ITEMS: usize = 8;
+pub const MAX_ACCEPT_OFFERS_BATCH_ITEMS: usize = 4;
+pub const MAX_UNSTAKE_DATA_LEN: usize = 128;
+pub const MAX_ORDER_BOOK_LEVELS: usize = 16; // price levels per side of an order book
+pub const MAX_ORDER_BOOK_LEVEL_ORDERS: usize = 16; // resting orders per price level
+pub const MIN_TREASURY_LEFTOVER: u64 = 1_000_000_000; // 1 SOL, kept by treasury withdrawals, buybacks and royalty subsidies
 pub const MAX_PRICE: u64 = 8000000 * 1000000000;
+pub const MIN_SPL_PRICE: u64 = 10_000; // in base units, so that a 1bp fee is still at least one base unit
+pub const MAX_SPL_PRICE_DECIMALS: u8 = 6; // SPL prices can't be more precise than this many decimals
+pub const SOL_DECIMALS: u8 = 9;
 pub const CANCEL_AUTHORITY: Pubkey = pubkey!("CNTuB1JiQD8Xh5SoRcEmF61yivN9F7uzdSaGnRex36wi");
 pub const DEFAULT_MAKER_FEE_BP: i16 = 0;
 pub const DEFAULT_TAKER_FEE_BP: u16 = 250;
 pub const MAX_MAKER_FEE_BP: i16 = 500;
 pub const MAX_TAKER_FEE_BP: u16 = 500;
+pub const MAX_FEE_RAMP_HOURS: u16 = 24 * 30; // 30 days
 pub const DEFAULT_BID_EXPIRY_SECONDS_AFTER_NOW: i64 = 60 * 60 * 24 * 7; // 7 days
+pub const CLOCK_SKEW_SECONDS: i64 = 30; // expiries pass this long after the fact, start times are reached this long before
+pub const MAX_MINT_LISTING_INDEX_ENTRIES: usize = 4;
+pub const FLOOR_WINDOW_SECONDS: i64 = 60 * 60 * 24; // 1 day
+pub const TWAP_SLOTS: usize = 6; // recent sales CollectionStats keeps for its floor twap
+pub const MAX_FLOOR_PREMIUM_BP: i16 = 10000; // floor pegged listings can be priced up to 2x the floor
+pub const MAX_FRONTEND_FEE_SHARE_BP: u16 = 5000; // frontends can get at most half of the platform fee
+pub const MAX_INSURANCE_FUND_SHARE_BP: u16 = 2000; // the insurance fund can get at most a fifth of the taker fee
+pub const INSURANCE_PAYOUT_TIMELOCK_SECONDS: i64 = 60 * 60 * 24 * 2; // 2 days
+pub const MAX_COLLECTION_BID_QUANTITY: u16 = 1000;
+pub const MAX_REBATE_TICKETS: usize = 200; // takers entered into a fee rebate draw per epoch
+pub const MAX_AUCTION_DURATION_SECONDS: i64 = 60 * 60 * 24 * 30; // 30 days
+pub const CRANK_BOND_LAMPORTS: u64 = 100_000; // slashed from the CrankBond of crankers of permissionless instructions on invalid calls
+pub const EXPIRED_BID_CRANK_TIP_LAMPORTS: u64 = 10_000; // paid to crankers of expired bids out of the trade state rent
+pub const FILL_PROOF_LIFETIME_SLOTS: u64 = 216_000; // about a day, FillProofs can be closed after
+pub const MIN_SETTLEMENT_COMPUTE_UNITS: u32 = 250_000; // pNFT and OCP settlements fail part way through below this
+pub const DEFAULT_INSTRUCTION_COMPUTE_UNITS: u32 = 200_000; // runtime default without a SetComputeUnitLimit instruction
+pub const MAX_TRANSACTION_COMPUTE_UNITS: u32 = 1_400_000;
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    pubkey!("ComputeBudget111111111111111111111111111111");
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const SPL_NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+pub const MPL_CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+pub const MPL_TOKEN_AUTH_RULES_PROGRAM_ID: Pubkey =
+    pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
+pub const CORE_ROYALTIES_PLUGIN: u8 = 0; // PluginType of mpl core
+pub const CORE_TRANSFER_DELEGATE_PLUGIN: u8 = 3;
+
+pub const SWAP_PROGRAM_WHITELIST: [Pubkey; 2] = [
+    pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"), // Jupiter v6
+    pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"), // Orca whirlpool
+];
+
+pub const YIELD_VAULT_PROGRAM_WHITELIST: [Pubkey; 2] = [
+    pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD"), // Marinade
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"), // SPL stake pool
+];
+
+// yield vault instructions sweep_treasury builds, sha256("global:deposit")[..8] and
+// sha256("global:liquid_unstake")[..8] of Marinade, and the DepositSol and WithdrawSol tags of the
+// SPL stake pool
+pub const MARINADE_DEPOSIT_IX_DISCRIMINATOR: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const MARINADE_LIQUID_UNSTAKE_IX_DISCRIMINATOR: [u8; 8] = [30, 30, 119, 240, 191, 227, 12, 16];
+pub const STAKE_POOL_DEPOSIT_SOL_IX: u8 = 14;
+pub const STAKE_POOL_WITHDRAW_SOL_IX: u8 = 16;
+
+// sha256("account:CollectionPause")[..8], the discriminator of CollectionConfigs before the rename
+pub const COLLECTION_PAUSE_DISCRIMINATOR: [u8; 8] = [216, 230, 228, 147, 35, 38, 99, 174];
+
+pub const STAKING_PROGRAM_WHITELIST: [Pubkey; 2] = [
+    pubkey!("stkBL96RZkjY5ine4TvPihGqW8UHJfch2cokjAPzV8i"), // Cardinal stake pool
+    pubkey!("farmL4xeBFVXJqtfxCzU9b28QACM7E2W2ctT6epAjvE"), // Gem farm
+];
 
 pub const VALID_PAYMENT_MINTS: [Pubkey; 8] = if cfg!(feature = "anchor-test") {
     [
@@ -0,0 +1,76 @@
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*,
+};
+
+// CoreCancelSell cancels a core_sell listing and hands the asset's transfer delegate plugin back
+// to the seller, if the seller still owns the asset and program_as_signer is still the delegate.
+#[derive(Accounts)]
+pub struct CoreCancelSell<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: asset, checked against seller_trade_state and in check_core_asset
+    #[account(mut, address = seller_trade_state.asset)]
+    asset: UncheckedAccount<'info>,
+    /// CHECK: collection of the asset, the core program if there's none, checked by the core program
+    collection: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        mut,
+        close=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            CORE_LISTING.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            seller_trade_state.asset.as_ref(),
+        ],
+        bump=seller_trade_state.bump,
+        constraint = seller_trade_state.auction_house_key == auction_house.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, CoreSellerTradeState>>,
+    /// CHECK: core_program
+    #[account(address = MPL_CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CoreCancelSell<'info>>) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let asset = &ctx.accounts.asset;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    assert_valid_notary(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.notary,
+        100u8, // 100% enforced cosign
+    )?;
+
+    let core_asset = check_core_asset(asset)?;
+    let delegate = CoreAuthority::Address {
+        address: ctx.accounts.program_as_signer.key(),
+    };
+    if core_asset.owner == wallet.key()
+        && core_asset.plugins.transfer_delegate.as_ref() == Some(&delegate)
+    {
+        revoke_core_delegate(&CoreAccounts {
+            core_program: &ctx.accounts.core_program,
+            asset,
+            collection: &ctx.accounts.collection,
+            payer: wallet,
+            system_program: &ctx.accounts.system_program,
+        })?;
+    }
+
+    msg!(
+        "core_cancel_sell: {{\"seller_trade_state\":\"{}\",\"asset\":\"{}\"}}",
+        seller_trade_state.key(),
+        seller_trade_state.asset,
+    );
+    Ok(())
+}
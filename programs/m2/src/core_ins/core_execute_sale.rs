@@ -0,0 +1,324 @@
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::Token,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CoreExecuteSaleArgs {
+    pub price: u64,
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+}
+
+// CoreExecuteSale fills a core_sell listing with a buy_compressed bid on the asset and transfers
+// the asset to the buyer through the core program, signed by program_as_signer as the transfer
+// delegate. The seller has to still own the asset. Royalties come from the royalties plugin of the
+// asset, or of its collection if the asset has none. A plugin with a rule set enforces royalties,
+// they're paid in full regardless of the bid, and fills the rule set would reject are refused
+// before any payment is made.
+#[derive(Accounts)]
+#[instruction(args: CoreExecuteSaleArgs)]
+pub struct CoreExecuteSale<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: buyer
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: seller
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: optional, signs to apply maker_fee_bp and taker_fee_bp
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    /// CHECK: check seeds and check bid_args
+    #[account(
+        mut,
+        seeds=[
+          PREFIX.as_bytes(),
+          buyer.key().as_ref(),
+          auction_house.key().as_ref(),
+          seller_trade_state.asset.as_ref(),
+        ],
+        bump
+    )]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: must match the buyer_referral stored in buyer_trade_state
+    buyer_referral: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            CORE_LISTING.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            seller_trade_state.asset.as_ref(),
+        ],
+        bump=seller_trade_state.bump,
+        constraint = seller_trade_state.auction_house_key == auction_house.key() @ ErrorCode::InvalidAccountState,
+        constraint = seller_trade_state.seller_referral == seller_referral.key() @ ErrorCode::InvalidReferral,
+    )]
+    seller_trade_state: Box<Account<'info, CoreSellerTradeState>>,
+    /// CHECK: must match the seller_referral stored in seller_trade_state
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: asset, checked against seller_trade_state and in check_core_asset
+    #[account(mut, address = seller_trade_state.asset)]
+    asset: UncheckedAccount<'info>,
+    /// CHECK: collection of the asset, the core program if there's none, checked in get_core_collection_royalties
+    collection: UncheckedAccount<'info>,
+    /// CHECK: core_program
+    #[account(address = MPL_CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the listing is paid in a SPL token, this is the mint of that token
+    // 1. payment_source_token_account (optional) - the buyer escrow's token account
+    // 2. payment_seller_token_account (optional) - the seller's token account
+    // 3. payment_treasury_token_account (optional) - the treasury's token account
+    // ... creator accounts, same as for execute_sale_v2
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CoreExecuteSale<'info>>,
+    args: CoreExecuteSaleArgs,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let notary = &ctx.accounts.notary;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    if payer.key() != buyer.key() && payer.key() != seller.key() {
+        return Err(ErrorCode::NoValidSignerPresent.into());
+    }
+    let remaining_accounts = ctx.remaining_accounts;
+
+    let now = Clock::get()?.unix_timestamp;
+    if seller_trade_state.buyer_price != args.price
+        || is_trade_state_expired(seller_trade_state.expiry, now)
+    {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if buyer_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+    let is_spl = seller_trade_state.payment_mint != Pubkey::default();
+    if bid_args.auction_house_key != auction_house.key() || bid_args.escrowless {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    bid_args.check_args(
+        ctx.accounts.buyer_referral.key,
+        args.price,
+        &seller_trade_state.asset,
+        1,
+        &seller_trade_state.payment_mint,
+    )?;
+    if is_spl {
+        assert_keys_equal(
+            index_ra!(remaining_accounts, 0).key,
+            &seller_trade_state.payment_mint,
+        )?;
+    }
+    bid_args.assert_target_seller(seller.key)?;
+    if is_trade_state_expired(bid_args.expiry, now) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    let asset = &ctx.accounts.asset;
+    let core_asset = check_core_asset(asset)?;
+    if core_asset.owner != seller.key() {
+        return Err(ErrorCode::IncorrectOwner.into());
+    }
+    let collection_royalties =
+        get_core_collection_royalties(&ctx.accounts.collection, &core_asset)?;
+    let metadata_parsed =
+        get_core_metadata(&asset.key(), &core_asset, collection_royalties.as_ref());
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let auction_house_key = auction_house.key();
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    let royalty_bp = get_core_royalty_bp(
+        &core_asset,
+        collection_royalties.as_ref(),
+        bid_args.buyer_creator_royalty_bp,
+        &[program_as_signer.owner, buyer.owner],
+    )?;
+    let royalty = if royalty_bp == 0 {
+        0
+    } else {
+        pay_creator_fees(
+            &mut (if is_spl {
+                remaining_accounts[4..].iter()
+            } else {
+                remaining_accounts.iter()
+            }),
+            None,
+            &metadata_parsed,
+            escrow_payment_account,
+            escrow_signer_seeds,
+            args.price,
+            royalty_bp,
+            if is_spl {
+                Some(TransferCreatorSplArgs {
+                    buyer,
+                    payer,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+        )?
+    };
+
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+    transfer_listing_payment(
+        args.price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        payer,
+        seller,
+        escrow_payment_account,
+        &ctx.accounts.auction_house_treasury,
+        if is_spl {
+            Some(TransferListingPaymentSplArgs {
+                payer,
+                buyer,
+                mint: index_ra!(remaining_accounts, 0),
+                payment_source_token_account: index_ra!(remaining_accounts, 1),
+                payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                system_program,
+                token_program,
+            })
+        } else {
+            None
+        },
+        escrow_signer_seeds,
+    )?;
+
+    transfer_core(
+        &CoreAccounts {
+            core_program: &ctx.accounts.core_program,
+            asset,
+            collection: &ctx.accounts.collection,
+            payer,
+            system_program,
+        },
+        program_as_signer,
+        buyer,
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+
+    record_sale(
+        &ctx.accounts.collection_stats,
+        &ctx.accounts.last_sale,
+        &ctx.accounts.trade_sequence,
+        payer,
+        &auction_house_key,
+        &metadata_parsed,
+        &seller_trade_state.payment_mint,
+        buyer.key,
+        seller.key,
+        args.price,
+        get_payment_decimals(if is_spl {
+            Some(index_ra!(remaining_accounts, 0))
+        } else {
+            None
+        })?,
+    )?;
+
+    msg!(
+        "core_execute_sale: {{\"asset\":\"{}\",\"price\":{},\"royalty\":{},\"seller_expiry\":{},\"buyer_expiry\":{}}}",
+        seller_trade_state.asset,
+        args.price,
+        royalty,
+        seller_trade_state.expiry,
+        bid_args.expiry,
+    );
+
+    close_account_anchor(buyer_trade_state, buyer)?;
+    try_close_buyer_escrow(
+        escrow_payment_account,
+        buyer,
+        system_program,
+        escrow_signer_seeds,
+    )
+}
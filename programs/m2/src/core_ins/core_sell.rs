@@ -0,0 +1,154 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CoreSellArgs {
+    pub price: u64,
+    pub expiry: i64,
+}
+
+// CoreSell lists an MPL Core asset. The seller keeps the asset and makes program_as_signer the
+// authority of its transfer delegate plugin, adding the plugin if needed. The asset's collection
+// stands in for the verified collection of a metadata account in collection pauses, hold periods
+// and price ticks. Listing again changes the price.
+#[derive(Accounts)]
+pub struct CoreSell<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: optional
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: asset, checked in check_core_asset
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+    /// CHECK: collection of the asset, the core program if there's none, checked in get_core_collection_royalties
+    collection: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+        bump=auction_house.bump,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            CORE_LISTING.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            asset.key().as_ref(),
+        ],
+        space=CoreSellerTradeState::LEN,
+        bump,
+    )]
+    seller_trade_state: Box<Account<'info, CoreSellerTradeState>>,
+    /// CHECK: payment_mint, Pubkey::default() for SOL
+    payment_mint: UncheckedAccount<'info>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_hold_period_elapsed
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: core_program
+    #[account(address = MPL_CORE_PROGRAM_ID)]
+    core_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CoreSell<'info>>,
+    args: CoreSellArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let asset = &ctx.accounts.asset;
+    let auction_house = &ctx.accounts.auction_house;
+    let payment_mint = &ctx.accounts.payment_mint;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.expiry >= 0 {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if seller_trade_state.bump != 0 && seller_trade_state.auction_house_key != auction_house.key() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    let core_asset = check_core_asset(asset)?;
+    if core_asset.owner != wallet.key() {
+        return Err(ErrorCode::IncorrectOwner.into());
+    }
+    let collection_royalties =
+        get_core_collection_royalties(&ctx.accounts.collection, &core_asset)?;
+    let metadata_parsed =
+        get_core_metadata(&asset.key(), &core_asset, collection_royalties.as_ref());
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_hold_period_elapsed(
+        &ctx.accounts.collection_config,
+        &ctx.accounts.last_sale,
+        &auction_house.key(),
+        &metadata_parsed,
+        wallet.key,
+    )?;
+    if payment_mint.key() != Pubkey::default() {
+        assert_payment_mint(payment_mint)?;
+        assert_price_precision(args.price, payment_mint)?;
+    } else {
+        assert_collection_price_on_tick(
+            &ctx.accounts.collection_config,
+            &auction_house.key(),
+            &get_collection_key(&metadata_parsed),
+            args.price,
+        )?;
+    }
+
+    delegate_core(
+        &CoreAccounts {
+            core_program: &ctx.accounts.core_program,
+            asset,
+            collection: &ctx.accounts.collection,
+            payer: wallet,
+            system_program: &ctx.accounts.system_program,
+        },
+        core_asset.plugins.transfer_delegate.as_ref(),
+        ctx.accounts.program_as_signer.key,
+    )?;
+
+    seller_trade_state.auction_house_key = auction_house.key();
+    seller_trade_state.seller = wallet.key();
+    seller_trade_state.seller_referral = ctx.accounts.seller_referral.key();
+    seller_trade_state.asset = asset.key();
+    seller_trade_state.buyer_price = args.price;
+    seller_trade_state.payment_mint = payment_mint.key();
+    seller_trade_state.expiry = args.expiry;
+    seller_trade_state.bump = ctx.bumps.seller_trade_state;
+
+    let payment_decimals = get_payment_decimals(if payment_mint.key() != Pubkey::default() {
+        Some(payment_mint)
+    } else {
+        None
+    })?;
+    msg!(
+        "core_sell: {{\"seller_trade_state\":\"{}\",\"asset\":\"{}\",\"price\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
+        seller_trade_state.key(),
+        seller_trade_state.asset,
+        seller_trade_state.buyer_price,
+        seller_trade_state.expiry,
+        payment_decimals,
+        get_ui_price(seller_trade_state.buyer_price, payment_decimals),
+    );
+    Ok(())
+}
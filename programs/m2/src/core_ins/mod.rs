@@ -0,0 +1,8 @@
+pub mod core_sell;
+pub use core_sell::*;
+
+pub mod core_cancel_sell;
+pub use core_cancel_sell::*;
+
+pub mod core_execute_sale;
+pub use core_execute_sale::*;
@@ -115,4 +115,151 @@ pub enum ErrorCode {
     Deprecated,
     #[msg("Missing remaining account")]
     MissingRemainingAccount,
+    // 338
+    #[msg("Trading is paused for this collection")]
+    CollectionPaused,
+    // 339
+    #[msg("Swap program is not whitelisted")]
+    InvalidSwapProgram,
+    // 340
+    #[msg("Swap output is below the minimum amount out")]
+    SlippageExceeded,
+    // 341
+    #[msg("Floor price is not available for this collection")]
+    FloorPriceUnavailable,
+    // 342
+    #[msg("Seller has suspended all listings")]
+    SellerSuspended,
+    // 343
+    #[msg("Buyer has suspended all bids")]
+    BuyerSuspended,
+    // 344
+    #[msg("Price is below the minimum price for the payment mint")]
+    PriceBelowMintMinimum,
+    // 345
+    #[msg("Price has more precision than the payment mint supports")]
+    InvalidPricePrecision,
+    // 346
+    #[msg("Referral account does not match the trade state")]
+    InvalidReferral,
+    // 347
+    #[msg("Not enough compute units requested, increase the compute unit limit")]
+    InsufficientComputeBudget,
+    // 348
+    #[msg("Token is not in the verified collection of the bid")]
+    InvalidCollection,
+    // 349
+    #[msg("Trait bids need the notary to attest the token's traits")]
+    TraitAttestationRequired,
+    // 350
+    #[msg("Auction is not accepting bids")]
+    AuctionNotActive,
+    // 351
+    #[msg("Auction bid is lower than the minimum bid")]
+    AuctionBidTooLow,
+    // 352
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+    // 353
+    #[msg("Price feed is invalid or stale")]
+    InvalidPriceFeed,
+    // 354
+    #[msg("Sealed bid doesn't match its commitment")]
+    InvalidSealedBid,
+    // 355
+    #[msg("Price is outside of the attested fair price band")]
+    PriceOutsideAttestedBand,
+    // 356
+    #[msg("Invalid yield vault program")]
+    InvalidYieldVaultProgram,
+    // 357
+    #[msg("Listing is reserved for another buyer")]
+    BuyerNotAllowed,
+    // 358
+    #[msg("Delegated payment doesn't cover the bid")]
+    InsufficientDelegatedAmount,
+    // 359
+    #[msg("Price is not a multiple of the collection's tick size")]
+    PriceOffTick,
+    // 360
+    #[msg("Rental term hasn't ended yet")]
+    RentalNotExpired,
+    // 361
+    #[msg("Trade state hasn't expired yet")]
+    TradeStateNotExpired,
+    // 362
+    #[msg("Token is still within the collection's hold period")]
+    HoldPeriodNotElapsed,
+    // 363
+    #[msg("Sale costs more than the taker's max price")]
+    MaxPriceExceeded,
+    // 364
+    #[msg("Seller's net proceeds are below the seller's min net proceeds")]
+    MinNetProceedsNotMet,
+    // 365
+    #[msg("Listings with a secondary payment leg have to be taken by the buyer")]
+    SecondaryLegNeedsBuyerTaker,
+    // 366
+    #[msg("Listing can't be filled before its not_before time")]
+    ListingNotYetActive,
+    // 367
+    #[msg("Offer is targeted at another seller")]
+    SellerNotTargeted,
+    // 368
+    #[msg("Accounts of the Token-2022 mint's transfer hook are missing")]
+    MissingTransferHookAccounts,
+    // 369
+    #[msg("Creators don't match the creator hash of the compressed NFT")]
+    InvalidCompressedCreators,
+    // 370
+    #[msg("Seed doesn't match the commitment of the rebate draw")]
+    InvalidRebateSeed,
+    // 371
+    #[msg("Buyer isn't an enabled custodial platform of the auction house")]
+    InvalidCustodialPlatform,
+    // 372
+    #[msg("Fill violates the rule set of the core royalties plugin")]
+    CoreRuleSetViolation,
+    // 373
+    #[msg("Auction reached its reserve price and has to be settled")]
+    AuctionReserveMet,
+    // 374
+    #[msg("Best bid and ask of the order book don't cross")]
+    OrderBookNotCrossed,
+    // 375
+    #[msg("Fill proof can't be closed before its lifetime passed")]
+    FillProofNotExpired,
+    // 376
+    #[msg("Edition sale has no editions left to print")]
+    EditionSaleSoldOut,
+    // 377
+    #[msg("Insurance fund payout is still timelocked")]
+    InsurancePayoutLocked,
+    // 378
+    #[msg("Asset is non-transferable and can't be listed")]
+    NonTransferableAsset,
+    // 379
+    #[msg("Authorization rules accounts don't match the rule set of the pNFT")]
+    InvalidAuthorizationRules,
+    // 380
+    #[msg("Staking program is not whitelisted")]
+    InvalidStakingProgram,
+    // 381
+    #[msg("Staking program didn't release the token to the buyer")]
+    StakedTokenNotReleased,
+    // 382
+    #[msg("Mint has a permanent delegate the auction house hasn't approved")]
+    PermanentDelegateNotApproved,
+    // 383
+    #[msg("Wallet has reached the open order limit of the auction house")]
+    OpenOrderLimitReached,
+    // 384
+    #[msg("Order isn't in the published cancellation snapshot")]
+    InvalidCancellationProof,
+    // 385
+    #[msg("Asset standard can't be routed")]
+    UnsupportedAssetStandard,
+    // 386
+    #[msg("Crank bond doesn't cover the slash or the withdrawal")]
+    InsufficientCrankBond,
 }
@@ -0,0 +1,633 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{constants::TWAP_SLOTS, states::*};
+
+/// Offset or size of fields whose encoding isn't fixed: Options and Vecs, and every field after
+/// one of them. Readers deserialize from the first such field on
+pub const VARIABLE: u16 = u16::MAX;
+
+#[derive(Clone, Copy)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub offset: u16, // from the start of the account, the discriminator included
+    pub size: u16,
+}
+
+/// Byte layout of a program account. version is bumped whenever fields are added or reordered,
+/// so readers that hard-code offsets can tell a layout they don't know
+pub struct StateLayout {
+    pub name: &'static str,
+    pub discriminator: [u8; 8],
+    pub version: u8,
+    pub len: u16, // allocated size of new accounts, padding included
+    pub fields: &'static [FieldLayout],
+}
+
+const fn with_offsets<const N: usize>(fields: [(&'static str, u16); N]) -> [FieldLayout; N] {
+    let mut layouts = [FieldLayout {
+        name: "",
+        offset: 0,
+        size: 0,
+    }; N];
+    let mut offset = 8; // discriminator
+    let mut i = 0;
+    while i < N {
+        layouts[i] = FieldLayout {
+            name: fields[i].0,
+            offset,
+            size: fields[i].1,
+        };
+        offset = if offset == VARIABLE || fields[i].1 == VARIABLE {
+            VARIABLE
+        } else {
+            offset + fields[i].1
+        };
+        i += 1;
+    }
+    layouts
+}
+
+macro_rules! field_size {
+    (var) => {
+        VARIABLE
+    };
+    ($ty:ty) => {
+        std::mem::size_of::<$ty>() as u16 // same as the borsh size of the fixed size types we use
+    };
+}
+
+macro_rules! state_layout {
+    ($state:ident, $version:expr, $len:expr, [$($field:ident: $ty:tt),* $(,)?]) => {
+        StateLayout {
+            name: stringify!($state),
+            discriminator: <$state as Discriminator>::DISCRIMINATOR,
+            version: $version,
+            len: $len as u16,
+            fields: &with_offsets([$((stringify!($field), field_size!($ty))),*]),
+        }
+    };
+}
+
+/// Layouts of every account of the program, for readers that parse accounts by offset
+pub const STATE_LAYOUTS: &[StateLayout] = &[
+    state_layout!(BuyerTradeState, 1, BuyerTradeState::LEN, [
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        buyer_referral: Pubkey,
+        buyer_price: u64,
+        token_mint: Pubkey,
+        token_size: u64,
+        bump: u8,
+        expiry: i64,
+    ]),
+    state_layout!(SellerTradeState, 1, SellerTradeState::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        seller_referral: Pubkey,
+        buyer_price: u64,
+        token_mint: Pubkey,
+        token_account: Pubkey,
+        token_size: u64,
+        bump: u8,
+        expiry: i64,
+    ]),
+    state_layout!(SellerTradeStateV2, 2, SellerTradeStateV2::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        seller_referral: Pubkey,
+        buyer_price: u64,
+        token_mint: Pubkey,
+        token_account: Pubkey,
+        token_size: u64,
+        bump: u8,
+        expiry: i64,
+        payment_mint: Pubkey,
+        floor_premium_bp: var,
+        creator_listing: bool,
+        dutch_end_price: u64,
+        dutch_start_time: i64,
+        dutch_decay_interval: i64,
+        allowed_buyer: Pubkey,
+        auto_renew_period: i64,
+        auto_renew_bump_bp: u16,
+        secondary_payment_mint: Pubkey,
+        secondary_price: u64,
+        not_before: i64,
+    ]),
+    state_layout!(AuctionHouse, 1, AUCTION_HOUSE_SIZE, [
+        auction_house_treasury: Pubkey,
+        treasury_withdrawal_destination: Pubkey,
+        authority: Pubkey,
+        creator: Pubkey,
+        notary: Pubkey,
+        bump: u8,
+        treasury_bump: u8,
+        seller_fee_basis_points: u16,
+        buyer_referral_bp: u16,
+        seller_referral_bp: u16,
+        requires_notary: bool,
+        nprob: u8,
+    ]),
+    state_layout!(BuyerTradeStateV2, 2, BuyerTradeStateV2::LEN, [
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        buyer_referral: Pubkey,
+        buyer_price: u64,
+        token_mint: Pubkey,
+        token_size: u64,
+        bump: u8,
+        expiry: i64,
+        buyer_creator_royalty_bp: u16,
+        payment_mint: Pubkey,
+        escrowless: bool,
+        take_profit_price: u64,
+        target_seller: Pubkey,
+    ]),
+    state_layout!(MintListingIndex, 1, MintListingIndex::LEN, [
+        token_mint: Pubkey,
+        bump: u8,
+        updated_at: i64,
+        listings: var,
+    ]),
+    state_layout!(CollectionConfig, 1, CollectionConfig::LEN, [
+        auction_house_key: Pubkey,
+        collection: Pubkey,
+        paused: bool,
+        bump: u8,
+        updated_at: i64,
+        tick_size: u64,
+        hold_period: i64,
+    ]),
+    state_layout!(CollectionStats, 1, CollectionStats::LEN, [
+        auction_house_key: Pubkey,
+        collection: Pubkey,
+        payment_mint: Pubkey,
+        bump: u8,
+        sale_count: u64,
+        volume: u128,
+        last_sale_price: u64,
+        last_sale_at: i64,
+        floor_window_start: i64,
+        floor_price: u64,
+        twap_prices: [u64; TWAP_SLOTS],
+        twap_sale_times: [i64; TWAP_SLOTS],
+        twap_head: u8,
+        twap_price: u64,
+    ]),
+    state_layout!(LastSale, 1, LastSale::LEN, [
+        token_mint: Pubkey,
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        seller: Pubkey,
+        payment_mint: Pubkey,
+        price: u64,
+        sold_at: i64,
+        bump: u8,
+        trade_seq: u64,
+    ]),
+    state_layout!(TradeSequence, 1, TradeSequence::LEN, [
+        seq: u64,
+        bump: u8,
+    ]),
+    state_layout!(SellerStatus, 1, SellerStatus::LEN, [
+        seller: Pubkey,
+        suspended: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(BuyerStatus, 1, BuyerStatus::LEN, [
+        buyer: Pubkey,
+        suspended: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(FrontendConfig, 1, FrontendConfig::LEN, [
+        auction_house_key: Pubkey,
+        frontend: Pubkey,
+        fee_share_bp: u16,
+        enabled: bool,
+        bump: u8,
+        sale_count: u64,
+        volume: u128,
+        fees_earned: u64,
+    ]),
+    state_layout!(ListingOperator, 1, ListingOperator::LEN, [
+        seller: Pubkey,
+        operator: Pubkey,
+        enabled: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(CollectionBidState, 1, CollectionBidState::LEN, [
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        buyer_referral: Pubkey,
+        collection: Pubkey,
+        buyer_price: u64,
+        quantity: u16,
+        filled: u16,
+        payment_mint: Pubkey,
+        buyer_creator_royalty_bp: u16,
+        royalty_inclusive: bool,
+        expiry: i64,
+        bump: u8,
+        trait_hash: [u8; 32],
+        allowed_auction_houses: [Pubkey; 2],
+    ]),
+    state_layout!(RoyaltyFallback, 1, RoyaltyFallback::LEN, [
+        collection: Pubkey,
+        recipient: Pubkey,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(AuctionState, 2, AuctionState::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        token_mint: Pubkey,
+        payment_mint: Pubkey,
+        start_price: u64,
+        min_increment_bp: u16,
+        start_time: i64,
+        end_time: i64,
+        highest_bid: u64,
+        highest_bidder: Pubkey,
+        bid_count: u32,
+        bump: u8,
+        escrow_bump: u8,
+        extension_window: i64,
+        extension_seconds: i64,
+        reserve_price: u64,
+        reveal_end_time: i64,
+        commit_count: u32,
+        buy_now_price: u64,
+        tick_size: u64,
+        highest_max_bid: u64,
+    ]),
+    state_layout!(TakerFeeConfig, 1, TakerFeeConfig::LEN, [
+        auction_house_key: Pubkey,
+        price_feed: Pubkey,
+        flat_fee_micro_usd: u64,
+        max_fee_bp: u16,
+        max_staleness: i64,
+        enabled: bool,
+        bump: u8,
+        prev_flat_fee_micro_usd: u64,
+        prev_max_fee_bp: u16,
+        updated_at: i64,
+        ramp_seconds: i64,
+    ]),
+    state_layout!(BuybackConfig, 1, BuybackConfig::LEN, [
+        auction_house_key: Pubkey,
+        collection: Pubkey,
+        max_price: u64,
+        budget: u64,
+        spent: u64,
+        burned: u32,
+        bump: u8,
+    ]),
+    state_layout!(BidExpiryAlarm, 1, BidExpiryAlarm::LEN, [
+        auction_house_key: Pubkey,
+        bucket: i64,
+        page: u8,
+        bump: u8,
+        updated_at: i64,
+        entries: var,
+    ]),
+    state_layout!(SealedBid, 1, SealedBid::LEN, [
+        auction_state: Pubkey,
+        bidder: Pubkey,
+        commitment: [u8; 32],
+        deposit: u64,
+        reveal_end_time: i64,
+        bump: u8,
+    ]),
+    state_layout!(PriceBandConfig, 1, PriceBandConfig::LEN, [
+        auction_house_key: Pubkey,
+        collection: Pubkey,
+        enabled: bool,
+        max_age: i64,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(PriceAttestation, 1, PriceAttestation::LEN, [
+        auction_house_key: Pubkey,
+        token_mint: Pubkey,
+        payment_mint: Pubkey,
+        min_price: u64,
+        max_price: u64,
+        attested_at: i64,
+        bump: u8,
+    ]),
+    state_layout!(TreasuryYieldConfig, 1, TreasuryYieldConfig::LEN, [
+        auction_house_key: Pubkey,
+        vault_program: Pubkey,
+        vault_state: Pubkey,
+        share_mint: Pubkey,
+        float: u64,
+        shares: u64,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(BundleSellerState, 1, BundleSellerState::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        seller_referral: Pubkey,
+        bundle_id: u64,
+        buyer_price: u64,
+        expiry: i64,
+        sealed: bool,
+        bump: u8,
+        items: var,
+    ]),
+    state_layout!(SwapOffer, 1, SwapOffer::LEN, [
+        auction_house_key: Pubkey,
+        proposer: Pubkey,
+        offered_mint: Pubkey,
+        offered_token_account: Pubkey,
+        counterparty: Pubkey,
+        requested_mint: Pubkey,
+        sweetener: u64,
+        expiry: i64,
+        bump: u8,
+    ]),
+    state_layout!(RentalListing, 1, RentalListing::LEN, [
+        auction_house_key: Pubkey,
+        owner: Pubkey,
+        token_mint: Pubkey,
+        token_account: Pubkey,
+        fee: u64,
+        term: i64,
+        renter: Pubkey,
+        rented_until: i64,
+        bump: u8,
+    ]),
+    state_layout!(EscrowDepositor, 1, EscrowDepositor::LEN, [
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        depositor: Pubkey,
+        enabled: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(RoyaltySubsidy, 1, RoyaltySubsidy::LEN, [
+        auction_house_key: Pubkey,
+        collection: Pubkey,
+        subsidy_bp: u16,
+        start_time: i64,
+        end_time: i64,
+        budget: u64,
+        spent: u64,
+        bump: u8,
+    ]),
+    state_layout!(PortfolioBidState, 1, PortfolioBidState::LEN, [
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        buyer_referral: Pubkey,
+        bid_id: u64,
+        buyer_price: u64,
+        payment_mint: Pubkey,
+        buyer_creator_royalty_bp: u16,
+        expiry: i64,
+        bump: u8,
+        mints: var,
+    ]),
+    state_layout!(CompressedSellerTradeState, 1, CompressedSellerTradeState::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        seller_referral: Pubkey,
+        asset_id: Pubkey,
+        merkle_tree: Pubkey,
+        nonce: u64,
+        index: u32,
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        buyer_price: u64,
+        payment_mint: Pubkey,
+        expiry: i64,
+        bump: u8,
+    ]),
+    state_layout!(CoreSellerTradeState, 1, CoreSellerTradeState::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        seller_referral: Pubkey,
+        asset: Pubkey,
+        buyer_price: u64,
+        payment_mint: Pubkey,
+        expiry: i64,
+        bump: u8,
+    ]),
+    state_layout!(CustodialPlatform, 1, CustodialPlatform::LEN, [
+        auction_house_key: Pubkey,
+        platform: Pubkey,
+        custody_wallet: Pubkey,
+        enabled: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(EditionSale, 1, EditionSale::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        master_mint: Pubkey,
+        price: u64,
+        max_prints: u64,
+        printed: u64,
+        bump: u8,
+    ]),
+    state_layout!(FillProof, 1, FillProof::LEN, [
+        auction_house_key: Pubkey,
+        buyer: Pubkey,
+        seller: Pubkey,
+        token_mint: Pubkey,
+        payment_mint: Pubkey,
+        price: u64,
+        token_size: u64,
+        trade_seq: u64,
+        slot: u64,
+        rent_payer: Pubkey,
+        bump: u8,
+    ]),
+    state_layout!(InsuranceFund, 1, InsuranceFund::LEN, [
+        auction_house_key: Pubkey,
+        taker_fee_share_bp: u16,
+        enabled: bool,
+        bump: u8,
+        total_funded: u64,
+        total_paid_out: u64,
+        payout_recipient: Pubkey,
+        payout_amount: u64,
+        payout_available_at: i64,
+        updated_at: i64,
+    ]),
+    state_layout!(OrderBook, 1, OrderBook::LEN, [
+        auction_house_key: Pubkey,
+        token_mint: Pubkey,
+        bump: u8,
+        next_seq: u64,
+        bid_prices: var,
+        ask_prices: var,
+    ]),
+    state_layout!(OrderBookLevel, 1, OrderBookLevel::LEN, [
+        order_book: Pubkey,
+        is_bid: bool,
+        price: u64,
+        bump: u8,
+        orders: var,
+    ]),
+    state_layout!(RebateCampaign, 1, RebateCampaign::LEN, [
+        auction_house_key: Pubkey,
+        randomness_authority: Pubkey,
+        prize: u64,
+        enabled: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(RebateEpoch, 1, RebateEpoch::LEN, [
+        auction_house_key: Pubkey,
+        epoch: u64,
+        commitment: [u8; 32],
+        entries_hash: [u8; 32],
+        ticket_count: u32,
+        winner: Pubkey,
+        prize: u64,
+        drawn: bool,
+        bump: u8,
+        tickets: var,
+    ]),
+    state_layout!(StakedListing, 1, StakedListing::LEN, [
+        auction_house_key: Pubkey,
+        seller: Pubkey,
+        token_mint: Pubkey,
+        token_account: Pubkey,
+        staking_program: Pubkey,
+        price: u64,
+        expiry: i64,
+        bump: u8,
+        unstake_data: var,
+    ]),
+    state_layout!(PermanentDelegateApproval, 1, PermanentDelegateApproval::LEN, [
+        auction_house_key: Pubkey,
+        mint: Pubkey,
+        permanent_delegate: Pubkey,
+        approved: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(OrderLimitConfig, 1, OrderLimitConfig::LEN, [
+        auction_house_key: Pubkey,
+        max_open_listings: u32,
+        max_open_bids: u32,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(WalletOrderCounter, 1, WalletOrderCounter::LEN, [
+        auction_house_key: Pubkey,
+        wallet: Pubkey,
+        open_listings: u32,
+        open_bids: u32,
+        bump: u8,
+    ]),
+    state_layout!(CountedOrder, 1, CountedOrder::LEN, [
+        trade_state: Pubkey,
+        bump: u8,
+    ]),
+    state_layout!(PermanentDelegateConfig, 1, PermanentDelegateConfig::LEN, [
+        auction_house_key: Pubkey,
+        flag_only: bool,
+        bump: u8,
+        updated_at: i64,
+    ]),
+    state_layout!(CancellationSnapshot, 1, CancellationSnapshot::LEN, [
+        auction_house_key: Pubkey,
+        merkle_root: [u8; 32],
+        order_count: u32,
+        published_at: i64,
+        bump: u8,
+    ]),
+    state_layout!(CrankBond, 1, CrankBond::LEN, [
+        cranker: Pubkey,
+        amount: u64,
+        bump: u8,
+    ]),
+];
+
+pub fn get_state_layout(discriminator: &[u8; 8]) -> Option<&'static StateLayout> {
+    STATE_LAYOUTS
+        .iter()
+        .find(|layout| layout.discriminator == *discriminator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_len(layout: &StateLayout) -> usize {
+        let last = layout.fields.last().unwrap();
+        last.offset as usize + last.size as usize
+    }
+
+    #[test]
+    fn state_layouts_match_serialized_accounts() {
+        let lens = [
+            (
+                BuyerTradeStateV2::DISCRIMINATOR,
+                BuyerTradeStateV2::default().try_to_vec().unwrap().len(),
+            ),
+            (
+                CollectionStats::DISCRIMINATOR,
+                CollectionStats::default().try_to_vec().unwrap().len(),
+            ),
+            (
+                AuctionState::DISCRIMINATOR,
+                AuctionState::default().try_to_vec().unwrap().len(),
+            ),
+            (
+                CompressedSellerTradeState::DISCRIMINATOR,
+                CompressedSellerTradeState::default()
+                    .try_to_vec()
+                    .unwrap()
+                    .len(),
+            ),
+            (
+                CoreSellerTradeState::DISCRIMINATOR,
+                CoreSellerTradeState::default().try_to_vec().unwrap().len(),
+            ),
+        ];
+        for (discriminator, len) in lens {
+            let layout = get_state_layout(&discriminator).unwrap();
+            assert_eq!(fixed_len(layout), 8 + len, "{}", layout.name);
+        }
+
+        let bts = BuyerTradeStateV2 {
+            target_seller: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let data = bts.try_to_vec().unwrap();
+        let layout = get_state_layout(&BuyerTradeStateV2::DISCRIMINATOR).unwrap();
+        let field = layout
+            .fields
+            .iter()
+            .find(|f| f.name == "target_seller")
+            .unwrap();
+        let start = field.offset as usize - 8;
+        assert_eq!(
+            &data[start..start + field.size as usize],
+            bts.target_seller.as_ref()
+        );
+
+        for layout in STATE_LAYOUTS {
+            if layout.fields.iter().all(|f| f.size != VARIABLE) {
+                assert!(fixed_len(layout) <= layout.len as usize, "{}", layout.name);
+            }
+            assert_eq!(
+                STATE_LAYOUTS
+                    .iter()
+                    .filter(|l| l.discriminator == layout.discriminator)
+                    .count(),
+                1
+            );
+        }
+        let sts_v2 = get_state_layout(&SellerTradeStateV2::DISCRIMINATOR).unwrap();
+        assert_eq!(sts_v2.fields[10].size, VARIABLE);
+        assert_eq!(sts_v2.fields[11].offset, VARIABLE);
+    }
+}
@@ -1,16 +1,23 @@
 #![allow(clippy::result_large_err)]
 
+mod cnft_ins;
 pub mod constants;
+mod core_ins;
 mod errors;
+pub mod layouts;
 mod m2_ins;
 pub mod mip1_ins;
 mod ocp_ins;
 pub mod states;
+mod t22_ins;
 mod utils;
 
+use crate::cnft_ins::*;
+use crate::core_ins::*;
 use crate::m2_ins::*;
 use crate::mip1_ins::*;
 use crate::ocp_ins::*;
+use crate::t22_ins::*;
 use anchor_lang::prelude::*;
 
 anchor_lang::declare_id!("M2mx93ekt1fmXSVkTrUL9xVFHkmME8HTUi5Cyc5aF7K");
@@ -130,6 +137,724 @@ pub mod m2 {
         m2_ins::cancel_buy::handle(ctx, buyer_price, token_size, buyer_state_expiry)
     }
 
+    pub fn migrate_buyer_trade_state<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateBuyerTradeState<'info>>,
+    ) -> Result<()> {
+        m2_ins::migrate_buyer_trade_state::handle(ctx)
+    }
+
+    pub fn sync_mint_listing_index<'info>(
+        ctx: Context<'_, '_, '_, 'info, SyncMintListingIndex<'info>>,
+    ) -> Result<()> {
+        m2_ins::sync_mint_listing_index::handle(ctx)
+    }
+
+    pub fn set_collection_pause<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetCollectionPause<'info>>,
+        paused: bool,
+    ) -> Result<()> {
+        m2_ins::set_collection_pause::handle(ctx, paused)
+    }
+
+    pub fn migrate_collection_pause<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateCollectionPause<'info>>,
+    ) -> Result<()> {
+        m2_ins::migrate_collection_pause::handle(ctx)
+    }
+
+    pub fn swap_into_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapIntoEscrow<'info>>,
+        args: SwapIntoEscrowArgs,
+    ) -> Result<()> {
+        m2_ins::swap_into_escrow::handle(ctx, args)
+    }
+
+    pub fn set_floor_pricing<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetFloorPricing<'info>>,
+        floor_premium_bp: Option<i16>,
+    ) -> Result<()> {
+        m2_ins::set_floor_pricing::handle(ctx, floor_premium_bp)
+    }
+
+    pub fn suspend_all_listings<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetSellerStatus<'info>>,
+    ) -> Result<()> {
+        m2_ins::set_seller_status::handle(ctx, true)
+    }
+
+    pub fn resume_all_listings<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetSellerStatus<'info>>,
+    ) -> Result<()> {
+        m2_ins::set_seller_status::handle(ctx, false)
+    }
+
+    pub fn suspend_all_bids<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBuyerStatus<'info>>,
+    ) -> Result<()> {
+        m2_ins::set_buyer_status::handle(ctx, true)
+    }
+
+    pub fn resume_all_bids<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBuyerStatus<'info>>,
+    ) -> Result<()> {
+        m2_ins::set_buyer_status::handle(ctx, false)
+    }
+
+    pub fn close_stale_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseStaleListing<'info>>,
+    ) -> Result<()> {
+        m2_ins::close_stale_listing::handle(ctx)
+    }
+
+    pub fn custody_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, CustodySell<'info>>,
+        args: CustodySellArgs,
+    ) -> Result<()> {
+        m2_ins::custody_sell::handle(ctx, args)
+    }
+
+    pub fn custody_cancel_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, CustodyCancelSell<'info>>,
+    ) -> Result<()> {
+        m2_ins::custody_cancel_sell::handle(ctx)
+    }
+
+    pub fn set_frontend_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetFrontendConfig<'info>>,
+        args: SetFrontendConfigArgs,
+    ) -> Result<()> {
+        m2_ins::set_frontend_config::handle(ctx, args)
+    }
+
+    pub fn claim_frontend_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimFrontendFees<'info>>,
+    ) -> Result<()> {
+        m2_ins::claim_frontend_fees::handle(ctx)
+    }
+
+    pub fn mark_creator_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, MarkCreatorListing<'info>>,
+    ) -> Result<()> {
+        m2_ins::mark_creator_listing::handle(ctx)
+    }
+
+    pub fn set_listing_operator<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetListingOperator<'info>>,
+        enabled: bool,
+    ) -> Result<()> {
+        m2_ins::set_listing_operator::handle(ctx, enabled)
+    }
+
+    pub fn operator_reprice_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, OperatorRepriceListing<'info>>,
+        args: OperatorRepriceListingArgs,
+    ) -> Result<()> {
+        m2_ins::operator_reprice_listing::handle(ctx, args)
+    }
+
+    pub fn collection_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectionBid<'info>>,
+        args: CollectionBidArgs,
+    ) -> Result<()> {
+        m2_ins::collection_bid::handle(ctx, args)
+    }
+
+    pub fn sell_into_collection_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellIntoCollectionBid<'info>>,
+        args: SellIntoCollectionBidArgs,
+    ) -> Result<()> {
+        m2_ins::sell_into_collection_bid::handle(ctx, args)
+    }
+
+    pub fn cancel_collection_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelCollectionBid<'info>>,
+    ) -> Result<()> {
+        m2_ins::cancel_collection_bid::handle(ctx)
+    }
+
+    pub fn set_royalty_fallback<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRoyaltyFallback<'info>>,
+    ) -> Result<()> {
+        m2_ins::set_royalty_fallback::handle(ctx)
+    }
+
+    pub fn sell_dutch<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellDutch<'info>>,
+        args: SellDutchArgs,
+    ) -> Result<()> {
+        m2_ins::sell_dutch::handle(ctx, args)
+    }
+
+    pub fn create_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAuction<'info>>,
+        args: CreateAuctionArgs,
+    ) -> Result<()> {
+        m2_ins::create_auction::handle(ctx, args)
+    }
+
+    pub fn place_auction_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceAuctionBid<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        m2_ins::place_auction_bid::handle(ctx, amount, false)
+    }
+
+    pub fn place_auction_proxy_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceAuctionBid<'info>>,
+        max_bid: u64,
+    ) -> Result<()> {
+        m2_ins::place_auction_bid::handle(ctx, max_bid, true)
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+        args: SettleAuctionArgs,
+    ) -> Result<()> {
+        m2_ins::settle_auction::handle(ctx, args)
+    }
+
+    pub fn set_taker_fee_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetTakerFeeConfig<'info>>,
+        args: SetTakerFeeConfigArgs,
+    ) -> Result<()> {
+        m2_ins::set_taker_fee_config::handle(ctx, args)
+    }
+
+    pub fn set_buyback_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBuybackConfig<'info>>,
+        args: SetBuybackConfigArgs,
+    ) -> Result<()> {
+        m2_ins::set_buyback_config::handle(ctx, args)
+    }
+
+    pub fn buyback_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuybackListing<'info>>,
+    ) -> Result<()> {
+        m2_ins::buyback_listing::handle(ctx)
+    }
+
+    pub fn sync_bid_expiry_alarm<'info>(
+        ctx: Context<'_, '_, '_, 'info, SyncBidExpiryAlarm<'info>>,
+        bucket: i64,
+        page: u8,
+    ) -> Result<()> {
+        m2_ins::sync_bid_expiry_alarm::handle(ctx, bucket, page)
+    }
+
+    pub fn commit_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitBid<'info>>,
+        args: CommitBidArgs,
+    ) -> Result<()> {
+        m2_ins::commit_bid::handle(ctx, args)
+    }
+
+    pub fn reveal_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealBid<'info>>,
+        args: RevealBidArgs,
+    ) -> Result<()> {
+        m2_ins::reveal_bid::handle(ctx, args)
+    }
+
+    pub fn reclaim_sealed_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimSealedBid<'info>>,
+    ) -> Result<()> {
+        m2_ins::reclaim_sealed_bid::handle(ctx)
+    }
+
+    pub fn reclaim_unsold<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimUnsold<'info>>,
+    ) -> Result<()> {
+        m2_ins::reclaim_unsold::handle(ctx)
+    }
+
+    pub fn set_price_band_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetPriceBandConfig<'info>>,
+        args: SetPriceBandConfigArgs,
+    ) -> Result<()> {
+        m2_ins::set_price_band_config::handle(ctx, args)
+    }
+
+    pub fn attest_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, AttestPrice<'info>>,
+        args: AttestPriceArgs,
+    ) -> Result<()> {
+        m2_ins::attest_price::handle(ctx, args)
+    }
+
+    pub fn set_treasury_yield_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetTreasuryYieldConfig<'info>>,
+        args: SetTreasuryYieldConfigArgs,
+    ) -> Result<()> {
+        m2_ins::set_treasury_yield_config::handle(ctx, args)
+    }
+
+    pub fn sweep_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepTreasury<'info>>,
+        args: SweepTreasuryArgs,
+    ) -> Result<()> {
+        m2_ins::sweep_treasury::handle(ctx, args)
+    }
+
+    pub fn sell_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellBundle<'info>>,
+        args: SellBundleArgs,
+    ) -> Result<()> {
+        m2_ins::sell_bundle::handle(ctx, args)
+    }
+
+    pub fn append_bundle_items<'info>(
+        ctx: Context<'_, '_, '_, 'info, AppendBundleItems<'info>>,
+        args: AppendBundleItemsArgs,
+    ) -> Result<()> {
+        m2_ins::append_bundle_items::handle(ctx, args)
+    }
+
+    pub fn cancel_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelBundle<'info>>,
+    ) -> Result<()> {
+        m2_ins::cancel_bundle::handle(ctx)
+    }
+
+    pub fn execute_bundle_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteBundleSale<'info>>,
+        args: ExecuteBundleSaleArgs,
+    ) -> Result<()> {
+        m2_ins::execute_bundle_sale::handle(ctx, args)
+    }
+
+    pub fn set_allowed_buyer<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetAllowedBuyer<'info>>,
+        allowed_buyer: Option<Pubkey>,
+    ) -> Result<()> {
+        m2_ins::set_allowed_buyer::handle(ctx, allowed_buyer)
+    }
+
+    pub fn return_all_assets<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReturnAllAssets<'info>>,
+    ) -> Result<()> {
+        m2_ins::return_all_assets::handle(ctx)
+    }
+
+    pub fn buy_delegated<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyDelegated<'info>>,
+        args: BuyDelegatedArgs,
+    ) -> Result<()> {
+        m2_ins::buy_delegated::handle(ctx, args)
+    }
+
+    pub fn sell_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellBatch<'info>>,
+        args: SellBatchArgs,
+    ) -> Result<()> {
+        m2_ins::sell_batch::handle(ctx, args)
+    }
+
+    pub fn notary_cancel_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, NotaryCancelBid<'info>>,
+        reason: u8,
+    ) -> Result<()> {
+        m2_ins::notary_cancel_bid::handle(ctx, reason)
+    }
+
+    pub fn propose_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProposeSwap<'info>>,
+        args: ProposeSwapArgs,
+    ) -> Result<()> {
+        m2_ins::propose_swap::handle(ctx, args)
+    }
+
+    pub fn accept_swap<'info>(ctx: Context<'_, '_, '_, 'info, AcceptSwap<'info>>) -> Result<()> {
+        m2_ins::accept_swap::handle(ctx)
+    }
+
+    pub fn cancel_swap<'info>(ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>) -> Result<()> {
+        m2_ins::cancel_swap::handle(ctx)
+    }
+
+    pub fn set_price_tick<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetPriceTick<'info>>,
+        tick_size: u64,
+    ) -> Result<()> {
+        m2_ins::set_price_tick::handle(ctx, tick_size)
+    }
+
+    pub fn list_rental<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListRental<'info>>,
+        args: ListRentalArgs,
+    ) -> Result<()> {
+        m2_ins::list_rental::handle(ctx, args)
+    }
+
+    pub fn rent_nft<'info>(ctx: Context<'_, '_, '_, 'info, RentNft<'info>>) -> Result<()> {
+        m2_ins::rent_nft::handle(ctx)
+    }
+
+    pub fn reclaim_rental<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimRental<'info>>,
+    ) -> Result<()> {
+        m2_ins::reclaim_rental::handle(ctx)
+    }
+
+    pub fn close_expired_buyer_state<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredBuyerState<'info>>,
+    ) -> Result<()> {
+        m2_ins::close_expired_buyer_state::handle(ctx)
+    }
+
+    pub fn set_hold_period<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetHoldPeriod<'info>>,
+        hold_period: i64,
+    ) -> Result<()> {
+        m2_ins::set_hold_period::handle(ctx, hold_period)
+    }
+
+    pub fn set_auto_renew<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetAutoRenew<'info>>,
+        period: i64,
+        bump_bp: u16,
+    ) -> Result<()> {
+        m2_ins::set_auto_renew::handle(ctx, period, bump_bp)
+    }
+
+    pub fn renew_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, RenewListing<'info>>,
+    ) -> Result<()> {
+        m2_ins::renew_listing::handle(ctx)
+    }
+
+    pub fn provision_creator_atas<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProvisionCreatorAtas<'info>>,
+    ) -> Result<()> {
+        m2_ins::provision_creator_atas::handle(ctx)
+    }
+
+    pub fn set_escrow_depositor<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetEscrowDepositor<'info>>,
+        enabled: bool,
+    ) -> Result<()> {
+        m2_ins::set_escrow_depositor::handle(ctx, enabled)
+    }
+
+    pub fn depositor_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositorDeposit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        m2_ins::depositor_deposit::handle(ctx, amount)
+    }
+
+    pub fn set_royalty_subsidy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRoyaltySubsidy<'info>>,
+        args: SetRoyaltySubsidyArgs,
+    ) -> Result<()> {
+        m2_ins::set_royalty_subsidy::handle(ctx, args)
+    }
+
+    pub fn portfolio_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PortfolioBid<'info>>,
+        args: PortfolioBidArgs,
+    ) -> Result<()> {
+        m2_ins::portfolio_bid::handle(ctx, args)
+    }
+
+    pub fn sell_into_portfolio_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellIntoPortfolioBid<'info>>,
+        args: SellIntoPortfolioBidArgs,
+    ) -> Result<()> {
+        m2_ins::sell_into_portfolio_bid::handle(ctx, args)
+    }
+
+    pub fn cancel_portfolio_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelPortfolioBid<'info>>,
+    ) -> Result<()> {
+        m2_ins::cancel_portfolio_bid::handle(ctx)
+    }
+
+    pub fn set_secondary_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetSecondaryPrice<'info>>,
+        price: u64,
+    ) -> Result<()> {
+        m2_ins::set_secondary_price::handle(ctx, price)
+    }
+
+    pub fn set_not_before<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetNotBefore<'info>>,
+        not_before: i64,
+    ) -> Result<()> {
+        m2_ins::set_not_before::handle(ctx, not_before)
+    }
+
+    pub fn set_offer_target<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetOfferTarget<'info>>,
+        target_seller: Option<Pubkey>,
+    ) -> Result<()> {
+        m2_ins::set_offer_target::handle(ctx, target_seller)
+    }
+
+    pub fn accept_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>,
+        args: AcceptOfferArgs,
+    ) -> Result<()> {
+        m2_ins::accept_offer::handle(ctx, args)
+    }
+
+    pub fn accept_offers_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptOffersBatch<'info>>,
+        args: AcceptOffersBatchArgs,
+    ) -> Result<()> {
+        m2_ins::accept_offers_batch::handle(ctx, args)
+    }
+
+    pub fn gift<'info>(ctx: Context<'_, '_, '_, 'info, Gift<'info>>, amount: u64) -> Result<()> {
+        m2_ins::gift::handle(ctx, amount)
+    }
+
+    pub fn t22_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, T22Sell<'info>>,
+        args: T22SellArgs,
+    ) -> Result<()> {
+        t22_ins::t22_sell::handle(ctx, args)
+    }
+
+    pub fn t22_cancel_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, T22CancelSell<'info>>,
+    ) -> Result<()> {
+        t22_ins::t22_cancel_sell::handle(ctx)
+    }
+
+    pub fn t22_execute_sale_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, T22ExecuteSaleV2<'info>>,
+        args: T22ExecuteSaleV2Args,
+    ) -> Result<()> {
+        t22_ins::t22_execute_sale_v2::handle(ctx, args)
+    }
+
+    pub fn sell_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellCompressed<'info>>,
+        args: SellCompressedArgs,
+    ) -> Result<()> {
+        cnft_ins::sell_compressed::handle(ctx, args)
+    }
+
+    pub fn cancel_sell_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelSellCompressed<'info>>,
+        args: CancelSellCompressedArgs,
+    ) -> Result<()> {
+        cnft_ins::cancel_sell_compressed::handle(ctx, args)
+    }
+
+    pub fn buy_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyCompressed<'info>>,
+        args: BuyCompressedArgs,
+    ) -> Result<()> {
+        cnft_ins::buy_compressed::handle(ctx, args)
+    }
+
+    pub fn execute_compressed_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteCompressedSale<'info>>,
+        args: ExecuteCompressedSaleArgs,
+    ) -> Result<()> {
+        cnft_ins::execute_compressed_sale::handle(ctx, args)
+    }
+
+    pub fn get_state_layout(
+        ctx: Context<GetStateLayout>,
+        discriminator: [u8; 8],
+    ) -> Result<StateLayoutData> {
+        m2_ins::get_state_layout::handle(ctx, discriminator)
+    }
+
+    pub fn set_rebate_campaign<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRebateCampaign<'info>>,
+        args: SetRebateCampaignArgs,
+    ) -> Result<()> {
+        m2_ins::set_rebate_campaign::handle(ctx, args)
+    }
+
+    pub fn commit_rebate_draw<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitRebateDraw<'info>>,
+        args: CommitRebateDrawArgs,
+    ) -> Result<()> {
+        m2_ins::commit_rebate_draw::handle(ctx, args)
+    }
+
+    pub fn draw_rebate<'info>(
+        ctx: Context<'_, '_, '_, 'info, DrawRebate<'info>>,
+        seed: [u8; 32],
+    ) -> Result<()> {
+        m2_ins::draw_rebate::handle(ctx, seed)
+    }
+
+    pub fn claim_rebate<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRebate<'info>>) -> Result<()> {
+        m2_ins::claim_rebate::handle(ctx)
+    }
+
+    pub fn core_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, CoreSell<'info>>,
+        args: CoreSellArgs,
+    ) -> Result<()> {
+        core_ins::core_sell::handle(ctx, args)
+    }
+
+    pub fn core_cancel_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, CoreCancelSell<'info>>,
+    ) -> Result<()> {
+        core_ins::core_cancel_sell::handle(ctx)
+    }
+
+    pub fn core_execute_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, CoreExecuteSale<'info>>,
+        args: CoreExecuteSaleArgs,
+    ) -> Result<()> {
+        core_ins::core_execute_sale::handle(ctx, args)
+    }
+
+    pub fn set_custodial_platform<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetCustodialPlatform<'info>>,
+        custody_wallet: Pubkey,
+        enabled: bool,
+    ) -> Result<()> {
+        m2_ins::set_custodial_platform::handle(ctx, custody_wallet, enabled)
+    }
+
+    pub fn place_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceOrder<'info>>,
+        args: PlaceOrderArgs,
+    ) -> Result<()> {
+        m2_ins::place_order::handle(ctx, args)
+    }
+
+    pub fn cancel_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>,
+        args: CancelOrderArgs,
+    ) -> Result<()> {
+        m2_ins::cancel_order::handle(ctx, args)
+    }
+
+    pub fn match_orders<'info>(ctx: Context<'_, '_, '_, 'info, MatchOrders<'info>>) -> Result<()> {
+        m2_ins::match_orders::handle(ctx)
+    }
+
+    pub fn close_fill_proof<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseFillProof<'info>>,
+    ) -> Result<()> {
+        m2_ins::close_fill_proof::handle(ctx)
+    }
+
+    pub fn sell_editions<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellEditions<'info>>,
+        args: SellEditionsArgs,
+    ) -> Result<()> {
+        m2_ins::sell_editions::handle(ctx, args)
+    }
+
+    pub fn buy_edition<'info>(ctx: Context<'_, '_, '_, 'info, BuyEdition<'info>>) -> Result<()> {
+        m2_ins::buy_edition::handle(ctx)
+    }
+
+    pub fn cancel_edition_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelEditionSale<'info>>,
+    ) -> Result<()> {
+        m2_ins::cancel_edition_sale::handle(ctx)
+    }
+
+    pub fn set_insurance_fund<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetInsuranceFund<'info>>,
+        args: SetInsuranceFundArgs,
+    ) -> Result<()> {
+        m2_ins::set_insurance_fund::handle(ctx, args)
+    }
+
+    pub fn queue_insurance_payout<'info>(
+        ctx: Context<'_, '_, '_, 'info, QueueInsurancePayout<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        m2_ins::queue_insurance_payout::handle(ctx, amount)
+    }
+
+    pub fn execute_insurance_payout<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteInsurancePayout<'info>>,
+    ) -> Result<()> {
+        m2_ins::execute_insurance_payout::handle(ctx)
+    }
+
+    pub fn list_staked<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListStaked<'info>>,
+        args: ListStakedArgs,
+    ) -> Result<()> {
+        m2_ins::list_staked::handle(ctx, args)
+    }
+
+    pub fn buy_staked<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyStaked<'info>>,
+        price: u64,
+    ) -> Result<()> {
+        m2_ins::buy_staked::handle(ctx, price)
+    }
+
+    pub fn cancel_staked_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelStakedListing<'info>>,
+    ) -> Result<()> {
+        m2_ins::cancel_staked_listing::handle(ctx)
+    }
+
+    pub fn set_permanent_delegate_approval<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetPermanentDelegateApproval<'info>>,
+        approved: bool,
+    ) -> Result<()> {
+        m2_ins::set_permanent_delegate_approval::handle(ctx, approved)
+    }
+
+    pub fn set_order_limit_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetOrderLimitConfig<'info>>,
+        max_open_listings: u32,
+        max_open_bids: u32,
+    ) -> Result<()> {
+        m2_ins::set_order_limit_config::handle(ctx, max_open_listings, max_open_bids)
+    }
+
+    pub fn set_permanent_delegate_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetPermanentDelegateConfig<'info>>,
+        flag_only: bool,
+    ) -> Result<()> {
+        m2_ins::set_permanent_delegate_config::handle(ctx, flag_only)
+    }
+
+    pub fn publish_cancellation_root<'info>(
+        ctx: Context<'_, '_, '_, 'info, PublishCancellationRoot<'info>>,
+        merkle_root: [u8; 32],
+        order_count: u32,
+    ) -> Result<()> {
+        m2_ins::publish_cancellation_root::handle(ctx, merkle_root, order_count)
+    }
+
+    pub fn cancel_with_proof<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelWithProof<'info>>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        m2_ins::cancel_with_proof::handle(ctx, proof)
+    }
+
+    pub fn execute_sale_auto<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSaleAuto<'info>>,
+        args: ExecuteSaleAutoArgs,
+    ) -> Result<()> {
+        m2_ins::execute_sale_auto::handle(ctx, args)
+    }
+
+    pub fn deposit_crank_bond<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositCrankBond<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        m2_ins::deposit_crank_bond::handle(ctx, amount)
+    }
+
+    pub fn withdraw_crank_bond<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawCrankBond<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        m2_ins::withdraw_crank_bond::handle(ctx, amount)
+    }
+
     pub fn ocp_sell<'info>(
         ctx: Context<'_, '_, '_, 'info, OCPSell<'info>>,
         args: OCPSellArgs,
@@ -160,6 +885,9 @@ pub mod m2 {
         _seller_state_expiry: i64,
         maker_fee_bp: i16,
         taker_fee_bp: u16,
+        max_price: Option<u64>,
+        min_net_proceeds: Option<u64>,
+        custody_user_tag: Option<u64>,
     ) -> Result<()> {
         m2_ins::execute_sale_v2::handle(
             ctx,
@@ -169,6 +897,9 @@ pub mod m2 {
             token_size,
             maker_fee_bp,
             taker_fee_bp,
+            max_price,
+            min_net_proceeds,
+            custody_user_tag,
         )
     }
 
@@ -179,6 +910,13 @@ pub mod m2 {
         mip1_ins::mip1_sell::handle_mip1_sell(ctx, &args)
     }
 
+    pub fn mip1_migrate_and_list<'info>(
+        ctx: Context<'_, '_, '_, 'info, MIP1MigrateAndList<'info>>,
+        args: MIP1SellArgs,
+    ) -> Result<()> {
+        mip1_ins::mip1_migrate_and_list::handle_mip1_migrate_and_list(ctx, &args)
+    }
+
     pub fn mip1_execute_sale_v2<'info>(
         ctx: Context<'_, '_, '_, 'info, MIP1ExecuteSaleV2<'info>>,
         args: MIP1ExecuteSaleV2Args,
@@ -0,0 +1,346 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Mint, Token, TokenAccount},
+    solana_program::program_option::COption,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AcceptOfferArgs {
+    pub price: u64, // has to match the bid's buyer_price, guards the seller against bid updates
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub min_net_proceeds: Option<u64>, // what the seller keeps at least after fees
+}
+
+// AcceptOffer lets the holder of an unlisted token fill a bid on it directly, without creating a
+// listing first. Bids targeted with set_offer_target can only be accepted by their target_seller.
+// Bids with a take profit price relist the token through a listing and have to be filled with
+// execute_sale_v2, and programmable tokens can't be moved by the seller's signature alone.
+#[derive(Accounts)]
+#[instruction(args: AcceptOfferArgs)]
+pub struct AcceptOffer<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: buyer, checked against bid_args
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: optional, signs to apply maker_fee_bp and taker_fee_bp
+    notary: UncheckedAccount<'info>,
+    #[account(mut, token::mint = token_mint, token::authority = seller)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: buyer_receipt_token_account
+    #[account(mut)]
+    buyer_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    /// CHECK: check seeds and check bid_args
+    #[account(
+        mut,
+        seeds=[
+          PREFIX.as_bytes(),
+          buyer.key().as_ref(),
+          auction_house.key().as_ref(),
+          token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: must match the buyer_referral stored in buyer_trade_state
+    #[account(mut)]
+    buyer_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    price_band_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    price_attestation: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the buyer, derivation checked in uncount_open_order
+    #[account(mut)]
+    buyer_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    buyer_counted_order: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts: same layout as sell_into_collection_bid, the
+    // payment_source_token_account of an escrowless bid is the buyer's token account delegated to
+    // program_as_signer
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>,
+    args: AcceptOfferArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller = &ctx.accounts.seller;
+    let buyer = &ctx.accounts.buyer;
+    let notary = &ctx.accounts.notary;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    if buyer_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+    let is_spl = bid_args.payment_mint != Pubkey::default();
+    if bid_args.auction_house_key != auction_house.key() || bid_args.take_profit_price > 0 {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    bid_args.check_args(
+        ctx.accounts.buyer_referral.key,
+        args.price,
+        &token_mint.key(),
+        bid_args.token_size,
+        if is_spl {
+            index_ra!(remaining_accounts, 0).key
+        } else {
+            &bid_args.payment_mint
+        },
+    )?;
+    bid_args.assert_target_seller(seller.key)?;
+    if is_trade_state_expired(bid_args.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    let price = bid_args.buyer_price;
+    if bid_args.escrowless {
+        assert_delegated_payment(
+            index_ra!(remaining_accounts, 1),
+            buyer.key,
+            program_as_signer.key,
+            &bid_args.payment_mint,
+            price,
+        )?;
+    }
+
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let auction_house_key = auction_house.key();
+    let unit_price = get_unit_price(price, bid_args.token_size)?;
+    assert_price_attested(
+        &ctx.accounts.price_band_config,
+        Some(&ctx.accounts.price_attestation),
+        &auction_house_key,
+        &metadata_parsed,
+        &bid_args.payment_mint,
+        unit_price,
+    )?;
+
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+    let program_as_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        SIGNER.as_bytes(),
+        &[ctx.bumps.program_as_signer],
+    ]];
+    let (payment_authority, payment_signer_seeds) = if bid_args.escrowless {
+        (program_as_signer.to_account_info(), program_as_signer_seeds)
+    } else {
+        (
+            escrow_payment_account.to_account_info(),
+            escrow_signer_seeds,
+        )
+    };
+
+    let royalty = if bid_args.buyer_creator_royalty_bp == 0 {
+        0
+    } else {
+        pay_creator_fees(
+            &mut (if is_spl {
+                remaining_accounts[4..].iter()
+            } else {
+                remaining_accounts.iter()
+            }),
+            None,
+            &metadata_parsed,
+            &payment_authority,
+            payment_signer_seeds,
+            price,
+            bid_args.buyer_creator_royalty_bp,
+            if is_spl {
+                Some(TransferCreatorSplArgs {
+                    buyer,
+                    payer: seller,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+        )?
+    };
+
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+    let (maker_fee, taker_fee) = transfer_listing_payment(
+        price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        seller,
+        seller,
+        &payment_authority,
+        auction_house_treasury,
+        if is_spl {
+            Some(TransferListingPaymentSplArgs {
+                payer: seller,
+                buyer,
+                mint: index_ra!(remaining_accounts, 0),
+                payment_source_token_account: index_ra!(remaining_accounts, 1),
+                payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                system_program,
+                token_program,
+            })
+        } else {
+            None
+        },
+        payment_signer_seeds,
+    )?;
+    assert_min_net_proceeds(
+        get_seller_net_proceeds(price, maker_fee, taker_fee, true),
+        args.min_net_proceeds,
+    )?;
+
+    let buyer_rec_acct = transfer_token(
+        &bid_args.token_size,
+        seller,
+        seller,
+        seller,
+        None,
+        DestinationSpecifier::Ai(buyer),
+        &token_mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.buyer_receipt_token_account,
+        token_program,
+        system_program,
+        None,
+        &[],
+    )?;
+    if let COption::Some(_) = buyer_rec_acct.delegate {
+        return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+    }
+
+    record_sale(
+        &ctx.accounts.collection_stats,
+        &ctx.accounts.last_sale,
+        &ctx.accounts.trade_sequence,
+        seller,
+        &auction_house_key,
+        &metadata_parsed,
+        &bid_args.payment_mint,
+        buyer.key,
+        seller.key,
+        unit_price,
+        get_payment_decimals(if is_spl {
+            Some(index_ra!(remaining_accounts, 0))
+        } else {
+            None
+        })?,
+    )?;
+
+    msg!(
+        "accept_offer: {{\"buyer_trade_state\":\"{}\",\"price\":{},\"token_size\":{},\"royalty\":{},\"buyer_expiry\":{}}}",
+        buyer_trade_state.key(),
+        price,
+        bid_args.token_size,
+        royalty,
+        bid_args.expiry,
+    );
+
+    close_account_anchor(buyer_trade_state, buyer)?;
+    uncount_open_order(
+        Some(&ctx.accounts.buyer_order_counter),
+        &ctx.accounts.buyer_counted_order,
+        &buyer_trade_state.key(),
+        buyer,
+        &auction_house_key,
+        OrderSide::Bid,
+    )?;
+    try_close_buyer_escrow(
+        escrow_payment_account,
+        buyer,
+        system_program,
+        escrow_signer_seeds,
+    )
+}
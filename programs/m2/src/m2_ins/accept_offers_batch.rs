@@ -0,0 +1,308 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::Token,
+    solana_program::program_option::COption,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AcceptOffersBatchItem {
+    pub price: u64, // has to match the bid's buyer_price, guards the seller against bid updates
+    pub min_net_proceeds: Option<u64>, // what the seller keeps at least after fees
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AcceptOffersBatchArgs {
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub items: Vec<AcceptOffersBatchItem>,
+}
+
+// AcceptOffersBatch fills up to MAX_ACCEPT_OFFERS_BATCH_ITEMS bids on tokens of the seller in one
+// transaction, the same as calling accept_offer for each of them with one notary cosign and fee
+// split for all. Only escrowed SOL bids can be accepted in a batch, escrowless and SPL bids have to
+// go through accept_offer.
+#[derive(Accounts)]
+pub struct AcceptOffersBatch<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: optional, signs to apply maker_fee_bp and taker_fee_bp
+    notary: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts, for every item in the order of args.items:
+    // 0..16. (buyer, token_account, token_mint, metadata, escrow_payment_account,
+    //         buyer_receipt_token_account, buyer_trade_state, buyer_referral, collection_config,
+    //         buyer_status, price_band_config, price_attestation, collection_stats, last_sale,
+    //         buyer_order_counter, buyer_counted_order)
+    // ** m ** creators of metadata, in the order of the metadata
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptOffersBatch<'info>>,
+    args: AcceptOffersBatchArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let remaining_accounts = ctx.remaining_accounts;
+
+    if args.items.is_empty() || args.items.len() > MAX_ACCEPT_OFFERS_BATCH_ITEMS {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if args.maker_fee_bp > MAX_MAKER_FEE_BP
+        || args.maker_fee_bp < -(args.taker_fee_bp as i16)
+        || args.taker_fee_bp > MAX_TAKER_FEE_BP
+    {
+        return Err(ErrorCode::InvalidPlatformFeeBp.into());
+    }
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(&ctx.accounts.notary, args.maker_fee_bp, args.taker_fee_bp);
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut cursor = 0;
+    for item in args.items.iter() {
+        if remaining_accounts.len() < cursor + 16 {
+            return Err(ErrorCode::MissingRemainingAccount.into());
+        }
+        let accounts = &remaining_accounts[cursor..cursor + 16];
+        let (
+            buyer,
+            token_account,
+            token_mint,
+            metadata,
+            escrow_payment_account,
+            buyer_receipt_token_account,
+            buyer_trade_state,
+            buyer_referral,
+            collection_config,
+            buyer_status,
+            price_band_config,
+            price_attestation,
+            collection_stats,
+            last_sale,
+            buyer_order_counter,
+            buyer_counted_order,
+        ) = (
+            &accounts[0],
+            &accounts[1],
+            &accounts[2],
+            &accounts[3],
+            &accounts[4],
+            &accounts[5],
+            &accounts[6],
+            &accounts[7],
+            &accounts[8],
+            &accounts[9],
+            &accounts[10],
+            &accounts[11],
+            &accounts[12],
+            &accounts[13],
+            &accounts[14],
+            &accounts[15],
+        );
+
+        let token: spl_token::state::Account = assert_initialized(token_account)?;
+        if token.owner != *seller.key || token.mint != *token_mint.key {
+            return Err(ErrorCode::IncorrectOwner.into());
+        }
+        assert_derivation(
+            ctx.program_id,
+            buyer_trade_state,
+            &[
+                PREFIX.as_bytes(),
+                buyer.key.as_ref(),
+                auction_house_key.as_ref(),
+                token_mint.key.as_ref(),
+            ],
+        )?;
+        let escrow_bump = assert_derivation(
+            ctx.program_id,
+            escrow_payment_account,
+            &[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                buyer.key.as_ref(),
+            ],
+        )?;
+
+        if buyer_trade_state.data_is_empty() {
+            return Err(ErrorCode::EmptyTradeState.into());
+        }
+        let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+        if bid_args.auction_house_key != auction_house_key
+            || bid_args.take_profit_price > 0
+            || bid_args.escrowless
+            || bid_args.payment_mint != Pubkey::default()
+        {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        bid_args.check_args(
+            buyer_referral.key,
+            item.price,
+            token_mint.key,
+            bid_args.token_size,
+            &bid_args.payment_mint,
+        )?;
+        bid_args.assert_target_seller(seller.key)?;
+        if is_trade_state_expired(bid_args.expiry, now) {
+            return Err(ErrorCode::InvalidExpiry.into());
+        }
+        let price = bid_args.buyer_price;
+
+        assert_metadata_valid(metadata, token_mint.key)?;
+        let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+        assert_collection_not_paused(collection_config, &auction_house_key, &metadata_parsed)?;
+        assert_buyer_not_suspended(buyer_status, buyer.key)?;
+        let unit_price = get_unit_price(price, bid_args.token_size)?;
+        assert_price_attested(
+            price_band_config,
+            Some(price_attestation),
+            &auction_house_key,
+            &metadata_parsed,
+            &bid_args.payment_mint,
+            unit_price,
+        )?;
+
+        let creators_len = metadata_parsed
+            .creators
+            .as_ref()
+            .map_or(0, |creators| creators.len());
+        cursor += 16;
+        if remaining_accounts.len() < cursor + creators_len {
+            return Err(ErrorCode::MissingRemainingAccount.into());
+        }
+        let creator_accounts = &remaining_accounts[cursor..cursor + creators_len];
+        cursor += creators_len;
+
+        let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            buyer.key.as_ref(),
+            &[escrow_bump],
+        ]];
+        let royalty = if bid_args.buyer_creator_royalty_bp == 0 {
+            0
+        } else {
+            pay_creator_fees(
+                &mut creator_accounts.iter(),
+                None,
+                &metadata_parsed,
+                escrow_payment_account,
+                escrow_signer_seeds,
+                price,
+                bid_args.buyer_creator_royalty_bp,
+                None,
+            )?
+        };
+        let (maker_fee, taker_fee) = transfer_listing_payment(
+            price,
+            actual_maker_fee_bp,
+            actual_taker_fee_bp,
+            seller,
+            seller,
+            escrow_payment_account,
+            auction_house_treasury,
+            None,
+            escrow_signer_seeds,
+        )?;
+        assert_min_net_proceeds(
+            get_seller_net_proceeds(price, maker_fee, taker_fee, true),
+            item.min_net_proceeds,
+        )?;
+
+        let buyer_rec_acct = transfer_token(
+            &bid_args.token_size,
+            seller,
+            seller,
+            seller,
+            None,
+            DestinationSpecifier::Ai(buyer),
+            token_mint,
+            token_account,
+            buyer_receipt_token_account,
+            token_program,
+            system_program,
+            None,
+            &[],
+        )?;
+        if let COption::Some(_) = buyer_rec_acct.delegate {
+            return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+        }
+
+        record_sale(
+            collection_stats,
+            last_sale,
+            &ctx.accounts.trade_sequence,
+            seller,
+            &auction_house_key,
+            &metadata_parsed,
+            &bid_args.payment_mint,
+            buyer.key,
+            seller.key,
+            unit_price,
+            get_payment_decimals(None)?,
+        )?;
+
+        msg!(
+            "accept_offers_batch_item: {{\"buyer_trade_state\":\"{}\",\"price\":{},\"token_size\":{},\"royalty\":{},\"buyer_expiry\":{}}}",
+            buyer_trade_state.key(),
+            price,
+            bid_args.token_size,
+            royalty,
+            bid_args.expiry,
+        );
+
+        close_account_anchor(buyer_trade_state, buyer)?;
+        uncount_open_order(
+            Some(buyer_order_counter),
+            buyer_counted_order,
+            buyer_trade_state.key,
+            buyer,
+            &auction_house_key,
+            OrderSide::Bid,
+        )?;
+        try_close_buyer_escrow(
+            escrow_payment_account,
+            buyer,
+            system_program,
+            escrow_signer_seeds,
+        )?;
+    }
+    if cursor != remaining_accounts.len() {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+
+    msg!(
+        "accept_offers_batch: {{\"seller\":\"{}\",\"count\":{}}}",
+        seller.key(),
+        args.items.len(),
+    );
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use solana_program::{program::invoke_signed, system_instruction};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Token, TokenAccount},
+    },
+};
+
+// AcceptSwap lets the counterparty of a swap offer take it: the requested token moves from the
+// counterparty to the proposer, the offered token from the proposer's ata held by
+// program_as_signer to the counterparty, and the sweetener, if any, from the proposer's escrow to
+// the counterparty. The swap offer is closed into the proposer.
+#[derive(Accounts)]
+pub struct AcceptSwap<'info> {
+    #[account(mut)]
+    counterparty: Signer<'info>,
+    /// CHECK: checked against swap_offer
+    #[account(mut)]
+    proposer: UncheckedAccount<'info>,
+    /// CHECK: checked against swap_offer
+    #[account(address = swap_offer.offered_mint)]
+    offered_mint: UncheckedAccount<'info>,
+    /// CHECK: checked against swap_offer, owned by program_as_signer
+    #[account(mut, address = swap_offer.offered_token_account)]
+    offered_token_account: UncheckedAccount<'info>,
+    /// CHECK: ata of the counterparty for offered_mint, created if needed
+    #[account(mut)]
+    counterparty_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: checked against swap_offer
+    #[account(address = swap_offer.requested_mint)]
+    requested_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::mint = requested_mint,
+        token::authority = counterparty,
+        constraint = requested_token_account.amount == 1 @ ErrorCode::InvalidTokenAmount,
+    )]
+    requested_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: ata of the proposer for requested_mint, created if needed
+    #[account(mut)]
+    proposer_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        mut,
+        close=proposer,
+        seeds=[
+            PREFIX.as_bytes(),
+            SWAP_OFFER.as_bytes(),
+            auction_house.key().as_ref(),
+            proposer.key().as_ref(),
+            swap_offer.offered_mint.as_ref(),
+        ],
+        bump=swap_offer.bump,
+        has_one=proposer,
+        has_one=counterparty,
+    )]
+    swap_offer: Box<Account<'info, SwapOffer>>,
+    /// CHECK: escrow_payment_account of the proposer, pays the sweetener
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), proposer.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, AcceptSwap<'info>>) -> Result<()> {
+    let counterparty = &ctx.accounts.counterparty;
+    let proposer = &ctx.accounts.proposer;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let swap_offer = &ctx.accounts.swap_offer;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    if swap_offer.expiry > 0 && is_expiry_passed(swap_offer.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    transfer_token(
+        &1,
+        counterparty,
+        counterparty,
+        counterparty,
+        None,
+        DestinationSpecifier::Ai(proposer),
+        &ctx.accounts.requested_mint,
+        &ctx.accounts.requested_token_account.to_account_info(),
+        &ctx.accounts.proposer_receipt_token_account,
+        token_program,
+        system_program,
+        None,
+        &[],
+    )?;
+    // the proposer's ata is closed into the proposer once it's empty
+    transfer_token(
+        &1,
+        counterparty,
+        &ctx.accounts.program_as_signer,
+        proposer,
+        None,
+        DestinationSpecifier::Ai(counterparty),
+        &ctx.accounts.offered_mint,
+        &ctx.accounts.offered_token_account,
+        &ctx.accounts.counterparty_receipt_token_account,
+        token_program,
+        system_program,
+        None,
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+    if swap_offer.sweetener > 0 {
+        invoke_signed(
+            &system_instruction::transfer(
+                escrow_payment_account.key,
+                counterparty.key,
+                swap_offer.sweetener,
+            ),
+            &[
+                escrow_payment_account.to_account_info(),
+                counterparty.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[&[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                proposer.key.as_ref(),
+                &[ctx.bumps.escrow_payment_account],
+            ]],
+        )?;
+    }
+
+    msg!(
+        "accept_swap: {{\"swap_offer\":\"{}\",\"offered_mint\":\"{}\",\"requested_mint\":\"{}\",\"sweetener\":{}}}",
+        swap_offer.key(),
+        swap_offer.offered_mint,
+        swap_offer.requested_mint,
+        swap_offer.sweetener,
+    );
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{SetAuthority, Token},
+    spl_token::instruction::AuthorityType,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AppendBundleItemsArgs {
+    pub seal: bool,
+}
+
+// AppendBundleItems adds a page of tokens to an unsealed bundle, up to MAX_BUNDLE_ITEMS. Every
+// token has to sit in the seller's ata, which is handed to program_as_signer like in the
+// non-movable sell mode. Sealing the bundle freezes its items and makes it sellable.
+#[derive(Accounts)]
+pub struct AppendBundleItems<'info> {
+    seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            BUNDLE.as_bytes(),
+            seller.key().as_ref(),
+            bundle_seller_state.auction_house_key.as_ref(),
+            bundle_seller_state.bundle_id.to_le_bytes().as_ref(),
+        ],
+        bump=bundle_seller_state.bump,
+        has_one=seller,
+    )]
+    bundle_seller_state: Box<Account<'info, BundleSellerState>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    // remaining accounts:
+    // 0..N. (token_account, token_mint, metadata) of every token to append
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, AppendBundleItems<'info>>,
+    args: AppendBundleItemsArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let bundle_seller_state = &mut ctx.accounts.bundle_seller_state;
+
+    if bundle_seller_state.sealed {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if !ctx.remaining_accounts.len().is_multiple_of(3) {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+
+    for item in ctx.remaining_accounts.chunks(3) {
+        let (token_account, token_mint, metadata) = (&item[0], &item[1], &item[2]);
+        if bundle_seller_state.items.len() >= MAX_BUNDLE_ITEMS {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        let mint: spl_token::state::Mint = assert_initialized(token_mint)?;
+        if mint.supply != 1 || mint.decimals != 0 {
+            return Err(ErrorCode::InvalidTokenMint.into());
+        }
+        let token = assert_is_ata(token_account, seller.key, token_mint.key, seller.key)?;
+        if token.amount != 1 {
+            return Err(ErrorCode::InvalidTokenAmount.into());
+        }
+        assert_metadata_valid(metadata, token_mint.key)?;
+        assert_collection_not_paused(
+            &ctx.accounts.collection_config,
+            &bundle_seller_state.auction_house_key,
+            &Metadata::safe_deserialize(&metadata.data.borrow())?,
+        )?;
+
+        anchor_spl::token::set_authority(
+            CpiContext::new(
+                token_program.to_account_info(),
+                SetAuthority {
+                    account_or_mint: token_account.to_account_info(),
+                    current_authority: seller.to_account_info(),
+                },
+            ),
+            AuthorityType::AccountOwner,
+            Some(program_as_signer.key()),
+        )?;
+        bundle_seller_state.items.push(BundleItem {
+            token_mint: token_mint.key(),
+            token_account: token_account.key(),
+        });
+    }
+
+    if args.seal {
+        if bundle_seller_state.items.is_empty() {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        bundle_seller_state.sealed = true;
+    }
+
+    msg!(
+        "append_bundle_items: {{\"bundle\":\"{}\",\"items\":{},\"sealed\":{}}}",
+        bundle_seller_state.key(),
+        bundle_seller_state.items.len(),
+        bundle_seller_state.sealed,
+    );
+    Ok(())
+}
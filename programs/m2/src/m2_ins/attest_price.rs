@@ -0,0 +1,71 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AttestPriceArgs {
+    pub payment_mint: Pubkey,
+    pub min_price: u64,
+    pub max_price: u64,
+}
+
+// AttestPrice posts the notary's fair price band of a token, sales of tokens in collections with an
+// enabled PriceBandConfig have to settle within it while it's fresh.
+#[derive(Accounts)]
+pub struct AttestPrice<'info> {
+    #[account(mut)]
+    notary: Signer<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: token mint
+    token_mint: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=notary,
+        seeds=[
+            PREFIX.as_bytes(),
+            PRICE_ATTESTATION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        space=PriceAttestation::LEN,
+        bump,
+    )]
+    price_attestation: Account<'info, PriceAttestation>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, AttestPrice<'info>>,
+    args: AttestPriceArgs,
+) -> Result<()> {
+    if args.min_price > args.max_price || args.max_price > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    let price_attestation = &mut ctx.accounts.price_attestation;
+    price_attestation.auction_house_key = ctx.accounts.auction_house.key();
+    price_attestation.token_mint = ctx.accounts.token_mint.key();
+    price_attestation.payment_mint = args.payment_mint;
+    price_attestation.min_price = args.min_price;
+    price_attestation.max_price = args.max_price;
+    price_attestation.attested_at = Clock::get()?.unix_timestamp;
+    price_attestation.bump = ctx.bumps.price_attestation;
+
+    msg!(
+        "attest_price: {{\"token_mint\":\"{}\",\"payment_mint\":\"{}\",\"min_price\":{},\"max_price\":{},\"attested_at\":{}}}",
+        price_attestation.token_mint,
+        price_attestation.payment_mint,
+        price_attestation.min_price,
+        price_attestation.max_price,
+        price_attestation.attested_at,
+    );
+    Ok(())
+}
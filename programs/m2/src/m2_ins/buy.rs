@@ -1,4 +1,5 @@
 use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
 use solana_program::{program::invoke, system_instruction};
 
 use {
@@ -57,6 +58,12 @@ pub struct Buy<'info> {
     token_program: Program<'info, Token>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
+    // remaining accounts:
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. order_limit_config (required) - OrderLimitConfig of the auction house, derivation checked in count_open_order
+    // 2. wallet_order_counter - WalletOrderCounter of the wallet, required if the order_limit_config has a limit, created if needed
+    // 3. counted_order - CountedOrder of the buyer_trade_state, required if the order_limit_config has a limit, created if needed
 }
 
 pub fn handle<'info>(
@@ -76,6 +83,8 @@ pub fn handle<'info>(
     let buyer_trade_state = &mut ctx.accounts.buyer_trade_state;
     let system_program = &ctx.accounts.system_program;
     let auction_house_key = auction_house.key();
+    let (_, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
 
     let discriminator_ai = buyer_trade_state_clone.try_borrow_data()?;
     if discriminator_ai[..8] != BuyerTradeState::discriminator() && discriminator_ai[..8] != [0; 8]
@@ -113,6 +122,27 @@ pub fn handle<'info>(
 
     let token_mint_key = token_mint.key();
     assert_metadata_valid(metadata, &token_mint_key)?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(collection_config, &auction_house_key, &metadata_parsed)?;
+    assert_collection_price_on_tick(
+        collection_config,
+        &auction_house_key,
+        &get_collection_key(&metadata_parsed),
+        buyer_price,
+    )?;
+    // buyer_trade_state was just created by init_if_needed
+    if buyer_trade_state.buyer == Pubkey::default() {
+        count_open_order(
+            optional_accounts.require(1)?,
+            optional_accounts.get(2),
+            optional_accounts.get(3),
+            buyer_trade_state_clone.key,
+            wallet,
+            &auction_house_key,
+            wallet.key,
+            OrderSide::Bid,
+        )?;
+    }
     buyer_trade_state.auction_house_key = auction_house_key;
     buyer_trade_state.buyer = wallet.key();
     buyer_trade_state.buyer_referral = buyer_referral.key();
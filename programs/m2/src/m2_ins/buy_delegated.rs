@@ -0,0 +1,193 @@
+use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Approve, Mint, Token, TokenAccount},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyDelegatedArgs {
+    pub buyer_price: u64,
+    pub token_size: u64,
+    pub buyer_state_expiry: i64,
+    pub buyer_creator_royalty_bp: u16,
+    pub delegated_amount: u64,
+    pub take_profit_price: u64,
+}
+
+// BuyDelegated places an escrowless SPL bid: instead of funding the escrow, the buyer delegates
+// delegated_amount of the payment_token_account to program_as_signer and execute_sale_v2 pulls the
+// payment out of it at fill time. A token account has a single delegation, so delegated_amount
+// backs all escrowless bids paid out of it, each fill lowers it. Use the native mint for wSOL bids.
+#[derive(Accounts)]
+pub struct BuyDelegated<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint)]
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: seeds check + discriminator check
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump)]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: buyer_referral
+    buyer_referral: UncheckedAccount<'info>,
+    payment_mint: Box<Account<'info, Mint>>,
+    #[account(mut, token::mint = payment_mint, token::authority = wallet)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in count_open_order
+    order_limit_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in count_open_order, created if needed
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the buyer_trade_state, derivation checked in count_open_order, created if needed
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyDelegated<'info>>,
+    args: BuyDelegatedArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let metadata = &ctx.accounts.metadata;
+    let token_mint = &ctx.accounts.token_mint;
+    let auction_house = &ctx.accounts.auction_house;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let payment_mint = &ctx.accounts.payment_mint;
+    let payment_token_account = &ctx.accounts.payment_token_account;
+
+    if buyer_trade_state.data_len() > 0 {
+        let discriminator_data = &buyer_trade_state.try_borrow_data()?[0..8];
+        if discriminator_data != BuyerTradeState::discriminator()
+            && discriminator_data != BuyerTradeStateV2::discriminator()
+        {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+    }
+    if args.buyer_creator_royalty_bp > 10_000 {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    if args.buyer_price > MAX_PRICE || args.buyer_price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.take_profit_price > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.take_profit_price > 0 {
+        assert_price_precision(args.take_profit_price, &payment_mint.to_account_info())?;
+    }
+    if args.token_size == 0 {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    assert_payment_mint(&payment_mint.to_account_info())?;
+    assert_price_precision(args.buyer_price, &payment_mint.to_account_info())?;
+    if args.delegated_amount < args.buyer_price || payment_token_account.amount < args.buyer_price {
+        return Err(ErrorCode::InsufficientDelegatedAmount.into());
+    }
+
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &Metadata::safe_deserialize(&metadata.data.borrow())?,
+    )?;
+
+    anchor_spl::token::approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: payment_token_account.to_account_info(),
+                delegate: ctx.accounts.program_as_signer.to_account_info(),
+                authority: wallet.to_account_info(),
+            },
+        ),
+        args.delegated_amount,
+    )?;
+
+    if buyer_trade_state.data_is_empty() {
+        count_open_order(
+            &ctx.accounts.order_limit_config,
+            Some(&ctx.accounts.wallet_order_counter),
+            Some(&ctx.accounts.counted_order),
+            buyer_trade_state.key,
+            wallet,
+            &auction_house.key(),
+            wallet.key,
+            OrderSide::Bid,
+        )?;
+    }
+    let bts_bump = ctx.bumps.buyer_trade_state;
+    create_or_realloc_buyer_trade_state(
+        buyer_trade_state,
+        wallet,
+        &[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+            &[bts_bump],
+        ],
+    )?;
+    let bts_v2 = BuyerTradeStateV2 {
+        auction_house_key: auction_house.key(),
+        buyer: wallet.key(),
+        buyer_referral: ctx.accounts.buyer_referral.key(),
+        buyer_price: args.buyer_price,
+        token_mint: token_mint.key(),
+        token_size: args.token_size,
+        bump: bts_bump,
+        buyer_creator_royalty_bp: args.buyer_creator_royalty_bp,
+        expiry: get_default_buyer_state_expiry(args.buyer_state_expiry),
+        payment_mint: payment_mint.key(),
+        escrowless: true,
+        take_profit_price: args.take_profit_price,
+        target_seller: Pubkey::default(),
+    };
+    let bts_v2_serialized = bts_v2.try_to_vec()?;
+    buyer_trade_state.try_borrow_mut_data()?[8..8 + bts_v2_serialized.len()]
+        .copy_from_slice(&bts_v2_serialized);
+
+    msg!(
+        "buy_delegated: {{\"buyer_trade_state\":\"{}\",\"price\":{},\"buyer_expiry\":{},\"payment_token_account\":\"{}\",\"delegated_amount\":{}}}",
+        buyer_trade_state.key(),
+        bts_v2.buyer_price,
+        bts_v2.expiry,
+        payment_token_account.key(),
+        args.delegated_amount,
+    );
+    Ok(())
+}
@@ -0,0 +1,255 @@
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata},
+    instructions::{
+        MintNewEditionFromMasterEditionViaTokenBuilder, UpdatePrimarySaleHappenedViaTokenBuilder,
+    },
+    types::MintNewEditionFromMasterEditionViaTokenArgs,
+};
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*, anchor_spl::token::Token,
+};
+
+// BuyEdition prints the next edition of an edition sale to the buyer for the listed price. The
+// buyer creates new_mint with a supply of one in their new_token_account beforehand, as
+// token-metadata requires, and stays its mint authority. Until the master's primary sale happened,
+// the price less the platform fee is the primary sale proceeds and goes to the creators by their
+// shares. After that the seller gets the price less the platform fee and the buyer pays full
+// royalties on top, as with any listing. Either way the printed edition is flagged as sold, so its
+// resales pay royalties.
+#[derive(Accounts)]
+pub struct BuyEdition<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: checked against edition_sale
+    #[account(mut, address = edition_sale.seller)]
+    seller: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=auction_house_treasury,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            EDITION_SALE.as_bytes(),
+            auction_house.key().as_ref(),
+            edition_sale.master_mint.as_ref(),
+        ],
+        bump=edition_sale.bump,
+        constraint = !edition_sale.is_sold_out() @ ErrorCode::EditionSaleSoldOut,
+    )]
+    edition_sale: Box<Account<'info, EditionSale>>,
+    /// CHECK: checked against edition_sale
+    #[account(address = edition_sale.master_mint)]
+    master_mint: UncheckedAccount<'info>,
+    /// CHECK: metadata of master_mint
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        master_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    master_metadata: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in CPI
+    #[account(mut, address = MasterEdition::find_pda(&master_mint.key()).0)]
+    master_edition: UncheckedAccount<'info>,
+    /// CHECK: ata of edition_sale holding the master edition token, checked in CPI
+    edition_vault: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut)]
+    new_mint: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut)]
+    new_metadata: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut)]
+    new_edition: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut)]
+    edition_mark_pda: UncheckedAccount<'info>,
+    /// CHECK: token account of the buyer holding the one new_mint token, checked in CPI
+    new_token_account: UncheckedAccount<'info>,
+    /// CHECK: update authority of master_metadata, checked in handle
+    update_authority: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** m ** creators of master_metadata, in the order of the metadata
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, BuyEdition<'info>>) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let master_metadata = &ctx.accounts.master_metadata;
+    let master_edition = &ctx.accounts.master_edition;
+    let system_program = &ctx.accounts.system_program;
+    let price = ctx.accounts.edition_sale.price;
+
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+    let metadata_parsed = Metadata::safe_deserialize(&master_metadata.data.borrow())?;
+    assert_keys_equal(
+        ctx.accounts.update_authority.key,
+        &metadata_parsed.update_authority,
+    )?;
+    let edition = MasterEdition::from_bytes(&master_edition.data.borrow())
+        .map_err(|_| ErrorCode::InvalidAccountState)?
+        .supply
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    let primary_sale = !metadata_parsed.primary_sale_happened;
+    let (maker_fee, taker_fee, seller_amount) =
+        get_listing_payment_amounts(price, DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP, false)?;
+    let creators_paid = if primary_sale {
+        let paid_to_creators = pay_primary_sale_proceeds(
+            &mut ctx.remaining_accounts.iter(),
+            &metadata_parsed,
+            &buyer.to_account_info(),
+            seller_amount,
+        )?;
+        // creator shares that couldn't be paid go to the seller
+        for (account, lamports) in [
+            (seller.to_account_info(), seller_amount - paid_to_creators),
+            (
+                auction_house_treasury.to_account_info(),
+                (maker_fee + taker_fee as i64).max(0) as u64,
+            ),
+        ] {
+            if lamports > 0 {
+                invoke(
+                    &system_instruction::transfer(buyer.key, account.key, lamports),
+                    &[
+                        buyer.to_account_info(),
+                        account,
+                        system_program.to_account_info(),
+                    ],
+                )?;
+            }
+        }
+        paid_to_creators
+    } else {
+        transfer_listing_payment(
+            price,
+            DEFAULT_MAKER_FEE_BP,
+            DEFAULT_TAKER_FEE_BP,
+            buyer,
+            seller,
+            buyer,
+            auction_house_treasury,
+            None,
+            &[],
+        )?;
+        pay_creator_fees(
+            &mut ctx.remaining_accounts.iter(),
+            None,
+            &metadata_parsed,
+            buyer,
+            &[],
+            price,
+            10_000,
+            None,
+        )?
+    };
+
+    let edition_sale = &ctx.accounts.edition_sale;
+    let ins = MintNewEditionFromMasterEditionViaTokenBuilder::new()
+        .new_metadata(ctx.accounts.new_metadata.key())
+        .new_edition(ctx.accounts.new_edition.key())
+        .master_edition(master_edition.key())
+        .new_mint(ctx.accounts.new_mint.key())
+        .edition_mark_pda(ctx.accounts.edition_mark_pda.key())
+        .new_mint_authority(buyer.key())
+        .payer(buyer.key())
+        .token_account_owner(edition_sale.key())
+        .token_account(ctx.accounts.edition_vault.key())
+        .new_metadata_update_authority(ctx.accounts.update_authority.key())
+        .metadata(master_metadata.key())
+        .token_program(ctx.accounts.token_program.key())
+        .system_program(system_program.key())
+        .mint_new_edition_from_master_edition_via_token_args(
+            MintNewEditionFromMasterEditionViaTokenArgs { edition },
+        )
+        .instruction();
+    invoke_signed(
+        &ins,
+        &[
+            ctx.accounts.new_metadata.to_account_info(),
+            ctx.accounts.new_edition.to_account_info(),
+            master_edition.to_account_info(),
+            ctx.accounts.new_mint.to_account_info(),
+            ctx.accounts.edition_mark_pda.to_account_info(),
+            buyer.to_account_info(),
+            edition_sale.to_account_info(),
+            ctx.accounts.edition_vault.to_account_info(),
+            ctx.accounts.update_authority.to_account_info(),
+            master_metadata.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            system_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&[
+            PREFIX.as_bytes(),
+            EDITION_SALE.as_bytes(),
+            edition_sale.auction_house_key.as_ref(),
+            edition_sale.master_mint.as_ref(),
+            &[edition_sale.bump],
+        ]],
+    )?;
+
+    // the printed edition takes over the flag of the master, it's sold now either way
+    let new_metadata_parsed = Metadata::safe_deserialize(&ctx.accounts.new_metadata.data.borrow())?;
+    if !new_metadata_parsed.primary_sale_happened {
+        let ins = UpdatePrimarySaleHappenedViaTokenBuilder::new()
+            .metadata(ctx.accounts.new_metadata.key())
+            .owner(buyer.key())
+            .token(ctx.accounts.new_token_account.key())
+            .instruction();
+        invoke(
+            &ins,
+            &[
+                ctx.accounts.new_metadata.to_account_info(),
+                buyer.to_account_info(),
+                ctx.accounts.new_token_account.to_account_info(),
+                ctx.accounts.token_metadata_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    let edition_sale = &mut ctx.accounts.edition_sale;
+    edition_sale.printed = edition_sale
+        .printed
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "buy_edition: {{\"edition_sale\":\"{}\",\"buyer\":\"{}\",\"new_mint\":\"{}\",\"edition\":{},\"price\":{},\"primary_sale\":{},\"creators_paid\":{}}}",
+        edition_sale.key(),
+        buyer.key(),
+        ctx.accounts.new_mint.key(),
+        edition,
+        price,
+        primary_sale,
+        creators_paid,
+    );
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+    solana_program::program_option::COption,
+};
+
+// BuyStaked fills a staked listing. The buyer pays the price plus the taker fee and full royalties,
+// then staking_program is invoked with the listing's unstake_data and staked_listing as a signer,
+// and has to leave the token in the buyer's ata. The staking program gets staked_listing,
+// token_account and buyer_receipt_token_account first, followed by the staking accounts.
+#[derive(Accounts)]
+pub struct BuyStaked<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: checked against staked_listing
+    #[account(mut, address = staked_listing.seller)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: checked against staked_listing
+    #[account(address = staked_listing.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: checked against staked_listing, released by the staking program
+    #[account(mut, address = staked_listing.token_account)]
+    token_account: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=auction_house_treasury,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            STAKED_LISTING.as_bytes(),
+            auction_house.key().as_ref(),
+            seller.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump=staked_listing.bump,
+    )]
+    staked_listing: Box<Account<'info, StakedListing>>,
+    /// CHECK: ata of the buyer for token_mint, created if needed
+    #[account(mut)]
+    buyer_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: checked against staked_listing
+    #[account(address = staked_listing.staking_program)]
+    staking_program: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** m ** creators of metadata, in the order of the metadata
+    // 0..N. staking accounts, passed as-is to staking_program
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, BuyStaked<'info>>, price: u64) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let token_mint = &ctx.accounts.token_mint;
+    let token_account = &ctx.accounts.token_account;
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+    let staked_listing = &ctx.accounts.staked_listing;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    if price != staked_listing.price {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if is_trade_state_expired(staked_listing.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    // the token is still staked by the seller
+    let token: spl_token::state::Account = assert_initialized(token_account)?;
+    if token.owner != seller.key() || token.mint != token_mint.key() || token.amount != 1 {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    assert_metadata_valid(&ctx.accounts.metadata, token_mint.key)?;
+    let metadata_parsed = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house_key,
+        &metadata_parsed,
+    )?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let creators_len = metadata_parsed
+        .creators
+        .as_ref()
+        .map_or(0, |creators| creators.len());
+    if ctx.remaining_accounts.len() < creators_len {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+    let (creator_accounts, staking_accounts) = ctx.remaining_accounts.split_at(creators_len);
+
+    let (maker_fee, taker_fee) = transfer_listing_payment(
+        price,
+        DEFAULT_MAKER_FEE_BP,
+        DEFAULT_TAKER_FEE_BP,
+        buyer,
+        seller,
+        buyer,
+        &ctx.accounts.auction_house_treasury,
+        None,
+        &[],
+    )?;
+    let royalty = pay_creator_fees(
+        &mut creator_accounts.iter(),
+        None,
+        &metadata_parsed,
+        buyer,
+        &[],
+        price,
+        10_000,
+        None,
+    )?;
+
+    if buyer_receipt_token_account.data_is_empty() {
+        make_ata(
+            buyer_receipt_token_account.to_account_info(),
+            buyer.to_account_info(),
+            buyer.to_account_info(),
+            token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(staked_listing.key(), true),
+        AccountMeta::new(token_account.key(), false),
+        AccountMeta::new(buyer_receipt_token_account.key(), false),
+    ];
+    accounts.extend(staking_accounts.iter().map(|a| AccountMeta {
+        pubkey: a.key(),
+        is_signer: a.is_signer,
+        is_writable: a.is_writable,
+    }));
+    let mut account_infos = vec![
+        staked_listing.to_account_info(),
+        token_account.to_account_info(),
+        buyer_receipt_token_account.to_account_info(),
+    ];
+    account_infos.extend(staking_accounts.iter().cloned());
+    invoke_signed(
+        &Instruction {
+            program_id: ctx.accounts.staking_program.key(),
+            accounts,
+            data: staked_listing.unstake_data.clone(),
+        },
+        &account_infos,
+        &[&[
+            PREFIX.as_bytes(),
+            STAKED_LISTING.as_bytes(),
+            auction_house_key.as_ref(),
+            seller.key.as_ref(),
+            token_mint.key.as_ref(),
+            &[staked_listing.bump],
+        ]],
+    )?;
+
+    let buyer_rec_acct = assert_is_ata(
+        buyer_receipt_token_account,
+        buyer.key,
+        token_mint.key,
+        buyer.key,
+    )?;
+    if buyer_rec_acct.amount != 1 || buyer_rec_acct.is_frozen() {
+        return Err(ErrorCode::StakedTokenNotReleased.into());
+    }
+    if let COption::Some(_) = buyer_rec_acct.delegate {
+        return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+    }
+
+    msg!(
+        "buy_staked: {{\"staked_listing\":\"{}\",\"buyer\":\"{}\",\"seller\":\"{}\",\"token_mint\":\"{}\",\"price\":{},\"maker_fee\":{},\"taker_fee\":{},\"royalty\":{}}}",
+        staked_listing.key(),
+        buyer.key(),
+        seller.key(),
+        token_mint.key(),
+        price,
+        maker_fee,
+        taker_fee,
+        royalty,
+    );
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
 use solana_program::{program::invoke, system_instruction};
 
 use crate::index_ra;
@@ -9,7 +10,7 @@ use {
     crate::states::*,
     crate::utils::*,
     anchor_lang::prelude::*,
-    anchor_spl::token::{Mint, Token},
+    anchor_spl::{token::Token, token_interface::Mint},
 };
 
 #[derive(Accounts)]
@@ -19,10 +20,9 @@ pub struct BuyV2<'info> {
     /// CHECK: notary is not dangerous because we don't read or write from this account
     notary: UncheckedAccount<'info>,
     #[account(
-        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
         constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint
     )]
-    token_mint: Account<'info, Mint>,
+    token_mint: InterfaceAccount<'info, Mint>, // token or Token-2022 mint
     /// CHECK: metadata
     #[account(
     seeds = [
@@ -60,6 +60,11 @@ pub struct BuyV2<'info> {
     // 0. payment_mint (optional) - if the buyer is paying in a token, this is the mint of that token
     // 1. payment_source_token_account (optional) - if the buyer is paying in a token, this is the source token account, we need to verify sufficient balance
     // ...
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. order_limit_config (required) - OrderLimitConfig of the auction house, derivation checked in count_open_order
+    // 2. wallet_order_counter - WalletOrderCounter of the wallet, required if the order_limit_config has a limit, created if needed
+    // 3. counted_order - CountedOrder of the buyer_trade_state, required if the order_limit_config has a limit, created if needed
     // -1. payer (optional) - this wallet will try to subsidize SOL for the buyer if bidding in SOL, and will pay for bts rent
 }
 
@@ -69,10 +74,12 @@ pub fn handle<'info>(
     token_size: u64,
     buyer_state_expiry: i64,
     buyer_creator_royalty_bp: u16,
-    _extra_args: &[u8],
+    extra_args: &[u8],
 ) -> Result<()> {
     let (remaining_accounts, possible_payer) =
         split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
     let payer = if let Some(p) = possible_payer {
         p
     } else {
@@ -103,6 +110,19 @@ pub fn handle<'info>(
     if buyer_price > MAX_PRICE || buyer_price == 0 {
         return Err(ErrorCode::InvalidPrice.into());
     }
+    // extra_args[0..8] (optional) - take_profit_price as u64 le, the price execute_sale_v2
+    // relists the bought token at
+    let take_profit_price = match extra_args.get(..8) {
+        Some(bytes) => {
+            let mut le_bytes = [0u8; 8];
+            le_bytes.copy_from_slice(bytes);
+            u64::from_le_bytes(le_bytes)
+        }
+        None => 0,
+    };
+    if take_profit_price > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
 
     if remaining_accounts.is_empty() {
         // SOL
@@ -122,6 +142,10 @@ pub fn handle<'info>(
     } else if is_spl {
         // SPL
         assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(buyer_price, index_ra!(remaining_accounts, 0))?;
+        if take_profit_price > 0 {
+            assert_price_precision(take_profit_price, index_ra!(remaining_accounts, 0))?;
+        }
         let payment_token_account_parsed = assert_is_ata(
             index_ra!(remaining_accounts, 1),
             escrow_payment_account.key,
@@ -136,6 +160,28 @@ pub fn handle<'info>(
     }
 
     assert_metadata_valid(metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(collection_config, &auction_house.key(), &metadata_parsed)?;
+    if !is_spl {
+        assert_collection_price_on_tick(
+            collection_config,
+            &auction_house.key(),
+            &get_collection_key(&metadata_parsed),
+            buyer_price,
+        )?;
+    }
+    if buyer_trade_state.data_is_empty() {
+        count_open_order(
+            optional_accounts.require(1)?,
+            optional_accounts.get(2),
+            optional_accounts.get(3),
+            buyer_trade_state.key,
+            payer,
+            &auction_house.key(),
+            ctx.accounts.wallet.key,
+            OrderSide::Bid,
+        )?;
+    }
     let bts_bump = ctx.bumps.buyer_trade_state;
     // create or reallocate the buyer trade state
     // after this call the correct size should be allocated and discriminator should be written
@@ -166,6 +212,9 @@ pub fn handle<'info>(
         } else {
             Pubkey::default()
         },
+        escrowless: false,
+        take_profit_price,
+        target_seller: Pubkey::default(),
     };
 
     // serialize
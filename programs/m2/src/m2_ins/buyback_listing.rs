@@ -0,0 +1,335 @@
+use mpl_token_metadata::{accounts::Metadata, instructions::BurnNft, types::TokenStandard};
+use solana_program::{program::invoke_signed, system_instruction};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+// BuybackListing lets the auction house authority fill a SOL listing of a collection with a
+// BuybackConfig out of the treasury, and burns the bought token in the same instruction. Creators
+// get their full royalty, no platform fee is charged. Picking the best listing is up to the caller,
+// the listing only has to be priced at or below the config's max_price and fit in its budget.
+#[derive(Accounts)]
+pub struct BuybackListing<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            BUYBACK_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            buyback_config.collection.as_ref(),
+        ],
+        bump=buyback_config.bump,
+    )]
+    buyback_config: Box<Account<'info, BuybackConfig>>,
+    /// CHECK: seller, checked in seller_trade_state seeds
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: token_account, checked in handle
+    #[account(mut)]
+    token_account: UncheckedAccount<'info>,
+    /// CHECK: token_mint, burned
+    #[account(mut)]
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: metadata
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: master edition of token_mint, checked in the burn
+    #[account(mut)]
+    master_edition: UncheckedAccount<'info>,
+    /// CHECK: metadata of the buyback collection
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            buyback_config.collection.as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: ata of auction_house_treasury for token_mint, created and checked in transfer_token
+    #[account(mut)]
+    treasury_token_account: UncheckedAccount<'info>,
+    /// CHECK: check seeds and check sell_args
+    #[account(
+        mut,
+        seeds=[
+          PREFIX.as_bytes(),
+          seller.key().as_ref(),
+          auction_house.key().as_ref(),
+          token_account.key().as_ref(),
+          token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    seller_trade_state: AccountInfo<'info>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the seller, derivation checked in uncount_open_order
+    #[account(mut)]
+    seller_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    seller_counted_order: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    /// CHECK: token_metadata_program
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+    // remaining accounts:
+    // 0..=4. creators (optional) - same layout as in execute_sale_v2
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, BuybackListing<'info>>) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let seller = &ctx.accounts.seller;
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let treasury_token_account = &ctx.accounts.treasury_token_account;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let buyback_config = &mut ctx.accounts.buyback_config;
+
+    if buyback_config.auction_house_key != auction_house.key() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if seller_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+    sell_args.assert_allowed_buyer(ctx.accounts.auction_house_treasury.key)?;
+    if sell_args.payment_mint != Pubkey::default() {
+        return Err(ErrorCode::InvalidTokenMint.into());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if is_trade_state_expired(sell_args.expiry, now) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    assert_listing_active(sell_args.not_before, now)?;
+
+    assert_metadata_valid(metadata, token_mint.key)?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    match &metadata_parsed.collection {
+        Some(c) if c.verified && c.key == buyback_config.collection => {}
+        _ => return Err(ErrorCode::InvalidCollection.into()),
+    }
+    // programmable tokens can't be burned through BurnNft
+    if metadata_parsed.token_standard == Some(TokenStandard::ProgrammableNonFungible) {
+        return Err(ErrorCode::InvalidTokenStandard.into());
+    }
+
+    let price = get_listing_price(
+        &sell_args,
+        if sell_args.has_dynamic_price() {
+            buyback_config.max_price
+        } else {
+            sell_args.buyer_price
+        },
+        Some(&ctx.accounts.collection_stats),
+        &auction_house.key(),
+        &metadata_parsed,
+        &sell_args.payment_mint,
+    )?;
+    sell_args.check_args(
+        ctx.accounts.seller_referral.key,
+        &sell_args.buyer_price,
+        token_mint.key,
+        &1,
+        &Pubkey::default(),
+    )?;
+    if price > buyback_config.max_price {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    let delegate = get_delegate_from_token_account(token_account)?;
+    if let Some(d) = delegate {
+        assert_keys_equal(program_as_signer.key, &d)?;
+    } else if !is_token_owner(token_account, &program_as_signer.key())? {
+        return Err(ErrorCode::IncorrectOwner.into());
+    }
+    if !is_custody_vault(token_account.key, token_mint.key) {
+        assert_is_ata(
+            &token_account.to_account_info(),
+            &seller.key(),
+            token_mint.key,
+            &program_as_signer.key(),
+        )?;
+    }
+
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+
+    let auction_house_key = auction_house.key();
+    let treasury_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        TREASURY.as_bytes(),
+        &[auction_house.treasury_bump],
+    ]];
+
+    let royalty = pay_creator_fees(
+        &mut ctx.remaining_accounts.iter(),
+        None,
+        &metadata_parsed,
+        auction_house_treasury,
+        treasury_signer_seeds,
+        price,
+        10_000,
+        None,
+    )?;
+    invoke_signed(
+        &system_instruction::transfer(auction_house_treasury.key, seller.key, price),
+        &[
+            auction_house_treasury.to_account_info(),
+            seller.to_account_info(),
+            system_program.to_account_info(),
+        ],
+        treasury_signer_seeds,
+    )?;
+
+    let spent = buyback_config
+        .spent
+        .checked_add(price)
+        .and_then(|s| s.checked_add(royalty))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if spent > buyback_config.budget || auction_house_treasury.lamports() < MIN_TREASURY_LEFTOVER {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    transfer_token(
+        &1,
+        authority,
+        program_as_signer,
+        seller,
+        None,
+        DestinationSpecifier::Ai(auction_house_treasury),
+        token_mint,
+        token_account,
+        treasury_token_account,
+        token_program,
+        system_program,
+        None,
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+
+    record_sale(
+        &ctx.accounts.collection_stats,
+        &ctx.accounts.last_sale,
+        &ctx.accounts.trade_sequence,
+        authority,
+        &auction_house_key,
+        &metadata_parsed,
+        &sell_args.payment_mint,
+        auction_house_treasury.key,
+        seller.key,
+        price,
+        get_payment_decimals(None)?, // buybacks are paid in SOL
+    )?;
+    close_account_anchor(seller_trade_state, seller)?;
+    uncount_open_order(
+        Some(&ctx.accounts.seller_order_counter),
+        &ctx.accounts.seller_counted_order,
+        &seller_trade_state.key(),
+        seller,
+        &auction_house_key,
+        OrderSide::Listing,
+    )?;
+
+    // burning closes the treasury token account, its rent goes back to the treasury
+    let collection_metadata = &ctx.accounts.collection_metadata;
+    invoke_signed(
+        &BurnNft {
+            metadata: metadata.key(),
+            owner: auction_house_treasury.key(),
+            mint: token_mint.key(),
+            token_account: treasury_token_account.key(),
+            master_edition_account: ctx.accounts.master_edition.key(),
+            spl_token_program: token_program.key(),
+            collection_metadata: Some(collection_metadata.key()),
+        }
+        .instruction(),
+        &[
+            metadata.to_account_info(),
+            auction_house_treasury.to_account_info(),
+            token_mint.to_account_info(),
+            treasury_token_account.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            token_program.to_account_info(),
+            collection_metadata.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        treasury_signer_seeds,
+    )?;
+
+    buyback_config.spent = spent;
+    buyback_config.burned = buyback_config.burned.saturating_add(1);
+    msg!(
+        "buyback_listing: {{\"collection\":\"{}\",\"token_mint\":\"{}\",\"seller\":\"{}\",\"price\":{},\"royalty\":{},\"spent\":{},\"burned\":{}}}",
+        buyback_config.collection,
+        token_mint.key(),
+        seller.key(),
+        price,
+        royalty,
+        spent,
+        buyback_config.burned,
+    );
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::prelude::*,
+    anchor_spl::token::{SetAuthority, Token},
+    spl_token::instruction::AuthorityType,
+};
+
+// CancelBundle hands the token accounts passed in remaining accounts back to the seller and drops
+// them from the bundle, so large bundles can be cancelled in pages. A cancelled bundle is unsealed,
+// and it's closed into the seller once its last item is returned.
+#[derive(Accounts)]
+pub struct CancelBundle<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            BUNDLE.as_bytes(),
+            seller.key().as_ref(),
+            bundle_seller_state.auction_house_key.as_ref(),
+            bundle_seller_state.bundle_id.to_le_bytes().as_ref(),
+        ],
+        bump=bundle_seller_state.bump,
+        has_one=seller,
+    )]
+    bundle_seller_state: Box<Account<'info, BundleSellerState>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    // remaining accounts:
+    // 0..N. token_account of every item to return
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelBundle<'info>>) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let bundle_seller_state = &mut ctx.accounts.bundle_seller_state;
+
+    for token_account in ctx.remaining_accounts.iter() {
+        if !bundle_seller_state
+            .items
+            .iter()
+            .any(|i| i.token_account == token_account.key())
+        {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        anchor_spl::token::set_authority(
+            CpiContext::new(
+                token_program.to_account_info(),
+                SetAuthority {
+                    account_or_mint: token_account.to_account_info(),
+                    current_authority: program_as_signer.to_account_info(),
+                },
+            )
+            .with_signer(&[&[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[ctx.bumps.program_as_signer],
+            ]]),
+            AuthorityType::AccountOwner,
+            Some(seller.key()),
+        )?;
+        bundle_seller_state
+            .items
+            .retain(|i| i.token_account != token_account.key());
+    }
+    bundle_seller_state.sealed = false;
+
+    msg!(
+        "cancel_bundle: {{\"bundle\":\"{}\",\"items\":{}}}",
+        bundle_seller_state.key(),
+        bundle_seller_state.items.len(),
+    );
+    if bundle_seller_state.items.is_empty() {
+        bundle_seller_state.close(seller.to_account_info())?;
+    }
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use {
-    crate::constants::*, crate::errors::ErrorCode, crate::states::*,
-    crate::utils::close_account_anchor, anchor_lang::prelude::*, anchor_spl::token::Mint,
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::{close_account_anchor, split_optional_accounts, uncount_open_order, OrderSide},
+    anchor_lang::prelude::*,
 };
 
 #[derive(Accounts)]
@@ -10,8 +13,9 @@ pub struct CancelBuy<'info> {
     wallet: UncheckedAccount<'info>,
     /// CHECK: notary is not dangerous because we don't read or write from this account
     notary: UncheckedAccount<'info>,
+    /// CHECK: token_mint, or the asset id of a buy_compressed bid, checked with the seeds
     #[account(mut)]
-    token_mint: Account<'info, Mint>,
+    token_mint: UncheckedAccount<'info>,
     /// CHECK: authority
     authority: UncheckedAccount<'info>,
     #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
@@ -30,6 +34,10 @@ pub struct CancelBuy<'info> {
     buyer_trade_state: AccountInfo<'info>,
     /// CHECK: buyer_referral
     buyer_referral: UncheckedAccount<'info>,
+    // remaining accounts:
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. wallet_order_counter - WalletOrderCounter of the wallet, required if the counted_order exists
+    // 1. counted_order (required) - CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
 }
 
 pub fn handle<'info>(
@@ -66,6 +74,15 @@ pub fn handle<'info>(
     }
 
     close_account_anchor(buyer_trade_state, wallet)?;
+    let (_, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    uncount_open_order(
+        optional_accounts.get(0),
+        optional_accounts.require(1)?,
+        &buyer_trade_state.key(),
+        wallet,
+        &ctx.accounts.auction_house.key(),
+        OrderSide::Bid,
+    )?;
 
     Ok(())
 }
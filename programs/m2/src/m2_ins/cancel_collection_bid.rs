@@ -0,0 +1,34 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct CancelCollectionBid<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        close=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_BID.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            collection_bid_state.collection.as_ref(),
+        ],
+        bump=collection_bid_state.bump,
+    )]
+    collection_bid_state: Account<'info, CollectionBidState>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelCollectionBid<'info>>) -> Result<()> {
+    msg!(
+        "cancel_collection_bid: {{\"collection\":\"{}\",\"price\":{},\"quantity\":{}}}",
+        ctx.accounts.collection_bid_state.collection,
+        ctx.accounts.collection_bid_state.buyer_price,
+        ctx.accounts.collection_bid_state.quantity,
+    );
+    Ok(())
+}
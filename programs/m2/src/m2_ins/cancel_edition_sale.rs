@@ -0,0 +1,75 @@
+use {
+    crate::constants::*,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+// CancelEditionSale ends an edition sale: the master edition token goes back from the
+// edition_vault to the seller, the vault is closed and the edition_sale rent returned.
+#[derive(Accounts)]
+pub struct CancelEditionSale<'info> {
+    #[account(mut, address = edition_sale.seller)]
+    seller: Signer<'info>,
+    /// CHECK: checked against edition_sale
+    #[account(address = edition_sale.master_mint)]
+    master_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            EDITION_SALE.as_bytes(),
+            edition_sale.auction_house_key.as_ref(),
+            edition_sale.master_mint.as_ref(),
+        ],
+        bump=edition_sale.bump,
+    )]
+    edition_sale: Box<Account<'info, EditionSale>>,
+    /// CHECK: ata of edition_sale for master_mint, checked in transfer_token
+    #[account(mut)]
+    edition_vault: UncheckedAccount<'info>,
+    /// CHECK: ata of the seller for master_mint, created if needed
+    #[account(mut)]
+    seller_token_account: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelEditionSale<'info>>) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let edition_sale = &ctx.accounts.edition_sale;
+
+    // the vault is emptied and closed to the seller by transfer_token
+    transfer_token(
+        &1,
+        seller,
+        &edition_sale.to_account_info(),
+        seller,
+        None,
+        DestinationSpecifier::Ai(seller),
+        &ctx.accounts.master_mint,
+        &ctx.accounts.edition_vault,
+        &ctx.accounts.seller_token_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        None,
+        &[&[
+            PREFIX.as_bytes(),
+            EDITION_SALE.as_bytes(),
+            edition_sale.auction_house_key.as_ref(),
+            edition_sale.master_mint.as_ref(),
+            &[edition_sale.bump],
+        ]],
+    )?;
+
+    msg!(
+        "cancel_edition_sale: {{\"edition_sale\":\"{}\",\"seller\":\"{}\",\"printed\":{}}}",
+        edition_sale.key(),
+        seller.key(),
+        edition_sale.printed,
+    );
+    Ok(())
+}
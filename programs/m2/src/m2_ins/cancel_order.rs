@@ -0,0 +1,121 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelOrderArgs {
+    pub seq: u64,
+}
+
+// CancelOrder takes a resting order of the owner off the order book. What's left of an ask goes
+// back from the book_vault to the owner's token account, what's left of a bid's deposit back to the
+// owner. The price leaves the order book once its level has no orders left.
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+    /// CHECK: checked against order_book
+    #[account(address = order_book.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK.as_bytes(),
+            order_book.auction_house_key.as_ref(),
+            order_book.token_mint.as_ref(),
+        ],
+        bump=order_book.bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK_LEVEL.as_bytes(),
+            order_book.key().as_ref(),
+            &[order_book_level.is_bid as u8],
+            &order_book_level.price.to_le_bytes(),
+        ],
+        bump=order_book_level.bump,
+    )]
+    order_book_level: Box<Account<'info, OrderBookLevel>>,
+    /// CHECK: ata of order_book for token_mint, checked in transfer_token for asks
+    #[account(mut)]
+    book_vault: UncheckedAccount<'info>,
+    /// CHECK: ata of the owner for token_mint, created if needed for asks, unused for bids
+    #[account(mut)]
+    owner_token_account: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>,
+    args: CancelOrderArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let order_book = &mut ctx.accounts.order_book;
+    let order_book_level = &mut ctx.accounts.order_book_level;
+    let is_bid = order_book_level.is_bid;
+    let price = order_book_level.price;
+
+    let order = order_book_level.remove(owner.key, args.seq)?;
+    if order_book_level.orders.is_empty() {
+        order_book.remove_price(is_bid, price);
+    }
+
+    if is_bid {
+        let refund = get_order_book_amount(price, order.size)?;
+        let level_info = order_book_level.to_account_info();
+        **level_info.try_borrow_mut_lamports()? = level_info
+            .lamports()
+            .checked_sub(refund)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        **owner.try_borrow_mut_lamports()? = owner
+            .lamports()
+            .checked_add(refund)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    } else {
+        let auction_house_key = order_book.auction_house_key;
+        let token_mint_key = order_book.token_mint;
+        transfer_token(
+            &order.size,
+            owner,
+            &order_book.to_account_info(),
+            owner,
+            None,
+            DestinationSpecifier::Ai(owner),
+            &ctx.accounts.token_mint,
+            &ctx.accounts.book_vault,
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            None,
+            &[&[
+                PREFIX.as_bytes(),
+                ORDER_BOOK.as_bytes(),
+                auction_house_key.as_ref(),
+                token_mint_key.as_ref(),
+                &[order_book.bump],
+            ]],
+        )?;
+    }
+
+    msg!(
+        "cancel_order: {{\"order_book\":\"{}\",\"owner\":\"{}\",\"is_bid\":{},\"price\":{},\"size\":{},\"seq\":{}}}",
+        order_book.key(),
+        owner.key(),
+        is_bid,
+        price,
+        order.size,
+        order.seq,
+    );
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct CancelPortfolioBid<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        close=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            PORTFOLIO_BID.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            portfolio_bid_state.bid_id.to_le_bytes().as_ref(),
+        ],
+        bump=portfolio_bid_state.bump,
+    )]
+    portfolio_bid_state: Box<Account<'info, PortfolioBidState>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelPortfolioBid<'info>>) -> Result<()> {
+    msg!(
+        "cancel_portfolio_bid: {{\"portfolio_bid_state\":\"{}\",\"price\":{}}}",
+        ctx.accounts.portfolio_bid_state.key(),
+        ctx.accounts.portfolio_bid_state.buyer_price,
+    );
+    Ok(())
+}
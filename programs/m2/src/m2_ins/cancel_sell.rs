@@ -1,4 +1,9 @@
-use solana_program::program::invoke;
+use mpl_token_metadata::{
+    accounts::TokenRecord,
+    instructions::{RevokeUtilityV1Builder, UnlockV1Builder},
+    types::{TokenDelegateRole, TokenState},
+};
+use solana_program::program::{invoke, invoke_signed};
 use spl_token::instruction::revoke;
 
 use {
@@ -13,7 +18,7 @@ use {
 
 #[derive(Accounts)]
 pub struct CancelSell<'info> {
-    /// CHECK: wallet must sign, otherwise delist authority (notary) must sign
+    /// CHECK: wallet must sign, otherwise delist authority (notary) or a listing operator must sign
     #[account(mut)]
     wallet: UncheckedAccount<'info>,
     /// CHECK: notary is not dangerous because we don't read or write from this account
@@ -41,6 +46,18 @@ pub struct CancelSell<'info> {
     /// CHECK: seller_referral
     seller_referral: UncheckedAccount<'info>,
     token_program: Program<'info, Token>,
+    // remaining accounts:
+    // 0. program_as_signer (required if seller_state_expiry < 0)
+    // 1. operator (optional) - signer, a listing operator of the wallet cancelling in place of the wallet
+    // 2. listing_operator (optional) - ListingOperator pda of (wallet, operator)
+    // 3..=11. pNFT accounts (required if the token account is frozen and the wallet signs) - to release program_as_signer (0.) as the utility delegate of the pNFT:
+    //         metadata, edition, token_record, rental_listing, token_metadata_program,
+    //         authorization_rules_program, authorization_rules, instructions, system_program
+    //
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. wallet_order_counter - WalletOrderCounter of the wallet, required if the counted_order exists
+    // 1. counted_order (required) - CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
+    // 2. mint_listing_index - MintListingIndex of the token_mint, derivation checked in update_mint_listing_index
 }
 
 pub fn handle<'info>(
@@ -56,6 +73,7 @@ pub fn handle<'info>(
     let token_program = &ctx.accounts.token_program;
     let notary = &ctx.accounts.notary;
     let auction_house = &ctx.accounts.auction_house;
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
 
     let sell_args = SellArgs::from_account_info(seller_trade_state)?;
     sell_args.check_args(
@@ -71,8 +89,10 @@ pub fn handle<'info>(
 
     // If wallet doesn't sign, notary must be CANCEL_AUTHORITY and also sign.
     let cancel_authority_signed = notary.is_signer && *notary.key == CANCEL_AUTHORITY;
+    let operator_signed = remaining_accounts.len() >= 3
+        && is_listing_operator(&remaining_accounts[2], &remaining_accounts[1], wallet.key)?;
 
-    if !wallet.is_signer && !cancel_authority_signed {
+    if !wallet.is_signer && !cancel_authority_signed && !operator_signed {
         return Err(ErrorCode::NoValidSignerPresent.into());
     }
 
@@ -87,17 +107,21 @@ pub fn handle<'info>(
     if seller_trade_state.to_account_info().data_is_empty() {
         return Err(ErrorCode::EmptyTradeState.into());
     }
+    // custody listings are cancelled with custody_cancel_sell, the vault stays with program_as_signer
+    if is_custody_vault(&token_account.key(), token_mint.key) {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
 
     // If seller_state_expiry is negative, we treat it that program_as_signer is the authority
     // For max compatibility, we derive the authority from the first remaining accounts.
     if seller_state_expiry < 0 {
-        if ctx.remaining_accounts.is_empty() {
+        if remaining_accounts.is_empty() {
             return Err(ErrorCode::InvalidRemainingAccountsWithoutProgramAsSigner.into());
         }
 
         let (program_as_signer, wallet_bump) =
             Pubkey::find_program_address(&[PREFIX.as_bytes(), SIGNER.as_bytes()], ctx.program_id);
-        if ctx.remaining_accounts[0].key() != program_as_signer {
+        if remaining_accounts[0].key() != program_as_signer {
             return Err(ErrorCode::InvalidRemainingAccountsWithoutProgramAsSigner.into());
         }
         let seeds = &[PREFIX.as_bytes(), SIGNER.as_bytes(), &[wallet_bump][..]];
@@ -106,7 +130,7 @@ pub fn handle<'info>(
                 token_program.to_account_info(),
                 SetAuthority {
                     account_or_mint: token_account.to_account_info(),
-                    current_authority: ctx.remaining_accounts[0].clone(),
+                    current_authority: remaining_accounts[0].clone(),
                 },
             )
             .with_signer(&[&seeds[..]]),
@@ -115,23 +139,181 @@ pub fn handle<'info>(
         )?;
     }
 
-    if seller_state_expiry >= 0 && token_account.owner == wallet.key() {
-        invoke(
-            &revoke(
-                &token_program.key(),
-                &token_account.key(),
-                &wallet.key(),
-                &[],
-            )
-            .unwrap(),
-            &[
-                token_program.to_account_info(),
-                token_account.to_account_info(),
-                wallet.to_account_info(),
-            ],
-        )?;
+    // revoking the delegate needs the wallet signature
+    if seller_state_expiry >= 0 && token_account.owner == wallet.key() && wallet.is_signer {
+        if token_account.is_frozen() {
+            // spl revoke fails on the frozen account, only a pNFT can be released through its
+            // token record
+            release_pnft_utility_delegate(&ctx, remaining_accounts)?;
+        } else {
+            invoke(
+                &revoke(
+                    &token_program.key(),
+                    &token_account.key(),
+                    &wallet.key(),
+                    &[],
+                )
+                .unwrap(),
+                &[
+                    token_program.to_account_info(),
+                    token_account.to_account_info(),
+                    wallet.to_account_info(),
+                ],
+            )?;
+        }
     }
     close_account_anchor(seller_trade_state, wallet)?;
+    uncount_open_order(
+        optional_accounts.get(0),
+        optional_accounts.require(1)?,
+        &seller_trade_state.key(),
+        wallet,
+        &auction_house.key(),
+        OrderSide::Listing,
+    )?;
+    if let Some(mint_listing_index) = optional_accounts.get(2) {
+        update_mint_listing_index(
+            mint_listing_index,
+            token_mint.key,
+            seller_trade_state.key,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Unlocks and revokes program_as_signer as the utility delegate of the seller's pNFT, which a rental
+// ended without reclaim_rental leaves behind. The lock of any other delegate, or of a delegate whose
+// rental listing is still open, is left alone and cancelling only closes the listing. Only pNFTs
+// can be released this way, any other frozen account or missing pNFT accounts fail the cancel
+fn release_pnft_utility_delegate<'info>(
+    ctx: &Context<'_, '_, '_, 'info, CancelSell<'info>>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if remaining_accounts.len() < 12 {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+    let wallet = &ctx.accounts.wallet;
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let program_as_signer = &remaining_accounts[0];
+    let (
+        metadata,
+        edition,
+        token_record,
+        rental_listing,
+        token_metadata_program,
+        authorization_rules_program,
+        authorization_rules,
+        instructions,
+        system_program,
+    ) = (
+        &remaining_accounts[3],
+        &remaining_accounts[4],
+        &remaining_accounts[5],
+        &remaining_accounts[6],
+        &remaining_accounts[7],
+        &remaining_accounts[8],
+        &remaining_accounts[9],
+        &remaining_accounts[10],
+        &remaining_accounts[11],
+    );
+
+    let (program_as_signer_key, program_as_signer_bump) =
+        Pubkey::find_program_address(&[PREFIX.as_bytes(), SIGNER.as_bytes()], &crate::ID);
+    assert_keys_equal(program_as_signer.key, &program_as_signer_key)?;
+    assert_keys_equal(token_metadata_program.key, &mpl_token_metadata::ID)?;
+    assert_programmable_metadata(metadata, &token_mint.key())?;
+    assert_keys_equal(
+        token_record.key,
+        &TokenRecord::find_pda(&token_mint.key(), &token_account.key()).0,
+    )?;
+    let (delegate, delegate_role, token_state) =
+        get_delegate_info_and_token_state_from_token_record(token_record)?;
+    if delegate != Some(program_as_signer_key) || delegate_role != Some(TokenDelegateRole::Utility)
+    {
+        return Ok(());
+    }
+    assert_derivation(
+        &crate::ID,
+        rental_listing,
+        &[
+            PREFIX.as_bytes(),
+            RENTAL_LISTING.as_bytes(),
+            ctx.accounts.auction_house.key().as_ref(),
+            wallet.key.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+    )?;
+    if !rental_listing.data_is_empty() {
+        return Ok(());
+    }
+
+    let account_infos = [
+        program_as_signer.clone(),
+        wallet.to_account_info(),
+        token_account.to_account_info(),
+        token_mint.to_account_info(),
+        metadata.clone(),
+        edition.clone(),
+        token_record.clone(),
+        system_program.clone(),
+        instructions.clone(),
+        ctx.accounts.token_program.to_account_info(),
+        authorization_rules_program.clone(),
+        authorization_rules.clone(),
+        token_metadata_program.clone(),
+    ];
+    if token_state == TokenState::Locked {
+        let ins = UnlockV1Builder::new()
+            .authority(program_as_signer_key)
+            .token_owner(Some(wallet.key()))
+            .token(token_account.key())
+            .mint(token_mint.key())
+            .metadata(metadata.key())
+            .edition(Some(edition.key()))
+            .token_record(Some(token_record.key()))
+            .payer(wallet.key())
+            .system_program(system_program.key())
+            .sysvar_instructions(instructions.key())
+            .spl_token_program(Some(ctx.accounts.token_program.key()))
+            .authorization_rules_program(Some(authorization_rules_program.key()))
+            .authorization_rules(Some(authorization_rules.key()))
+            .instruction();
+        invoke_signed(
+            &ins,
+            &account_infos,
+            &[&[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[program_as_signer_bump],
+            ]],
+        )?;
+    }
+
+    let ins = RevokeUtilityV1Builder::new()
+        .delegate(program_as_signer_key)
+        .metadata(metadata.key())
+        .master_edition(Some(edition.key()))
+        .token_record(Some(token_record.key()))
+        .mint(token_mint.key())
+        .token(token_account.key())
+        .authority(wallet.key())
+        .payer(wallet.key())
+        .system_program(system_program.key())
+        .sysvar_instructions(instructions.key())
+        .spl_token_program(Some(ctx.accounts.token_program.key()))
+        .authorization_rules_program(Some(authorization_rules_program.key()))
+        .authorization_rules(Some(authorization_rules.key()))
+        .instruction();
+    invoke(&ins, &account_infos)?;
 
+    msg!(
+        "pnft_delegate_released: {{\"token_mint\":\"{}\",\"wallet\":\"{}\",\"was_locked\":{}}}",
+        token_mint.key(),
+        wallet.key(),
+        token_state == TokenState::Locked,
+    );
     Ok(())
 }
@@ -0,0 +1,33 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// CancelStakedListing closes a staked listing and returns its rent to the seller. The token stays
+// staked, the seller revokes the staked_listing PDA in the staking program on their own.
+#[derive(Accounts)]
+pub struct CancelStakedListing<'info> {
+    #[account(mut, address = staked_listing.seller)]
+    seller: Signer<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            STAKED_LISTING.as_bytes(),
+            staked_listing.auction_house_key.as_ref(),
+            seller.key().as_ref(),
+            staked_listing.token_mint.as_ref(),
+        ],
+        bump=staked_listing.bump,
+    )]
+    staked_listing: Box<Account<'info, StakedListing>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelStakedListing<'info>>) -> Result<()> {
+    let staked_listing = &ctx.accounts.staked_listing;
+    msg!(
+        "cancel_staked_listing: {{\"staked_listing\":\"{}\",\"seller\":\"{}\",\"token_mint\":\"{}\"}}",
+        staked_listing.key(),
+        staked_listing.seller,
+        staked_listing.token_mint,
+    );
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use {
+    crate::constants::*,
+    crate::states::*,
+    anchor_lang::prelude::*,
+    anchor_spl::token::{SetAuthority, Token},
+    spl_token::instruction::AuthorityType,
+};
+
+// CancelSwap withdraws a swap offer: the offered token account is handed back to the proposer and
+// the swap offer is closed into the proposer.
+#[derive(Accounts)]
+pub struct CancelSwap<'info> {
+    #[account(mut)]
+    proposer: Signer<'info>,
+    /// CHECK: checked against swap_offer, owned by program_as_signer
+    #[account(mut, address = swap_offer.offered_token_account)]
+    offered_token_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=proposer,
+        seeds=[
+            PREFIX.as_bytes(),
+            SWAP_OFFER.as_bytes(),
+            swap_offer.auction_house_key.as_ref(),
+            proposer.key().as_ref(),
+            swap_offer.offered_mint.as_ref(),
+        ],
+        bump=swap_offer.bump,
+        has_one=proposer,
+    )]
+    swap_offer: Box<Account<'info, SwapOffer>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>) -> Result<()> {
+    let proposer = &ctx.accounts.proposer;
+
+    anchor_spl::token::set_authority(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                account_or_mint: ctx.accounts.offered_token_account.to_account_info(),
+                current_authority: ctx.accounts.program_as_signer.to_account_info(),
+            },
+        )
+        .with_signer(&[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]]),
+        AuthorityType::AccountOwner,
+        Some(proposer.key()),
+    )?;
+
+    msg!(
+        "cancel_swap: {{\"swap_offer\":\"{}\"}}",
+        ctx.accounts.swap_offer.key(),
+    );
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use anchor_lang::Discriminator;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::token::{SetAuthority, Token},
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::instruction::AuthorityType,
+};
+
+// CancelWithProof is a permissionless instruction that closes an order in the auction house's
+// CancellationSnapshot, proven with a merkle proof of its trade state. The rent goes back to the
+// seller or buyer. A listing whose token account was handed to program_as_signer gets it back, an
+// escrowed bid keeps its funds in the escrow for the buyer to withdraw. Listings of frozen tokens
+// (pNFTs, OCP) have to be cancelled with their own cancel instruction, which can unlock them.
+// The cranker needs a CrankBond, cranking an order that isn't in the snapshot slashes
+// CRANK_BOND_LAMPORTS of it to the seller or buyer.
+#[derive(Accounts)]
+pub struct CancelWithProof<'info> {
+    cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), CRANK_BOND.as_bytes(), cranker.key().as_ref()],
+        bump=crank_bond.bump,
+        constraint = crank_bond.amount >= CRANK_BOND_LAMPORTS @ ErrorCode::InsufficientCrankBond,
+    )]
+    crank_bond: Account<'info, CrankBond>,
+    /// CHECK: seller of the listing or buyer of the bid, checked against the trade state
+    #[account(mut)]
+    wallet: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            CANCELLATION_SNAPSHOT.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=cancellation_snapshot.bump,
+    )]
+    cancellation_snapshot: Box<Account<'info, CancellationSnapshot>>,
+    /// CHECK: seeds check and check sell_args or bid_args
+    #[account(mut, owner = crate::ID)]
+    trade_state: AccountInfo<'info>,
+    /// CHECK: token account of a listing, checked against sell_args, not read for bids
+    #[account(mut)]
+    token_account: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the wallet, derivation checked in uncount_open_order
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelWithProof<'info>>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let trade_state = &ctx.accounts.trade_state;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let merkle_root = ctx.accounts.cancellation_snapshot.merkle_root;
+
+    let is_listing = {
+        let data = trade_state.try_borrow_data()?;
+        if data.len() < 8 {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+        if data[0..8] == SellerTradeState::discriminator()
+            || data[0..8] == SellerTradeStateV2::discriminator()
+        {
+            true
+        } else if data[0..8] == BuyerTradeState::discriminator()
+            || data[0..8] == BuyerTradeStateV2::discriminator()
+        {
+            false
+        } else {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+    };
+    let sell_args = if is_listing {
+        let sell_args = SellArgs::from_account_info(trade_state)?;
+        assert_keys_equal(wallet.key, &sell_args.seller)?;
+        assert_keys_equal(&auction_house_key, &sell_args.auction_house_key)?;
+        assert_keys_equal(ctx.accounts.token_account.key, &sell_args.token_account)?;
+        assert_trade_state_key(
+            trade_state,
+            &[
+                PREFIX.as_bytes(),
+                sell_args.seller.as_ref(),
+                sell_args.auction_house_key.as_ref(),
+                sell_args.token_account.as_ref(),
+                sell_args.token_mint.as_ref(),
+                &[sell_args.bump],
+            ],
+        )?;
+        Some(sell_args)
+    } else {
+        let bid_args = BidArgs::from_account_info(trade_state)?;
+        assert_keys_equal(wallet.key, &bid_args.buyer)?;
+        assert_keys_equal(&auction_house_key, &bid_args.auction_house_key)?;
+        assert_trade_state_key(
+            trade_state,
+            &[
+                PREFIX.as_bytes(),
+                bid_args.buyer.as_ref(),
+                bid_args.auction_house_key.as_ref(),
+                bid_args.token_mint.as_ref(),
+                &[bid_args.bump],
+            ],
+        )?;
+        None
+    };
+
+    // the trade state is checked to be the wallet's, so the slash goes to the wallet it was
+    // cranked against
+    if merkle_root == [0; 32]
+        || !verify_merkle_proof(get_cancellation_leaf(trade_state.key), &proof, &merkle_root)
+    {
+        return slash_crank_bond(&mut ctx.accounts.crank_bond, wallet);
+    }
+    let side = match &sell_args {
+        Some(sell_args) => {
+            return_listed_token(&ctx, sell_args)?;
+            OrderSide::Listing
+        }
+        None => OrderSide::Bid,
+    };
+
+    close_account_anchor(trade_state, wallet)?;
+    uncount_open_order(
+        Some(&ctx.accounts.wallet_order_counter),
+        &ctx.accounts.counted_order,
+        &trade_state.key(),
+        wallet,
+        &auction_house_key,
+        side,
+    )?;
+
+    msg!(
+        "cancel_with_proof: {{\"trade_state\":\"{}\",\"wallet\":\"{}\",\"cancellation_snapshot\":\"{}\",\"is_bid\":{}}}",
+        trade_state.key(),
+        wallet.key(),
+        ctx.accounts.cancellation_snapshot.key(),
+        side == OrderSide::Bid,
+    );
+    Ok(())
+}
+
+fn assert_trade_state_key(trade_state: &AccountInfo, seeds: &[&[u8]]) -> Result<()> {
+    let derived_key = Pubkey::create_program_address(seeds, &crate::ID)
+        .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+    if derived_key != *trade_state.key {
+        return Err(ErrorCode::DerivedKeyInvalid.into());
+    }
+    Ok(())
+}
+
+// hands the seller's ata back to the seller if sell gave it to program_as_signer, delegate based
+// listings leave the token with the seller and need nothing
+fn return_listed_token(ctx: &Context<CancelWithProof>, sell_args: &SellArgs) -> Result<()> {
+    let token_account = &ctx.accounts.token_account;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    if token_account.data_is_empty() || token_account.owner != &spl_token::id() {
+        return Ok(());
+    }
+    let account: spl_token::state::Account = assert_initialized(token_account)?;
+    if account.is_frozen() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if account.owner != program_as_signer.key() {
+        return Ok(());
+    }
+    // escrowed listings hold the token in an account of program_as_signer itself
+    if *token_account.key != get_associated_token_address(&sell_args.seller, &sell_args.token_mint)
+    {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    anchor_spl::token::set_authority(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                account_or_mint: token_account.to_account_info(),
+                current_authority: program_as_signer.to_account_info(),
+            },
+        )
+        .with_signer(&[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]]),
+        AuthorityType::AccountOwner,
+        Some(sell_args.seller),
+    )
+}
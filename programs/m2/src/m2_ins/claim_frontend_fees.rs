@@ -0,0 +1,45 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// ClaimFrontendFees withdraws the fee share accumulated in a FrontendConfig to the frontend wallet,
+// keeping the FrontendConfig rent exempt.
+#[derive(Accounts)]
+pub struct ClaimFrontendFees<'info> {
+    #[account(mut)]
+    frontend: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            FRONTEND_CONFIG.as_bytes(),
+            frontend_config.auction_house_key.as_ref(),
+            frontend.key().as_ref(),
+        ],
+        bump=frontend_config.bump,
+        has_one=frontend,
+    )]
+    frontend_config: Account<'info, FrontendConfig>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ClaimFrontendFees<'info>>) -> Result<()> {
+    let frontend = &ctx.accounts.frontend;
+    let frontend_config = ctx.accounts.frontend_config.to_account_info();
+
+    let claimable = frontend_config
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(frontend_config.data_len()));
+    if claimable == 0 {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    **frontend_config.lamports.borrow_mut() -= claimable;
+    **frontend.lamports.borrow_mut() = frontend
+        .lamports()
+        .checked_add(claimable)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "claim_frontend_fees: {{\"frontend\":\"{}\",\"amount\":{}}}",
+        frontend.key(),
+        claimable,
+    );
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+
+// ClaimRebate pays the winner of an epoch's fee rebate draw its prize out of the treasury, which
+// keeps MIN_TREASURY_LEFTOVER like for withdrawals. The drawn RebateEpoch is closed to the
+// randomness_authority that paid for it.
+#[derive(Accounts)]
+pub struct ClaimRebate<'info> {
+    #[account(mut)]
+    winner: Signer<'info>,
+    /// CHECK: randomness_authority, receives the rent of rebate_epoch
+    #[account(mut)]
+    randomness_authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=auction_house_treasury)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_CAMPAIGN.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=rebate_campaign.bump,
+        has_one=randomness_authority,
+    )]
+    rebate_campaign: Account<'info, RebateCampaign>,
+    #[account(
+        mut,
+        close=randomness_authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_EPOCH.as_bytes(),
+            auction_house.key().as_ref(),
+            rebate_epoch.epoch.to_le_bytes().as_ref(),
+        ],
+        bump=rebate_epoch.bump,
+        constraint = rebate_epoch.drawn && rebate_epoch.winner == winner.key() @ ErrorCode::InvalidAccountState,
+    )]
+    rebate_epoch: Box<Account<'info, RebateEpoch>>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRebate<'info>>) -> Result<()> {
+    let winner = &ctx.accounts.winner;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let rebate_epoch = &ctx.accounts.rebate_epoch;
+    let prize = rebate_epoch.prize;
+
+    if auction_house_treasury.lamports().saturating_sub(prize) < MIN_TREASURY_LEFTOVER {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if prize > 0 {
+        let ah_key = ctx.accounts.auction_house.key();
+        invoke_signed(
+            &system_instruction::transfer(auction_house_treasury.key, winner.key, prize),
+            &[
+                auction_house_treasury.to_account_info(),
+                winner.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[
+                PREFIX.as_bytes(),
+                ah_key.as_ref(),
+                TREASURY.as_bytes(),
+                &[ctx.accounts.auction_house.treasury_bump],
+            ]],
+        )?;
+    }
+
+    msg!(
+        "claim_rebate: {{\"epoch\":{},\"winner\":\"{}\",\"prize\":{}}}",
+        rebate_epoch.epoch,
+        winner.key(),
+        prize,
+    );
+    Ok(())
+}
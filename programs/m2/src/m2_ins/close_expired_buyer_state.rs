@@ -0,0 +1,119 @@
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*, anchor_spl::token::Token,
+};
+
+// CloseExpiredBuyerState is a permissionless crank for bids past their buyer_state_expiry. Anyone
+// can close the buyer_trade_state: up to the bid price is refunded from the escrow to the buyer,
+// the cranker gets EXPIRED_BID_CRANK_TIP_LAMPORTS out of the trade state rent, and the rest of the
+// rent goes back to the buyer. Bids that never expire can only be cancelled by the buyer. The
+// cranker needs a CrankBond, cranking a bid that hasn't expired slashes CRANK_BOND_LAMPORTS of it
+// to the buyer.
+#[derive(Accounts)]
+pub struct CloseExpiredBuyerState<'info> {
+    #[account(mut)]
+    cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), CRANK_BOND.as_bytes(), cranker.key().as_ref()],
+        bump=crank_bond.bump,
+        constraint = crank_bond.amount >= CRANK_BOND_LAMPORTS @ ErrorCode::InsufficientCrankBond,
+    )]
+    crank_bond: Account<'info, CrankBond>,
+    /// CHECK: wallet of the buyer, checked against bid_args, receives the refund
+    #[account(mut)]
+    wallet: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: seeds check and check bid_args
+    #[account(mut, owner = crate::ID)]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the wallet, derivation checked in uncount_open_order
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** IF THE BID IS PAID IN SPL **
+    // 0. payment_mint (required) - the payment mint of the bid
+    // 1. payment_source_token_account (required) - ata of escrow_payment_account
+    // 2. payment_destination_token_account (required) - ata of wallet, created by the cranker if needed
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CloseExpiredBuyerState<'info>>) -> Result<()> {
+    let cranker = &ctx.accounts.cranker;
+    let wallet = &ctx.accounts.wallet;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+    assert_keys_equal(wallet.key, &bid_args.buyer)?;
+    assert_keys_equal(&auction_house_key, &bid_args.auction_house_key)?;
+    let derived_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            bid_args.buyer.as_ref(),
+            bid_args.auction_house_key.as_ref(),
+            bid_args.token_mint.as_ref(),
+            &[bid_args.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+    if derived_key != *buyer_trade_state.key {
+        return Err(ErrorCode::DerivedKeyInvalid.into());
+    }
+    if !is_trade_state_expired(bid_args.expiry, Clock::get()?.unix_timestamp) {
+        return slash_crank_bond(&mut ctx.accounts.crank_bond, wallet);
+    }
+
+    let refund = refund_bid_escrow(
+        &bid_args,
+        cranker,
+        wallet,
+        &ctx.accounts.escrow_payment_account,
+        ctx.remaining_accounts,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &[&[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            wallet.key.as_ref(),
+            &[ctx.bumps.escrow_payment_account],
+        ]],
+    )?;
+
+    let tip = EXPIRED_BID_CRANK_TIP_LAMPORTS.min(buyer_trade_state.lamports());
+    **buyer_trade_state.lamports.borrow_mut() -= tip;
+    **cranker.lamports.borrow_mut() = cranker
+        .lamports()
+        .checked_add(tip)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    close_account_anchor(buyer_trade_state, wallet)?;
+    uncount_open_order(
+        Some(&ctx.accounts.wallet_order_counter),
+        &ctx.accounts.counted_order,
+        &buyer_trade_state.key(),
+        wallet,
+        &auction_house_key,
+        OrderSide::Bid,
+    )?;
+
+    msg!(
+        "close_expired_buyer_state: {{\"buyer_trade_state\":\"{}\",\"buyer\":\"{}\",\"payment_mint\":\"{}\",\"refund\":{},\"tip\":{}}}",
+        buyer_trade_state.key(),
+        wallet.key(),
+        bid_args.payment_mint,
+        refund,
+        tip,
+    );
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// CloseFillProof is a permissionless crank for fill proofs older than FILL_PROOF_LIFETIME_SLOTS,
+// their rent goes back to whoever paid for them at settlement. Programs reading a fill proof have
+// to do so within that window.
+#[derive(Accounts)]
+pub struct CloseFillProof<'info> {
+    cranker: Signer<'info>,
+    /// CHECK: checked against fill_proof
+    #[account(mut, address = fill_proof.rent_payer)]
+    rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=rent_payer,
+        seeds=[
+            PREFIX.as_bytes(),
+            FILL_PROOF.as_bytes(),
+            fill_proof.buyer.as_ref(),
+            fill_proof.token_mint.as_ref(),
+        ],
+        bump=fill_proof.bump,
+    )]
+    fill_proof: Account<'info, FillProof>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CloseFillProof<'info>>) -> Result<()> {
+    let fill_proof = &ctx.accounts.fill_proof;
+    let closable_at = fill_proof.slot.saturating_add(FILL_PROOF_LIFETIME_SLOTS);
+    if Clock::get()?.slot < closable_at {
+        return Err(ErrorCode::FillProofNotExpired.into());
+    }
+
+    msg!(
+        "close_fill_proof: {{\"buyer\":\"{}\",\"token_mint\":\"{}\",\"trade_seq\":{}}}",
+        fill_proof.buyer,
+        fill_proof.token_mint,
+        fill_proof.trade_seq,
+    );
+    Ok(())
+}
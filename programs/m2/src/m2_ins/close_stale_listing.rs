@@ -0,0 +1,100 @@
+use anchor_lang::Discriminator;
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*,
+};
+
+// CloseStaleListing is a permissionless crank that closes a listing whose token account no longer
+// backs it. It doesn't hook into the transfer itself, so a stale listing stays open until someone
+// cranks it, it just can't be filled in the meantime. Listings of sell and sell_batch can't go
+// stale since the token account is owned by program_as_signer (the non-movable mode, the only one
+// sell accepts), and neither can mip1_sell listings, which are escrowed in an ata of
+// program_as_signer, or ocp_sell listings, which are locked by OCP. The ones that can are delegate
+// based: t22_sell listings, where the seller keeps the token and delegates it to program_as_signer,
+// and SellerTradeState listings left over from the movable mode. The rent goes back to the seller.
+// The cranker needs a CrankBond, see deposit_crank_bond. Cranking a listing that is still backed
+// doesn't fail, instead CRANK_BOND_LAMPORTS of the bond are slashed to the seller.
+#[derive(Accounts)]
+pub struct CloseStaleListing<'info> {
+    cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), CRANK_BOND.as_bytes(), cranker.key().as_ref()],
+        bump=crank_bond.bump,
+        constraint = crank_bond.amount >= CRANK_BOND_LAMPORTS @ ErrorCode::InsufficientCrankBond,
+    )]
+    crank_bond: Account<'info, CrankBond>,
+    /// CHECK: seller, checked against sell_args, receives the sts rent
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: token_account, checked against sell_args, can be closed already
+    token_account: UncheckedAccount<'info>,
+    /// CHECK: seeds check and check sell_args
+    #[account(mut, owner = crate::ID)]
+    seller_trade_state: AccountInfo<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the seller, derivation checked in uncount_open_order
+    #[account(mut)]
+    seller_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    seller_counted_order: UncheckedAccount<'info>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CloseStaleListing<'info>>) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let token_account = &ctx.accounts.token_account;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+
+    {
+        let data = seller_trade_state.try_borrow_data()?;
+        if data.len() < 8
+            || (data[0..8] != SellerTradeState::discriminator()
+                && data[0..8] != SellerTradeStateV2::discriminator())
+        {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+    }
+    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+    assert_keys_equal(seller.key, &sell_args.seller)?;
+    assert_keys_equal(token_account.key, &sell_args.token_account)?;
+    let derived_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            sell_args.seller.as_ref(),
+            sell_args.auction_house_key.as_ref(),
+            sell_args.token_account.as_ref(),
+            sell_args.token_mint.as_ref(),
+            &[sell_args.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+    if derived_key != *seller_trade_state.key {
+        return Err(ErrorCode::DerivedKeyInvalid.into());
+    }
+
+    if is_listing_backed(token_account, &sell_args, program_as_signer.key)? {
+        return slash_crank_bond(&mut ctx.accounts.crank_bond, seller);
+    }
+    close_account_anchor(seller_trade_state, seller)?;
+    uncount_open_order(
+        Some(&ctx.accounts.seller_order_counter),
+        &ctx.accounts.seller_counted_order,
+        &seller_trade_state.key(),
+        seller,
+        &sell_args.auction_house_key,
+        OrderSide::Listing,
+    )?;
+
+    msg!(
+        "close_stale_listing: {{\"seller_trade_state\":\"{}\",\"token_mint\":\"{}\"}}",
+        seller_trade_state.key(),
+        sell_args.token_mint,
+    );
+    Ok(())
+}
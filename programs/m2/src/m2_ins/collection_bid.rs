@@ -0,0 +1,182 @@
+use solana_program::{program::invoke, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CollectionBidArgs {
+    pub price: u64,
+    pub quantity: u16,
+    pub expiry: i64,
+    pub buyer_creator_royalty_bp: u16,
+    pub royalty_inclusive: bool,
+    pub trait_hash: [u8; 32], // zero to accept any token of the collection
+    pub allowed_auction_houses: [Pubkey; 2], // default for none, have to share the authority at fill time
+}
+
+// CollectionBid places (or updates) a bid for any token of a verified collection, a seller fills
+// it with sell_into_collection_bid. A non zero trait_hash scopes the bid to tokens with the hashed
+// attributes, which the notary attests to by signing the fill. Funds stay in the buyer's escrow_payment_account like for
+// single mint bids, so one deposit can back both kinds of bids. The bid can also be filled on up
+// to two other auction houses of the same authority, e.g. a USDC house next to the SOL house,
+// the funds still come from the escrow of this auction house.
+#[derive(Accounts)]
+pub struct CollectionBid<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// CHECK: verified collection key, usually the collection mint, or the token group of Token-2022 members
+    collection: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_BID.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=CollectionBidState::LEN,
+        bump,
+    )]
+    collection_bid_state: Account<'info, CollectionBidState>,
+    /// CHECK: buyer_referral
+    buyer_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_key_not_paused
+    collection_config: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the buyer is paying in a token, this is the mint of that token
+    // 1. payment_source_token_account (optional) - if the buyer is paying in a token, this is the source token account, we need to verify sufficient balance
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectionBid<'info>>,
+    args: CollectionBidArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let collection_bid_state = &mut ctx.accounts.collection_bid_state;
+    let remaining_accounts = ctx.remaining_accounts;
+    let is_spl = remaining_accounts.len() == 2;
+
+    if args.buyer_creator_royalty_bp > 10_000 {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.quantity == 0 || args.quantity > MAX_COLLECTION_BID_QUANTITY {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    let payment_mint = if is_spl {
+        index_ra!(remaining_accounts, 0).key()
+    } else {
+        Pubkey::default()
+    };
+    if args.allowed_auction_houses.contains(&auction_house.key()) {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if collection_bid_state.bump != 0 && collection_bid_state.payment_mint != payment_mint {
+        // the payment mint of an existing bid can't be changed, cancel and bid again instead
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    // the escrow has to cover every token the bid still wants
+    let total_price = args
+        .price
+        .checked_mul(args.quantity as u64)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if remaining_accounts.is_empty() {
+        // SOL
+        if escrow_payment_account.lamports() < total_price {
+            let diff = total_price
+                .checked_sub(escrow_payment_account.lamports())
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            invoke(
+                &system_instruction::transfer(wallet.key, &escrow_payment_account.key(), diff),
+                &[
+                    wallet.to_account_info(),
+                    escrow_payment_account.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else if is_spl {
+        // SPL
+        assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.price, index_ra!(remaining_accounts, 0))?;
+        let payment_token_account_parsed = assert_is_ata(
+            index_ra!(remaining_accounts, 1),
+            escrow_payment_account.key,
+            index_ra!(remaining_accounts, 0).key,
+            escrow_payment_account.key,
+        )?;
+        if payment_token_account_parsed.amount < total_price {
+            return Err(ErrorCode::InvalidTokenAmount.into());
+        }
+    } else {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    assert_collection_key_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        ctx.accounts.collection.key,
+    )?;
+    if !is_spl {
+        assert_collection_price_on_tick(
+            &ctx.accounts.collection_config,
+            &auction_house.key(),
+            ctx.accounts.collection.key,
+            args.price,
+        )?;
+    }
+
+    collection_bid_state.auction_house_key = auction_house.key();
+    collection_bid_state.buyer = wallet.key();
+    collection_bid_state.buyer_referral = ctx.accounts.buyer_referral.key();
+    collection_bid_state.collection = ctx.accounts.collection.key();
+    collection_bid_state.buyer_price = args.price;
+    collection_bid_state.quantity = args.quantity;
+    collection_bid_state.payment_mint = payment_mint;
+    collection_bid_state.buyer_creator_royalty_bp = args.buyer_creator_royalty_bp;
+    collection_bid_state.royalty_inclusive = args.royalty_inclusive;
+    collection_bid_state.expiry = get_default_buyer_state_expiry(args.expiry);
+    collection_bid_state.bump = ctx.bumps.collection_bid_state;
+    collection_bid_state.trait_hash = args.trait_hash;
+    collection_bid_state.allowed_auction_houses = args.allowed_auction_houses;
+
+    msg!(
+        "collection_bid: {{\"collection\":\"{}\",\"price\":{},\"quantity\":{},\"buyer_expiry\":{},\"royalty_inclusive\":{},\"trait_bid\":{},\"allowed_auction_houses\":{}}}",
+        collection_bid_state.collection,
+        collection_bid_state.buyer_price,
+        collection_bid_state.quantity,
+        collection_bid_state.expiry,
+        collection_bid_state.royalty_inclusive,
+        collection_bid_state.trait_hash != [0; 32],
+        collection_bid_state
+            .allowed_auction_houses
+            .iter()
+            .filter(|k| **k != Pubkey::default())
+            .count(),
+    );
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use solana_program::{program::invoke, system_instruction};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CommitBidArgs {
+    pub commitment: [u8; 32],
+    pub deposit: u64,
+}
+
+// CommitBid places a hidden bid in a sealed auction. The commitment is the keccak hash of the
+// auction_state, the bidder, the price and a salt (see SealedBid::get_commitment), and the deposit
+// has to cover the price, it's held by the sealed_bid until reveal_bid or reclaim_sealed_bid.
+#[derive(Accounts)]
+pub struct CommitBid<'info> {
+    #[account(mut)]
+    bidder: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            AUCTION.as_bytes(),
+            auction_state.auction_house_key.as_ref(),
+            auction_state.token_mint.as_ref(),
+        ],
+        bump=auction_state.bump,
+    )]
+    auction_state: Box<Account<'info, AuctionState>>,
+    #[account(
+        init,
+        payer=bidder,
+        seeds=[
+            PREFIX.as_bytes(),
+            SEALED_BID.as_bytes(),
+            auction_state.key().as_ref(),
+            bidder.key().as_ref(),
+        ],
+        space=SealedBid::LEN,
+        bump,
+    )]
+    sealed_bid: Box<Account<'info, SealedBid>>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CommitBid<'info>>,
+    args: CommitBidArgs,
+) -> Result<()> {
+    let bidder = &ctx.accounts.bidder;
+    let auction_state = &mut ctx.accounts.auction_state;
+
+    let now = Clock::get()?.unix_timestamp;
+    if !auction_state.is_sealed()
+        || !is_start_reached(auction_state.start_time, now)
+        || now >= auction_state.end_time
+    {
+        return Err(ErrorCode::AuctionNotActive.into());
+    }
+    if bidder.key() == auction_state.seller {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if args.deposit > MAX_PRICE || args.deposit < auction_state.start_price {
+        return Err(ErrorCode::AuctionBidTooLow.into());
+    }
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, bidder.key)?;
+
+    invoke(
+        &system_instruction::transfer(bidder.key, &ctx.accounts.sealed_bid.key(), args.deposit),
+        &[
+            bidder.to_account_info(),
+            ctx.accounts.sealed_bid.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let sealed_bid = &mut ctx.accounts.sealed_bid;
+    sealed_bid.auction_state = auction_state.key();
+    sealed_bid.bidder = bidder.key();
+    sealed_bid.commitment = args.commitment;
+    sealed_bid.deposit = args.deposit;
+    sealed_bid.reveal_end_time = auction_state.reveal_end_time;
+    sealed_bid.bump = ctx.bumps.sealed_bid;
+    auction_state.commit_count = auction_state
+        .commit_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "commit_bid: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"deposit\":{},\"commit_count\":{}}}",
+        sealed_bid.auction_state,
+        sealed_bid.bidder,
+        sealed_bid.deposit,
+        auction_state.commit_count,
+    );
+    Ok(())
+}
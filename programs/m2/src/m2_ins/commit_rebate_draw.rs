@@ -0,0 +1,70 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CommitRebateDrawArgs {
+    pub epoch: u64,
+    pub commitment: [u8; 32], // keccak of the seed draw_rebate reveals
+}
+
+// CommitRebateDraw opens the fee rebate draw of a future epoch, committing to its seed. Only
+// settlements of committed epochs are entered.
+#[derive(Accounts)]
+#[instruction(args: CommitRebateDrawArgs)]
+pub struct CommitRebateDraw<'info> {
+    #[account(mut)]
+    randomness_authority: Signer<'info>,
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_CAMPAIGN.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=rebate_campaign.bump,
+        has_one=randomness_authority,
+        constraint = rebate_campaign.enabled @ ErrorCode::InvalidAccountState,
+    )]
+    rebate_campaign: Account<'info, RebateCampaign>,
+    #[account(
+        init,
+        payer=randomness_authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_EPOCH.as_bytes(),
+            auction_house.key().as_ref(),
+            args.epoch.to_le_bytes().as_ref(),
+        ],
+        space=RebateEpoch::LEN,
+        bump,
+    )]
+    rebate_epoch: Box<Account<'info, RebateEpoch>>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CommitRebateDraw<'info>>,
+    args: CommitRebateDrawArgs,
+) -> Result<()> {
+    // the seed has to be fixed before any settlement of the epoch is entered
+    if args.epoch <= Clock::get()?.epoch {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    let rebate_epoch = &mut ctx.accounts.rebate_epoch;
+    rebate_epoch.auction_house_key = ctx.accounts.auction_house.key();
+    rebate_epoch.epoch = args.epoch;
+    rebate_epoch.commitment = args.commitment;
+    rebate_epoch.bump = ctx.bumps.rebate_epoch;
+
+    msg!(
+        "commit_rebate_draw: {{\"rebate_epoch\":\"{}\",\"epoch\":{}}}",
+        rebate_epoch.key(),
+        rebate_epoch.epoch,
+    );
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::{program::invoke, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Mint, Token, TokenAccount},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAuctionArgs {
+    pub start_price: u64,
+    pub min_increment_bp: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub extension_window: i64,
+    pub extension_seconds: i64,
+    pub reserve_price: u64,
+    pub reveal_end_time: i64, // 0 for an english auction
+    pub buy_now_price: u64,   // 0 to disable buy now
+}
+
+// CreateAuction starts an english auction: the token moves into a token_vault owned by the
+// auction_escrow PDA, bids are escrowed by place_auction_bid and settle_auction pays out the
+// highest bid once the auction ended. The seller funds the auction_escrow rent, it's returned at
+// settlement. A bid placed within extension_window seconds of the end extends the auction by
+// extension_seconds, so it can't be sniped at the last second. If the highest bid stays below the
+// reserve_price, settlement refunds it and returns the token to the seller. With a reveal_end_time
+// the auction is sealed: commit_bid takes hidden bids until end_time, reveal_bid opens them until
+// reveal_end_time and settlement pays out the highest revealed bid. An english auction can carry a
+// buy_now_price: the first bid reaching it ends the auction, refunds the outbid bidder and can be
+// settled right away. Prices of SOL auctions sit on the collection's tick, which bids keep to.
+#[derive(Accounts)]
+pub struct CreateAuction<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(mut, token::mint = token_mint, token::authority = seller)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init,
+        payer=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            AUCTION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        space=AuctionState::LEN,
+        bump,
+    )]
+    auction_state: Box<Account<'info, AuctionState>>,
+    /// CHECK: holds the bids and owns the token_vault
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), AUCTION_ESCROW.as_bytes(), auction_state.key().as_ref()],
+        bump,
+    )]
+    auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: ata of auction_escrow for token_mint, created and checked in transfer_token
+    #[account(mut)]
+    token_vault: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the seller wants payment in a SPL token, this is the mint of that token
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAuction<'info>>,
+    args: CreateAuctionArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller = &ctx.accounts.seller;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_escrow = &ctx.accounts.auction_escrow;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+    let start_time = args.start_time.max(now);
+    if args.end_time <= start_time || args.end_time - start_time > MAX_AUCTION_DURATION_SECONDS {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if args.extension_window < 0
+        || args.extension_seconds < 0
+        || args.extension_window > MAX_AUCTION_EXTENSION_SECONDS
+        || args.extension_seconds > MAX_AUCTION_EXTENSION_SECONDS
+    {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if args.start_price > MAX_PRICE || args.start_price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.reveal_end_time != 0
+        && (args.reveal_end_time <= args.end_time
+            || args.reveal_end_time - args.end_time > MAX_AUCTION_REVEAL_SECONDS
+            || args.extension_seconds != 0)
+    {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if args.reserve_price > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.buy_now_price > MAX_PRICE
+        || (args.buy_now_price > 0
+            && (args.buy_now_price < args.start_price
+                || args.buy_now_price < args.reserve_price
+                || args.reveal_end_time != 0))
+    {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.min_increment_bp > 10_000 {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    let payment_mint = if remaining_accounts.len() == 1 {
+        // sealed bids are deposited in lamports
+        if args.reveal_end_time != 0 {
+            return Err(ErrorCode::InvalidTokenMint.into());
+        }
+        assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.start_price, index_ra!(remaining_accounts, 0))?;
+        if args.reserve_price > 0 {
+            assert_price_precision(args.reserve_price, index_ra!(remaining_accounts, 0))?;
+        }
+        if args.buy_now_price > 0 {
+            assert_price_precision(args.buy_now_price, index_ra!(remaining_accounts, 0))?;
+        }
+        index_ra!(remaining_accounts, 0).key()
+    } else {
+        Pubkey::default()
+    };
+
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    // SPL prices are only held to the precision of their mint
+    let tick_size = if payment_mint == Pubkey::default() {
+        get_collection_tick_size(
+            &ctx.accounts.collection_config,
+            &auction_house.key(),
+            &get_collection_key(&metadata_parsed),
+        )?
+    } else {
+        0
+    };
+    assert_price_on_tick(args.start_price, tick_size)?;
+    assert_price_on_tick(args.reserve_price, tick_size)?;
+    assert_price_on_tick(args.buy_now_price, tick_size)?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+
+    transfer_token(
+        &1,
+        seller,
+        seller,
+        seller,
+        None,
+        DestinationSpecifier::Ai(auction_escrow),
+        &token_mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.token_vault,
+        &ctx.accounts.token_program,
+        system_program,
+        None,
+        &[],
+    )?;
+    invoke(
+        &system_instruction::transfer(
+            seller.key,
+            auction_escrow.key,
+            Rent::get()?.minimum_balance(0),
+        ),
+        &[
+            seller.to_account_info(),
+            auction_escrow.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    let auction_state = &mut ctx.accounts.auction_state;
+    auction_state.auction_house_key = auction_house.key();
+    auction_state.seller = seller.key();
+    auction_state.token_mint = token_mint.key();
+    auction_state.payment_mint = payment_mint;
+    auction_state.start_price = args.start_price;
+    auction_state.min_increment_bp = args.min_increment_bp;
+    auction_state.start_time = start_time;
+    auction_state.end_time = args.end_time;
+    auction_state.extension_window = args.extension_window;
+    auction_state.extension_seconds = args.extension_seconds;
+    auction_state.reserve_price = args.reserve_price;
+    auction_state.reveal_end_time = args.reveal_end_time;
+    auction_state.buy_now_price = args.buy_now_price;
+    auction_state.tick_size = tick_size;
+    auction_state.bump = ctx.bumps.auction_state;
+    auction_state.escrow_bump = ctx.bumps.auction_escrow;
+
+    msg!(
+        "create_auction: {{\"auction\":\"{}\",\"token_mint\":\"{}\",\"payment_mint\":\"{}\",\"start_price\":{},\"start_time\":{},\"end_time\":{},\"reveal_end_time\":{},\"buy_now_price\":{}}}",
+        auction_state.key(),
+        auction_state.token_mint,
+        auction_state.payment_mint,
+        auction_state.start_price,
+        auction_state.start_time,
+        auction_state.end_time,
+        auction_state.reveal_end_time,
+        auction_state.buy_now_price,
+    );
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Mint, Token},
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[derive(Accounts)]
+pub struct CustodyCancelSell<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    token_mint: Account<'info, Mint>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: custody_vault, checked in seeds
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), CUSTODY_VAULT.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    custody_vault: UncheckedAccount<'info>,
+    /// CHECK: ata(wallet, token_mint), created if needed
+    #[account(mut, address = get_associated_token_address(&wallet.key(), &token_mint.key()))]
+    token_ata: UncheckedAccount<'info>,
+    /// CHECK: seeds check and check sell_args
+    #[account(
+        mut,
+        seeds=[
+          PREFIX.as_bytes(),
+          wallet.key().as_ref(),
+          auction_house.key().as_ref(),
+          custody_vault.key().as_ref(),
+          token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    seller_trade_state: AccountInfo<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CustodyCancelSell<'info>>) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let token_mint = &ctx.accounts.token_mint;
+    let custody_vault = &ctx.accounts.custody_vault;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+
+    if seller_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+    assert_keys_equal(&sell_args.token_account, custody_vault.key)?;
+    assert_keys_equal(&sell_args.token_mint, &token_mint.key())?;
+
+    // the vault is closed into the seller once it's empty
+    transfer_token(
+        &sell_args.token_size,
+        wallet,
+        program_as_signer,
+        wallet,
+        None,
+        DestinationSpecifier::Ai(wallet),
+        token_mint.as_ref(),
+        custody_vault,
+        &ctx.accounts.token_ata,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        None,
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+    close_account_anchor(seller_trade_state, wallet)?;
+
+    msg!(
+        "custody_cancel_sell: {{\"seller_trade_state\":\"{}\",\"custody_vault\":\"{}\"}}",
+        seller_trade_state.key(),
+        custody_vault.key()
+    );
+    Ok(())
+}
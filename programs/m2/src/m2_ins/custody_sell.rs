@@ -0,0 +1,223 @@
+use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CustodySellArgs {
+    pub price: u64,
+    pub expiry: i64,
+}
+
+// CustodySell is the classic escrow listing mode: the token is transferred into a custody vault
+// owned by program_as_signer when listed, execute_sale_v2 transfers it out of the vault and
+// custody_cancel_sell returns it to the seller. Calling it again on a listed token changes the price.
+#[derive(Accounts)]
+pub struct CustodySell<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// token_account that holds the token, can be the custody_vault when changing price
+    #[account(mut, token::mint = token_mint)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[PREFIX.as_bytes(), CUSTODY_VAULT.as_bytes(), token_mint.key().as_ref()],
+        token::mint = token_mint,
+        token::authority = program_as_signer,
+        bump,
+    )]
+    custody_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in seeds
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            custody_vault.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    seller_trade_state: UncheckedAccount<'info>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_hold_period_elapsed
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the seller wants payment in a SPL token, this is the mint of that token
+    // ...
+    // -1. payer (optional) - this wallet will try to pay for sts rent
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, CustodySell<'info>>,
+    args: CustodySellArgs,
+) -> Result<()> {
+    let (remaining_accounts, possible_payer) =
+        split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let wallet = &ctx.accounts.wallet;
+    let payer = if let Some(p) = possible_payer {
+        p
+    } else {
+        wallet
+    };
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let custody_vault = &ctx.accounts.custody_vault;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let payment_mint = if remaining_accounts.len() == 1 {
+        assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.price, index_ra!(remaining_accounts, 0))?;
+        *index_ra!(remaining_accounts, 0).key
+    } else {
+        Pubkey::default()
+    };
+
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    // negative expiry, non-movable listing mode like the other listing modes
+    if args.expiry >= 0 {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_hold_period_elapsed(
+        &ctx.accounts.collection_config,
+        &ctx.accounts.last_sale,
+        &auction_house.key(),
+        &metadata_parsed,
+        wallet.key,
+    )?;
+    if payment_mint == Pubkey::default() {
+        assert_collection_price_on_tick(
+            &ctx.accounts.collection_config,
+            &auction_house.key(),
+            &get_collection_key(&metadata_parsed),
+            args.price,
+        )?;
+    }
+
+    if seller_trade_state.data_is_empty() {
+        if custody_vault.amount != 0 {
+            // already in custody of another listing, e.g. from another auction house
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: token_account.to_account_info(),
+                    to: custody_vault.to_account_info(),
+                    authority: wallet.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+    } else {
+        let discriminator_ai = seller_trade_state.try_borrow_data()?;
+        if discriminator_ai[..8] != SellerTradeStateV2::discriminator() {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+        if custody_vault.amount != 1 {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+    }
+
+    create_or_realloc_seller_trade_state(
+        seller_trade_state,
+        payer,
+        &[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            custody_vault.key().as_ref(),
+            token_mint.key().as_ref(),
+            &[ctx.bumps.seller_trade_state],
+        ],
+    )?;
+    let sts = SellerTradeStateV2 {
+        auction_house_key: auction_house.key(),
+        seller: wallet.key(),
+        seller_referral: ctx.accounts.seller_referral.key(),
+        buyer_price: args.price,
+        token_mint: token_mint.key(),
+        token_account: custody_vault.key(),
+        token_size: 1,
+        bump: ctx.bumps.seller_trade_state,
+        expiry: args.expiry,
+        payment_mint,
+        floor_premium_bp: None,
+        creator_listing: false,
+        dutch_end_price: 0,
+        dutch_start_time: 0,
+        dutch_decay_interval: 0,
+        allowed_buyer: Pubkey::default(),
+        auto_renew_period: 0,
+        auto_renew_bump_bp: 0,
+        secondary_payment_mint: Pubkey::default(),
+        secondary_price: 0,
+        not_before: 0,
+    };
+    let sts_v2_serialized = sts.try_to_vec()?;
+    seller_trade_state.try_borrow_mut_data()?[8..8 + sts_v2_serialized.len()]
+        .copy_from_slice(&sts_v2_serialized);
+
+    msg!(
+        "custody_sell: {{\"seller_trade_state\":\"{}\",\"custody_vault\":\"{}\"}}",
+        seller_trade_state.key(),
+        custody_vault.key()
+    );
+    let payment_decimals = get_payment_decimals(if remaining_accounts.len() == 1 {
+        Some(index_ra!(remaining_accounts, 0))
+    } else {
+        None
+    })?;
+    msg!(
+        "{{\"price\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
+        args.price,
+        args.expiry,
+        payment_decimals,
+        get_ui_price(args.price, payment_decimals),
+    );
+    Ok(())
+}
@@ -10,7 +10,9 @@ use {
     crate::constants::*,
     crate::errors::ErrorCode,
     crate::states::*,
-    crate::utils::{assert_keys_equal, assert_payment_mint, transfer_token},
+    crate::utils::{
+        assert_keys_equal, assert_payment_mint, get_balance_from_token_account, transfer_token,
+    },
     anchor_lang::{prelude::*, solana_program::system_instruction},
 };
 
@@ -53,19 +55,21 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Deposit<'info>>, amount: u6
     let escrow_payment_account = &ctx.accounts.escrow_payment_account;
     let system_program = &ctx.accounts.system_program;
 
-    if remaining_accounts.is_empty() {
+    let (deposited, escrow_balance, payment_mint) = if remaining_accounts.is_empty() {
+        let deposited = cmp::max(amount, Rent::get()?.minimum_balance(0));
         invoke(
-            &system_instruction::transfer(
-                payer.key,
-                &escrow_payment_account.key(),
-                cmp::max(amount, Rent::get()?.minimum_balance(0)),
-            ),
+            &system_instruction::transfer(payer.key, &escrow_payment_account.key(), deposited),
             &[
                 escrow_payment_account.to_account_info(),
                 payer.to_account_info(),
                 system_program.to_account_info(),
             ],
         )?;
+        (
+            deposited,
+            escrow_payment_account.lamports(),
+            Pubkey::default(),
+        )
     } else {
         assert_keys_equal(index_ra!(remaining_accounts, 3).key, &spl_token::id())?;
         assert_payment_mint(index_ra!(remaining_accounts, 0))?;
@@ -84,7 +88,19 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Deposit<'info>>, amount: u6
             None,
             &[],
         )?;
-    }
+        (
+            amount,
+            get_balance_from_token_account(index_ra!(remaining_accounts, 2))?,
+            index_ra!(remaining_accounts, 0).key(),
+        )
+    };
 
+    msg!(
+        "deposit: {{\"wallet\":\"{}\",\"payment_mint\":\"{}\",\"amount\":{},\"escrow_balance\":{}}}",
+        ctx.accounts.wallet.key(),
+        payment_mint,
+        deposited,
+        escrow_balance,
+    );
     Ok(())
 }
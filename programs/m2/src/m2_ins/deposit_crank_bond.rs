@@ -0,0 +1,50 @@
+use solana_program::{program::invoke, system_instruction};
+
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// DepositCrankBond posts lamports into the cranker's CrankBond, which close_stale_listing,
+// close_expired_buyer_state and cancel_with_proof require to hold at least CRANK_BOND_LAMPORTS.
+#[derive(Accounts)]
+pub struct DepositCrankBond<'info> {
+    #[account(mut)]
+    cranker: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer=cranker,
+        seeds=[PREFIX.as_bytes(), CRANK_BOND.as_bytes(), cranker.key().as_ref()],
+        space=CrankBond::LEN,
+        bump,
+    )]
+    crank_bond: Account<'info, CrankBond>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositCrankBond<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let cranker = &ctx.accounts.cranker;
+    invoke(
+        &system_instruction::transfer(cranker.key, &ctx.accounts.crank_bond.key(), amount),
+        &[
+            cranker.to_account_info(),
+            ctx.accounts.crank_bond.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+    let crank_bond = &mut ctx.accounts.crank_bond;
+    crank_bond.cranker = cranker.key();
+    crank_bond.amount = crank_bond
+        .amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    crank_bond.bump = ctx.bumps.crank_bond;
+
+    msg!(
+        "deposit_crank_bond: {{\"cranker\":\"{}\",\"amount\":{},\"bond\":{}}}",
+        crank_bond.cranker,
+        amount,
+        crank_bond.amount,
+    );
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use solana_program::program::invoke;
+use std::cmp;
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, solana_program::system_instruction},
+};
+
+// DepositorDeposit tops up a buyer's escrow from an enabled EscrowDepositor of the buyer. SOL is
+// paid by the depositor itself. SPL is moved out of the buyer's payment token account, which the
+// buyer delegates to program_as_signer the same way as for escrowless bids, so the funds never
+// leave the buyer's custody except into the buyer's own escrow.
+#[derive(Accounts)]
+pub struct DepositorDeposit<'info> {
+    #[account(mut)]
+    depositor: Signer<'info>,
+    /// CHECK: seeds check, this is the beneficiary of the deposit
+    wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            ESCROW_DEPOSITOR.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        bump=escrow_depositor.bump,
+        constraint = escrow_depositor.enabled @ ErrorCode::NoValidSignerPresent,
+    )]
+    escrow_depositor: Account<'info, EscrowDepositor>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if included, must be a valid token mint
+    // 1. deposit_source_token_account (optional) - payment token account of wallet, delegated to program_as_signer
+    // 2. deposit_destination_token_account (optional) - ata of escrow_payment_account
+    // 3. token_program (optional)
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositorDeposit<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let depositor = &ctx.accounts.depositor;
+    let wallet = &ctx.accounts.wallet;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let system_program = &ctx.accounts.system_program;
+
+    let (deposited, escrow_balance, payment_mint) = if remaining_accounts.is_empty() {
+        let deposited = cmp::max(amount, Rent::get()?.minimum_balance(0));
+        invoke(
+            &system_instruction::transfer(depositor.key, escrow_payment_account.key, deposited),
+            &[
+                escrow_payment_account.to_account_info(),
+                depositor.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+        (
+            deposited,
+            escrow_payment_account.lamports(),
+            Pubkey::default(),
+        )
+    } else {
+        let payment_mint = index_ra!(remaining_accounts, 0);
+        let deposit_source_token_account = index_ra!(remaining_accounts, 1);
+        let deposit_destination_token_account = index_ra!(remaining_accounts, 2);
+        assert_keys_equal(index_ra!(remaining_accounts, 3).key, &spl_token::id())?;
+        assert_payment_mint(payment_mint)?;
+        assert_delegated_payment(
+            deposit_source_token_account,
+            wallet.key,
+            program_as_signer.key,
+            payment_mint.key,
+            amount,
+        )?;
+        transfer_token(
+            &amount,
+            depositor,
+            program_as_signer,
+            wallet,
+            None,
+            DestinationSpecifier::Ai(escrow_payment_account),
+            payment_mint,
+            deposit_source_token_account,
+            deposit_destination_token_account,
+            index_ra!(remaining_accounts, 3),
+            system_program,
+            None,
+            &[&[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[ctx.bumps.program_as_signer],
+            ]],
+        )?;
+        (
+            amount,
+            get_balance_from_token_account(deposit_destination_token_account)?,
+            payment_mint.key(),
+        )
+    };
+
+    msg!(
+        "depositor_deposit: {{\"wallet\":\"{}\",\"depositor\":\"{}\",\"payment_mint\":\"{}\",\"amount\":{},\"escrow_balance\":{}}}",
+        wallet.key(),
+        depositor.key(),
+        payment_mint,
+        deposited,
+        escrow_balance,
+    );
+    Ok(())
+}
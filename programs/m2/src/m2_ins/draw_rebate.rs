@@ -0,0 +1,79 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, solana_program::keccak},
+};
+
+// DrawRebate reveals the seed of an epoch's fee rebate draw once the epoch is over and picks the
+// winner among its tickets. The prize is fixed at the campaign's prize as of the draw, 0 if the
+// campaign was disabled. An epoch without tickets has nobody to claim it and is closed to the
+// randomness_authority right away.
+#[derive(Accounts)]
+pub struct DrawRebate<'info> {
+    #[account(mut)]
+    randomness_authority: Signer<'info>,
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_CAMPAIGN.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=rebate_campaign.bump,
+        has_one=randomness_authority,
+    )]
+    rebate_campaign: Account<'info, RebateCampaign>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_EPOCH.as_bytes(),
+            auction_house.key().as_ref(),
+            rebate_epoch.epoch.to_le_bytes().as_ref(),
+        ],
+        bump=rebate_epoch.bump,
+    )]
+    rebate_epoch: Box<Account<'info, RebateEpoch>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, DrawRebate<'info>>,
+    seed: [u8; 32],
+) -> Result<()> {
+    let rebate_campaign = &ctx.accounts.rebate_campaign;
+    let rebate_epoch = &mut ctx.accounts.rebate_epoch;
+    if rebate_epoch.drawn || rebate_epoch.epoch >= Clock::get()?.epoch {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if keccak::hash(&seed).to_bytes() != rebate_epoch.commitment {
+        return Err(ErrorCode::InvalidRebateSeed.into());
+    }
+
+    rebate_epoch.drawn = true;
+    if rebate_epoch.tickets.is_empty() {
+        rebate_epoch.close(ctx.accounts.randomness_authority.to_account_info())?;
+    } else {
+        let index = get_rebate_winner_index(
+            &seed,
+            &rebate_epoch.entries_hash,
+            rebate_epoch.tickets.len(),
+        );
+        rebate_epoch.winner = rebate_epoch.tickets[index];
+        rebate_epoch.prize = if rebate_campaign.enabled {
+            rebate_campaign.prize
+        } else {
+            0
+        };
+    }
+
+    msg!(
+        "draw_rebate: {{\"epoch\":{},\"tickets\":{},\"winner\":\"{}\",\"prize\":{}}}",
+        rebate_epoch.epoch,
+        rebate_epoch.ticket_count,
+        rebate_epoch.winner,
+        rebate_epoch.prize,
+    );
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteBundleSaleArgs {
+    pub price: u64, // has to match the bundle's buyer_price, guards the buyer against repricing
+    pub buyer_creator_royalty_bp: u16,
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+}
+
+// ExecuteBundleSale lets a buyer take a sealed bundle for its price out of their escrow. All items
+// have to carry the verified collection of the first one, so royalties are paid once, on the full
+// price, to the creators of the first item, and fees are settled once as for a single listing.
+// Bundle prices don't say anything about single tokens, so they don't feed the collection stats.
+#[derive(Accounts)]
+#[instruction(args: ExecuteBundleSaleArgs)]
+pub struct ExecuteBundleSale<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: seller, checked in bundle_seller_state has_one
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: optional
+    notary: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.buyer_creator_royalty_bp <= 10_000 @ ErrorCode::InvalidBasisPoints,
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            BUNDLE.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            bundle_seller_state.bundle_id.to_le_bytes().as_ref(),
+        ],
+        bump=bundle_seller_state.bump,
+        has_one=seller,
+    )]
+    bundle_seller_state: Box<Account<'info, BundleSellerState>>,
+    /// CHECK: must match the seller_referral stored in bundle_seller_state
+    #[account(mut)]
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    // remaining accounts:
+    // 0..4N. (token_account, token_mint, metadata, buyer_receipt_token_account) of every item, in bundle order
+    // 4N..=4N+4. creators (optional) - creators of the first item, same layout as in execute_sale_v2
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteBundleSale<'info>>,
+    args: ExecuteBundleSaleArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let notary = &ctx.accounts.notary;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let bundle_seller_state = &ctx.accounts.bundle_seller_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    if bundle_seller_state.auction_house_key != auction_house.key()
+        || !bundle_seller_state.sealed
+        || bundle_seller_state.buyer_price != args.price
+    {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if bundle_seller_state.seller_referral != ctx.accounts.seller_referral.key() {
+        return Err(ErrorCode::InvalidReferral.into());
+    }
+    if is_trade_state_expired(bundle_seller_state.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    let item_count = bundle_seller_state.items.len();
+    if remaining_accounts.len() < item_count * 4 {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+    let (item_accounts, creator_accounts) = remaining_accounts.split_at(item_count * 4);
+
+    let mut metadatas = Vec::with_capacity(item_count);
+    for (item, accounts) in bundle_seller_state
+        .items
+        .iter()
+        .zip(item_accounts.chunks(4))
+    {
+        assert_keys_equal(accounts[0].key, &item.token_account)?;
+        assert_keys_equal(accounts[1].key, &item.token_mint)?;
+        assert_metadata_valid(&accounts[2], &item.token_mint)?;
+        let metadata_parsed = Metadata::safe_deserialize(&accounts[2].data.borrow())?;
+        match (&metadata_parsed.collection, metadatas.first()) {
+            (Some(c), None) if c.verified => {}
+            (
+                Some(c),
+                Some(Metadata {
+                    collection: Some(first),
+                    ..
+                }),
+            ) if c.verified && c.key == first.key => {}
+            _ => return Err(ErrorCode::InvalidCollection.into()),
+        }
+        metadatas.push(metadata_parsed);
+    }
+    let first_metadata = &metadatas[0];
+
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        first_metadata,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let auction_house_key = auction_house.key();
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    let royalty = if args.buyer_creator_royalty_bp == 0 {
+        0
+    } else {
+        pay_creator_fees(
+            &mut creator_accounts.iter(),
+            None,
+            first_metadata,
+            &escrow_payment_account.to_account_info(),
+            escrow_signer_seeds,
+            args.price,
+            args.buyer_creator_royalty_bp,
+            None,
+        )?
+    };
+
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+    transfer_listing_payment(
+        args.price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        buyer,
+        seller,
+        escrow_payment_account,
+        auction_house_treasury,
+        None,
+        escrow_signer_seeds,
+    )?;
+
+    for accounts in item_accounts.chunks(4) {
+        transfer_token(
+            &1,
+            buyer,
+            program_as_signer,
+            seller,
+            None,
+            DestinationSpecifier::Ai(buyer),
+            &accounts[1],
+            &accounts[0],
+            &accounts[3],
+            token_program,
+            system_program,
+            None,
+            &[&[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[ctx.bumps.program_as_signer],
+            ]],
+        )?;
+    }
+
+    try_close_buyer_escrow(
+        escrow_payment_account,
+        buyer,
+        system_program,
+        escrow_signer_seeds,
+    )?;
+
+    msg!(
+        "execute_bundle_sale: {{\"bundle\":\"{}\",\"price\":{},\"royalty\":{},\"items\":{}}}",
+        bundle_seller_state.key(),
+        args.price,
+        royalty,
+        item_count,
+    );
+    Ok(())
+}
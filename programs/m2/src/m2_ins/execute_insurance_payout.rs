@@ -0,0 +1,65 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// ExecuteInsurancePayout pays the payout queued with queue_insurance_payout from the InsuranceFund
+// to its recipient once the timelock passed, keeping the fund rent exempt. It takes the authority
+// as well as the timelock, so a queued payout can still be cancelled until it's executed.
+#[derive(Accounts)]
+pub struct ExecuteInsurancePayout<'info> {
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: checked against insurance_fund
+    #[account(mut, address = insurance_fund.payout_recipient)]
+    recipient: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            INSURANCE_FUND.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=insurance_fund.bump,
+        constraint = insurance_fund.payout_amount > 0 @ ErrorCode::InvalidAccountState,
+    )]
+    insurance_fund: Account<'info, InsuranceFund>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteInsurancePayout<'info>>) -> Result<()> {
+    let recipient = &ctx.accounts.recipient;
+    let now = Clock::get()?.unix_timestamp;
+    if now < ctx.accounts.insurance_fund.payout_available_at {
+        return Err(ErrorCode::InsurancePayoutLocked.into());
+    }
+
+    let fund_info = ctx.accounts.insurance_fund.to_account_info();
+    let amount = ctx.accounts.insurance_fund.payout_amount;
+    let available = fund_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(fund_info.data_len()));
+    if amount > available {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    **fund_info.lamports.borrow_mut() -= amount;
+    **recipient.lamports.borrow_mut() = recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.total_paid_out = insurance_fund
+        .total_paid_out
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    insurance_fund.payout_recipient = Pubkey::default();
+    insurance_fund.payout_amount = 0;
+    insurance_fund.payout_available_at = 0;
+    insurance_fund.updated_at = now;
+
+    msg!(
+        "execute_insurance_payout: {{\"auction_house\":\"{}\",\"recipient\":\"{}\",\"amount\":{}}}",
+        insurance_fund.auction_house_key,
+        recipient.key(),
+        amount,
+    );
+    Ok(())
+}
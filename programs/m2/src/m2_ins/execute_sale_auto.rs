@@ -0,0 +1,370 @@
+use std::collections::BTreeSet;
+
+use {
+    crate::cnft_ins::*,
+    crate::core_ins::*,
+    crate::errors::ErrorCode,
+    crate::m2_ins::*,
+    crate::mip1_ins::*,
+    crate::t22_ins::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CompressedSaleArgs {
+    pub root: [u8; 32], // current root of the tree, the proof nodes are the last proof_len remaining accounts
+    pub proof_len: u8,
+    // attested by the notary, compressed NFTs don't have an on-chain metadata account
+    pub seller_fee_basis_points: u16,
+    pub collection: Option<Pubkey>,
+    pub creators: Vec<CompressedCreator>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteSaleAutoArgs {
+    pub price: u64,      // bid price of the whole fill
+    pub token_size: u64, // 1 unless it's a fill of a fungible SPL or Token-2022 listing
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub escrow_payment_bump: u8,    // only read for legacy SPL tokens
+    pub program_as_signer_bump: u8, // only read for legacy SPL tokens
+    pub compressed: Option<CompressedSaleArgs>, // has to be set for compressed NFTs
+    // the guards of execute_sale_v2, only supported for legacy SPL tokens
+    pub max_price: Option<u64>,
+    pub min_net_proceeds: Option<u64>,
+    pub custody_user_tag: Option<u64>,
+}
+
+// ExecuteSaleAuto fills a listing of any token standard with one instruction. It looks at the
+// standard of asset and routes to the execute instruction of that standard: execute_sale_v2 for
+// legacy SPL tokens, mip1_execute_sale_v2 for pNFTs, t22_execute_sale_v2 for Token-2022 mints,
+// core_execute_sale for core assets and execute_compressed_sale for compressed NFTs. The routed
+// instruction runs all of its own checks as if it was called directly, so routing a mismatched
+// asset gains nothing. OCP tokens are SPL Token mints too and still go through
+// ocp_execute_sale_v2.
+#[derive(Accounts)]
+pub struct ExecuteSaleAuto<'info> {
+    /// CHECK: token mint, core asset or asset id of a compressed NFT, its standard picks the route
+    asset: UncheckedAccount<'info>,
+    /// CHECK: metadata of the mint, only read for SPL Token mints, checked in get_asset_standard
+    metadata: UncheckedAccount<'info>,
+    // remaining accounts:
+    // 0..N. accounts of the routed instruction in its order, followed by its remaining accounts,
+    //       its OptionalAccounts included, laid out as if it was called directly
+}
+
+// deserializes the accounts of a routed instruction the way its entrypoint does, runs its handler
+// and writes its accounts back
+macro_rules! route {
+    ($accounts_ty:ident, $bumps_ty:ident, $accounts:expr, $ix_data:expr, $handle:path, $($arg:expr),*) => {{
+        let mut accounts = $accounts;
+        let mut bumps = $bumps_ty::default();
+        let mut reallocs = BTreeSet::new();
+        let mut routed = $accounts_ty::try_accounts(
+            &crate::ID,
+            &mut accounts,
+            $ix_data,
+            &mut bumps,
+            &mut reallocs,
+        )?;
+        $handle(
+            Context::new(&crate::ID, &mut routed, accounts, bumps),
+            $($arg),*
+        )?;
+        routed.exit(&crate::ID)
+    }};
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteSaleAuto<'info>>,
+    args: ExecuteSaleAutoArgs,
+) -> Result<()> {
+    let standard = get_asset_standard(&ctx.accounts.asset, &ctx.accounts.metadata)?;
+    assert_routable(standard, &args)?;
+
+    let accounts = ctx.remaining_accounts;
+    match standard {
+        AssetStandard::Legacy => route_legacy(accounts, &args)?,
+        AssetStandard::Programmable => route_programmable(accounts, &args)?,
+        AssetStandard::Token2022 => route_t22(accounts, &args)?,
+        AssetStandard::Core => route_core(accounts, &args)?,
+        AssetStandard::Compressed => route_compressed(accounts, args)?,
+    }
+
+    msg!(
+        "execute_sale_auto: {{\"asset\":\"{}\",\"standard\":\"{:?}\"}}",
+        ctx.accounts.asset.key(),
+        standard,
+    );
+    Ok(())
+}
+
+// the other routes have no token_size, and only execute_sale_v2 takes the price, proceeds and
+// custody guards, which can't be dropped silently
+fn assert_routable(standard: AssetStandard, args: &ExecuteSaleAutoArgs) -> Result<()> {
+    if standard != AssetStandard::Legacy
+        && standard != AssetStandard::Token2022
+        && args.token_size != 1
+    {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    if standard != AssetStandard::Legacy
+        && (args.max_price.is_some()
+            || args.min_net_proceeds.is_some()
+            || args.custody_user_tag.is_some())
+    {
+        return Err(ErrorCode::UnsupportedAssetStandard.into());
+    }
+    Ok(())
+}
+
+// the instruction data of execute_sale_v2, built from its generated instruction struct so it keeps
+// to the entrypoint's signature, the expiries aren't read anymore
+fn get_legacy_ix_data(args: &ExecuteSaleAutoArgs) -> Result<Vec<u8>> {
+    Ok(crate::instruction::ExecuteSaleV2 {
+        escrow_payment_bump: args.escrow_payment_bump,
+        program_as_signer_bump: args.program_as_signer_bump,
+        buyer_price: args.price,
+        token_size: args.token_size,
+        _buyer_state_expiry: 0,
+        _seller_state_expiry: 0,
+        maker_fee_bp: args.maker_fee_bp,
+        taker_fee_bp: args.taker_fee_bp,
+        max_price: args.max_price,
+        min_net_proceeds: args.min_net_proceeds,
+        custody_user_tag: args.custody_user_tag,
+    }
+    .try_to_vec()?)
+}
+
+// every route gets its own stack frame, the routed accounts structs are large
+
+#[inline(never)]
+fn route_legacy<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    args: &ExecuteSaleAutoArgs,
+) -> Result<()> {
+    route!(
+        ExecuteSaleV2,
+        ExecuteSaleV2Bumps,
+        accounts,
+        &get_legacy_ix_data(args)?,
+        crate::m2_ins::execute_sale_v2::handle,
+        args.escrow_payment_bump,
+        args.program_as_signer_bump,
+        args.price,
+        args.token_size,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        args.max_price,
+        args.min_net_proceeds,
+        args.custody_user_tag
+    )
+}
+
+#[inline(never)]
+fn route_programmable<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    args: &ExecuteSaleAutoArgs,
+) -> Result<()> {
+    let routed_args = MIP1ExecuteSaleV2Args {
+        price: args.price,
+        maker_fee_bp: args.maker_fee_bp,
+        taker_fee_bp: args.taker_fee_bp,
+    };
+    route!(
+        MIP1ExecuteSaleV2,
+        MIP1ExecuteSaleV2Bumps,
+        accounts,
+        &routed_args.try_to_vec()?,
+        crate::mip1_ins::mip1_execute_sale_v2::handle_mip1_execute_sale,
+        routed_args
+    )
+}
+
+#[inline(never)]
+fn route_t22<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    args: &ExecuteSaleAutoArgs,
+) -> Result<()> {
+    let routed_args = T22ExecuteSaleV2Args {
+        price: args.price,
+        token_size: args.token_size,
+        maker_fee_bp: args.maker_fee_bp,
+        taker_fee_bp: args.taker_fee_bp,
+    };
+    route!(
+        T22ExecuteSaleV2,
+        T22ExecuteSaleV2Bumps,
+        accounts,
+        &routed_args.try_to_vec()?,
+        crate::t22_ins::t22_execute_sale_v2::handle,
+        routed_args
+    )
+}
+
+#[inline(never)]
+fn route_core<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    args: &ExecuteSaleAutoArgs,
+) -> Result<()> {
+    let routed_args = CoreExecuteSaleArgs {
+        price: args.price,
+        maker_fee_bp: args.maker_fee_bp,
+        taker_fee_bp: args.taker_fee_bp,
+    };
+    route!(
+        CoreExecuteSale,
+        CoreExecuteSaleBumps,
+        accounts,
+        &routed_args.try_to_vec()?,
+        crate::core_ins::core_execute_sale::handle,
+        routed_args
+    )
+}
+
+#[inline(never)]
+fn route_compressed<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    args: ExecuteSaleAutoArgs,
+) -> Result<()> {
+    let leaf = args.compressed.ok_or(ErrorCode::UnsupportedAssetStandard)?;
+    let routed_args = ExecuteCompressedSaleArgs {
+        price: args.price,
+        maker_fee_bp: args.maker_fee_bp,
+        taker_fee_bp: args.taker_fee_bp,
+        root: leaf.root,
+        proof_len: leaf.proof_len,
+        seller_fee_basis_points: leaf.seller_fee_basis_points,
+        collection: leaf.collection,
+        creators: leaf.creators,
+    };
+    route!(
+        ExecuteCompressedSale,
+        ExecuteCompressedSaleBumps,
+        accounts,
+        &routed_args.try_to_vec()?,
+        crate::cnft_ins::execute_compressed_sale::handle,
+        routed_args
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Accounts)]
+    pub struct RoutedTest<'info> {
+        /// CHECK: first account of the routed instruction
+        first: UncheckedAccount<'info>,
+        /// CHECK: second account of the routed instruction
+        second: UncheckedAccount<'info>,
+    }
+
+    fn handle_routed_test<'info>(
+        ctx: Context<'_, '_, '_, 'info, RoutedTest<'info>>,
+        expected: [Pubkey; 4],
+    ) -> Result<()> {
+        let (remaining_accounts, optional_accounts) =
+            split_optional_accounts(ctx.remaining_accounts);
+        assert_eq!(ctx.accounts.first.key(), expected[0]);
+        assert_eq!(ctx.accounts.second.key(), expected[1]);
+        assert_eq!(remaining_accounts.len(), 1);
+        assert_eq!(remaining_accounts[0].key, &expected[2]);
+        assert!(optional_accounts.get(0).is_none());
+        assert_eq!(optional_accounts.require(1)?.key, &expected[3]);
+        assert!(optional_accounts.require(2).is_err());
+        Ok(())
+    }
+
+    fn test_args() -> ExecuteSaleAutoArgs {
+        ExecuteSaleAutoArgs {
+            price: 1_000,
+            token_size: 1,
+            maker_fee_bp: -50,
+            taker_fee_bp: 200,
+            escrow_payment_bump: 254,
+            program_as_signer_bump: 253,
+            compressed: None,
+            max_price: Some(1_100),
+            min_net_proceeds: Some(900),
+            custody_user_tag: Some(7),
+        }
+    }
+
+    #[test]
+    fn legacy_route_forwards_the_execute_sale_v2_guards() -> Result<()> {
+        let ix_data = get_legacy_ix_data(&test_args())?;
+        let routed = crate::instruction::ExecuteSaleV2::try_from_slice(&ix_data)?;
+        assert_eq!(routed.escrow_payment_bump, 254);
+        assert_eq!(routed.program_as_signer_bump, 253);
+        assert_eq!(routed.buyer_price, 1_000);
+        assert_eq!(routed.token_size, 1);
+        assert_eq!(routed.maker_fee_bp, -50);
+        assert_eq!(routed.taker_fee_bp, 200);
+        assert_eq!(routed.max_price, Some(1_100));
+        assert_eq!(routed.min_net_proceeds, Some(900));
+        assert_eq!(routed.custody_user_tag, Some(7));
+        Ok(())
+    }
+
+    #[test]
+    fn routed_instruction_reads_its_optional_accounts_after_its_accounts() -> Result<()> {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let optional = Pubkey::new_unique();
+        // the routed accounts, its remaining accounts, the marker and its OptionalAccounts with
+        // the program id in the slot of a skipped one
+        let keys = [first, second, creator, crate::ID, crate::ID, optional];
+        let mut lamports = [0u64; 6];
+        let mut data: [[u8; 0]; 6] = [[]; 6];
+        let owner = solana_program::system_program::ID;
+        let accounts = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+            })
+            .collect::<Vec<_>>();
+
+        route!(
+            RoutedTest,
+            RoutedTestBumps,
+            &accounts[..],
+            &[],
+            handle_routed_test,
+            [first, second, creator, optional]
+        )
+    }
+
+    #[test]
+    fn routes_without_the_guards_reject_them() {
+        let args = test_args();
+        assert!(assert_routable(AssetStandard::Legacy, &args).is_ok());
+        for standard in [
+            AssetStandard::Programmable,
+            AssetStandard::Token2022,
+            AssetStandard::Core,
+            AssetStandard::Compressed,
+        ] {
+            assert!(assert_routable(standard, &args).is_err());
+        }
+
+        let args = ExecuteSaleAutoArgs {
+            max_price: None,
+            min_net_proceeds: None,
+            custody_user_tag: None,
+            ..test_args()
+        };
+        assert!(assert_routable(AssetStandard::Core, &args).is_ok());
+        let args = ExecuteSaleAutoArgs {
+            token_size: 2,
+            ..args
+        };
+        assert!(assert_routable(AssetStandard::Token2022, &args).is_ok());
+        assert!(assert_routable(AssetStandard::Core, &args).is_err());
+    }
+}
@@ -1,15 +1,16 @@
-use mpl_token_metadata::accounts::Metadata;
-
-use crate::index_ra;
-
 use {
     crate::constants::*,
     crate::errors::ErrorCode,
     crate::states::*,
     crate::utils::*,
     anchor_lang::{prelude::*, AnchorDeserialize},
-    anchor_spl::{associated_token::AssociatedToken, token::Token},
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{SetAuthority, Token},
+    },
+    mpl_token_metadata::accounts::Metadata,
     solana_program::program_option::COption,
+    spl_token::instruction::AuthorityType,
 };
 
 #[derive(Accounts)]
@@ -119,6 +120,14 @@ pub struct ExecuteSaleV2<'info> {
     // remaining accounts:
     // ** IF USING NATIVE SOL **
     // 0..=4. creators (optional) - if the buyer is paying in SOL, these are the creators of the token
+    //                                 a creator that can't receive lamports is followed by its collection's royalty_fallback and recipient
+    //
+    // ** IF USING NATIVE SOL WITH A SECONDARY LEG, see set_secondary_price **
+    // 0. secondary_payment_mint (required)
+    // 1. secondary_source_token_account (required) - token account of the buyer delegated to program_as_signer
+    // 2. secondary_seller_token_account (required) - token account controlled by seller
+    // 3. secondary_treasury_token_account (required) - token account controlled by auction_house_treasury
+    // 4..=8. creators (optional) - same as for native SOL
     //
     // ** IF USING SPL **
     // 0. payment_mint (required) - if the buyer is paying in a token, this is the mint of that token
@@ -129,6 +138,31 @@ pub struct ExecuteSaleV2<'info> {
     //                                            if the creator token accounts are not initialized, the creator itself needs to be
     //                                            included, in the format of creator_1_ATA, creator_1, creator_2_ATA, creator_2, ...
     // ...
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. seller_status (required) - SellerStatus of the seller, derivation checked in assert_seller_not_suspended
+    // 2. buyer_status (required) - BuyerStatus of the buyer, derivation checked in assert_buyer_not_suspended
+    // 3. price_band_config (required) - PriceBandConfig of the collection, derivation checked in assert_price_attested
+    // 4. price_attestation - PriceAttestation of the token, required if the price_band_config is enabled
+    // 5. collection_stats - CollectionStats of the collection, required for floor pegged listings, created if needed
+    // 6. last_sale (required) - LastSale of the token, created if needed
+    // 7. trade_sequence - TradeSequence, created if needed, the sale is only recorded on the collection_stats and trade_sequence with both of them
+    // 8. frontend_config - FrontendConfig of the frontend that routed the sale, checked in attribute_frontend_sale
+    // 9. taker_fee_config - TakerFeeConfig of the auction house, derivation checked in get_taker_fee_bp
+    // 10. price_feed - pyth SOL/USD price account, required if the taker_fee_config is enabled
+    // 11. royalty_subsidy - RoyaltySubsidy of the collection, derivation checked in get_active_royalty_subsidy
+    // 12. rebate_epoch - RebateEpoch of the current epoch, derivation checked in enter_rebate_draw
+    // 13. fill_proof - FillProof of the buyer and the token, derivation checked in record_fill_proof
+    // 14. insurance_fund - InsuranceFund of the auction house, derivation checked in fund_insurance
+    // 15. buyer_order_counter - WalletOrderCounter of the buyer, required if the buyer_counted_order exists
+    // 16. seller_order_counter - WalletOrderCounter of the seller, required if the seller_counted_order exists
+    // 17. buyer_counted_order (required) - CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    // 18. seller_counted_order (required) - CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
+    // 19. custodial_platform - CustodialPlatform of the buyer, required if a custody_user_tag is passed
+    // 20. take_profit_trade_state - seller_trade_state of the buyer's take profit listing, required to relist
+    // 21. order_limit_config - OrderLimitConfig of the auction house, required to relist
+    // 22. take_profit_counted_order - CountedOrder of the take_profit_trade_state, required if the order_limit_config has a limit
+    // 23. mint_listing_index - MintListingIndex of the token_mint, derivation checked in update_mint_listing_index
     // -1. payer (optional) - this wallet will try to pay for rent
 }
 
@@ -140,21 +174,20 @@ pub fn handle<'info>(
     token_size: u64,
     maker_fee_bp: i16,
     taker_fee_bp: u16,
+    max_price: Option<u64>,
+    min_net_proceeds: Option<u64>,
+    custody_user_tag: Option<u64>,
 ) -> Result<()> {
     let (remaining_accounts, possible_payer) =
         split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
     let buyer = &ctx.accounts.buyer;
     let seller = &ctx.accounts.seller;
-    let notary = &ctx.accounts.notary;
     let token_account = &ctx.accounts.token_account;
     let token_mint = &ctx.accounts.token_mint;
-    let metadata = &ctx.accounts.metadata;
     let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
-    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
     let auction_house = &ctx.accounts.auction_house;
-    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
-    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
-    let seller_trade_state = &ctx.accounts.seller_trade_state;
     let token_program = &ctx.accounts.token_program;
     let system_program = &ctx.accounts.system_program;
     let program_as_signer = &ctx.accounts.program_as_signer;
@@ -172,41 +205,6 @@ pub fn handle<'info>(
     if !buyer.is_signer && !seller.is_signer {
         return Err(ErrorCode::SaleRequiresSigner.into());
     }
-
-    if buyer_trade_state.data_is_empty() || seller_trade_state.to_account_info().data_is_empty() {
-        return Err(ErrorCode::BothPartiesNeedToAgreeToSale.into());
-    }
-    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
-    let is_spl = bid_args.payment_mint != Pubkey::default();
-
-    bid_args.check_args(
-        ctx.accounts.buyer_referral.key,
-        buyer_price,
-        token_mint.key,
-        token_size,
-        if is_spl {
-            index_ra!(remaining_accounts, 0).key // mint account
-        } else {
-            &bid_args.payment_mint
-        },
-    )?;
-    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
-    sell_args.check_args(
-        ctx.accounts.seller_referral.key,
-        &buyer_price,
-        token_mint.key,
-        &token_size,
-        &bid_args.payment_mint, // check that mints match, equality is transitive
-    )?;
-
-    let clock = Clock::get()?;
-    if bid_args.expiry.abs() > 1 && clock.unix_timestamp > bid_args.expiry.abs() {
-        return Err(ErrorCode::InvalidExpiry.into());
-    }
-    if sell_args.expiry.abs() > 1 && clock.unix_timestamp > sell_args.expiry.abs() {
-        return Err(ErrorCode::InvalidExpiry.into());
-    }
-
     let taker = if buyer.is_signer { buyer } else { seller };
     let payer = if let Some(p) = possible_payer {
         p
@@ -214,6 +212,50 @@ pub fn handle<'info>(
         taker
     };
 
+    let settlement = SettlementContext {
+        auction_house,
+        auction_house_treasury: &ctx.accounts.auction_house_treasury,
+        notary: &ctx.accounts.notary,
+        buyer,
+        seller,
+        taker,
+        payer,
+        buyer_trade_state: &ctx.accounts.buyer_trade_state,
+        seller_trade_state: &ctx.accounts.seller_trade_state,
+        buyer_referral: &ctx.accounts.buyer_referral,
+        seller_referral: &ctx.accounts.seller_referral,
+        escrow_payment_account: &ctx.accounts.escrow_payment_account,
+        escrow_payment_bump,
+        program_as_signer: &ctx.accounts.program_as_signer,
+        program_as_signer_bump: ctx.bumps.program_as_signer,
+        metadata: &ctx.accounts.metadata,
+        collection_config,
+        seller_status: optional_accounts.require(1)?,
+        buyer_status: optional_accounts.require(2)?,
+        price_band_config: optional_accounts.require(3)?,
+        price_attestation: optional_accounts.get(4),
+        collection_stats: optional_accounts.get(5),
+        last_sale: optional_accounts.require(6)?,
+        trade_sequence: optional_accounts.get(7),
+        frontend_config: optional_accounts.get(8),
+        taker_fee_config: optional_accounts.get(9),
+        price_feed: optional_accounts.get(10),
+        royalty_subsidy: optional_accounts.get(11),
+        rebate_epoch: optional_accounts.get(12),
+        fill_proof: optional_accounts.get(13),
+        insurance_fund: optional_accounts.get(14),
+        buyer_order_counter: optional_accounts.get(15),
+        seller_order_counter: optional_accounts.get(16),
+        buyer_counted_order: optional_accounts.require(17)?,
+        seller_counted_order: optional_accounts.require(18)?,
+        ocp_policy: None,
+        t22_mint: None,
+        token_program,
+        system_program,
+        remaining_accounts,
+    };
+    let terms = settlement.validate(token_mint.key, token_size, buyer_price)?;
+
     let delegate = get_delegate_from_token_account(token_account)?;
     if let Some(d) = delegate {
         assert_keys_equal(program_as_signer.key, &d)?;
@@ -221,78 +263,51 @@ pub fn handle<'info>(
         return Err(ErrorCode::IncorrectOwner.into());
     }
 
-    assert_is_ata(
-        &token_account.to_account_info(),
-        &seller.key(),
-        token_mint.key,
-        &program_as_signer.key(),
-    )?;
-
-    assert_metadata_valid(metadata, token_mint.key)?;
-
-    let auction_house_key = auction_house.key();
-    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
-        PREFIX.as_bytes(),
-        auction_house_key.as_ref(),
-        buyer.key.as_ref(),
-        &[escrow_payment_bump],
-    ]];
+    // custody listings are settled out of the custody vault, which is closed into the seller
+    if !is_custody_vault(token_account.key, token_mint.key) {
+        assert_is_ata(
+            &token_account.to_account_info(),
+            &seller.key(),
+            token_mint.key,
+            &program_as_signer.key(),
+        )?;
+    }
 
-    let royalty = if bid_args.buyer_creator_royalty_bp == 0 {
-        0
-    } else {
-        pay_creator_fees(
-            &mut (if is_spl {
-                remaining_accounts[4..].iter()
-            } else {
-                remaining_accounts.iter()
-            }),
-            None,
-            &Metadata::safe_deserialize(&metadata.data.borrow())?,
-            &escrow_payment_account.to_account_info(),
-            escrow_signer_seeds,
-            buyer_price,
-            bid_args.buyer_creator_royalty_bp,
-            if is_spl {
-                Some(TransferCreatorSplArgs {
-                    buyer,
-                    payer,
-                    mint: index_ra!(remaining_accounts, 0),
-                    payment_source_token_account: index_ra!(remaining_accounts, 1),
-                    system_program,
-                    token_program,
-                })
-            } else {
-                None
-            },
-        )?
+    // purchases of a custodial platform are delivered to its custody wallet, tagged with the user
+    // the platform bought for
+    let custody_wallet = match custody_user_tag {
+        Some(_) => {
+            if !buyer.is_signer || terms.bid_args.take_profit_price > 0 {
+                return Err(ErrorCode::InvalidCustodialPlatform.into());
+            }
+            Some(
+                get_custodial_platform(
+                    optional_accounts
+                        .get(19)
+                        .ok_or(ErrorCode::MissingRemainingAccount)?,
+                    &auction_house.key(),
+                    buyer.key,
+                )?
+                .custody_wallet,
+            )
+        }
+        None => None,
     };
 
-    let (actual_maker_fee_bp, actual_taker_fee_bp) =
-        get_actual_maker_taker_fee_bp(notary, maker_fee_bp, taker_fee_bp);
-    transfer_listing_payment(
-        buyer_price,
-        actual_maker_fee_bp,
-        actual_taker_fee_bp,
-        taker,
-        seller,
-        escrow_payment_account,
-        auction_house_treasury,
-        if is_spl {
-            Some(TransferListingPaymentSplArgs {
-                payer,
-                buyer,
-                mint: index_ra!(remaining_accounts, 0),
-                payment_source_token_account: index_ra!(remaining_accounts, 1),
-                payment_seller_token_account: index_ra!(remaining_accounts, 2),
-                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
-                system_program,
-                token_program,
-            })
-        } else {
-            None
-        },
-        escrow_signer_seeds,
+    let payout = settlement.pay(
+        &terms,
+        maker_fee_bp,
+        taker_fee_bp,
+        terms.bid_args.buyer_creator_royalty_bp,
+    )?;
+    // the buyer's cost is only known once fees and dutch or floor pegged prices are resolved
+    assert_max_price(
+        payout.get_buyer_cost(terms.price, taker.key == seller.key)?,
+        max_price,
+    )?;
+    assert_min_net_proceeds(
+        payout.get_seller_proceeds(terms.price, taker.key == seller.key),
+        min_net_proceeds,
     )?;
 
     let buyer_rec_acct = transfer_token(
@@ -301,7 +316,10 @@ pub fn handle<'info>(
         program_as_signer,
         seller,
         None,
-        DestinationSpecifier::Ai(buyer),
+        match &custody_wallet {
+            Some(custody_wallet) => DestinationSpecifier::Key(custody_wallet),
+            None => DestinationSpecifier::Ai(buyer),
+        },
         token_mint,
         token_account,
         buyer_receipt_token_account,
@@ -325,23 +343,135 @@ pub fn handle<'info>(
         }
     }
 
-    // we don't need to zero out buyer_trade_state, just copy zero discriminator to it and then close
-    close_account_anchor(buyer_trade_state, buyer)?;
-    close_account_anchor(seller_trade_state, seller)?;
+    settlement.close(&terms, &payout)?;
+    let mint_listing_index = optional_accounts.get(23);
+    if let Some(mint_listing_index) = mint_listing_index {
+        // partially filled listings stay in the index
+        if ctx.accounts.seller_trade_state.lamports() == 0 {
+            update_mint_listing_index(
+                mint_listing_index,
+                token_mint.key,
+                ctx.accounts.seller_trade_state.key,
+                None,
+            )?;
+        }
+    }
 
-    try_close_buyer_escrow(
-        escrow_payment_account,
-        buyer,
-        system_program,
-        escrow_signer_seeds,
-    )?;
+    // the bought token is relisted in the non-movable mode, which needs the buyer to sign. When the
+    // seller takes the bid the buyer keeps the token and the skipped relist is logged
+    let take_profit_price = terms.bid_args.take_profit_price;
+    if take_profit_price > 0 && !buyer.is_signer {
+        msg!(
+            "take_profit_skipped: {{\"buyer_trade_state\":\"{}\",\"price\":{},\"reason\":\"buyer_not_signer\"}}",
+            ctx.accounts.buyer_trade_state.key(),
+            take_profit_price,
+        );
+    } else if take_profit_price > 0 {
+        let take_profit_trade_state = optional_accounts
+            .get(20)
+            .ok_or(ErrorCode::MissingRemainingAccount)?;
+        let auction_house_key = auction_house.key();
+        // the relist is a new listing of the buyer and goes through the same checks as sell, the
+        // collection is already checked not to be paused by the settlement. A hold period can't
+        // have elapsed on a token bought in this transaction
+        let metadata_parsed = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+        let collection_key = get_collection_key(&metadata_parsed);
+        if get_collection_hold_period(collection_config, &auction_house_key, &collection_key)? > 0 {
+            return Err(ErrorCode::HoldPeriodNotElapsed.into());
+        }
+        if terms.bid_args.payment_mint == Pubkey::default() {
+            assert_collection_price_on_tick(
+                collection_config,
+                &auction_house_key,
+                &collection_key,
+                take_profit_price,
+            )?;
+        }
+        let sts_seeds: &[&[u8]] = &[
+            PREFIX.as_bytes(),
+            buyer.key.as_ref(),
+            auction_house_key.as_ref(),
+            buyer_receipt_token_account.key.as_ref(),
+            token_mint.key.as_ref(),
+        ];
+        let sts_bump = assert_derivation(ctx.program_id, take_profit_trade_state, sts_seeds)?;
+        // the bid is uncounted by the settlement above, so a wallet at its limit can still relist
+        count_open_order(
+            optional_accounts.require(21)?,
+            optional_accounts.get(15),
+            optional_accounts.get(22),
+            take_profit_trade_state.key,
+            payer,
+            &auction_house_key,
+            buyer.key,
+            OrderSide::Listing,
+        )?;
+        anchor_spl::token::set_authority(
+            CpiContext::new(
+                token_program.to_account_info(),
+                SetAuthority {
+                    account_or_mint: buyer_receipt_token_account.to_account_info(),
+                    current_authority: buyer.to_account_info(),
+                },
+            ),
+            AuthorityType::AccountOwner,
+            Some(program_as_signer.key()),
+        )?;
+        create_or_realloc_seller_trade_state(
+            take_profit_trade_state,
+            payer,
+            &[sts_seeds, &[&[sts_bump]]].concat(),
+        )?;
+        let sts = get_take_profit_listing(
+            &terms.bid_args,
+            buyer_receipt_token_account.key,
+            terms.token_size,
+            sts_bump,
+        );
+        let sts_v2_serialized = sts.try_to_vec()?;
+        take_profit_trade_state.try_borrow_mut_data()?[8..8 + sts_v2_serialized.len()]
+            .copy_from_slice(&sts_v2_serialized);
+        if let Some(mint_listing_index) = mint_listing_index {
+            update_mint_listing_index(
+                mint_listing_index,
+                token_mint.key,
+                take_profit_trade_state.key,
+                Some(MintListingEntry {
+                    seller_trade_state: take_profit_trade_state.key(),
+                    auction_house_key,
+                    seller: buyer.key(),
+                    buyer_price: sts.buyer_price,
+                    expiry: sts.expiry,
+                    payment_mint: sts.payment_mint,
+                }),
+            )?;
+        }
+        msg!(
+            "take_profit_listing: {{\"seller_trade_state\":\"{}\",\"price\":{}}}",
+            take_profit_trade_state.key(),
+            take_profit_price,
+        );
+    }
+
+    if let (Some(custody_wallet), Some(user_tag)) = (custody_wallet, custody_user_tag) {
+        msg!(
+            "custodial_settlement: {{\"platform\":\"{}\",\"custody_wallet\":\"{}\",\"custody_token_account\":\"{}\",\"user_tag\":{}}}",
+            buyer.key(),
+            custody_wallet,
+            buyer_receipt_token_account.key(),
+            user_tag,
+        );
+    }
 
     msg!(
-        "{{\"price\":{},\"seller_expiry\":{},\"buyer_expiry\":{},\"royalty\":{}}}",
-        buyer_price,
-        sell_args.expiry,
-        bid_args.expiry,
-        royalty,
+        "{{\"price\":{},\"token_size\":{},\"seller_expiry\":{},\"buyer_expiry\":{},\"royalty\":{},\"royalty_subsidy\":{},\"creator_listing\":{}}}",
+        terms.price,
+        terms.token_size,
+        terms.sell_args.expiry,
+        terms.bid_args.expiry,
+        payout.royalty,
+        payout.royalty_subsidy,
+        terms.sell_args.creator_listing,
     );
 
     Ok(())
@@ -0,0 +1,46 @@
+use {
+    crate::errors::ErrorCode,
+    crate::layouts::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FieldLayoutData {
+    pub name: String,
+    pub offset: u16, // VARIABLE if the field doesn't have a fixed offset
+    pub size: u16,   // VARIABLE for Options and Vecs
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StateLayoutData {
+    pub name: String,
+    pub discriminator: [u8; 8],
+    pub version: u8,
+    pub len: u16,
+    pub fields: Vec<FieldLayoutData>,
+}
+
+// GetStateLayout returns the layout of the account type with the given discriminator as return
+// data, so other programs and zero-copy readers can look the offsets up instead of hard-coding
+// them. Programs depending on the crate can use layouts::STATE_LAYOUTS directly.
+#[derive(Accounts)]
+pub struct GetStateLayout {}
+
+pub fn handle(_ctx: Context<GetStateLayout>, discriminator: [u8; 8]) -> Result<StateLayoutData> {
+    let layout = get_state_layout(&discriminator).ok_or(ErrorCode::InvalidDiscriminator)?;
+    Ok(StateLayoutData {
+        name: layout.name.to_string(),
+        discriminator: layout.discriminator,
+        version: layout.version,
+        len: layout.len,
+        fields: layout
+            .fields
+            .iter()
+            .map(|field| FieldLayoutData {
+                name: field.name.to_string(),
+                offset: field.offset,
+                size: field.size,
+            })
+            .collect(),
+    })
+}
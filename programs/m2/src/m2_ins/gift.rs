@@ -0,0 +1,80 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, Token, TokenAccount},
+    solana_program::program_option::COption,
+};
+
+// Gift transfers a token to a recipient at zero price, without any fees, through the
+// marketplace so the transfer takes a seq on the TradeSequence and shows up for indexers next to
+// sales. The notary has to sign, which keeps gifts from being used to wash the trade log.
+// Programmable tokens can't be moved by the sender's signature alone and aren't supported.
+#[derive(Accounts)]
+pub struct Gift<'info> {
+    #[account(mut)]
+    sender: Signer<'info>,
+    /// CHECK: recipient
+    recipient: UncheckedAccount<'info>,
+    notary: Signer<'info>,
+    #[account(mut, token::mint = token_mint, token::authority = sender)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: recipient_token_account, created if needed
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: derivation checked in record_gift, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, Gift<'info>>, amount: u64) -> Result<()> {
+    let sender = &ctx.accounts.sender;
+    let recipient = &ctx.accounts.recipient;
+    let token_mint = &ctx.accounts.token_mint;
+    if amount == 0 || sender.key() == recipient.key() {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+
+    let recipient_token_account = transfer_token(
+        &amount,
+        sender,
+        sender,
+        sender,
+        None,
+        DestinationSpecifier::Ai(recipient),
+        &token_mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        None,
+        &[],
+    )?;
+    if let COption::Some(_) = recipient_token_account.delegate {
+        return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+    }
+
+    let trade_seq = record_gift(&ctx.accounts.trade_sequence, sender, &token_mint.key())?;
+
+    msg!(
+        "gift: {{\"auction_house\":\"{}\",\"token_mint\":\"{}\",\"sender\":\"{}\",\"recipient\":\"{}\",\"amount\":{},\"trade_seq\":{}}}",
+        ctx.accounts.auction_house.key(),
+        token_mint.key(),
+        sender.key(),
+        recipient.key(),
+        amount,
+        trade_seq,
+    );
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use mpl_token_metadata::{
+    accounts::{Metadata, TokenRecord},
+    instructions::DelegateUtilityV1Builder,
+};
+use solana_program::{program::invoke, sysvar};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Mint, Token, TokenAccount},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ListRentalArgs {
+    pub fee: u64,  // lamports for one term
+    pub term: i64, // seconds
+}
+
+// ListRental offers a pNFT for rent. The token stays in the owner's ata, the owner makes
+// program_as_signer its utility delegate so that rent_nft can lock it for the term without the
+// owner signing again. The rental listing records the renter and the end of the term, it's what
+// consumers of the rental check. reclaim_rental revokes the delegate and closes the listing.
+#[derive(Accounts)]
+pub struct ListRental<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+    #[account(
+        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: check in cpi
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        constraint = token_account.amount == 1 @ ErrorCode::InvalidTokenAmount,
+    )]
+    token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in CPI
+    edition: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut, address = TokenRecord::find_pda(&token_mint.key(), &token_account.key()).0)]
+    token_record: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init,
+        payer=owner,
+        seeds=[
+            PREFIX.as_bytes(),
+            RENTAL_LISTING.as_bytes(),
+            auction_house.key().as_ref(),
+            owner.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        space=RentalListing::LEN,
+        bump,
+    )]
+    rental_listing: Box<Account<'info, RentalListing>>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    authorization_rules_program: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    authorization_rules: UncheckedAccount<'info>,
+    /// CHECK: check in cpi
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ListRental<'info>>,
+    args: ListRentalArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let token_account = &ctx.accounts.token_account;
+    let token_record = &ctx.accounts.token_record;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    if args.fee > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.term <= 0 || args.term > MAX_RENTAL_TERM_SECONDS {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    check_programmable(&metadata_parsed)?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house_key,
+        &metadata_parsed,
+    )?;
+    // the token can only have one token delegate, a listed or staked token can't be rented out
+    let (delegate, _, _) = get_delegate_info_and_token_state_from_token_record(token_record)?;
+    if delegate.is_some() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    let ins = DelegateUtilityV1Builder::new()
+        .delegate(program_as_signer.key())
+        .metadata(metadata.key())
+        .master_edition(Some(ctx.accounts.edition.key()))
+        .token_record(Some(token_record.key()))
+        .mint(token_mint.key())
+        .token(token_account.key())
+        .authority(owner.key())
+        .payer(owner.key())
+        .system_program(ctx.accounts.system_program.key())
+        .sysvar_instructions(ctx.accounts.instructions.key())
+        .spl_token_program(Some(ctx.accounts.token_program.key()))
+        .authorization_rules_program(Some(ctx.accounts.authorization_rules_program.key()))
+        .authorization_rules(Some(ctx.accounts.authorization_rules.key()))
+        .amount(1)
+        .instruction();
+    invoke(
+        &ins,
+        &[
+            owner.to_account_info(),
+            program_as_signer.to_account_info(),
+            metadata.to_account_info(),
+            ctx.accounts.edition.to_account_info(),
+            token_record.to_account_info(),
+            token_mint.to_account_info(),
+            token_account.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.instructions.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.to_account_info(),
+            ctx.accounts.authorization_rules.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+    )?;
+
+    let rental_listing = &mut ctx.accounts.rental_listing;
+    rental_listing.auction_house_key = auction_house_key;
+    rental_listing.owner = owner.key();
+    rental_listing.token_mint = token_mint.key();
+    rental_listing.token_account = token_account.key();
+    rental_listing.fee = args.fee;
+    rental_listing.term = args.term;
+    rental_listing.bump = ctx.bumps.rental_listing;
+
+    msg!(
+        "list_rental: {{\"rental_listing\":\"{}\",\"token_mint\":\"{}\",\"fee\":{},\"term\":{}}}",
+        rental_listing.key(),
+        rental_listing.token_mint,
+        rental_listing.fee,
+        rental_listing.term,
+    );
+    Ok(())
+}
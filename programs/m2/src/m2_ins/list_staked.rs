@@ -0,0 +1,131 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Mint, TokenAccount},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ListStakedArgs {
+    pub price: u64,
+    pub expiry: i64,
+    pub unstake_data: Vec<u8>, // instruction data of the staking program releasing the token
+}
+
+// ListStaked lists a token that a whitelisted staking program keeps locked in the seller's token
+// account, as its delegate, so the seller keeps earning staking rewards until the token sells.
+// Before listing, the seller authorizes the staked_listing PDA in the staking program to release
+// the token. buy_staked invokes staking_program with unstake_data and staked_listing as a signer,
+// and the staking program has to move the token to the buyer. Staked listings are paid in SOL,
+// listing again changes the price and unstake_data.
+#[derive(Accounts)]
+pub struct ListStaked<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(
+        token::mint = token_mint,
+        token::authority = seller,
+        constraint = token_account.amount == 1 @ ErrorCode::InvalidTokenAmount,
+        constraint = token_account.delegate.is_some() @ ErrorCode::InvalidAccountState,
+    )]
+    token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            STAKED_LISTING.as_bytes(),
+            auction_house.key().as_ref(),
+            seller.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        space=StakedListing::LEN,
+        bump,
+    )]
+    staked_listing: Box<Account<'info, StakedListing>>,
+    /// CHECK: checked against STAKING_PROGRAM_WHITELIST
+    #[account(constraint = STAKING_PROGRAM_WHITELIST.contains(staking_program.key) @ ErrorCode::InvalidStakingProgram)]
+    staking_program: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ListStaked<'info>>,
+    args: ListStakedArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let token_mint = &ctx.accounts.token_mint;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if is_trade_state_expired(args.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if args.unstake_data.len() > MAX_UNSTAKE_DATA_LEN {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    assert_metadata_valid(&ctx.accounts.metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house_key,
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_collection_price_on_tick(
+        &ctx.accounts.collection_config,
+        &auction_house_key,
+        &get_collection_key(&metadata_parsed),
+        args.price,
+    )?;
+
+    let staked_listing = &mut ctx.accounts.staked_listing;
+    staked_listing.auction_house_key = auction_house_key;
+    staked_listing.seller = seller.key();
+    staked_listing.token_mint = token_mint.key();
+    staked_listing.token_account = ctx.accounts.token_account.key();
+    staked_listing.staking_program = ctx.accounts.staking_program.key();
+    staked_listing.price = args.price;
+    staked_listing.expiry = args.expiry;
+    staked_listing.bump = ctx.bumps.staked_listing;
+    staked_listing.unstake_data = args.unstake_data;
+
+    msg!(
+        "list_staked: {{\"staked_listing\":\"{}\",\"seller\":\"{}\",\"token_mint\":\"{}\",\"staking_program\":\"{}\",\"price\":{},\"expiry\":{}}}",
+        staked_listing.key(),
+        seller.key(),
+        token_mint.key(),
+        staked_listing.staking_program,
+        args.price,
+        args.expiry,
+    );
+    Ok(())
+}
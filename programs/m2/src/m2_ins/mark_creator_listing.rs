@@ -0,0 +1,47 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {crate::errors::ErrorCode, crate::states::*, crate::utils::*, anchor_lang::prelude::*};
+
+// MarkCreatorListing flags a v2 listing as a creator listing when the seller is the update authority
+// or a verified creator of the token. Creator listings don't pay a maker fee when filled, and
+// settlement logs creator_listing so primary-adjacent sales by the creator can be told apart.
+// Relisting through sell/mip1_sell/custody_sell clears the flag.
+#[derive(Accounts)]
+pub struct MarkCreatorListing<'info> {
+    wallet: Signer<'info>,
+    /// CHECK: metadata of the listed token mint
+    #[account(
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            seller_trade_state.token_mint.as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, MarkCreatorListing<'info>>) -> Result<()> {
+    let metadata = &ctx.accounts.metadata;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+
+    assert_metadata_valid(metadata, &seller_trade_state.token_mint)?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    if !is_update_authority_or_verified_creator(&metadata_parsed, ctx.accounts.wallet.key) {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    seller_trade_state.creator_listing = true;
+
+    msg!(
+        "mark_creator_listing: {{\"seller_trade_state\":\"{}\",\"token_mint\":\"{}\"}}",
+        seller_trade_state.key(),
+        seller_trade_state.token_mint,
+    );
+    Ok(())
+}
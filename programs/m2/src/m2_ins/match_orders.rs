@@ -0,0 +1,177 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+// MatchOrders can be called by anyone while the best bid and ask of an order book cross. It fills
+// the oldest order of the best bid level against the oldest order of the best ask level, at the
+// price of the one that rested first, for the smaller of their sizes. The bidder gets the tokens
+// and the difference to their bid price back, the seller the proceeds less the default maker and
+// taker fee, which goes to the treasury. Fills are royalty free, like bids without
+// buyer_creator_royalty_bp. A filled order leaves its level, and a level without orders leaves the
+// order book.
+#[derive(Accounts)]
+pub struct MatchOrders<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: owner of the oldest order of bid_level
+    #[account(mut)]
+    bidder: UncheckedAccount<'info>,
+    /// CHECK: ata of the bidder for token_mint, created if needed
+    #[account(mut)]
+    bidder_token_account: UncheckedAccount<'info>,
+    /// CHECK: owner of the oldest order of ask_level
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: checked against order_book
+    #[account(address = order_book.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=auction_house_treasury,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK.as_bytes(),
+            auction_house.key().as_ref(),
+            order_book.token_mint.as_ref(),
+        ],
+        bump=order_book.bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK_LEVEL.as_bytes(),
+            order_book.key().as_ref(),
+            &[true as u8],
+            &bid_level.price.to_le_bytes(),
+        ],
+        bump=bid_level.bump,
+        constraint = order_book.get_best_price(true) == Some(bid_level.price) @ ErrorCode::InvalidAccountState,
+    )]
+    bid_level: Box<Account<'info, OrderBookLevel>>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK_LEVEL.as_bytes(),
+            order_book.key().as_ref(),
+            &[false as u8],
+            &ask_level.price.to_le_bytes(),
+        ],
+        bump=ask_level.bump,
+        constraint = order_book.get_best_price(false) == Some(ask_level.price) @ ErrorCode::InvalidAccountState,
+    )]
+    ask_level: Box<Account<'info, OrderBookLevel>>,
+    /// CHECK: ata of order_book for token_mint, checked in transfer_token
+    #[account(mut)]
+    book_vault: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, MatchOrders<'info>>) -> Result<()> {
+    let bidder = &ctx.accounts.bidder;
+    let seller = &ctx.accounts.seller;
+    let order_book = &mut ctx.accounts.order_book;
+    let bid_level = &mut ctx.accounts.bid_level;
+    let ask_level = &mut ctx.accounts.ask_level;
+
+    let bid = *bid_level
+        .orders
+        .first()
+        .ok_or(ErrorCode::InvalidAccountState)?;
+    let ask = *ask_level
+        .orders
+        .first()
+        .ok_or(ErrorCode::InvalidAccountState)?;
+    assert_keys_equal(bidder.key, &bid.owner)?;
+    assert_keys_equal(seller.key, &ask.owner)?;
+    let (price, size) = get_order_book_fill(bid_level.price, &bid, ask_level.price, &ask)?;
+
+    // the bid level holds bid price * size for the order, the fill takes price * size of it
+    let escrowed = get_order_book_amount(bid_level.price, size)?;
+    let amount = get_order_book_amount(price, size)?;
+    let fee = (amount as u128)
+        .checked_mul((DEFAULT_MAKER_FEE_BP as i32 + DEFAULT_TAKER_FEE_BP as i32).max(0) as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    let level_info = bid_level.to_account_info();
+    for (account, lamports) in [
+        (seller.to_account_info(), amount - fee),
+        (ctx.accounts.auction_house_treasury.to_account_info(), fee),
+        (bidder.to_account_info(), escrowed - amount),
+    ] {
+        **level_info.try_borrow_mut_lamports()? = level_info
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        **account.try_borrow_mut_lamports()? = account
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    }
+
+    let auction_house_key = order_book.auction_house_key;
+    let token_mint_key = order_book.token_mint;
+    transfer_token(
+        &size,
+        &ctx.accounts.payer,
+        &order_book.to_account_info(),
+        seller,
+        None,
+        DestinationSpecifier::Ai(bidder),
+        &ctx.accounts.token_mint,
+        &ctx.accounts.book_vault,
+        &ctx.accounts.bidder_token_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        None,
+        &[&[
+            PREFIX.as_bytes(),
+            ORDER_BOOK.as_bytes(),
+            auction_house_key.as_ref(),
+            token_mint_key.as_ref(),
+            &[order_book.bump],
+        ]],
+    )?;
+
+    for level in [bid_level, ask_level] {
+        let head = &mut level.orders[0];
+        head.size -= size;
+        if head.size == 0 {
+            level.orders.remove(0);
+        }
+        if level.orders.is_empty() {
+            order_book.remove_price(level.is_bid, level.price);
+        }
+    }
+
+    msg!(
+        "match_orders: {{\"order_book\":\"{}\",\"bidder\":\"{}\",\"seller\":\"{}\",\"bid_seq\":{},\"ask_seq\":{},\"price\":{},\"size\":{},\"fee\":{}}}",
+        order_book.key(),
+        bid.owner,
+        ask.owner,
+        bid.seq,
+        ask.seq,
+        price,
+        size,
+        fee,
+    );
+    Ok(())
+}
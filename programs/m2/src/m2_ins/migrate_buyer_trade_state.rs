@@ -0,0 +1,255 @@
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*, anchor_spl::token::Mint,
+};
+
+// MigrateBuyerTradeState moves an open bid from one auction house to another one
+// operated by the same authority, e.g. during a market re-deployment. Price, expiry,
+// royalty and payment mint are carried over as-is, and the escrow commitment is moved
+// from the old escrow to the new escrow if the new escrow can't cover the bid already.
+// The bid is uncounted on the old auction house and counted against the order limit of
+// the new one, whose notary has to cosign if it requires one.
+#[derive(Accounts)]
+pub struct MigrateBuyerTradeState<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary of new_auction_house, checked in assert_valid_notary
+    notary: UncheckedAccount<'info>,
+    token_mint: Account<'info, Mint>,
+    /// CHECK: authority, shared by both auction houses
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), new_auction_house.creator.as_ref()],
+        bump=new_auction_house.bump,
+        has_one=authority,
+        constraint = new_auction_house.key() != auction_house.key() @ ErrorCode::PublicKeysShouldBeUnique,
+    )]
+    new_auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: new_escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), new_auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    new_escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: seeds check + bid_args check
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump)]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: seeds check, must be empty
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            new_auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump)]
+    new_buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: WalletOrderCounter of the wallet on auction_house, derivation checked in uncount_open_order
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    /// CHECK: OrderLimitConfig of new_auction_house, derivation checked in count_open_order
+    new_order_limit_config: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the wallet on new_auction_house, derivation checked in count_open_order, created if needed
+    #[account(mut)]
+    new_wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the new_buyer_trade_state, derivation checked in count_open_order, created if needed
+    #[account(mut)]
+    new_counted_order: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** IF BID IS IN SPL **
+    // 0. payment_mint (required) - the payment mint of the bid
+    // 1. payment_source_token_account (required) - token account controlled by escrow_payment_account
+    // 2. payment_destination_token_account (required) - token account controlled by new_escrow_payment_account
+    // 3. token_program (required)
+    // 4. associated_token_program (required)
+    // ...
+    // -1. payer (optional) - this wallet will pay for the new bts rent
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, MigrateBuyerTradeState<'info>>) -> Result<()> {
+    let (remaining_accounts, possible_payer) =
+        split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let wallet = &ctx.accounts.wallet;
+    let payer = if let Some(p) = possible_payer {
+        p
+    } else {
+        wallet
+    };
+    let token_mint = &ctx.accounts.token_mint;
+    let auction_house = &ctx.accounts.auction_house;
+    let new_auction_house = &ctx.accounts.new_auction_house;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let new_escrow_payment_account = &ctx.accounts.new_escrow_payment_account;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let new_buyer_trade_state = &ctx.accounts.new_buyer_trade_state;
+    let system_program = &ctx.accounts.system_program;
+
+    if buyer_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    if !new_buyer_trade_state.data_is_empty() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+    if bid_args.buyer != wallet.key() || bid_args.token_mint != token_mint.key() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    let clock = Clock::get()?;
+    if is_trade_state_expired(bid_args.expiry, clock.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    assert_valid_notary(
+        new_auction_house,
+        &ctx.accounts.notary,
+        100u8, // 100% enforced cosign
+    )?;
+
+    let auction_house_key = auction_house.key();
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    let is_spl = bid_args.payment_mint != Pubkey::default();
+    let moved = if is_spl {
+        let payment_mint = index_ra!(remaining_accounts, 0);
+        let token_program = index_ra!(remaining_accounts, 3);
+        assert_keys_equal(payment_mint.key, &bid_args.payment_mint)?;
+        assert_keys_equal(token_program.key, &spl_token::id())?;
+        let new_escrow_balance = if index_ra!(remaining_accounts, 2).data_is_empty() {
+            0
+        } else {
+            assert_is_ata(
+                index_ra!(remaining_accounts, 2),
+                new_escrow_payment_account.key,
+                payment_mint.key,
+                new_escrow_payment_account.key,
+            )?
+            .amount
+        };
+        let diff = bid_args.buyer_price.saturating_sub(new_escrow_balance);
+        if diff > 0 {
+            transfer_token(
+                &diff,
+                payer,
+                escrow_payment_account,
+                wallet,
+                None,
+                DestinationSpecifier::Ai(new_escrow_payment_account),
+                payment_mint,
+                index_ra!(remaining_accounts, 1),
+                index_ra!(remaining_accounts, 2),
+                token_program,
+                system_program,
+                None,
+                escrow_signer_seeds,
+            )?;
+        }
+        diff
+    } else {
+        let diff = bid_args
+            .buyer_price
+            .saturating_sub(new_escrow_payment_account.lamports());
+        if diff > 0 {
+            if escrow_payment_account.lamports() < diff {
+                return Err(ErrorCode::InvalidTokenAmount.into());
+            }
+            invoke_signed(
+                &system_instruction::transfer(
+                    escrow_payment_account.key,
+                    new_escrow_payment_account.key,
+                    diff,
+                ),
+                &[
+                    escrow_payment_account.to_account_info(),
+                    new_escrow_payment_account.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+                escrow_signer_seeds,
+            )?;
+            try_close_buyer_escrow(
+                escrow_payment_account,
+                wallet,
+                system_program,
+                escrow_signer_seeds,
+            )?;
+        }
+        diff
+    };
+
+    count_open_order(
+        &ctx.accounts.new_order_limit_config,
+        Some(&ctx.accounts.new_wallet_order_counter),
+        Some(&ctx.accounts.new_counted_order),
+        new_buyer_trade_state.key,
+        payer,
+        &new_auction_house.key(),
+        wallet.key,
+        OrderSide::Bid,
+    )?;
+    let new_bts_bump = ctx.bumps.new_buyer_trade_state;
+    create_or_realloc_buyer_trade_state(
+        new_buyer_trade_state,
+        payer,
+        &[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            new_auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+            &[new_bts_bump],
+        ],
+    )?;
+    let bts_v2 = BuyerTradeStateV2 {
+        auction_house_key: new_auction_house.key(),
+        bump: new_bts_bump,
+        ..BuyerTradeStateV2::from_bid_args(&bid_args)
+    };
+    let bts_v2_serialized = bts_v2.try_to_vec()?;
+    new_buyer_trade_state.try_borrow_mut_data()?[8..8 + bts_v2_serialized.len()]
+        .copy_from_slice(&bts_v2_serialized);
+
+    close_account_anchor(buyer_trade_state, wallet)?;
+    uncount_open_order(
+        Some(&ctx.accounts.wallet_order_counter),
+        &ctx.accounts.counted_order,
+        buyer_trade_state.key,
+        wallet,
+        &auction_house_key,
+        OrderSide::Bid,
+    )?;
+
+    msg!(
+        "migrate_buyer_trade_state: {{\"old_buyer_trade_state\":\"{}\",\"new_buyer_trade_state\":\"{}\",\"escrow_moved\":{}}}",
+        buyer_trade_state.key(),
+        new_buyer_trade_state.key(),
+        moved,
+    );
+    msg!(
+        "{{\"price\":{},\"buyer_expiry\":{}}}",
+        bts_v2.buyer_price,
+        bts_v2.expiry
+    );
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize},
+};
+
+// MigrateCollectionPause moves a CollectionPause account, seeded with COLLECTION_PAUSE, to the
+// CollectionConfig of the same collection, keeping its pause, tick size and hold period. The old
+// account is closed to the authority. Until a collection is migrated none of its settings apply.
+#[derive(Accounts)]
+pub struct MigrateCollectionPause<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint, or the token mint if the token has no verified collection
+    collection: UncheckedAccount<'info>,
+    /// CHECK: seeds checked, discriminator checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_PAUSE.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    collection_pause: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=CollectionConfig::LEN,
+        bump,
+    )]
+    collection_config: Account<'info, CollectionConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, MigrateCollectionPause<'info>>) -> Result<()> {
+    let collection_pause = &ctx.accounts.collection_pause;
+    // CollectionPause had the same fields as CollectionConfig
+    let old = {
+        let data = collection_pause.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != COLLECTION_PAUSE_DISCRIMINATOR {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+        CollectionConfig::deserialize(&mut &data[8..])?
+    };
+
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.auction_house_key = old.auction_house_key;
+    collection_config.collection = old.collection;
+    collection_config.paused = old.paused;
+    collection_config.tick_size = old.tick_size;
+    collection_config.hold_period = old.hold_period;
+    collection_config.bump = ctx.bumps.collection_config;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+
+    close_account_anchor(collection_pause, &ctx.accounts.authority)?;
+
+    msg!(
+        "migrate_collection_pause: {{\"collection\":\"{}\",\"paused\":{},\"tick_size\":{},\"hold_period\":{}}}",
+        collection_config.collection,
+        collection_config.paused,
+        collection_config.tick_size,
+        collection_config.hold_period,
+    );
+    Ok(())
+}
@@ -27,3 +27,218 @@ pub use execute_sale_v2::*;
 
 pub mod buy_v2;
 pub use buy_v2::*;
+
+pub mod migrate_buyer_trade_state;
+pub use migrate_buyer_trade_state::*;
+
+pub mod sync_mint_listing_index;
+pub use sync_mint_listing_index::*;
+
+pub mod set_collection_pause;
+pub use set_collection_pause::*;
+pub mod migrate_collection_pause;
+pub use migrate_collection_pause::*;
+
+pub mod swap_into_escrow;
+pub use swap_into_escrow::*;
+
+pub mod set_floor_pricing;
+pub use set_floor_pricing::*;
+
+pub mod set_seller_status;
+pub use set_seller_status::*;
+
+pub mod set_buyer_status;
+pub use set_buyer_status::*;
+
+pub mod close_stale_listing;
+pub use close_stale_listing::*;
+
+pub mod custody_sell;
+pub use custody_sell::*;
+
+pub mod custody_cancel_sell;
+pub use custody_cancel_sell::*;
+
+pub mod set_frontend_config;
+pub use set_frontend_config::*;
+
+pub mod claim_frontend_fees;
+pub use claim_frontend_fees::*;
+
+pub mod mark_creator_listing;
+pub use mark_creator_listing::*;
+
+pub mod set_listing_operator;
+pub use set_listing_operator::*;
+
+pub mod operator_reprice_listing;
+pub use operator_reprice_listing::*;
+
+pub mod collection_bid;
+pub use collection_bid::*;
+
+pub mod sell_into_collection_bid;
+pub use sell_into_collection_bid::*;
+
+pub mod cancel_collection_bid;
+pub use cancel_collection_bid::*;
+
+pub mod set_royalty_fallback;
+pub use set_royalty_fallback::*;
+
+pub mod sell_dutch;
+pub use sell_dutch::*;
+
+pub mod create_auction;
+pub use create_auction::*;
+
+pub mod place_auction_bid;
+pub use place_auction_bid::*;
+
+pub mod settle_auction;
+pub use settle_auction::*;
+pub mod set_taker_fee_config;
+pub use set_taker_fee_config::*;
+pub mod set_buyback_config;
+pub use set_buyback_config::*;
+pub mod buyback_listing;
+pub use buyback_listing::*;
+pub mod sync_bid_expiry_alarm;
+pub use sync_bid_expiry_alarm::*;
+pub mod commit_bid;
+pub use commit_bid::*;
+pub mod reveal_bid;
+pub use reveal_bid::*;
+pub mod reclaim_sealed_bid;
+pub use reclaim_sealed_bid::*;
+pub mod reclaim_unsold;
+pub use reclaim_unsold::*;
+pub mod set_price_band_config;
+pub use set_price_band_config::*;
+pub mod attest_price;
+pub use attest_price::*;
+pub mod set_treasury_yield_config;
+pub use set_treasury_yield_config::*;
+pub mod sweep_treasury;
+pub use sweep_treasury::*;
+pub mod sell_bundle;
+pub use sell_bundle::*;
+pub mod append_bundle_items;
+pub use append_bundle_items::*;
+pub mod cancel_bundle;
+pub use cancel_bundle::*;
+pub mod execute_bundle_sale;
+pub use execute_bundle_sale::*;
+pub mod set_allowed_buyer;
+pub use set_allowed_buyer::*;
+pub mod return_all_assets;
+pub use return_all_assets::*;
+pub mod buy_delegated;
+pub use buy_delegated::*;
+pub mod sell_batch;
+pub use sell_batch::*;
+pub mod notary_cancel_bid;
+pub use notary_cancel_bid::*;
+pub mod propose_swap;
+pub use propose_swap::*;
+pub mod accept_swap;
+pub use accept_swap::*;
+pub mod cancel_swap;
+pub use cancel_swap::*;
+pub mod set_price_tick;
+pub use set_price_tick::*;
+pub mod list_rental;
+pub use list_rental::*;
+pub mod rent_nft;
+pub use rent_nft::*;
+pub mod reclaim_rental;
+pub use reclaim_rental::*;
+pub mod close_expired_buyer_state;
+pub use close_expired_buyer_state::*;
+pub mod set_hold_period;
+pub use set_hold_period::*;
+pub mod set_auto_renew;
+pub use set_auto_renew::*;
+pub mod renew_listing;
+pub use renew_listing::*;
+pub mod provision_creator_atas;
+pub use provision_creator_atas::*;
+pub mod set_escrow_depositor;
+pub use set_escrow_depositor::*;
+pub mod depositor_deposit;
+pub use depositor_deposit::*;
+pub mod set_royalty_subsidy;
+pub use set_royalty_subsidy::*;
+pub mod portfolio_bid;
+pub use portfolio_bid::*;
+pub mod sell_into_portfolio_bid;
+pub use sell_into_portfolio_bid::*;
+pub mod cancel_portfolio_bid;
+pub use cancel_portfolio_bid::*;
+pub mod set_secondary_price;
+pub use set_secondary_price::*;
+pub mod set_not_before;
+pub use set_not_before::*;
+pub mod set_offer_target;
+pub use set_offer_target::*;
+pub mod accept_offer;
+pub use accept_offer::*;
+pub mod accept_offers_batch;
+pub use accept_offers_batch::*;
+pub mod gift;
+pub use gift::*;
+pub mod get_state_layout;
+pub use get_state_layout::*;
+pub mod set_rebate_campaign;
+pub use set_rebate_campaign::*;
+pub mod commit_rebate_draw;
+pub use commit_rebate_draw::*;
+pub mod draw_rebate;
+pub use draw_rebate::*;
+pub mod claim_rebate;
+pub use claim_rebate::*;
+pub mod set_custodial_platform;
+pub use set_custodial_platform::*;
+pub mod place_order;
+pub use place_order::*;
+pub mod cancel_order;
+pub use cancel_order::*;
+pub mod match_orders;
+pub use match_orders::*;
+pub mod close_fill_proof;
+pub use close_fill_proof::*;
+pub mod sell_editions;
+pub use sell_editions::*;
+pub mod buy_edition;
+pub use buy_edition::*;
+pub mod cancel_edition_sale;
+pub use cancel_edition_sale::*;
+pub mod set_insurance_fund;
+pub use set_insurance_fund::*;
+pub mod queue_insurance_payout;
+pub use queue_insurance_payout::*;
+pub mod execute_insurance_payout;
+pub use execute_insurance_payout::*;
+pub mod list_staked;
+pub use list_staked::*;
+pub mod buy_staked;
+pub use buy_staked::*;
+pub mod cancel_staked_listing;
+pub use cancel_staked_listing::*;
+pub mod set_permanent_delegate_approval;
+pub use set_permanent_delegate_approval::*;
+pub mod set_order_limit_config;
+pub use set_order_limit_config::*;
+pub mod set_permanent_delegate_config;
+pub use set_permanent_delegate_config::*;
+pub mod publish_cancellation_root;
+pub use publish_cancellation_root::*;
+pub mod cancel_with_proof;
+pub use cancel_with_proof::*;
+pub mod execute_sale_auto;
+pub use execute_sale_auto::*;
+pub mod deposit_crank_bond;
+pub use deposit_crank_bond::*;
+pub mod withdraw_crank_bond;
+pub use withdraw_crank_bond::*;
@@ -0,0 +1,113 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, Token},
+};
+
+// NotaryCancelBid lets the auction house notary cancel a bid on behalf of a buyer who reported a
+// compromised session key. The buyer_trade_state is closed into the wallet and up to the bid price
+// is refunded from the escrow straight to the wallet, so whoever holds the session key can't get
+// the bid filled anymore. Escrowless bids have nothing escrowed, only their trade state is closed.
+// The reason code is logged for indexers, e.g. to tell reported session keys apart.
+#[derive(Accounts)]
+pub struct NotaryCancelBid<'info> {
+    /// CHECK: wallet of the buyer, receives the refund
+    #[account(mut)]
+    wallet: UncheckedAccount<'info>,
+    #[account(mut)]
+    notary: Signer<'info>,
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: check bid_args
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the wallet, derivation checked in uncount_open_order
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** IF THE BID IS PAID IN SPL **
+    // 0. payment_mint (required) - the payment mint of the bid
+    // 1. payment_source_token_account (required) - ata of escrow_payment_account
+    // 2. payment_destination_token_account (required) - ata of wallet, created if needed
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, NotaryCancelBid<'info>>,
+    reason: u8,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let notary = &ctx.accounts.notary;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    if buyer_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    let refund = refund_bid_escrow(
+        &bid_args,
+        notary,
+        wallet,
+        escrow_payment_account,
+        ctx.remaining_accounts,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        escrow_signer_seeds,
+    )?;
+
+    close_account_anchor(buyer_trade_state, wallet)?;
+    uncount_open_order(
+        Some(&ctx.accounts.wallet_order_counter),
+        &ctx.accounts.counted_order,
+        &buyer_trade_state.key(),
+        wallet,
+        &auction_house_key,
+        OrderSide::Bid,
+    )?;
+
+    msg!(
+        "notary_cancel_bid: {{\"buyer_trade_state\":\"{}\",\"buyer\":\"{}\",\"payment_mint\":\"{}\",\"refund\":{},\"reason\":{}}}",
+        buyer_trade_state.key(),
+        wallet.key(),
+        bid_args.payment_mint,
+        refund,
+        reason,
+    );
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::index_ra;
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OperatorRepriceListingArgs {
+    pub price: u64,
+    pub expiry: i64,
+}
+
+// OperatorRepriceListing lets an enabled ListingOperator of the seller change the price and expiry
+// of any v2 listing of the seller, without the seller having to sign again.
+#[derive(Accounts)]
+pub struct OperatorRepriceListing<'info> {
+    operator: Signer<'info>,
+    /// CHECK: derivation checked in is_listing_operator
+    listing_operator: UncheckedAccount<'info>,
+    #[account(mut)]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+    // remaining accounts:
+    // 0. payment_mint (required if the listing is in SPL) - the payment mint of the listing
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, OperatorRepriceListing<'info>>,
+    args: OperatorRepriceListingArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+
+    if !is_listing_operator(
+        &ctx.accounts.listing_operator,
+        &ctx.accounts.operator,
+        &seller_trade_state.seller,
+    )? {
+        return Err(ErrorCode::NoValidSignerPresent.into());
+    }
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    // the listing mode is part of the expiry sign, operators can't change it
+    if (args.expiry < 0) != (seller_trade_state.expiry < 0) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if seller_trade_state.payment_mint != Pubkey::default() {
+        let payment_mint = index_ra!(remaining_accounts, 0);
+        assert_keys_equal(payment_mint.key, &seller_trade_state.payment_mint)?;
+        assert_price_precision(args.price, payment_mint)?;
+    }
+
+    seller_trade_state.buyer_price = args.price;
+    seller_trade_state.expiry = args.expiry;
+
+    msg!(
+        "operator_reprice_listing: {{\"seller_trade_state\":\"{}\",\"operator\":\"{}\"}}",
+        seller_trade_state.key(),
+        ctx.accounts.operator.key(),
+    );
+    msg!(
+        "{{\"price\":{},\"seller_expiry\":{}}}",
+        args.price,
+        args.expiry
+    );
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+// PlaceAuctionBid escrows a new highest bid in the auction_escrow and refunds the bidder it
+// outbids in the same instruction, so at most one bid is held by the escrow at any time. Bids landing
+// in the auction's extension window push end_time back. A bid reaching the buy_now_price is taken
+// at the buy_now_price and ends the auction, so it can be settled right away.
+// With place_auction_proxy_bid the amount is a maximum: it is escrowed in full, but the highest bid
+// is only raised by the minimum increment over the previous maximum. Later bids up to the maximum
+// raise the highest bid for the proxy bidder instead of outbidding them, and settle_auction refunds
+// what's left of the maximum.
+#[derive(Accounts)]
+pub struct PlaceAuctionBid<'info> {
+    #[account(mut)]
+    bidder: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            AUCTION.as_bytes(),
+            auction_state.auction_house_key.as_ref(),
+            auction_state.token_mint.as_ref(),
+        ],
+        bump=auction_state.bump,
+    )]
+    auction_state: Box<Account<'info, AuctionState>>,
+    /// CHECK: checked in seeds
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), AUCTION_ESCROW.as_bytes(), auction_state.key().as_ref()],
+        bump=auction_state.escrow_bump,
+    )]
+    auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: the outbid highest_bidder, any account if there's no bid yet
+    #[account(mut)]
+    previous_bidder: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    // remaining accounts:
+    // ** IF USING SPL **
+    // 0. payment_mint (required) - the payment mint of the auction
+    // 1. payment_source_token_account (required) - token account of the bidder
+    // 2. payment_escrow_token_account (required) - ata of auction_escrow, created if needed
+    // 3. payment_previous_bidder_token_account (required if there's a bid) - ata of the previous bidder, created if needed
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceAuctionBid<'info>>,
+    bid_amount: u64,
+    is_proxy: bool,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let bidder = &ctx.accounts.bidder;
+    let auction_escrow = &ctx.accounts.auction_escrow;
+    let previous_bidder = &ctx.accounts.previous_bidder;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let auction_state = &mut ctx.accounts.auction_state;
+    let auction_state_key = auction_state.key();
+
+    let now = Clock::get()?.unix_timestamp;
+    if auction_state.is_sealed()
+        || !is_start_reached(auction_state.start_time, now)
+        || now >= auction_state.end_time
+    {
+        return Err(ErrorCode::AuctionNotActive.into());
+    }
+    if bidder.key() == auction_state.seller {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    let has_previous_bid = auction_state.bid_count > 0;
+    let escrowed_bid = auction_state.get_escrowed_bid();
+    let is_buy_now = auction_state.is_buy_now(bid_amount);
+    // a proxy bid is bid_amount at most, a maximum reaching the buy now price buys right away
+    let max_bid = if is_buy_now {
+        auction_state.buy_now_price
+    } else {
+        bid_amount
+    };
+    let min_bid = auction_state.get_min_bid()?;
+    // the highest bid is always below the buy now price, a bid reaching it ends the auction
+    if !is_buy_now && (max_bid > MAX_PRICE || max_bid < min_bid) {
+        return Err(ErrorCode::AuctionBidTooLow.into());
+    }
+    assert_price_on_tick(max_bid, auction_state.tick_size)?;
+    if has_previous_bid {
+        assert_keys_equal(previous_bidder.key, &auction_state.highest_bidder)?;
+    }
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, bidder.key)?;
+
+    let is_highest_bidder = has_previous_bid && bidder.key() == auction_state.highest_bidder;
+    if !is_buy_now && is_highest_bidder && max_bid <= escrowed_bid {
+        return Err(ErrorCode::AuctionBidTooLow.into());
+    }
+    // the highest bidder raising their proxy maximum tops up the escrow and keeps the highest bid,
+    // a bid within the maximum of a proxy highest bidder only raises the highest bid up to it
+    let is_max_raise = !is_buy_now && is_proxy && is_highest_bidder;
+    let is_outbid =
+        !is_buy_now && !is_highest_bidder && has_previous_bid && max_bid <= escrowed_bid;
+    let (amount, escrow_amount, refund_amount) = if is_buy_now {
+        (max_bid, max_bid, escrowed_bid)
+    } else if is_max_raise {
+        (auction_state.highest_bid, max_bid - escrowed_bid, 0)
+    } else if is_outbid {
+        (
+            auction_state.get_proxy_bid(escrowed_bid, auction_state.get_min_raise(max_bid)?)?,
+            0,
+            0,
+        )
+    } else if is_proxy {
+        let min_amount = if has_previous_bid {
+            auction_state.get_min_raise(escrowed_bid)?.max(min_bid)
+        } else {
+            min_bid
+        };
+        (
+            auction_state.get_proxy_bid(max_bid, min_amount)?,
+            max_bid,
+            escrowed_bid,
+        )
+    } else {
+        (max_bid, max_bid, escrowed_bid)
+    };
+
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        AUCTION_ESCROW.as_bytes(),
+        auction_state_key.as_ref(),
+        &[auction_state.escrow_bump],
+    ]];
+
+    // escrow the new bid before refunding the outbid one, the escrow never runs empty
+    if auction_state.is_spl() && escrow_amount > 0 {
+        let payment_mint = index_ra!(remaining_accounts, 0);
+        assert_keys_equal(payment_mint.key, &auction_state.payment_mint)?;
+        assert_price_precision(max_bid, payment_mint)?;
+        transfer_token(
+            &escrow_amount,
+            bidder,
+            bidder,
+            bidder,
+            None,
+            DestinationSpecifier::Ai(auction_escrow),
+            payment_mint,
+            index_ra!(remaining_accounts, 1),
+            index_ra!(remaining_accounts, 2),
+            token_program,
+            system_program,
+            None,
+            &[],
+        )?;
+        if refund_amount > 0 {
+            transfer_token(
+                &refund_amount,
+                bidder,
+                auction_escrow,
+                bidder,
+                None,
+                DestinationSpecifier::Ai(previous_bidder),
+                payment_mint,
+                index_ra!(remaining_accounts, 2),
+                index_ra!(remaining_accounts, 3),
+                token_program,
+                system_program,
+                None,
+                escrow_signer_seeds,
+            )?;
+        }
+    } else if escrow_amount > 0 {
+        invoke(
+            &system_instruction::transfer(bidder.key, auction_escrow.key, escrow_amount),
+            &[
+                bidder.to_account_info(),
+                auction_escrow.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+        if refund_amount > 0 {
+            invoke_signed(
+                &system_instruction::transfer(
+                    auction_escrow.key,
+                    previous_bidder.key,
+                    refund_amount,
+                ),
+                &[
+                    auction_escrow.to_account_info(),
+                    previous_bidder.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+                escrow_signer_seeds,
+            )?;
+        }
+    }
+
+    if is_max_raise {
+        auction_state.highest_max_bid = max_bid;
+        msg!(
+            "auction_proxy_raised: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"max_bid\":{}}}",
+            auction_state_key,
+            bidder.key(),
+            max_bid,
+        );
+        return Ok(());
+    }
+    if is_outbid {
+        msg!(
+            "auction_proxy_outbid: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{},\"highest_bid\":{}}}",
+            auction_state_key,
+            bidder.key(),
+            max_bid,
+            amount,
+        );
+    }
+    if refund_amount > 0 {
+        msg!(
+            "auction_bid_refunded: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{}}}",
+            auction_state_key,
+            auction_state.highest_bidder,
+            refund_amount,
+        );
+    }
+    auction_state.highest_bid = amount;
+    if !is_outbid {
+        auction_state.highest_bidder = bidder.key();
+        auction_state.highest_max_bid = max_bid;
+    }
+    auction_state.bid_count = auction_state
+        .bid_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if is_buy_now {
+        // settle_auction can be called right away
+        auction_state.end_time = now;
+        msg!(
+            "auction_buy_now: {{\"auction\":\"{}\",\"buyer\":\"{}\",\"price\":{}}}",
+            auction_state_key,
+            bidder.key(),
+            amount,
+        );
+    } else {
+        let end_time = auction_state.get_extended_end_time(now)?;
+        if end_time != auction_state.end_time {
+            msg!(
+                "auction_extended: {{\"auction\":\"{}\",\"previous_end_time\":{},\"end_time\":{}}}",
+                auction_state_key,
+                auction_state.end_time,
+                end_time,
+            );
+            auction_state.end_time = end_time;
+        }
+    }
+
+    msg!(
+        "place_auction_bid: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{},\"end_time\":{}}}",
+        auction_state_key,
+        auction_state.highest_bidder,
+        amount,
+        auction_state.end_time,
+    );
+    Ok(())
+}
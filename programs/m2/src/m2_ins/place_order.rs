@@ -0,0 +1,145 @@
+use solana_program::{program::invoke, system_instruction};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Mint, Token},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlaceOrderArgs {
+    pub is_bid: bool,
+    pub price: u64, // lamports per base unit of the token
+    pub size: u64,  // base units of the token
+}
+
+// PlaceOrder rests a bid or an ask for a semi-fungible or fungible token on the order book of the
+// auction house for its mint. Orders at the same price share an order_book_level and fill oldest
+// first, and the order_book keeps the prices of its levels sorted best first, so match_orders can
+// fill by price-time priority. An ask moves the tokens into the book_vault, a bid deposits
+// price * size lamports into its level. Levels stay open once created and are reused.
+#[derive(Accounts)]
+#[instruction(args: PlaceOrderArgs)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+    #[account(constraint = token_mint.supply > 1 || token_mint.decimals > 0 @ ErrorCode::InvalidTokenMint)]
+    token_mint: Box<Account<'info, Mint>>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=owner,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        space=OrderBook::LEN,
+        bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+    #[account(
+        init_if_needed,
+        payer=owner,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_BOOK_LEVEL.as_bytes(),
+            order_book.key().as_ref(),
+            &[args.is_bid as u8],
+            &args.price.to_le_bytes(),
+        ],
+        space=OrderBookLevel::LEN,
+        bump,
+    )]
+    order_book_level: Box<Account<'info, OrderBookLevel>>,
+    /// CHECK: ata of order_book for token_mint, created and checked in transfer_token for asks
+    #[account(mut)]
+    book_vault: UncheckedAccount<'info>,
+    /// CHECK: token account of the owner holding the tokens of an ask, unused for bids
+    #[account(mut)]
+    owner_token_account: UncheckedAccount<'info>,
+    /// CHECK: buyer_status of the owner for bids, seller_status for asks, derivation checked in assert_*_not_suspended
+    owner_status: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceOrder<'info>>,
+    args: PlaceOrderArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let system_program = &ctx.accounts.system_program;
+
+    if args.price == 0 || args.price > MAX_PRICE || args.size == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    let amount = get_order_book_amount(args.price, args.size)?;
+
+    if args.is_bid {
+        assert_buyer_not_suspended(&ctx.accounts.owner_status, owner.key)?;
+        invoke(
+            &system_instruction::transfer(owner.key, &ctx.accounts.order_book_level.key(), amount),
+            &[
+                owner.to_account_info(),
+                ctx.accounts.order_book_level.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    } else {
+        assert_seller_not_suspended(&ctx.accounts.owner_status, owner.key)?;
+        transfer_token(
+            &args.size,
+            owner,
+            owner,
+            owner,
+            None,
+            DestinationSpecifier::Ai(&ctx.accounts.order_book.to_account_info()),
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.book_vault,
+            &ctx.accounts.token_program,
+            system_program,
+            None,
+            &[],
+        )?;
+    }
+
+    let order_book = &mut ctx.accounts.order_book;
+    order_book.auction_house_key = ctx.accounts.auction_house.key();
+    order_book.token_mint = ctx.accounts.token_mint.key();
+    order_book.bump = ctx.bumps.order_book;
+    order_book.add_price(args.is_bid, args.price)?;
+    let seq = order_book.take_seq()?;
+
+    let order_book_level = &mut ctx.accounts.order_book_level;
+    order_book_level.order_book = order_book.key();
+    order_book_level.is_bid = args.is_bid;
+    order_book_level.price = args.price;
+    order_book_level.bump = ctx.bumps.order_book_level;
+    order_book_level.push(BookOrder {
+        owner: owner.key(),
+        size: args.size,
+        seq,
+    })?;
+
+    msg!(
+        "place_order: {{\"order_book\":\"{}\",\"owner\":\"{}\",\"is_bid\":{},\"price\":{},\"size\":{},\"seq\":{}}}",
+        order_book.key(),
+        owner.key(),
+        args.is_bid,
+        args.price,
+        args.size,
+        seq,
+    );
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use solana_program::{program::invoke, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PortfolioBidArgs {
+    pub bid_id: u64,
+    pub price: u64,
+    pub expiry: i64,
+    pub buyer_creator_royalty_bp: u16,
+    pub mints: Vec<Pubkey>,
+}
+
+// PortfolioBid places (or updates) a bid for any one of up to MAX_PORTFOLIO_BID_MINTS mints, for
+// sweepers targeting a shortlist. Whichever seller fills it first with sell_into_portfolio_bid
+// gets it, and the bid is closed. Funds stay in the buyer's escrow_payment_account like for
+// single mint bids, so one deposit can back every kind of bid.
+#[derive(Accounts)]
+#[instruction(args: PortfolioBidArgs)]
+pub struct PortfolioBid<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            PORTFOLIO_BID.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            args.bid_id.to_le_bytes().as_ref(),
+        ],
+        space=PortfolioBidState::LEN,
+        bump,
+    )]
+    portfolio_bid_state: Box<Account<'info, PortfolioBidState>>,
+    /// CHECK: buyer_referral
+    buyer_referral: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the buyer is paying in a token, this is the mint of that token
+    // 1. payment_source_token_account (optional) - if the buyer is paying in a token, this is the source token account, we need to verify sufficient balance
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, PortfolioBid<'info>>,
+    args: PortfolioBidArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let portfolio_bid_state = &mut ctx.accounts.portfolio_bid_state;
+    let remaining_accounts = ctx.remaining_accounts;
+    let is_spl = remaining_accounts.len() == 2;
+
+    if args.buyer_creator_royalty_bp > 10_000 {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.mints.is_empty() || args.mints.len() > MAX_PORTFOLIO_BID_MINTS {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    let payment_mint = if is_spl {
+        index_ra!(remaining_accounts, 0).key()
+    } else {
+        Pubkey::default()
+    };
+    if portfolio_bid_state.bump != 0 && portfolio_bid_state.payment_mint != payment_mint {
+        // the payment mint of an existing bid can't be changed, cancel and bid again instead
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    // the bid is filled at most once, so the escrow only has to cover one token
+    if remaining_accounts.is_empty() {
+        // SOL
+        if escrow_payment_account.lamports() < args.price {
+            let diff = args
+                .price
+                .checked_sub(escrow_payment_account.lamports())
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            invoke(
+                &system_instruction::transfer(wallet.key, &escrow_payment_account.key(), diff),
+                &[
+                    wallet.to_account_info(),
+                    escrow_payment_account.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else if is_spl {
+        // SPL
+        assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.price, index_ra!(remaining_accounts, 0))?;
+        let payment_token_account_parsed = assert_is_ata(
+            index_ra!(remaining_accounts, 1),
+            escrow_payment_account.key,
+            index_ra!(remaining_accounts, 0).key,
+            escrow_payment_account.key,
+        )?;
+        if payment_token_account_parsed.amount < args.price {
+            return Err(ErrorCode::InvalidTokenAmount.into());
+        }
+    } else {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    portfolio_bid_state.auction_house_key = auction_house.key();
+    portfolio_bid_state.buyer = wallet.key();
+    portfolio_bid_state.buyer_referral = ctx.accounts.buyer_referral.key();
+    portfolio_bid_state.bid_id = args.bid_id;
+    portfolio_bid_state.buyer_price = args.price;
+    portfolio_bid_state.payment_mint = payment_mint;
+    portfolio_bid_state.buyer_creator_royalty_bp = args.buyer_creator_royalty_bp;
+    portfolio_bid_state.expiry = get_default_buyer_state_expiry(args.expiry);
+    portfolio_bid_state.bump = ctx.bumps.portfolio_bid_state;
+    portfolio_bid_state.mints = args.mints;
+
+    msg!(
+        "portfolio_bid: {{\"portfolio_bid_state\":\"{}\",\"price\":{},\"mints\":{},\"buyer_expiry\":{}}}",
+        portfolio_bid_state.key(),
+        portfolio_bid_state.buyer_price,
+        portfolio_bid_state.mints.len(),
+        portfolio_bid_state.expiry,
+    );
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Mint, SetAuthority, Token, TokenAccount},
+    spl_token::instruction::AuthorityType,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeSwapArgs {
+    pub counterparty: Pubkey,
+    pub sweetener: u64, // lamports, paid out of the proposer's escrow
+    pub expiry: i64,    // 0 never expires
+}
+
+// ProposeSwap offers the counterparty to trade the proposer's offered_mint for the counterparty's
+// requested_mint, optionally adding a sweetener out of the proposer's escrow. The offered token
+// stays in the proposer's ata, which is handed to program_as_signer the same way a non-movable
+// sell does, until accept_swap or cancel_swap. Swaps don't move any price, so they don't pay
+// royalties or fees and aren't recorded as sales.
+#[derive(Accounts)]
+pub struct ProposeSwap<'info> {
+    #[account(mut)]
+    proposer: Signer<'info>,
+    #[account(
+        constraint = offered_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = offered_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    offered_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata of offered_mint, checked in assert_metadata_valid
+    offered_metadata: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = offered_mint,
+        associated_token::authority = proposer,
+        constraint = offered_token_account.amount == 1 @ ErrorCode::InvalidTokenAmount,
+    )]
+    offered_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = requested_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = requested_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+        constraint = requested_mint.key() != offered_mint.key() @ ErrorCode::PublicKeysShouldBeUnique,
+    )]
+    requested_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata of requested_mint, checked in assert_metadata_valid
+    requested_metadata: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init,
+        payer=proposer,
+        seeds=[
+            PREFIX.as_bytes(),
+            SWAP_OFFER.as_bytes(),
+            auction_house.key().as_ref(),
+            proposer.key().as_ref(),
+            offered_mint.key().as_ref(),
+        ],
+        space=SwapOffer::LEN,
+        bump,
+    )]
+    swap_offer: Box<Account<'info, SwapOffer>>,
+    /// CHECK: escrow_payment_account of the proposer, has to hold the sweetener
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), proposer.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProposeSwap<'info>>,
+    args: ProposeSwapArgs,
+) -> Result<()> {
+    let proposer = &ctx.accounts.proposer;
+    let offered_mint = &ctx.accounts.offered_mint;
+    let offered_metadata = &ctx.accounts.offered_metadata;
+    let offered_token_account = &ctx.accounts.offered_token_account;
+    let requested_mint = &ctx.accounts.requested_mint;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    if args.counterparty == proposer.key() {
+        return Err(ErrorCode::PublicKeysShouldBeUnique.into());
+    }
+    if args.sweetener > MAX_PRICE || args.sweetener > ctx.accounts.escrow_payment_account.lamports()
+    {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.expiry < 0
+        || (args.expiry > 0 && is_expiry_passed(args.expiry, Clock::get()?.unix_timestamp))
+    {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    assert_metadata_valid(offered_metadata, &offered_mint.key())?;
+    assert_metadata_valid(&ctx.accounts.requested_metadata, &requested_mint.key())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house_key,
+        &Metadata::safe_deserialize(&offered_metadata.data.borrow())?,
+    )?;
+
+    anchor_spl::token::set_authority(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                account_or_mint: offered_token_account.to_account_info(),
+                current_authority: proposer.to_account_info(),
+            },
+        ),
+        AuthorityType::AccountOwner,
+        Some(ctx.accounts.program_as_signer.key()),
+    )?;
+
+    let swap_offer = &mut ctx.accounts.swap_offer;
+    swap_offer.auction_house_key = auction_house_key;
+    swap_offer.proposer = proposer.key();
+    swap_offer.offered_mint = offered_mint.key();
+    swap_offer.offered_token_account = offered_token_account.key();
+    swap_offer.counterparty = args.counterparty;
+    swap_offer.requested_mint = requested_mint.key();
+    swap_offer.sweetener = args.sweetener;
+    swap_offer.expiry = args.expiry;
+    swap_offer.bump = ctx.bumps.swap_offer;
+
+    msg!(
+        "propose_swap: {{\"swap_offer\":\"{}\",\"offered_mint\":\"{}\",\"counterparty\":\"{}\",\"requested_mint\":\"{}\",\"sweetener\":{},\"expiry\":{}}}",
+        swap_offer.key(),
+        swap_offer.offered_mint,
+        swap_offer.counterparty,
+        swap_offer.requested_mint,
+        swap_offer.sweetener,
+        swap_offer.expiry,
+    );
+    Ok(())
+}
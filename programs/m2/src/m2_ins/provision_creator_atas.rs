@@ -0,0 +1,73 @@
+use mpl_token_metadata::accounts::Metadata;
+use spl_associated_token_account::get_associated_token_address;
+
+use {
+    crate::errors::ErrorCode,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+// ProvisionCreatorAtas is a permissionless instruction that creates the payment_mint atas of the
+// creators of a token ahead of time, funded by the payer. Tokens of a collection share their
+// creators, so provisioning one token covers the collection, and SPL settlements of it don't have
+// to create creator atas (and pass the creators' wallets) anymore. Creators without a share and
+// atas that already exist are skipped.
+#[derive(Accounts)]
+pub struct ProvisionCreatorAtas<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: any token of the collection
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: metadata of token_mint, checked in assert_metadata_valid
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: checked in assert_payment_mint
+    payment_mint: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. (creator, creator_ata) of every creator of the metadata, in the order of the creators
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ProvisionCreatorAtas<'info>>) -> Result<()> {
+    let payment_mint = &ctx.accounts.payment_mint;
+
+    assert_payment_mint(payment_mint)?;
+    assert_metadata_valid(&ctx.accounts.metadata, ctx.accounts.token_mint.key)?;
+    let metadata_parsed = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+    let creators = metadata_parsed.creators.clone().unwrap_or_default();
+    if ctx.remaining_accounts.len() != creators.len() * 2 {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+
+    let mut created = 0u8;
+    for (creator, accounts) in creators.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (creator_info, creator_ata) = (&accounts[0], &accounts[1]);
+        assert_keys_equal(creator_info.key, &creator.address)?;
+        assert_keys_equal(
+            creator_ata.key,
+            &get_associated_token_address(&creator.address, payment_mint.key),
+        )?;
+        if creator.share == 0 || !creator_ata.data_is_empty() {
+            continue;
+        }
+        make_ata(
+            creator_ata.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            creator_info.to_account_info(),
+            payment_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+        created += 1;
+    }
+
+    msg!(
+        "provision_creator_atas: {{\"collection\":\"{}\",\"payment_mint\":\"{}\",\"created\":{}}}",
+        get_collection_key(&metadata_parsed),
+        payment_mint.key,
+        created,
+    );
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// PublishCancellationRoot lets the auction house authority force-cancel a set of orders, e.g. after
+// a critical bug in a listing mode, without signing for every one of them. merkle_root is the root
+// of a tree whose leaves are the keccak hashes of the trade states to cancel, see
+// get_cancellation_leaf, and anyone can then crank cancel_with_proof for each of them. Publishing
+// again replaces the snapshot, a zero root cancels nothing.
+#[derive(Accounts)]
+pub struct PublishCancellationRoot<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            CANCELLATION_SNAPSHOT.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=CancellationSnapshot::LEN,
+        bump,
+    )]
+    cancellation_snapshot: Account<'info, CancellationSnapshot>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, PublishCancellationRoot<'info>>,
+    merkle_root: [u8; 32],
+    order_count: u32,
+) -> Result<()> {
+    let cancellation_snapshot = &mut ctx.accounts.cancellation_snapshot;
+    cancellation_snapshot.auction_house_key = ctx.accounts.auction_house.key();
+    cancellation_snapshot.merkle_root = merkle_root;
+    cancellation_snapshot.order_count = order_count;
+    cancellation_snapshot.published_at = Clock::get()?.unix_timestamp;
+    cancellation_snapshot.bump = ctx.bumps.cancellation_snapshot;
+
+    msg!(
+        "publish_cancellation_root: {{\"cancellation_snapshot\":\"{}\",\"auction_house\":\"{}\",\"order_count\":{}}}",
+        cancellation_snapshot.key(),
+        cancellation_snapshot.auction_house_key,
+        order_count,
+    );
+    Ok(())
+}
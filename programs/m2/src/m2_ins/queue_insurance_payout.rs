@@ -0,0 +1,49 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// QueueInsurancePayout queues a payout of amount from the InsuranceFund to recipient, which the
+// authority can execute once INSURANCE_PAYOUT_TIMELOCK_SECONDS passed. Queueing replaces any payout
+// queued before and restarts the timelock, an amount of 0 cancels it.
+#[derive(Accounts)]
+pub struct QueueInsurancePayout<'info> {
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: wallet compensated by the payout
+    recipient: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            INSURANCE_FUND.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=insurance_fund.bump,
+    )]
+    insurance_fund: Account<'info, InsuranceFund>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, QueueInsurancePayout<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    if amount == 0 {
+        insurance_fund.payout_recipient = Pubkey::default();
+        insurance_fund.payout_available_at = 0;
+    } else {
+        insurance_fund.payout_recipient = ctx.accounts.recipient.key();
+        insurance_fund.payout_available_at = now + INSURANCE_PAYOUT_TIMELOCK_SECONDS;
+    }
+    insurance_fund.payout_amount = amount;
+    insurance_fund.updated_at = now;
+
+    msg!(
+        "queue_insurance_payout: {{\"auction_house\":\"{}\",\"recipient\":\"{}\",\"amount\":{},\"available_at\":{}}}",
+        insurance_fund.auction_house_key,
+        insurance_fund.payout_recipient,
+        amount,
+        insurance_fund.payout_available_at,
+    );
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use mpl_token_metadata::{
+    accounts::TokenRecord,
+    instructions::{RevokeUtilityV1Builder, UnlockV1Builder},
+};
+use solana_program::{
+    program::{invoke, invoke_signed},
+    sysvar,
+};
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+};
+
+// ReclaimRental returns control of a rental listing's pNFT to the owner: once the term is over,
+// program_as_signer unlocks the token, then the owner revokes the utility delegate. A listing that
+// was never rented can be reclaimed at any time, which is how a rental listing is cancelled. The
+// rental listing is closed into the owner.
+#[derive(Accounts)]
+pub struct ReclaimRental<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+    /// CHECK: checked against rental_listing
+    #[account(address = rental_listing.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: check in cpi
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: checked against rental_listing
+    #[account(mut, address = rental_listing.token_account)]
+    token_account: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    edition: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut, address = TokenRecord::find_pda(&token_mint.key(), &token_account.key()).0)]
+    token_record: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=owner,
+        seeds=[
+            PREFIX.as_bytes(),
+            RENTAL_LISTING.as_bytes(),
+            rental_listing.auction_house_key.as_ref(),
+            owner.key().as_ref(),
+            rental_listing.token_mint.as_ref(),
+        ],
+        bump=rental_listing.bump,
+        has_one=owner,
+    )]
+    rental_listing: Box<Account<'info, RentalListing>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    authorization_rules_program: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    authorization_rules: UncheckedAccount<'info>,
+    /// CHECK: check in cpi
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ReclaimRental<'info>>) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let rental_listing = &ctx.accounts.rental_listing;
+    let account_infos = [
+        program_as_signer.to_account_info(),
+        owner.to_account_info(),
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.edition.to_account_info(),
+        ctx.accounts.token_record.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.instructions.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.authorization_rules_program.to_account_info(),
+        ctx.accounts.authorization_rules.to_account_info(),
+        ctx.accounts.token_metadata_program.to_account_info(),
+    ];
+
+    if rental_listing.is_rented() {
+        if Clock::get()?.unix_timestamp < rental_listing.rented_until {
+            return Err(ErrorCode::RentalNotExpired.into());
+        }
+        let ins = UnlockV1Builder::new()
+            .authority(program_as_signer.key())
+            .token_owner(Some(owner.key()))
+            .token(ctx.accounts.token_account.key())
+            .mint(ctx.accounts.token_mint.key())
+            .metadata(ctx.accounts.metadata.key())
+            .edition(Some(ctx.accounts.edition.key()))
+            .token_record(Some(ctx.accounts.token_record.key()))
+            .payer(owner.key())
+            .system_program(ctx.accounts.system_program.key())
+            .sysvar_instructions(ctx.accounts.instructions.key())
+            .spl_token_program(Some(ctx.accounts.token_program.key()))
+            .authorization_rules_program(Some(ctx.accounts.authorization_rules_program.key()))
+            .authorization_rules(Some(ctx.accounts.authorization_rules.key()))
+            .instruction();
+        invoke_signed(
+            &ins,
+            &account_infos,
+            &[&[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[ctx.bumps.program_as_signer],
+            ]],
+        )?;
+    }
+
+    let ins = RevokeUtilityV1Builder::new()
+        .delegate(program_as_signer.key())
+        .metadata(ctx.accounts.metadata.key())
+        .master_edition(Some(ctx.accounts.edition.key()))
+        .token_record(Some(ctx.accounts.token_record.key()))
+        .mint(ctx.accounts.token_mint.key())
+        .token(ctx.accounts.token_account.key())
+        .authority(owner.key())
+        .payer(owner.key())
+        .system_program(ctx.accounts.system_program.key())
+        .sysvar_instructions(ctx.accounts.instructions.key())
+        .spl_token_program(Some(ctx.accounts.token_program.key()))
+        .authorization_rules_program(Some(ctx.accounts.authorization_rules_program.key()))
+        .authorization_rules(Some(ctx.accounts.authorization_rules.key()))
+        .instruction();
+    invoke(&ins, &account_infos)?;
+
+    msg!(
+        "reclaim_rental: {{\"rental_listing\":\"{}\",\"renter\":\"{}\"}}",
+        rental_listing.key(),
+        rental_listing.renter,
+    );
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// ReclaimSealedBid returns the deposit of a sealed bid that wasn't revealed once the reveal window
+// of its auction closed. It doesn't need the auction_state, which is closed at settlement.
+#[derive(Accounts)]
+pub struct ReclaimSealedBid<'info> {
+    #[account(mut)]
+    bidder: Signer<'info>,
+    #[account(
+        mut,
+        close=bidder,
+        seeds=[
+            PREFIX.as_bytes(),
+            SEALED_BID.as_bytes(),
+            sealed_bid.auction_state.as_ref(),
+            bidder.key().as_ref(),
+        ],
+        bump=sealed_bid.bump,
+        has_one=bidder,
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ReclaimSealedBid<'info>>) -> Result<()> {
+    let sealed_bid = &ctx.accounts.sealed_bid;
+    if Clock::get()?.unix_timestamp < sealed_bid.reveal_end_time {
+        return Err(ErrorCode::AuctionNotEnded.into());
+    }
+
+    msg!(
+        "reclaim_sealed_bid: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"deposit\":{}}}",
+        sealed_bid.auction_state,
+        sealed_bid.bidder,
+        sealed_bid.deposit,
+    );
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+// ReclaimUnsold lets the seller take the token back from an auction that ended without a
+// qualifying bid, without the notary, metadata and sale record accounts settle_auction needs. A
+// highest bid below the reserve price is refunded to its bidder. The token sits in a token_vault of
+// the auction_escrow rather than under a delegate or freeze, so moving it out is all that's needed;
+// the emptied vault is closed and the escrow rent returned to the seller.
+#[derive(Accounts)]
+pub struct ReclaimUnsold<'info> {
+    #[account(mut, address = auction_state.seller)]
+    seller: Signer<'info>,
+    /// CHECK: checked against auction_state if there was a bid, refunded the highest bid
+    #[account(mut)]
+    highest_bidder: UncheckedAccount<'info>,
+    /// CHECK: checked against auction_state
+    #[account(address = auction_state.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            AUCTION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump=auction_state.bump,
+    )]
+    auction_state: Box<Account<'info, AuctionState>>,
+    /// CHECK: checked in seeds
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), AUCTION_ESCROW.as_bytes(), auction_state.key().as_ref()],
+        bump=auction_state.escrow_bump,
+    )]
+    auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: ata of auction_escrow holding the token, checked in transfer_token
+    #[account(mut)]
+    token_vault: UncheckedAccount<'info>,
+    /// CHECK: ata of the seller for token_mint, created if needed
+    #[account(mut)]
+    seller_receipt_token_account: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    // remaining accounts, only if a SPL auction had a bid below the reserve price:
+    // 0. payment_mint (required) - the payment mint of the auction
+    // 1. payment_source_token_account (required) - ata of auction_escrow holding the highest bid
+    // 2. payment_bidder_token_account (required) - ata of the highest bidder
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ReclaimUnsold<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller = &ctx.accounts.seller;
+    let highest_bidder = &ctx.accounts.highest_bidder;
+    let auction_escrow = &ctx.accounts.auction_escrow;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let auction_state = &ctx.accounts.auction_state;
+    let auction_state_key = auction_state.key();
+    let has_bid = auction_state.bid_count > 0;
+
+    if Clock::get()?.unix_timestamp < auction_state.get_settle_time() {
+        return Err(ErrorCode::AuctionNotEnded.into());
+    }
+    if auction_state.is_reserve_met() {
+        return Err(ErrorCode::AuctionReserveMet.into());
+    }
+
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        AUCTION_ESCROW.as_bytes(),
+        auction_state_key.as_ref(),
+        &[auction_state.escrow_bump],
+    ]];
+
+    if has_bid {
+        assert_keys_equal(highest_bidder.key, &auction_state.highest_bidder)?;
+        if auction_state.is_spl() {
+            assert_keys_equal(
+                index_ra!(remaining_accounts, 0).key,
+                &auction_state.payment_mint,
+            )?;
+            transfer_token(
+                &auction_state.get_escrowed_bid(),
+                seller,
+                auction_escrow,
+                seller,
+                None,
+                DestinationSpecifier::Ai(highest_bidder),
+                index_ra!(remaining_accounts, 0),
+                index_ra!(remaining_accounts, 1),
+                index_ra!(remaining_accounts, 2),
+                token_program,
+                system_program,
+                None,
+                escrow_signer_seeds,
+            )?;
+        } else {
+            invoke_signed(
+                &system_instruction::transfer(
+                    auction_escrow.key,
+                    highest_bidder.key,
+                    auction_state.get_escrowed_bid(),
+                ),
+                &[
+                    auction_escrow.to_account_info(),
+                    highest_bidder.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+                escrow_signer_seeds,
+            )?;
+        }
+        msg!(
+            "auction_bid_refunded: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{}}}",
+            auction_state_key,
+            auction_state.highest_bidder,
+            auction_state.get_escrowed_bid(),
+        );
+    }
+
+    // the vault is emptied and closed to the seller by transfer_token
+    transfer_token(
+        &1,
+        seller,
+        auction_escrow,
+        seller,
+        None,
+        DestinationSpecifier::Ai(seller),
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.seller_receipt_token_account,
+        token_program,
+        system_program,
+        None,
+        escrow_signer_seeds,
+    )?;
+
+    let escrow_lamports = auction_escrow.lamports();
+    if escrow_lamports > 0 {
+        invoke_signed(
+            &system_instruction::transfer(auction_escrow.key, seller.key, escrow_lamports),
+            &[
+                auction_escrow.to_account_info(),
+                seller.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            escrow_signer_seeds,
+        )?;
+    }
+
+    msg!(
+        "reclaim_unsold: {{\"auction\":\"{}\",\"seller\":\"{}\",\"highest_bid\":{},\"bid_count\":{}}}",
+        auction_state_key,
+        seller.key(),
+        auction_state.highest_bid,
+        auction_state.bid_count,
+    );
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::index_ra;
+
+use {crate::errors::ErrorCode, crate::states::*, crate::utils::*, anchor_lang::prelude::*};
+
+// RenewListing is a permissionless crank for auto renewing listings, see set_auto_renew. Once a
+// listing is past its expiry, anyone can extend it by its auto_renew_period from now and raise its
+// price by its auto_renew_bump_bp, without the seller having to sign again. The listing mode in
+// the expiry sign is kept.
+#[derive(Accounts)]
+pub struct RenewListing<'info> {
+    #[account(mut)]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+    // remaining accounts:
+    // 0. payment_mint (required if the listing is in SPL) - the payment mint of the listing
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, RenewListing<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    let now = Clock::get()?.unix_timestamp;
+
+    if seller_trade_state.auto_renew_period == 0 {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if !is_trade_state_expired(seller_trade_state.expiry, now) {
+        return Err(ErrorCode::TradeStateNotExpired.into());
+    }
+    let price = get_renewed_price(
+        seller_trade_state.buyer_price,
+        seller_trade_state.auto_renew_bump_bp,
+    )?;
+    if seller_trade_state.payment_mint != Pubkey::default() {
+        let payment_mint = index_ra!(remaining_accounts, 0);
+        assert_keys_equal(payment_mint.key, &seller_trade_state.payment_mint)?;
+        assert_price_precision(price, payment_mint)?;
+    }
+    let expiry = now
+        .checked_add(seller_trade_state.auto_renew_period)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    seller_trade_state.buyer_price = price;
+    seller_trade_state.expiry = if seller_trade_state.expiry < 0 {
+        -expiry
+    } else {
+        expiry
+    };
+
+    msg!(
+        "renew_listing: {{\"seller_trade_state\":\"{}\"}}",
+        seller_trade_state.key(),
+    );
+    msg!(
+        "{{\"price\":{},\"seller_expiry\":{}}}",
+        seller_trade_state.buyer_price,
+        seller_trade_state.expiry
+    );
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use mpl_token_metadata::{accounts::TokenRecord, instructions::LockV1Builder};
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction, sysvar,
+};
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+};
+
+// RentNft rents a listed pNFT for one term: the renter pays the fee to the owner, and
+// program_as_signer, the utility delegate of the token, locks it in the owner's ata until the
+// term ends. The rental listing records the renter and rented_until.
+#[derive(Accounts)]
+pub struct RentNft<'info> {
+    #[account(mut)]
+    renter: Signer<'info>,
+    /// CHECK: checked against rental_listing
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+    /// CHECK: checked against rental_listing
+    #[account(address = rental_listing.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: check in cpi
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: checked against rental_listing
+    #[account(mut, address = rental_listing.token_account)]
+    token_account: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    edition: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    #[account(mut, address = TokenRecord::find_pda(&token_mint.key(), &token_account.key()).0)]
+    token_record: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            RENTAL_LISTING.as_bytes(),
+            rental_listing.auction_house_key.as_ref(),
+            owner.key().as_ref(),
+            rental_listing.token_mint.as_ref(),
+        ],
+        bump=rental_listing.bump,
+        has_one=owner,
+        constraint = !rental_listing.is_rented() @ ErrorCode::InvalidAccountState,
+        constraint = rental_listing.owner != renter.key() @ ErrorCode::PublicKeysShouldBeUnique,
+    )]
+    rental_listing: Box<Account<'info, RentalListing>>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in CPI
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    authorization_rules_program: UncheckedAccount<'info>,
+    /// CHECK: checked in CPI
+    authorization_rules: UncheckedAccount<'info>,
+    /// CHECK: check in cpi
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, RentNft<'info>>) -> Result<()> {
+    let renter = &ctx.accounts.renter;
+    let owner = &ctx.accounts.owner;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let system_program = &ctx.accounts.system_program;
+    let fee = ctx.accounts.rental_listing.fee;
+
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(renter.key, owner.key, fee),
+            &[
+                renter.to_account_info(),
+                owner.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    let ins = LockV1Builder::new()
+        .authority(program_as_signer.key())
+        .token_owner(Some(owner.key()))
+        .token(ctx.accounts.token_account.key())
+        .mint(ctx.accounts.token_mint.key())
+        .metadata(ctx.accounts.metadata.key())
+        .edition(Some(ctx.accounts.edition.key()))
+        .token_record(Some(ctx.accounts.token_record.key()))
+        .payer(renter.key())
+        .system_program(system_program.key())
+        .sysvar_instructions(ctx.accounts.instructions.key())
+        .spl_token_program(Some(ctx.accounts.token_program.key()))
+        .authorization_rules_program(Some(ctx.accounts.authorization_rules_program.key()))
+        .authorization_rules(Some(ctx.accounts.authorization_rules.key()))
+        .instruction();
+    invoke_signed(
+        &ins,
+        &[
+            program_as_signer.to_account_info(),
+            owner.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.edition.to_account_info(),
+            ctx.accounts.token_record.to_account_info(),
+            renter.to_account_info(),
+            system_program.to_account_info(),
+            ctx.accounts.instructions.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.authorization_rules_program.to_account_info(),
+            ctx.accounts.authorization_rules.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+
+    let rental_listing = &mut ctx.accounts.rental_listing;
+    rental_listing.renter = renter.key();
+    rental_listing.rented_until = Clock::get()?
+        .unix_timestamp
+        .checked_add(rental_listing.term)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "rent_nft: {{\"rental_listing\":\"{}\",\"renter\":\"{}\",\"fee\":{},\"rented_until\":{}}}",
+        rental_listing.key(),
+        rental_listing.renter,
+        fee,
+        rental_listing.rented_until,
+    );
+    Ok(())
+}
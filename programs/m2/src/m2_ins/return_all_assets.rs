@@ -0,0 +1,98 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+    spl_associated_token_account::get_associated_token_address,
+};
+
+// ReturnAllAssets lets the auction house authority wind down custody listings: every listing in
+// the page gets its token transferred out of the custody vault into the seller's ata, and its
+// seller_trade_state closed into the seller, the same as custody_cancel_sell without the seller
+// signing. The authority pays for atas that have to be created. Pages are sent until no custody
+// listing of the auction house is left.
+#[derive(Accounts)]
+pub struct ReturnAllAssets<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. (seller_trade_state, custody_vault, token_mint, seller, seller_token_ata) of every custody listing to return
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ReturnAllAssets<'info>>) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let program_as_signer = &ctx.accounts.program_as_signer;
+
+    if ctx.remaining_accounts.is_empty() || !ctx.remaining_accounts.len().is_multiple_of(5) {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+
+    for listing in ctx.remaining_accounts.chunks(5) {
+        let (seller_trade_state, custody_vault, token_mint, seller, seller_token_ata) = (
+            &listing[0],
+            &listing[1],
+            &listing[2],
+            &listing[3],
+            &listing[4],
+        );
+        let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+        assert_keys_equal(&sell_args.auction_house_key, &auction_house_key)?;
+        assert_keys_equal(&sell_args.token_account, custody_vault.key)?;
+        assert_keys_equal(&sell_args.token_mint, token_mint.key)?;
+        assert_keys_equal(&sell_args.seller, seller.key)?;
+        assert_keys_equal(
+            seller_token_ata.key,
+            &get_associated_token_address(seller.key, token_mint.key),
+        )?;
+        if !is_custody_vault(custody_vault.key, token_mint.key) {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+
+        // the vault is closed into the seller once it's empty
+        transfer_token(
+            &sell_args.token_size,
+            authority,
+            program_as_signer,
+            seller,
+            None,
+            DestinationSpecifier::Ai(seller),
+            token_mint,
+            custody_vault,
+            seller_token_ata,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            None,
+            &[&[
+                PREFIX.as_bytes(),
+                SIGNER.as_bytes(),
+                &[ctx.bumps.program_as_signer],
+            ]],
+        )?;
+        close_account_anchor(seller_trade_state, seller)?;
+
+        msg!(
+            "return_asset: {{\"seller_trade_state\":\"{}\",\"seller\":\"{}\",\"token_mint\":\"{}\"}}",
+            seller_trade_state.key(),
+            seller.key(),
+            token_mint.key(),
+        );
+    }
+
+    msg!(
+        "return_all_assets: {{\"auction_house\":\"{}\",\"count\":{}}}",
+        auction_house_key,
+        ctx.remaining_accounts.len() / 5,
+    );
+    Ok(())
+}
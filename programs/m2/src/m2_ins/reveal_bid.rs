@@ -0,0 +1,152 @@
+use solana_program::{program::invoke_signed, system_instruction};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevealBidArgs {
+    pub price: u64,
+    pub salt: [u8; 32],
+}
+
+// RevealBid opens a sealed bid between end_time and reveal_end_time. A bid beating the highest
+// revealed bid moves its price into the auction_escrow and the outbid one is refunded, so the
+// escrow only ever holds the highest bid, same as in english auctions. Whatever the outcome, the
+// rest of the deposit goes back to the bidder and the sealed_bid is closed.
+#[derive(Accounts)]
+pub struct RevealBid<'info> {
+    #[account(mut)]
+    bidder: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            AUCTION.as_bytes(),
+            auction_state.auction_house_key.as_ref(),
+            auction_state.token_mint.as_ref(),
+        ],
+        bump=auction_state.bump,
+    )]
+    auction_state: Box<Account<'info, AuctionState>>,
+    /// CHECK: checked in seeds
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), AUCTION_ESCROW.as_bytes(), auction_state.key().as_ref()],
+        bump=auction_state.escrow_bump,
+    )]
+    auction_escrow: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=bidder,
+        seeds=[
+            PREFIX.as_bytes(),
+            SEALED_BID.as_bytes(),
+            auction_state.key().as_ref(),
+            bidder.key().as_ref(),
+        ],
+        bump=sealed_bid.bump,
+        has_one=bidder,
+    )]
+    sealed_bid: Box<Account<'info, SealedBid>>,
+    /// CHECK: the current highest_bidder, any account if nothing was revealed yet
+    #[account(mut)]
+    previous_bidder: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealBid<'info>>,
+    args: RevealBidArgs,
+) -> Result<()> {
+    let bidder = &ctx.accounts.bidder;
+    let auction_escrow = &ctx.accounts.auction_escrow;
+    let previous_bidder = &ctx.accounts.previous_bidder;
+    let sealed_bid = &ctx.accounts.sealed_bid;
+    let auction_state = &mut ctx.accounts.auction_state;
+    let auction_state_key = auction_state.key();
+
+    let now = Clock::get()?.unix_timestamp;
+    if !auction_state.is_sealed()
+        || now < auction_state.end_time
+        || now >= auction_state.reveal_end_time
+    {
+        return Err(ErrorCode::AuctionNotActive.into());
+    }
+    // a sealed bid left over from an earlier auction of the same token
+    if sealed_bid.reveal_end_time != auction_state.reveal_end_time {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if SealedBid::get_commitment(&auction_state_key, bidder.key, args.price, &args.salt)
+        != sealed_bid.commitment
+        || args.price > sealed_bid.deposit
+    {
+        return Err(ErrorCode::InvalidSealedBid.into());
+    }
+    let has_previous_bid = auction_state.bid_count > 0;
+    if has_previous_bid {
+        assert_keys_equal(previous_bidder.key, &auction_state.highest_bidder)?;
+    }
+
+    // ties go to the bid revealed first
+    let is_highest = args.price >= auction_state.start_price
+        && (!has_previous_bid || args.price > auction_state.highest_bid);
+    if is_highest {
+        let sealed_bid_info = sealed_bid.to_account_info();
+        **sealed_bid_info.try_borrow_mut_lamports()? = sealed_bid_info
+            .lamports()
+            .checked_sub(args.price)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        **auction_escrow.try_borrow_mut_lamports()? = auction_escrow
+            .lamports()
+            .checked_add(args.price)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        if has_previous_bid {
+            invoke_signed(
+                &system_instruction::transfer(
+                    auction_escrow.key,
+                    previous_bidder.key,
+                    auction_state.highest_bid,
+                ),
+                &[
+                    auction_escrow.to_account_info(),
+                    previous_bidder.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                &[&[
+                    PREFIX.as_bytes(),
+                    AUCTION_ESCROW.as_bytes(),
+                    auction_state_key.as_ref(),
+                    &[auction_state.escrow_bump],
+                ]],
+            )?;
+            msg!(
+                "auction_bid_refunded: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{}}}",
+                auction_state_key,
+                auction_state.highest_bidder,
+                auction_state.highest_bid,
+            );
+        }
+        auction_state.highest_bid = args.price;
+        auction_state.highest_bidder = bidder.key();
+        auction_state.bid_count = auction_state
+            .bid_count
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    }
+
+    msg!(
+        "reveal_bid: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"price\":{},\"deposit\":{},\"highest\":{}}}",
+        auction_state_key,
+        bidder.key(),
+        args.price,
+        sealed_bid.deposit,
+        is_highest,
+    );
+    Ok(())
+}
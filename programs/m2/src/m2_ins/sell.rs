@@ -1,4 +1,5 @@
 use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
 
 use crate::index_ra;
 
@@ -28,7 +29,6 @@ pub struct Sell<'info> {
     #[account(mut)]
     token_ata: UncheckedAccount<'info>,
     #[account(
-        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
         constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
     )]
     token_mint: Account<'info, Mint>,
@@ -67,6 +67,13 @@ pub struct Sell<'info> {
     // remaining accounts:
     // 0. payment_mint (optional) - if the seller wants payment in a SPL token, this is the mint of that token
     // ...
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. last_sale (required) - LastSale of the token, derivation checked in assert_hold_period_elapsed
+    // 2. order_limit_config (required) - OrderLimitConfig of the auction house, derivation checked in count_open_order
+    // 3. wallet_order_counter - WalletOrderCounter of the wallet, required if the order_limit_config has a limit, created if needed
+    // 4. counted_order - CountedOrder of the seller_trade_state, required if the order_limit_config has a limit, created if needed
+    // 5. mint_listing_index - MintListingIndex of the token_mint, derivation checked in update_mint_listing_index
     // -1. payer (optional) - this wallet will try to pay for sts rent
 }
 
@@ -80,6 +87,8 @@ pub fn handle<'info>(
     let wallet = &ctx.accounts.wallet;
     let (remaining_accounts, possible_payer) =
         split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
     let payer = if let Some(p) = possible_payer {
         p
     } else {
@@ -97,6 +106,7 @@ pub fn handle<'info>(
     let token_account = &ctx.accounts.token_account;
     let payment_mint = if remaining_accounts.len() == 1 {
         assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(buyer_price, index_ra!(remaining_accounts, 0))?;
         Some(index_ra!(remaining_accounts, 0))
     } else {
         None
@@ -113,6 +123,8 @@ pub fn handle<'info>(
             return Err(ErrorCode::InvalidDiscriminator.into());
         }
     }
+    // buyer_price is the price of a single token, listings of more than one token of a
+    // semi-fungible mint can be filled partially by execute_sale_v2
     if token_size > token_account.amount || token_size == 0 {
         return Err(ErrorCode::InvalidTokenAmount.into());
     }
@@ -121,7 +133,7 @@ pub fn handle<'info>(
     }
     if token_account_ai.key != token_ata_ai.key {
         transfer_token(
-            &1,
+            &token_size,
             payer,
             wallet,
             wallet,
@@ -137,6 +149,23 @@ pub fn handle<'info>(
         )?;
     }
     assert_metadata_valid(metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(collection_config, &auction_house.key(), &metadata_parsed)?;
+    assert_hold_period_elapsed(
+        collection_config,
+        optional_accounts.require(1)?,
+        &auction_house.key(),
+        &metadata_parsed,
+        wallet.key,
+    )?;
+    if payment_mint.is_none() {
+        assert_collection_price_on_tick(
+            collection_config,
+            &auction_house.key(),
+            &get_collection_key(&metadata_parsed),
+            buyer_price,
+        )?;
+    }
 
     // seller_state_expiry < 0, non-movable listing mode
     //   - with program_as_signer to hold the authority
@@ -162,6 +191,18 @@ pub fn handle<'info>(
         return Err(ErrorCode::InvalidAccountState.into());
     }
 
+    if seller_trade_state.data_is_empty() {
+        count_open_order(
+            optional_accounts.require(2)?,
+            optional_accounts.get(3),
+            optional_accounts.get(4),
+            seller_trade_state.key,
+            payer,
+            &auction_house.key(),
+            wallet.key,
+            OrderSide::Listing,
+        )?;
+    }
     create_or_realloc_seller_trade_state(
         seller_trade_state,
         payer,
@@ -189,15 +230,44 @@ pub fn handle<'info>(
         } else {
             Pubkey::default()
         },
+        floor_premium_bp: None,
+        creator_listing: false,
+        dutch_end_price: 0,
+        dutch_start_time: 0,
+        dutch_decay_interval: 0,
+        allowed_buyer: Pubkey::default(),
+        auto_renew_period: 0,
+        auto_renew_bump_bp: 0,
+        secondary_payment_mint: Pubkey::default(),
+        secondary_price: 0,
+        not_before: 0,
     };
     let sts_v2_serialized = sts.try_to_vec()?;
     seller_trade_state.try_borrow_mut_data()?[8..8 + sts_v2_serialized.len()]
         .copy_from_slice(&sts_v2_serialized);
+    if let Some(mint_listing_index) = optional_accounts.get(5) {
+        update_mint_listing_index(
+            mint_listing_index,
+            &sts.token_mint,
+            seller_trade_state.key,
+            Some(MintListingEntry {
+                seller_trade_state: seller_trade_state.key(),
+                auction_house_key: sts.auction_house_key,
+                seller: sts.seller,
+                buyer_price: sts.buyer_price,
+                expiry: sts.expiry,
+                payment_mint: sts.payment_mint,
+            }),
+        )?;
+    }
 
+    let payment_decimals = get_payment_decimals(payment_mint)?;
     msg!(
-        "{{\"price\":{},\"seller_expiry\":{}}}",
+        "{{\"price\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
         buyer_price,
-        seller_state_expiry
+        seller_state_expiry,
+        payment_decimals,
+        get_ui_price(buyer_price, payment_decimals),
     );
     Ok(())
 }
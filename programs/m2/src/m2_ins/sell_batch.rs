@@ -0,0 +1,189 @@
+use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{SetAuthority, Token},
+    spl_token::instruction::AuthorityType,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellBatchItem {
+    pub buyer_price: u64,
+    pub token_size: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellBatchArgs {
+    pub items: Vec<SellBatchItem>,
+}
+
+// SellBatch lists up to MAX_SELL_BATCH_ITEMS tokens in the non-movable mode in one transaction,
+// the same as calling sell for each of them with seller_state_expiry -1. Every token has to sit in
+// the wallet's ata, listing an already listed token changes its price. Batch listings are paid in
+// SOL.
+#[derive(Accounts)]
+pub struct SellBatch<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+      seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+      has_one=authority,
+      bump=auction_house.bump,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. (token_ata, token_mint, metadata, seller_trade_state, last_sale) of every item, in the order of args.items
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellBatch<'info>>,
+    args: SellBatchArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+
+    if args.items.is_empty() || args.items.len() > MAX_SELL_BATCH_ITEMS {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if ctx.remaining_accounts.len() != args.items.len() * 5 {
+        return Err(ErrorCode::MissingRemainingAccount.into());
+    }
+
+    for (item, accounts) in args.items.iter().zip(ctx.remaining_accounts.chunks(5)) {
+        let (token_ata, token_mint, metadata, seller_trade_state, last_sale) = (
+            &accounts[0],
+            &accounts[1],
+            &accounts[2],
+            &accounts[3],
+            &accounts[4],
+        );
+        if item.buyer_price > MAX_PRICE || item.buyer_price == 0 {
+            return Err(ErrorCode::InvalidPrice.into());
+        }
+        let mint: spl_token::state::Mint = assert_initialized(token_mint)?;
+        if mint.decimals != 0 {
+            return Err(ErrorCode::InvalidTokenMint.into());
+        }
+        let token = assert_is_ata(
+            token_ata,
+            wallet.key,
+            token_mint.key,
+            if is_token_owner(token_ata, program_as_signer.key)? {
+                program_as_signer.key
+            } else {
+                wallet.key
+            },
+        )?;
+        if item.token_size > token.amount || item.token_size == 0 {
+            return Err(ErrorCode::InvalidTokenAmount.into());
+        }
+        assert_metadata_valid(metadata, token_mint.key)?;
+        let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+        assert_collection_not_paused(
+            &ctx.accounts.collection_config,
+            &auction_house_key,
+            &metadata_parsed,
+        )?;
+        assert_hold_period_elapsed(
+            &ctx.accounts.collection_config,
+            last_sale,
+            &auction_house_key,
+            &metadata_parsed,
+            wallet.key,
+        )?;
+        assert_collection_price_on_tick(
+            &ctx.accounts.collection_config,
+            &auction_house_key,
+            &get_collection_key(&metadata_parsed),
+            item.buyer_price,
+        )?;
+
+        let sts_seeds: &[&[u8]] = &[
+            PREFIX.as_bytes(),
+            wallet.key.as_ref(),
+            auction_house_key.as_ref(),
+            token_ata.key.as_ref(),
+            token_mint.key.as_ref(),
+        ];
+        let sts_bump = assert_derivation(ctx.program_id, seller_trade_state, sts_seeds)?;
+        if !seller_trade_state.data_is_empty() {
+            let discriminator_ai = seller_trade_state.try_borrow_data()?;
+            if discriminator_ai[..8] != SellerTradeState::discriminator()
+                && discriminator_ai[..8] != SellerTradeStateV2::discriminator()
+            {
+                return Err(ErrorCode::InvalidDiscriminator.into());
+            }
+        }
+        if token.owner != *program_as_signer.key {
+            anchor_spl::token::set_authority(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    SetAuthority {
+                        account_or_mint: token_ata.to_account_info(),
+                        current_authority: wallet.to_account_info(),
+                    },
+                ),
+                AuthorityType::AccountOwner,
+                Some(program_as_signer.key()),
+            )?;
+        } else if seller_trade_state.data_is_empty() {
+            // already held by program_as_signer without a listing of this auction house
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+
+        create_or_realloc_seller_trade_state(
+            seller_trade_state,
+            wallet,
+            &[sts_seeds, &[&[sts_bump]]].concat(),
+        )?;
+        let sts = SellerTradeStateV2 {
+            auction_house_key,
+            seller: wallet.key(),
+            seller_referral: ctx.accounts.seller_referral.key(),
+            buyer_price: item.buyer_price,
+            token_mint: token_mint.key(),
+            token_account: token_ata.key(),
+            token_size: item.token_size,
+            bump: sts_bump,
+            expiry: -1,
+            ..Default::default()
+        };
+        let sts_v2_serialized = sts.try_to_vec()?;
+        seller_trade_state.try_borrow_mut_data()?[8..8 + sts_v2_serialized.len()]
+            .copy_from_slice(&sts_v2_serialized);
+
+        msg!(
+            "sell_batch_item: {{\"seller_trade_state\":\"{}\",\"token_mint\":\"{}\",\"price\":{}}}",
+            seller_trade_state.key(),
+            token_mint.key(),
+            item.buyer_price,
+        );
+    }
+
+    msg!(
+        "sell_batch: {{\"wallet\":\"{}\",\"count\":{}}}",
+        wallet.key(),
+        args.items.len(),
+    );
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellBundleArgs {
+    pub bundle_id: u64,
+    pub price: u64,
+    pub expiry: i64,
+}
+
+// SellBundle lists several tokens for a single SOL price. It only creates or reprices the
+// BundleSellerState, the tokens are added in pages with append_bundle_items, and the bundle can be
+// sold with execute_bundle_sale once it's sealed.
+#[derive(Accounts)]
+#[instruction(args: SellBundleArgs)]
+pub struct SellBundle<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            BUNDLE.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            args.bundle_id.to_le_bytes().as_ref(),
+        ],
+        space=BundleSellerState::LEN,
+        bump,
+    )]
+    bundle_seller_state: Box<Account<'info, BundleSellerState>>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellBundle<'info>>,
+    args: SellBundleArgs,
+) -> Result<()> {
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if is_trade_state_expired(args.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    let bundle_seller_state = &mut ctx.accounts.bundle_seller_state;
+    bundle_seller_state.auction_house_key = ctx.accounts.auction_house.key();
+    bundle_seller_state.seller = ctx.accounts.seller.key();
+    bundle_seller_state.seller_referral = ctx.accounts.seller_referral.key();
+    bundle_seller_state.bundle_id = args.bundle_id;
+    bundle_seller_state.buyer_price = args.price;
+    bundle_seller_state.expiry = args.expiry;
+    bundle_seller_state.bump = ctx.bumps.bundle_seller_state;
+
+    msg!(
+        "sell_bundle: {{\"bundle\":\"{}\",\"price\":{},\"expiry\":{},\"items\":{},\"sealed\":{}}}",
+        bundle_seller_state.key(),
+        bundle_seller_state.buyer_price,
+        bundle_seller_state.expiry,
+        bundle_seller_state.items.len(),
+        bundle_seller_state.sealed,
+    );
+    Ok(())
+}
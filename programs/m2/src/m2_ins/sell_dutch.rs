@@ -0,0 +1,68 @@
+use crate::index_ra;
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellDutchArgs {
+    pub start_price: u64,
+    pub end_price: u64,
+    pub start_time: i64,
+    pub decay_interval: i64, // 0 turns the listing back into a fixed price listing at start_price
+}
+
+// SellDutch turns a v2 listing into a dutch listing whose price decays linearly from start_price
+// at start_time to end_price at start_time + decay_interval and then stays there. The current
+// price is computed from the clock at settlement, and any bid covering it fills at that price.
+// start_price is stored as the listing's buyer_price. Floor pegging is turned off.
+#[derive(Accounts)]
+pub struct SellDutch<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+    // remaining accounts:
+    // 0. payment_mint (required if the listing is in SPL) - the payment mint of the listing
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellDutch<'info>>,
+    args: SellDutchArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+
+    if args.start_price > MAX_PRICE
+        || args.end_price == 0
+        || args.end_price > args.start_price
+        || args.decay_interval < 0
+    {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if seller_trade_state.payment_mint != Pubkey::default() {
+        let payment_mint = index_ra!(remaining_accounts, 0);
+        assert_keys_equal(payment_mint.key, &seller_trade_state.payment_mint)?;
+        assert_price_precision(args.start_price, payment_mint)?;
+        assert_price_precision(args.end_price, payment_mint)?;
+    }
+
+    seller_trade_state.buyer_price = args.start_price;
+    seller_trade_state.dutch_end_price = args.end_price;
+    seller_trade_state.dutch_start_time = args.start_time;
+    seller_trade_state.dutch_decay_interval = args.decay_interval;
+    seller_trade_state.floor_premium_bp = None;
+
+    msg!(
+        "sell_dutch: {{\"seller_trade_state\":\"{}\",\"start_price\":{},\"end_price\":{},\"start_time\":{},\"decay_interval\":{}}}",
+        seller_trade_state.key(),
+        args.start_price,
+        args.end_price,
+        args.start_time,
+        args.decay_interval,
+    );
+    Ok(())
+}
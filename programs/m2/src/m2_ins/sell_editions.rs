@@ -0,0 +1,125 @@
+use mpl_token_metadata::accounts::MasterEdition;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Mint, Token, TokenAccount},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellEditionsArgs {
+    pub price: u64,
+    pub max_prints: u64, // 0 to print until the master edition's max supply
+}
+
+// SellEditions puts the master edition token of the seller into an edition_vault owned by the
+// edition_sale PDA, so buy_edition can print the next edition to each buyer for the listed price.
+// cancel_edition_sale returns the master edition token to the seller. Edition sales are SOL only.
+#[derive(Accounts)]
+#[instruction(args: SellEditionsArgs)]
+pub struct SellEditions<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(mut, token::mint = master_mint, token::authority = seller)]
+    master_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = master_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = master_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    master_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        master_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: checked by address and owner, editions can only be printed from a master edition
+    #[account(
+        address = MasterEdition::find_pda(&master_mint.key()).0,
+        owner = mpl_token_metadata::ID,
+    )]
+    master_edition: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init,
+        payer=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            EDITION_SALE.as_bytes(),
+            auction_house.key().as_ref(),
+            master_mint.key().as_ref(),
+        ],
+        space=EditionSale::LEN,
+        bump,
+    )]
+    edition_sale: Box<Account<'info, EditionSale>>,
+    /// CHECK: ata of edition_sale for master_mint, created and checked in transfer_token
+    #[account(mut)]
+    edition_vault: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellEditions<'info>>,
+    args: SellEditionsArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let master_mint = &ctx.accounts.master_mint;
+
+    if args.price == 0 || args.price > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    assert_metadata_valid(&ctx.accounts.metadata, &master_mint.key())?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+
+    transfer_token(
+        &1,
+        seller,
+        seller,
+        seller,
+        None,
+        DestinationSpecifier::Ai(&ctx.accounts.edition_sale.to_account_info()),
+        &master_mint.to_account_info(),
+        &ctx.accounts.master_token_account.to_account_info(),
+        &ctx.accounts.edition_vault,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        None,
+        &[],
+    )?;
+
+    let edition_sale = &mut ctx.accounts.edition_sale;
+    edition_sale.auction_house_key = ctx.accounts.auction_house.key();
+    edition_sale.seller = seller.key();
+    edition_sale.master_mint = master_mint.key();
+    edition_sale.price = args.price;
+    edition_sale.max_prints = args.max_prints;
+    edition_sale.printed = 0;
+    edition_sale.bump = ctx.bumps.edition_sale;
+
+    msg!(
+        "sell_editions: {{\"edition_sale\":\"{}\",\"seller\":\"{}\",\"master_mint\":\"{}\",\"price\":{},\"max_prints\":{}}}",
+        edition_sale.key(),
+        seller.key(),
+        master_mint.key(),
+        args.price,
+        args.max_prints,
+    );
+    Ok(())
+}
@@ -0,0 +1,411 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{
+        associated_token::{create_idempotent, AssociatedToken, Create},
+        token::Token,
+        token_interface::{Mint, TokenAccount},
+    },
+    solana_program::program_option::COption,
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellIntoCollectionBidArgs {
+    pub price: u64, // has to match the bid's buyer_price, guards the seller against bid updates
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub min_net_proceeds: Option<u64>, // what the seller keeps at least after royalties and fees
+}
+
+// SellIntoCollectionBid fills one unit of a collection bid with a token the seller holds. The
+// token's metadata has to carry the bid's collection as a verified collection, or for Token-2022
+// mints without one, the mint has to be a member of the bid's token group. For trait bids the
+// notary has to sign to attest that the token has the bid's traits. The bid can be filled on the
+// auction house it was placed on, or on one of its allowed auction houses with the same authority,
+// the funds come from the escrow of the bid's auction house and the fees go to the filling one.
+#[derive(Accounts)]
+#[instruction(args: SellIntoCollectionBidArgs)]
+pub struct SellIntoCollectionBid<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: buyer, checked in collection_bid_state has_one
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: optional, has to sign for trait bids
+    notary: UncheckedAccount<'info>,
+    #[account(mut, token::mint = token_mint, token::authority = seller)]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            collection_bid_state.auction_house_key.as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: buyer_receipt_token_account, the ata of the Token-2022 program for Token-2022 mints
+    #[account(mut)]
+    buyer_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        address = collection_bid_state.auction_house_key @ ErrorCode::InvalidAccountState,
+        constraint = bid_auction_house.authority == auction_house.authority @ ErrorCode::InvalidAccountState,
+    )]
+    bid_auction_house: Box<Account<'info, AuctionHouse>>, // the auction house the bid was placed on, usually auction_house
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_BID.as_bytes(),
+            buyer.key().as_ref(),
+            collection_bid_state.auction_house_key.as_ref(),
+            collection_bid_state.collection.as_ref(),
+        ],
+        bump=collection_bid_state.bump,
+        has_one=buyer,
+    )]
+    collection_bid_state: Box<Account<'info, CollectionBidState>>,
+    /// CHECK: must match the buyer_referral stored in collection_bid_state
+    #[account(mut)]
+    buyer_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    price_band_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    price_attestation: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_t22_permanent_delegate_allowed
+    permanent_delegate_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_t22_permanent_delegate_allowed
+    permanent_delegate_approval: UncheckedAccount<'info>,
+    /// CHECK: token program of token_mint, Token or Token-2022
+    #[account(constraint = asset_token_program.key() == *token_mint.to_account_info().owner @ ErrorCode::InvalidTokenMint)]
+    asset_token_program: UncheckedAccount<'info>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_program: Program<'info, Token>, // token program of SPL payments
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** IF USING NATIVE SOL **
+    // 0..=4. creators (optional) - if the buyer is paying in SOL, these are the creators of the token
+    //                                 a creator that can't receive lamports is followed by its collection's royalty_fallback and recipient
+    //
+    // ** IF USING SPL **
+    // 0. payment_mint (required) - if the buyer is paying in a token, this is the mint of that token
+    // 1. payment_source_token_account (required) - escrow token account controlled by escrow_payment_account
+    // 2. payment_seller_token_account (required) - token account controlled by seller
+    // 3. payment_treausry_token_account (required) - token account controlled by auction_house_treasury
+    // 4..=13. creator_token_account (optional) - same layout as in execute_sale_v2
+    //
+    // followed by the transfer hook program, its ExtraAccountMetaList and its extra accounts (if the mint is a Token-2022 mint with a transfer hook)
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellIntoCollectionBid<'info>>,
+    args: SellIntoCollectionBidArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller = &ctx.accounts.seller;
+    let buyer = &ctx.accounts.buyer;
+    let notary = &ctx.accounts.notary;
+    let token_mint = &ctx.accounts.token_mint;
+    let token_mint_ai = token_mint.to_account_info();
+    let metadata = &ctx.accounts.metadata;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let collection_bid_state = &mut ctx.accounts.collection_bid_state;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let is_spl = collection_bid_state.payment_mint != Pubkey::default();
+
+    collection_bid_state.assert_fillable_by(&auction_house.key())?;
+    if collection_bid_state.buyer_referral != ctx.accounts.buyer_referral.key() {
+        return Err(ErrorCode::InvalidReferral.into());
+    }
+    if collection_bid_state.buyer_price != args.price || collection_bid_state.quantity == 0 {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if is_spl {
+        assert_keys_equal(
+            index_ra!(remaining_accounts, 0).key,
+            &collection_bid_state.payment_mint,
+        )?;
+    }
+    if is_trade_state_expired(collection_bid_state.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    if collection_bid_state.trait_hash != [0; 32] && !notary.is_signer {
+        return Err(ErrorCode::TraitAttestationRequired.into());
+    }
+
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    let is_t22 = *token_mint_ai.owner == anchor_spl::token_2022::ID;
+    if is_t22 {
+        assert_t22_transferable(&token_mint_ai)?;
+        assert_t22_permanent_delegate_allowed(
+            &token_mint_ai,
+            &ctx.accounts.permanent_delegate_config,
+            &ctx.accounts.permanent_delegate_approval,
+            &auction_house.key(),
+        )?;
+        // the fee would withhold the only token
+        if get_t22_transfer_fee(&token_mint_ai, 1)? > 0 {
+            return Err(ErrorCode::InvalidTokenMint.into());
+        }
+    }
+    let mut metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    apply_t22_group_collection(&mut metadata_parsed, &token_mint_ai)?;
+    match &metadata_parsed.collection {
+        Some(c) if c.verified && c.key == collection_bid_state.collection => {}
+        _ => return Err(ErrorCode::InvalidCollection.into()),
+    }
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let auction_house_key = auction_house.key();
+    let bid_auction_house_key = collection_bid_state.auction_house_key;
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        bid_auction_house_key.as_ref(),
+        buyer.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    // royalty inclusive bids pay the royalty out of the quoted price, so the seller gets less
+    let price = get_collection_bid_sale_price(
+        collection_bid_state.buyer_price,
+        collection_bid_state.royalty_inclusive,
+        metadata_parsed.seller_fee_basis_points,
+        collection_bid_state.buyer_creator_royalty_bp,
+    )?;
+    assert_price_attested(
+        &ctx.accounts.price_band_config,
+        Some(&ctx.accounts.price_attestation),
+        &auction_house_key,
+        &metadata_parsed,
+        &collection_bid_state.payment_mint,
+        price,
+    )?;
+    let royalty = if collection_bid_state.buyer_creator_royalty_bp == 0 {
+        0
+    } else {
+        pay_creator_fees(
+            &mut (if is_spl {
+                remaining_accounts[4..].iter()
+            } else {
+                remaining_accounts.iter()
+            }),
+            None,
+            &metadata_parsed,
+            &escrow_payment_account.to_account_info(),
+            escrow_signer_seeds,
+            price,
+            collection_bid_state.buyer_creator_royalty_bp,
+            if is_spl {
+                Some(TransferCreatorSplArgs {
+                    buyer,
+                    payer: seller,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+        )?
+    };
+
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+    let (maker_fee, taker_fee) = transfer_listing_payment(
+        price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        seller,
+        seller,
+        escrow_payment_account,
+        auction_house_treasury,
+        if is_spl {
+            Some(TransferListingPaymentSplArgs {
+                payer: seller,
+                buyer,
+                mint: index_ra!(remaining_accounts, 0),
+                payment_source_token_account: index_ra!(remaining_accounts, 1),
+                payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                system_program,
+                token_program,
+            })
+        } else {
+            None
+        },
+        escrow_signer_seeds,
+    )?;
+    // price is already net of the royalty of royalty inclusive bids
+    assert_min_net_proceeds(
+        get_seller_net_proceeds(price, maker_fee, taker_fee, true),
+        args.min_net_proceeds,
+    )?;
+
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+    let buyer_rec_acct_delegate = if is_t22 {
+        let asset_token_program = &ctx.accounts.asset_token_program;
+        assert_keys_equal(
+            buyer_receipt_token_account.key,
+            &get_associated_token_address_with_program_id(
+                buyer.key,
+                &token_mint.key(),
+                asset_token_program.key,
+            ),
+        )?;
+        if buyer_receipt_token_account.data_is_empty() {
+            create_idempotent(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                Create {
+                    payer: seller.to_account_info(),
+                    associated_token: buyer_receipt_token_account.to_account_info(),
+                    authority: buyer.to_account_info(),
+                    mint: token_mint_ai.clone(),
+                    system_program: system_program.to_account_info(),
+                    token_program: asset_token_program.to_account_info(),
+                },
+            ))?;
+        }
+        transfer_t22_checked(
+            &asset_token_program.to_account_info(),
+            &ctx.accounts.token_account.to_account_info(),
+            &token_mint_ai,
+            buyer_receipt_token_account,
+            seller,
+            remaining_accounts,
+            1,
+            token_mint.decimals,
+            &[],
+        )?;
+        TokenAccount::try_deserialize(&mut &buyer_receipt_token_account.data.borrow()[..])?.delegate
+    } else {
+        transfer_token(
+            &1,
+            seller,
+            seller,
+            seller,
+            None,
+            DestinationSpecifier::Ai(buyer),
+            &token_mint_ai,
+            &ctx.accounts.token_account.to_account_info(),
+            buyer_receipt_token_account,
+            token_program,
+            system_program,
+            None,
+            &[],
+        )?
+        .delegate
+    };
+    if let COption::Some(_) = buyer_rec_acct_delegate {
+        return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+    }
+
+    record_sale(
+        &ctx.accounts.collection_stats,
+        &ctx.accounts.last_sale,
+        &ctx.accounts.trade_sequence,
+        seller,
+        &auction_house_key,
+        &metadata_parsed,
+        &collection_bid_state.payment_mint,
+        buyer.key,
+        seller.key,
+        price,
+        get_payment_decimals(if is_spl {
+            Some(index_ra!(remaining_accounts, 0))
+        } else {
+            None
+        })?,
+    )?;
+
+    collection_bid_state.quantity -= 1;
+    collection_bid_state.filled = collection_bid_state.filled.saturating_add(1);
+    msg!(
+        "sell_into_collection_bid: {{\"collection\":\"{}\",\"price\":{},\"royalty\":{},\"royalty_inclusive_price\":{},\"royalty_inclusive\":{},\"buyer_expiry\":{},\"quantity\":{}}}",
+        collection_bid_state.collection,
+        price,
+        royalty,
+        price.checked_add(royalty).ok_or(ErrorCode::NumericalOverflow)?,
+        collection_bid_state.royalty_inclusive,
+        collection_bid_state.expiry,
+        collection_bid_state.quantity,
+    );
+
+    if collection_bid_state.quantity == 0 {
+        collection_bid_state.close(buyer.to_account_info())?;
+        try_close_buyer_escrow(
+            escrow_payment_account,
+            buyer,
+            system_program,
+            escrow_signer_seeds,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,304 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::token::{Mint, Token, TokenAccount},
+    solana_program::program_option::COption,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SellIntoPortfolioBidArgs {
+    pub price: u64, // has to match the bid's buyer_price, guards the seller against bid updates
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+    pub min_net_proceeds: Option<u64>, // what the seller keeps at least after fees
+}
+
+// SellIntoPortfolioBid fills a portfolio bid with any one of its mints the seller holds, and
+// closes the bid.
+#[derive(Accounts)]
+#[instruction(args: SellIntoPortfolioBidArgs)]
+pub struct SellIntoPortfolioBid<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// CHECK: buyer, checked in portfolio_bid_state has_one
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+    /// CHECK: optional, signs to apply maker_fee_bp and taker_fee_bp
+    notary: UncheckedAccount<'info>,
+    #[account(mut, token::mint = token_mint, token::authority = seller)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = token_mint.supply == 1 @ ErrorCode::InvalidTokenMint,
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: escrow_payment_account
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: buyer_receipt_token_account
+    #[account(mut)]
+    buyer_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=buyer,
+        seeds=[
+            PREFIX.as_bytes(),
+            PORTFOLIO_BID.as_bytes(),
+            buyer.key().as_ref(),
+            auction_house.key().as_ref(),
+            portfolio_bid_state.bid_id.to_le_bytes().as_ref(),
+        ],
+        bump=portfolio_bid_state.bump,
+        has_one=buyer,
+    )]
+    portfolio_bid_state: Box<Account<'info, PortfolioBidState>>,
+    /// CHECK: must match the buyer_referral stored in portfolio_bid_state
+    #[account(mut)]
+    buyer_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    buyer_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    price_band_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    price_attestation: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts: same layout as sell_into_collection_bid
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellIntoPortfolioBid<'info>>,
+    args: SellIntoPortfolioBidArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller = &ctx.accounts.seller;
+    let buyer = &ctx.accounts.buyer;
+    let notary = &ctx.accounts.notary;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let portfolio_bid_state = &ctx.accounts.portfolio_bid_state;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let is_spl = portfolio_bid_state.payment_mint != Pubkey::default();
+    let price = portfolio_bid_state.buyer_price;
+
+    if portfolio_bid_state.auction_house_key != auction_house.key() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if portfolio_bid_state.buyer_referral != ctx.accounts.buyer_referral.key() {
+        return Err(ErrorCode::InvalidReferral.into());
+    }
+    if price != args.price {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if !portfolio_bid_state.mints.contains(&token_mint.key()) {
+        return Err(ErrorCode::InvalidTokenMint.into());
+    }
+    if is_spl {
+        assert_keys_equal(
+            index_ra!(remaining_accounts, 0).key,
+            &portfolio_bid_state.payment_mint,
+        )?;
+    }
+    if is_trade_state_expired(portfolio_bid_state.expiry, Clock::get()?.unix_timestamp) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_seller_not_suspended(&ctx.accounts.seller_status, seller.key)?;
+    assert_buyer_not_suspended(&ctx.accounts.buyer_status, buyer.key)?;
+
+    let auction_house_key = auction_house.key();
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer.key.as_ref(),
+        &[ctx.bumps.escrow_payment_account],
+    ]];
+
+    assert_price_attested(
+        &ctx.accounts.price_band_config,
+        Some(&ctx.accounts.price_attestation),
+        &auction_house_key,
+        &metadata_parsed,
+        &portfolio_bid_state.payment_mint,
+        price,
+    )?;
+    let royalty = if portfolio_bid_state.buyer_creator_royalty_bp == 0 {
+        0
+    } else {
+        pay_creator_fees(
+            &mut (if is_spl {
+                remaining_accounts[4..].iter()
+            } else {
+                remaining_accounts.iter()
+            }),
+            None,
+            &metadata_parsed,
+            &escrow_payment_account.to_account_info(),
+            escrow_signer_seeds,
+            price,
+            portfolio_bid_state.buyer_creator_royalty_bp,
+            if is_spl {
+                Some(TransferCreatorSplArgs {
+                    buyer,
+                    payer: seller,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+        )?
+    };
+
+    let (actual_maker_fee_bp, actual_taker_fee_bp) =
+        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+    let (maker_fee, taker_fee) = transfer_listing_payment(
+        price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        seller,
+        seller,
+        escrow_payment_account,
+        auction_house_treasury,
+        if is_spl {
+            Some(TransferListingPaymentSplArgs {
+                payer: seller,
+                buyer,
+                mint: index_ra!(remaining_accounts, 0),
+                payment_source_token_account: index_ra!(remaining_accounts, 1),
+                payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                system_program,
+                token_program,
+            })
+        } else {
+            None
+        },
+        escrow_signer_seeds,
+    )?;
+    assert_min_net_proceeds(
+        get_seller_net_proceeds(price, maker_fee, taker_fee, true),
+        args.min_net_proceeds,
+    )?;
+
+    let buyer_rec_acct = transfer_token(
+        &1,
+        seller,
+        seller,
+        seller,
+        None,
+        DestinationSpecifier::Ai(buyer),
+        &token_mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.buyer_receipt_token_account,
+        token_program,
+        system_program,
+        None,
+        &[],
+    )?;
+    if let COption::Some(_) = buyer_rec_acct.delegate {
+        return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+    }
+
+    record_sale(
+        &ctx.accounts.collection_stats,
+        &ctx.accounts.last_sale,
+        &ctx.accounts.trade_sequence,
+        seller,
+        &auction_house_key,
+        &metadata_parsed,
+        &portfolio_bid_state.payment_mint,
+        buyer.key,
+        seller.key,
+        price,
+        get_payment_decimals(if is_spl {
+            Some(index_ra!(remaining_accounts, 0))
+        } else {
+            None
+        })?,
+    )?;
+
+    msg!(
+        "sell_into_portfolio_bid: {{\"portfolio_bid_state\":\"{}\",\"token_mint\":\"{}\",\"price\":{},\"royalty\":{},\"buyer_expiry\":{}}}",
+        portfolio_bid_state.key(),
+        token_mint.key(),
+        price,
+        royalty,
+        portfolio_bid_state.expiry,
+    );
+
+    try_close_buyer_escrow(
+        escrow_payment_account,
+        buyer,
+        system_program,
+        escrow_signer_seeds,
+    )
+}
@@ -0,0 +1,32 @@
+use {crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SetAllowedBuyer makes a v2 listing private: only allowed_buyer can take it in the execute_sale
+// paths, anyone else is rejected. Passing None opens the listing to every buyer again.
+#[derive(Accounts)]
+pub struct SetAllowedBuyer<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetAllowedBuyer<'info>>,
+    allowed_buyer: Option<Pubkey>,
+) -> Result<()> {
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    let allowed_buyer = allowed_buyer.unwrap_or_default();
+    if allowed_buyer == seller_trade_state.seller {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    seller_trade_state.allowed_buyer = allowed_buyer;
+
+    msg!(
+        "set_allowed_buyer: {{\"seller_trade_state\":\"{}\",\"allowed_buyer\":\"{}\"}}",
+        seller_trade_state.key(),
+        seller_trade_state.allowed_buyer,
+    );
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SetAutoRenew marks a v2 listing as auto renewing: once it expires, anyone can call renew_listing
+// to extend it by period seconds, raising the price by bump_bp each time. Floor pegged and dutch
+// listings are priced at settlement and can't auto renew. A period of 0 turns auto renewal off.
+#[derive(Accounts)]
+pub struct SetAutoRenew<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetAutoRenew<'info>>,
+    period: i64,
+    bump_bp: u16,
+) -> Result<()> {
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    if !(0..=MAX_AUTO_RENEW_PERIOD_SECONDS).contains(&period) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if bump_bp > MAX_AUTO_RENEW_BUMP_BP {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    if seller_trade_state.floor_premium_bp.is_some() || seller_trade_state.dutch_decay_interval > 0
+    {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    seller_trade_state.auto_renew_period = period;
+    seller_trade_state.auto_renew_bump_bp = if period == 0 { 0 } else { bump_bp };
+
+    msg!(
+        "set_auto_renew: {{\"seller_trade_state\":\"{}\",\"period\":{},\"bump_bp\":{}}}",
+        seller_trade_state.key(),
+        seller_trade_state.auto_renew_period,
+        seller_trade_state.auto_renew_bump_bp,
+    );
+    Ok(())
+}
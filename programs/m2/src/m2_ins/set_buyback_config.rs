@@ -0,0 +1,59 @@
+use {
+    crate::constants::*,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetBuybackConfigArgs {
+    pub max_price: u64,
+    pub budget: u64,
+}
+
+// SetBuybackConfig lets the auction house authority set up a buyback program for a collection:
+// buyback_listing then spends up to budget lamports of the treasury on listings of the collection
+// priced at or below max_price. Setting the budget to what was already spent stops the program.
+#[derive(Accounts)]
+pub struct SetBuybackConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint
+    collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            BUYBACK_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=BuybackConfig::LEN,
+        bump,
+    )]
+    buyback_config: Account<'info, BuybackConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetBuybackConfig<'info>>,
+    args: SetBuybackConfigArgs,
+) -> Result<()> {
+    let buyback_config = &mut ctx.accounts.buyback_config;
+    buyback_config.auction_house_key = ctx.accounts.auction_house.key();
+    buyback_config.collection = ctx.accounts.collection.key();
+    buyback_config.max_price = args.max_price;
+    buyback_config.budget = args.budget;
+    buyback_config.bump = ctx.bumps.buyback_config;
+
+    msg!(
+        "set_buyback_config: {{\"collection\":\"{}\",\"max_price\":{},\"budget\":{},\"spent\":{}}}",
+        buyback_config.collection,
+        buyback_config.max_price,
+        buyback_config.budget,
+        buyback_config.spent,
+    );
+    Ok(())
+}
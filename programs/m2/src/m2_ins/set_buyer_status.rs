@@ -0,0 +1,37 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetBuyerStatus backs suspend_all_bids and resume_all_bids. While a buyer is suspended none of
+// their bids can be filled, e.g. as a kill switch when a pricing bot misbehaves, without having to
+// cancel each buyer trade state.
+#[derive(Accounts)]
+pub struct SetBuyerStatus<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[PREFIX.as_bytes(), BUYER_STATUS.as_bytes(), wallet.key().as_ref()],
+        space=BuyerStatus::LEN,
+        bump,
+    )]
+    buyer_status: Account<'info, BuyerStatus>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetBuyerStatus<'info>>,
+    suspended: bool,
+) -> Result<()> {
+    let buyer_status = &mut ctx.accounts.buyer_status;
+    buyer_status.buyer = ctx.accounts.wallet.key();
+    buyer_status.suspended = suspended;
+    buyer_status.bump = ctx.bumps.buyer_status;
+    buyer_status.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_buyer_status: {{\"buyer\":\"{}\",\"suspended\":{}}}",
+        buyer_status.buyer,
+        suspended,
+    );
+    Ok(())
+}
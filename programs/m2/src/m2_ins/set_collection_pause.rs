@@ -0,0 +1,44 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct SetCollectionPause<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint, or the token mint if the token has no verified collection
+    collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=CollectionConfig::LEN,
+        bump,
+    )]
+    collection_config: Account<'info, CollectionConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetCollectionPause<'info>>,
+    paused: bool,
+) -> Result<()> {
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.auction_house_key = ctx.accounts.auction_house.key();
+    collection_config.collection = ctx.accounts.collection.key();
+    collection_config.paused = paused;
+    collection_config.bump = ctx.bumps.collection_config;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_collection_pause: {{\"collection\":\"{}\",\"paused\":{}}}",
+        collection_config.collection,
+        paused,
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetCustodialPlatform lets the auction house authority register (or disable) a checkout provider
+// that buys on behalf of its users, e.g. for credit card payments, and holds the bought tokens in
+// custody. Purchases of the platform passing a custody_user_tag to execute_sale_v2 are delivered to
+// the token accounts of custody_wallet instead of the platform's own, and the tag is logged so
+// indexers can attribute the token to the platform's user.
+#[derive(Accounts)]
+pub struct SetCustodialPlatform<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: platform, the buyer wallet of the checkout provider
+    platform: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            CUSTODIAL_PLATFORM.as_bytes(),
+            auction_house.key().as_ref(),
+            platform.key().as_ref(),
+        ],
+        space=CustodialPlatform::LEN,
+        bump,
+    )]
+    custodial_platform: Account<'info, CustodialPlatform>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetCustodialPlatform<'info>>,
+    custody_wallet: Pubkey,
+    enabled: bool,
+) -> Result<()> {
+    let custodial_platform = &mut ctx.accounts.custodial_platform;
+    custodial_platform.auction_house_key = ctx.accounts.auction_house.key();
+    custodial_platform.platform = ctx.accounts.platform.key();
+    custodial_platform.custody_wallet = custody_wallet;
+    custodial_platform.enabled = enabled;
+    custodial_platform.bump = ctx.bumps.custodial_platform;
+    custodial_platform.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_custodial_platform: {{\"platform\":\"{}\",\"custody_wallet\":\"{}\",\"enabled\":{}}}",
+        custodial_platform.platform,
+        custodial_platform.custody_wallet,
+        enabled,
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetEscrowDepositor lets a buyer grant (or revoke) a wallet, e.g. a market making bot's hot key,
+// the right to top up the buyer's escrow with depositor_deposit. The depositor can never withdraw
+// or bid, withdrawals still need the buyer's own key.
+#[derive(Accounts)]
+pub struct SetEscrowDepositor<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: depositor wallet
+    depositor: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            ESCROW_DEPOSITOR.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        space=EscrowDepositor::LEN,
+        bump,
+    )]
+    escrow_depositor: Account<'info, EscrowDepositor>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetEscrowDepositor<'info>>,
+    enabled: bool,
+) -> Result<()> {
+    let escrow_depositor = &mut ctx.accounts.escrow_depositor;
+    escrow_depositor.auction_house_key = ctx.accounts.auction_house.key();
+    escrow_depositor.buyer = ctx.accounts.wallet.key();
+    escrow_depositor.depositor = ctx.accounts.depositor.key();
+    escrow_depositor.enabled = enabled;
+    escrow_depositor.bump = ctx.bumps.escrow_depositor;
+    escrow_depositor.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_escrow_depositor: {{\"buyer\":\"{}\",\"depositor\":\"{}\",\"enabled\":{}}}",
+        escrow_depositor.buyer,
+        escrow_depositor.depositor,
+        enabled,
+    );
+    Ok(())
+}
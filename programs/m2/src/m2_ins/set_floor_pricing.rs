@@ -0,0 +1,39 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SetFloorPricing turns a v2 listing into a floor pegged listing, priced at the collection floor
+// tracked in CollectionStats plus floor_premium_bp (which can be negative). The concrete price is
+// resolved at settlement and never goes below the listing's buyer_price, which becomes the seller's
+// minimum price. Passing None turns the listing back into a fixed price listing at buyer_price.
+// Floor pegging a dutch listing turns off the dutch pricing.
+#[derive(Accounts)]
+pub struct SetFloorPricing<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetFloorPricing<'info>>,
+    floor_premium_bp: Option<i16>,
+) -> Result<()> {
+    if let Some(bp) = floor_premium_bp {
+        if bp <= -10000 || bp > MAX_FLOOR_PREMIUM_BP {
+            return Err(ErrorCode::InvalidBasisPoints.into());
+        }
+    }
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    seller_trade_state.floor_premium_bp = floor_premium_bp;
+    if floor_premium_bp.is_some() {
+        seller_trade_state.dutch_decay_interval = 0;
+    }
+
+    msg!(
+        "set_floor_pricing: {{\"seller_trade_state\":\"{}\",\"floor_premium_bp\":{}}}",
+        seller_trade_state.key(),
+        floor_premium_bp.map_or("null".to_string(), |bp| bp.to_string()),
+    );
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetFrontendConfigArgs {
+    pub fee_share_bp: u16,
+    pub enabled: bool,
+}
+
+// SetFrontendConfig registers (or updates) a frontend on the auction house allowlist. Sales that
+// the notary co-signs with the frontend's FrontendConfig are attributed to the frontend, and SOL
+// sales pay fee_share_bp of the platform fee to it.
+#[derive(Accounts)]
+pub struct SetFrontendConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: frontend identifier, the wallet that can claim the frontend's fee share
+    frontend: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            FRONTEND_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            frontend.key().as_ref(),
+        ],
+        space=FrontendConfig::LEN,
+        bump,
+    )]
+    frontend_config: Account<'info, FrontendConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetFrontendConfig<'info>>,
+    args: SetFrontendConfigArgs,
+) -> Result<()> {
+    if args.fee_share_bp > MAX_FRONTEND_FEE_SHARE_BP {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    let frontend_config = &mut ctx.accounts.frontend_config;
+    frontend_config.auction_house_key = ctx.accounts.auction_house.key();
+    frontend_config.frontend = ctx.accounts.frontend.key();
+    frontend_config.fee_share_bp = args.fee_share_bp;
+    frontend_config.enabled = args.enabled;
+    frontend_config.bump = ctx.bumps.frontend_config;
+
+    msg!(
+        "set_frontend_config: {{\"frontend\":\"{}\",\"fee_share_bp\":{},\"enabled\":{}}}",
+        frontend_config.frontend,
+        args.fee_share_bp,
+        args.enabled,
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SetHoldPeriod lets the auction house authority opt a collection into a minimum holding period.
+// A wallet that bought a token of the collection can't list it again until hold_period seconds
+// after the purchase, as recorded on the LastSale of the token. The hold period is kept on the
+// CollectionConfig of the collection. A hold_period of 0 removes it.
+#[derive(Accounts)]
+pub struct SetHoldPeriod<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint, or the token mint if the token has no verified collection
+    collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=CollectionConfig::LEN,
+        bump,
+    )]
+    collection_config: Account<'info, CollectionConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetHoldPeriod<'info>>,
+    hold_period: i64,
+) -> Result<()> {
+    if !(0..=MAX_HOLD_PERIOD_SECONDS).contains(&hold_period) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.auction_house_key = ctx.accounts.auction_house.key();
+    collection_config.collection = ctx.accounts.collection.key();
+    collection_config.hold_period = hold_period;
+    collection_config.bump = ctx.bumps.collection_config;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_hold_period: {{\"collection\":\"{}\",\"hold_period\":{}}}",
+        collection_config.collection,
+        hold_period,
+    );
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetInsuranceFundArgs {
+    pub taker_fee_share_bp: u16,
+    pub enabled: bool,
+}
+
+// SetInsuranceFund sets up (or updates) the InsuranceFund of the auction house. While it's
+// enabled, every SOL settlement pays taker_fee_share_bp of its taker fee from the treasury into the
+// fund, which the authority can pay out with queue_insurance_payout and, once the timelock passed,
+// execute_insurance_payout.
+#[derive(Accounts)]
+pub struct SetInsuranceFund<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            INSURANCE_FUND.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=InsuranceFund::LEN,
+        bump,
+    )]
+    insurance_fund: Account<'info, InsuranceFund>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetInsuranceFund<'info>>,
+    args: SetInsuranceFundArgs,
+) -> Result<()> {
+    if args.taker_fee_share_bp > MAX_INSURANCE_FUND_SHARE_BP {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.auction_house_key = ctx.accounts.auction_house.key();
+    insurance_fund.taker_fee_share_bp = args.taker_fee_share_bp;
+    insurance_fund.enabled = args.enabled;
+    insurance_fund.bump = ctx.bumps.insurance_fund;
+    insurance_fund.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_insurance_fund: {{\"auction_house\":\"{}\",\"taker_fee_share_bp\":{},\"enabled\":{}}}",
+        insurance_fund.auction_house_key,
+        args.taker_fee_share_bp,
+        args.enabled,
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetListingOperator lets a seller grant (or revoke) a wallet, e.g. a portfolio manager bot, the
+// right to cancel and reprice their listings. The operator never gets any rights over the token or
+// the sale proceeds.
+#[derive(Accounts)]
+pub struct SetListingOperator<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: operator wallet
+    operator: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[
+            PREFIX.as_bytes(),
+            LISTING_OPERATOR.as_bytes(),
+            wallet.key().as_ref(),
+            operator.key().as_ref(),
+        ],
+        space=ListingOperator::LEN,
+        bump,
+    )]
+    listing_operator: Account<'info, ListingOperator>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetListingOperator<'info>>,
+    enabled: bool,
+) -> Result<()> {
+    let listing_operator = &mut ctx.accounts.listing_operator;
+    listing_operator.seller = ctx.accounts.wallet.key();
+    listing_operator.operator = ctx.accounts.operator.key();
+    listing_operator.enabled = enabled;
+    listing_operator.bump = ctx.bumps.listing_operator;
+    listing_operator.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_listing_operator: {{\"seller\":\"{}\",\"operator\":\"{}\",\"enabled\":{}}}",
+        listing_operator.seller,
+        listing_operator.operator,
+        enabled,
+    );
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use {crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SetNotBefore time locks a v2 listing so creators can pre-stage listings for a coordinated drop:
+// the execute_sale paths reject fills before not_before. A time lock has to end before the
+// listing expires, and 0 unlocks the listing.
+#[derive(Accounts)]
+pub struct SetNotBefore<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetNotBefore<'info>>,
+    not_before: i64,
+) -> Result<()> {
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    let expiry = seller_trade_state.expiry.abs();
+    if not_before < 0 || (expiry > 1 && not_before >= expiry) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    seller_trade_state.not_before = not_before;
+
+    msg!(
+        "set_not_before: {{\"seller_trade_state\":\"{}\",\"not_before\":{}}}",
+        seller_trade_state.key(),
+        seller_trade_state.not_before,
+    );
+    Ok(())
+}
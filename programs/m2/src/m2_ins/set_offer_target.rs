@@ -0,0 +1,52 @@
+use crate::index_ra;
+
+use {
+    crate::errors::ErrorCode, crate::states::*, crate::utils::*, anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+};
+
+// SetOfferTarget targets a v2 bid at the current holder of its mint, so the bid becomes an offer
+// on an unlisted token that only target_seller can fill, usually with accept_offer. Passing None
+// opens the bid to any seller again.
+#[derive(Accounts)]
+pub struct SetOfferTarget<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = buyer_trade_state.buyer == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    buyer_trade_state: Box<Account<'info, BuyerTradeStateV2>>,
+    // remaining accounts:
+    // 0. token_account (required if target_seller is set) - token account of target_seller holding the bid's token_size of the mint
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetOfferTarget<'info>>,
+    target_seller: Option<Pubkey>,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let buyer_trade_state = &mut ctx.accounts.buyer_trade_state;
+    let target_seller = target_seller.unwrap_or_default();
+    if target_seller == buyer_trade_state.buyer {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    if target_seller != Pubkey::default() {
+        let token_account = index_ra!(remaining_accounts, 0);
+        assert_owned_by(token_account, &spl_token::id())?;
+        let token_account = TokenAccount::try_deserialize(&mut &token_account.data.borrow()[..])?;
+        if token_account.owner != target_seller
+            || token_account.mint != buyer_trade_state.token_mint
+            || token_account.amount < buyer_trade_state.token_size
+        {
+            return Err(ErrorCode::InvalidTokenAmount.into());
+        }
+    }
+    buyer_trade_state.target_seller = target_seller;
+
+    msg!(
+        "set_offer_target: {{\"buyer_trade_state\":\"{}\",\"target_seller\":\"{}\"}}",
+        buyer_trade_state.key(),
+        buyer_trade_state.target_seller,
+    );
+    Ok(())
+}
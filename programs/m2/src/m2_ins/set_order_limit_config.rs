@@ -0,0 +1,48 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetOrderLimitConfig lets the auction house authority cap the listings and bids a wallet can have
+// open at the same time, so a spam wallet can't flood the program and indexers with dust orders.
+// Open orders are counted on a WalletOrderCounter per wallet when sell, mip1_sell, ocp_sell,
+// t22_sell, buy, buy_v2 or buy_delegated create a trade state, and uncounted when the trade state is
+// cancelled, filled or closed after expiring. A limit of 0 turns counting off for that side.
+#[derive(Accounts)]
+pub struct SetOrderLimitConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            ORDER_LIMIT_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=OrderLimitConfig::LEN,
+        bump,
+    )]
+    order_limit_config: Account<'info, OrderLimitConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetOrderLimitConfig<'info>>,
+    max_open_listings: u32,
+    max_open_bids: u32,
+) -> Result<()> {
+    let order_limit_config = &mut ctx.accounts.order_limit_config;
+    order_limit_config.auction_house_key = ctx.accounts.auction_house.key();
+    order_limit_config.max_open_listings = max_open_listings;
+    order_limit_config.max_open_bids = max_open_bids;
+    order_limit_config.bump = ctx.bumps.order_limit_config;
+    order_limit_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_order_limit_config: {{\"auction_house\":\"{}\",\"max_open_listings\":{},\"max_open_bids\":{}}}",
+        order_limit_config.auction_house_key,
+        max_open_listings,
+        max_open_bids,
+    );
+    Ok(())
+}
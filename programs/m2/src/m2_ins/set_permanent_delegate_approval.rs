@@ -0,0 +1,58 @@
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*, anchor_spl::token_2022,
+};
+
+// SetPermanentDelegateApproval lets the auction house authority approve (or revoke) listing the
+// tokens of a Token-2022 mint with the permanent-delegate extension. The permanent delegate can
+// transfer or burn tokens after they sold, so t22_sell rejects such mints unless they're approved
+// (or only flags them, see set_permanent_delegate_config). The approval is tied to the mint's
+// current delegate, a new delegate has to be approved again.
+#[derive(Accounts)]
+pub struct SetPermanentDelegateApproval<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: Token-2022 mint, the permanent delegate is read in handle
+    #[account(owner = token_2022::ID @ ErrorCode::InvalidTokenMint)]
+    mint: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            PERMANENT_DELEGATE_APPROVAL.as_bytes(),
+            auction_house.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        space=PermanentDelegateApproval::LEN,
+        bump,
+    )]
+    permanent_delegate_approval: Account<'info, PermanentDelegateApproval>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetPermanentDelegateApproval<'info>>,
+    approved: bool,
+) -> Result<()> {
+    let permanent_delegate =
+        get_t22_permanent_delegate(&ctx.accounts.mint)?.ok_or(ErrorCode::InvalidTokenMint)?;
+
+    let permanent_delegate_approval = &mut ctx.accounts.permanent_delegate_approval;
+    permanent_delegate_approval.auction_house_key = ctx.accounts.auction_house.key();
+    permanent_delegate_approval.mint = ctx.accounts.mint.key();
+    permanent_delegate_approval.permanent_delegate = permanent_delegate;
+    permanent_delegate_approval.approved = approved;
+    permanent_delegate_approval.bump = ctx.bumps.permanent_delegate_approval;
+    permanent_delegate_approval.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_permanent_delegate_approval: {{\"mint\":\"{}\",\"permanent_delegate\":\"{}\",\"approved\":{}}}",
+        permanent_delegate_approval.mint,
+        permanent_delegate,
+        approved,
+    );
+    Ok(())
+}
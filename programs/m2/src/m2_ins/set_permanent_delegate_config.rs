@@ -0,0 +1,45 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetPermanentDelegateConfig lets the auction house authority choose how t22_sell treats Token-2022
+// mints with a permanent delegate it hasn't approved in set_permanent_delegate_approval. By default
+// they're rejected with PermanentDelegateNotApproved. With flag_only they can be listed, and the
+// listing logs a permanent_delegate_flagged event so frontends can warn buyers that the delegate
+// can claw the token back after the sale.
+#[derive(Accounts)]
+pub struct SetPermanentDelegateConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            PERMANENT_DELEGATE_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=PermanentDelegateConfig::LEN,
+        bump,
+    )]
+    permanent_delegate_config: Account<'info, PermanentDelegateConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetPermanentDelegateConfig<'info>>,
+    flag_only: bool,
+) -> Result<()> {
+    let permanent_delegate_config = &mut ctx.accounts.permanent_delegate_config;
+    permanent_delegate_config.auction_house_key = ctx.accounts.auction_house.key();
+    permanent_delegate_config.flag_only = flag_only;
+    permanent_delegate_config.bump = ctx.bumps.permanent_delegate_config;
+    permanent_delegate_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_permanent_delegate_config: {{\"auction_house\":\"{}\",\"flag_only\":{}}}",
+        permanent_delegate_config.auction_house_key,
+        flag_only,
+    );
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPriceBandConfigArgs {
+    pub enabled: bool,
+    pub max_age: i64,
+}
+
+// SetPriceBandConfig lets the auction house authority flag a collection whose sale prices are easy
+// to manipulate. Sales of a flagged collection only settle within the band of a fresh
+// PriceAttestation posted by the notary with attest_price.
+#[derive(Accounts)]
+pub struct SetPriceBandConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint, or the token mint if the token has no verified collection
+    collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            PRICE_BAND_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=PriceBandConfig::LEN,
+        bump,
+    )]
+    price_band_config: Account<'info, PriceBandConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetPriceBandConfig<'info>>,
+    args: SetPriceBandConfigArgs,
+) -> Result<()> {
+    if args.max_age <= 0 {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    let price_band_config = &mut ctx.accounts.price_band_config;
+    price_band_config.auction_house_key = ctx.accounts.auction_house.key();
+    price_band_config.collection = ctx.accounts.collection.key();
+    price_band_config.enabled = args.enabled;
+    price_band_config.max_age = args.max_age;
+    price_band_config.bump = ctx.bumps.price_band_config;
+    price_band_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_price_band_config: {{\"collection\":\"{}\",\"enabled\":{},\"max_age\":{}}}",
+        price_band_config.collection,
+        price_band_config.enabled,
+        price_band_config.max_age,
+    );
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SetPriceTick lets the auction house authority set the tick size of a collection, e.g. 0.01 SOL.
+// SOL prices of new listings, bids and auctions of the collection have to be a multiple of it, and
+// auction bid increments round up to it. The tick is kept on the CollectionConfig of the collection.
+// A tick_size of 0 removes the tick. SPL prices aren't held to the tick, which is in lamports,
+// but to the precision of their payment mint.
+#[derive(Accounts)]
+pub struct SetPriceTick<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint, or the token mint if the token has no verified collection
+    collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=CollectionConfig::LEN,
+        bump,
+    )]
+    collection_config: Account<'info, CollectionConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetPriceTick<'info>>,
+    tick_size: u64,
+) -> Result<()> {
+    if tick_size > MAX_PRICE {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.auction_house_key = ctx.accounts.auction_house.key();
+    collection_config.collection = ctx.accounts.collection.key();
+    collection_config.tick_size = tick_size;
+    collection_config.bump = ctx.bumps.collection_config;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_price_tick: {{\"collection\":\"{}\",\"tick_size\":{}}}",
+        collection_config.collection,
+        tick_size,
+    );
+    Ok(())
+}
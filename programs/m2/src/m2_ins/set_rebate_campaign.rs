@@ -0,0 +1,62 @@
+use {
+    crate::constants::*,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRebateCampaignArgs {
+    pub randomness_authority: Pubkey,
+    pub prize: u64,
+    pub enabled: bool,
+}
+
+// SetRebateCampaign lets the auction house authority run a fee rebate draw every epoch: takers of
+// execute_sale_v2 settlements passing the epoch's RebateEpoch are entered, and the drawn winner
+// claims prize lamports from the treasury. The randomness_authority commits to the seed of an
+// epoch before it starts with commit_rebate_draw and reveals it in draw_rebate, so takers can't
+// pick the winner. There is no VRF behind the draw and the randomness_authority is trusted: it
+// knows the seed, so it can steer the draw by timing its own settlements near the end of the
+// epoch, or withhold the reveal and leave the epoch undrawn. Disabling the campaign stops prizes
+// of draws made afterwards.
+#[derive(Accounts)]
+pub struct SetRebateCampaign<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            REBATE_CAMPAIGN.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=RebateCampaign::LEN,
+        bump,
+    )]
+    rebate_campaign: Account<'info, RebateCampaign>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetRebateCampaign<'info>>,
+    args: SetRebateCampaignArgs,
+) -> Result<()> {
+    let rebate_campaign = &mut ctx.accounts.rebate_campaign;
+    rebate_campaign.auction_house_key = ctx.accounts.auction_house.key();
+    rebate_campaign.randomness_authority = args.randomness_authority;
+    rebate_campaign.prize = args.prize;
+    rebate_campaign.enabled = args.enabled;
+    rebate_campaign.bump = ctx.bumps.rebate_campaign;
+    rebate_campaign.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_rebate_campaign: {{\"randomness_authority\":\"{}\",\"prize\":{},\"enabled\":{}}}",
+        rebate_campaign.randomness_authority,
+        rebate_campaign.prize,
+        rebate_campaign.enabled,
+    );
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use mpl_token_metadata::accounts::Metadata;
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*,
+};
+
+// SetRoyaltyFallback lets the update authority of a collection pick where the royalty share of a
+// creator that can't receive lamports (e.g. an executable account) goes, so settlement doesn't
+// fail on such creators.
+#[derive(Accounts)]
+pub struct SetRoyaltyFallback<'info> {
+    #[account(mut)]
+    update_authority: Signer<'info>,
+    /// CHECK: collection mint
+    collection: UncheckedAccount<'info>,
+    /// CHECK: metadata of the collection mint
+    #[account(
+        seeds = [
+            "metadata".as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            collection.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: fallback recipient of the royalty share
+    recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=update_authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            ROYALTY_FALLBACK.as_bytes(),
+            collection.key().as_ref(),
+        ],
+        space=RoyaltyFallback::LEN,
+        bump,
+    )]
+    royalty_fallback: Account<'info, RoyaltyFallback>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, SetRoyaltyFallback<'info>>) -> Result<()> {
+    let collection_metadata = &ctx.accounts.collection_metadata;
+    assert_metadata_valid(collection_metadata, ctx.accounts.collection.key)?;
+    let metadata_parsed = Metadata::safe_deserialize(&collection_metadata.data.borrow())?;
+    if metadata_parsed.update_authority != ctx.accounts.update_authority.key() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    let royalty_fallback = &mut ctx.accounts.royalty_fallback;
+    royalty_fallback.collection = ctx.accounts.collection.key();
+    royalty_fallback.recipient = ctx.accounts.recipient.key();
+    royalty_fallback.bump = ctx.bumps.royalty_fallback;
+    royalty_fallback.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_royalty_fallback: {{\"collection\":\"{}\",\"recipient\":\"{}\"}}",
+        royalty_fallback.collection,
+        royalty_fallback.recipient,
+    );
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoyaltySubsidyArgs {
+    pub subsidy_bp: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub budget: u64,
+}
+
+// SetRoyaltySubsidy lets the auction house authority subsidize the creator royalties of a
+// collection for a limited time: between start_time and end_time, SOL sales of the collection pay
+// subsidy_bp of the buyer's royalty out of the treasury instead, until budget lamports are spent.
+// Creators still receive the full royalty. Setting the budget to what was already spent stops it.
+#[derive(Accounts)]
+pub struct SetRoyaltySubsidy<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: verified collection mint
+    collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            ROYALTY_SUBSIDY.as_bytes(),
+            auction_house.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        space=RoyaltySubsidy::LEN,
+        bump,
+    )]
+    royalty_subsidy: Account<'info, RoyaltySubsidy>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetRoyaltySubsidy<'info>>,
+    args: SetRoyaltySubsidyArgs,
+) -> Result<()> {
+    if args.subsidy_bp > 10000 {
+        return Err(ErrorCode::InvalidBasisPoints.into());
+    }
+    if args.start_time > args.end_time {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    let royalty_subsidy = &mut ctx.accounts.royalty_subsidy;
+    royalty_subsidy.auction_house_key = ctx.accounts.auction_house.key();
+    royalty_subsidy.collection = ctx.accounts.collection.key();
+    royalty_subsidy.subsidy_bp = args.subsidy_bp;
+    royalty_subsidy.start_time = args.start_time;
+    royalty_subsidy.end_time = args.end_time;
+    royalty_subsidy.budget = args.budget;
+    royalty_subsidy.bump = ctx.bumps.royalty_subsidy;
+
+    msg!(
+        "set_royalty_subsidy: {{\"collection\":\"{}\",\"subsidy_bp\":{},\"start_time\":{},\"end_time\":{},\"budget\":{},\"spent\":{}}}",
+        royalty_subsidy.collection,
+        royalty_subsidy.subsidy_bp,
+        royalty_subsidy.start_time,
+        royalty_subsidy.end_time,
+        royalty_subsidy.budget,
+        royalty_subsidy.spent,
+    );
+    Ok(())
+}
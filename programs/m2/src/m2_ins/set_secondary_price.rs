@@ -0,0 +1,49 @@
+use crate::index_ra;
+
+use {crate::errors::ErrorCode, crate::states::*, crate::utils::*, anchor_lang::prelude::*};
+
+// SetSecondaryPrice adds a second payment leg to a SOL v2 listing, for partner token promotions:
+// the buyer pays price per token in the secondary payment mint on top of the SOL price, and both
+// legs settle atomically in the same sale with fees taken per leg. The buyer pays the leg out of
+// a token account delegated to program_as_signer, so the listing can only be taken by the buyer.
+// The leg is a fixed price, also for floor pegged and dutch listings. A price of 0 removes it.
+#[derive(Accounts)]
+pub struct SetSecondaryPrice<'info> {
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        constraint = seller_trade_state.seller == wallet.key() @ ErrorCode::InvalidAccountState,
+    )]
+    seller_trade_state: Box<Account<'info, SellerTradeStateV2>>,
+    // remaining accounts:
+    // 0. secondary_payment_mint (required if price is not 0) - a valid payment mint
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetSecondaryPrice<'info>>,
+    price: u64,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let seller_trade_state = &mut ctx.accounts.seller_trade_state;
+    if seller_trade_state.payment_mint != Pubkey::default() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    if price == 0 {
+        seller_trade_state.secondary_payment_mint = Pubkey::default();
+    } else {
+        let secondary_payment_mint = index_ra!(remaining_accounts, 0);
+        assert_payment_mint(secondary_payment_mint)?;
+        assert_price_precision(price, secondary_payment_mint)?;
+        seller_trade_state.secondary_payment_mint = secondary_payment_mint.key();
+    }
+    seller_trade_state.secondary_price = price;
+
+    msg!(
+        "set_secondary_price: {{\"seller_trade_state\":\"{}\",\"secondary_payment_mint\":\"{}\",\"secondary_price\":{}}}",
+        seller_trade_state.key(),
+        seller_trade_state.secondary_payment_mint,
+        seller_trade_state.secondary_price,
+    );
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use {crate::constants::*, crate::states::*, anchor_lang::prelude::*};
+
+// SetSellerStatus backs suspend_all_listings and resume_all_listings. While a seller is suspended
+// none of their listings can be filled, without having to cancel and re-create each seller trade state.
+#[derive(Accounts)]
+pub struct SetSellerStatus<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer=wallet,
+        seeds=[PREFIX.as_bytes(), SELLER_STATUS.as_bytes(), wallet.key().as_ref()],
+        space=SellerStatus::LEN,
+        bump,
+    )]
+    seller_status: Account<'info, SellerStatus>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetSellerStatus<'info>>,
+    suspended: bool,
+) -> Result<()> {
+    let seller_status = &mut ctx.accounts.seller_status;
+    seller_status.seller = ctx.accounts.wallet.key();
+    seller_status.suspended = suspended;
+    seller_status.bump = ctx.bumps.seller_status;
+    seller_status.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_seller_status: {{\"seller\":\"{}\",\"suspended\":{}}}",
+        seller_status.seller,
+        suspended,
+    );
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::get_ramped_taker_fee,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTakerFeeConfigArgs {
+    pub flat_fee_micro_usd: u64,
+    pub max_fee_bp: u16,
+    pub max_staleness: i64,
+    pub enabled: bool,
+    pub ramp_hours: u16, // ramp the fee linearly from its current value over this many hours
+}
+
+// SetTakerFeeConfig lets the auction house authority charge the taker fee of SOL sales as a flat
+// USD amount instead of a bp of the price. The amount is converted with the pyth SOL/USD price_feed
+// at settlement and capped at max_fee_bp of the price. With ramp_hours, a fee update ramps linearly
+// from the fee in effect at the update to the new one, so a sudden change can't catch in-flight
+// orders.
+#[derive(Accounts)]
+#[instruction(args: SetTakerFeeConfigArgs)]
+pub struct SetTakerFeeConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        constraint = args.max_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: pyth SOL/USD price account, parsed at settlement
+    price_feed: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            TAKER_FEE_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=TakerFeeConfig::LEN,
+        bump,
+    )]
+    taker_fee_config: Account<'info, TakerFeeConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetTakerFeeConfig<'info>>,
+    args: SetTakerFeeConfigArgs,
+) -> Result<()> {
+    if args.max_staleness <= 0 {
+        return Err(ErrorCode::InvalidPriceFeed.into());
+    }
+
+    if args.ramp_hours > MAX_FEE_RAMP_HOURS {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let taker_fee_config = &mut ctx.accounts.taker_fee_config;
+    // a new config has nothing to ramp from
+    let (prev_flat_fee_micro_usd, prev_max_fee_bp) =
+        if taker_fee_config.auction_house_key == Pubkey::default() {
+            (args.flat_fee_micro_usd, args.max_fee_bp)
+        } else {
+            get_ramped_taker_fee(taker_fee_config, now)
+        };
+    taker_fee_config.prev_flat_fee_micro_usd = prev_flat_fee_micro_usd;
+    taker_fee_config.prev_max_fee_bp = prev_max_fee_bp;
+    taker_fee_config.updated_at = now;
+    taker_fee_config.ramp_seconds = args.ramp_hours as i64 * 60 * 60;
+    taker_fee_config.auction_house_key = ctx.accounts.auction_house.key();
+    taker_fee_config.price_feed = ctx.accounts.price_feed.key();
+    taker_fee_config.flat_fee_micro_usd = args.flat_fee_micro_usd;
+    taker_fee_config.max_fee_bp = args.max_fee_bp;
+    taker_fee_config.max_staleness = args.max_staleness;
+    taker_fee_config.enabled = args.enabled;
+    taker_fee_config.bump = ctx.bumps.taker_fee_config;
+
+    msg!(
+        "set_taker_fee_config: {{\"price_feed\":\"{}\",\"flat_fee_micro_usd\":{},\"max_fee_bp\":{},\"enabled\":{},\"ramp_seconds\":{}}}",
+        taker_fee_config.price_feed,
+        taker_fee_config.flat_fee_micro_usd,
+        taker_fee_config.max_fee_bp,
+        taker_fee_config.enabled,
+        taker_fee_config.ramp_seconds,
+    );
+    Ok(())
+}
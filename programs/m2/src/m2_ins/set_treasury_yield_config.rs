@@ -0,0 +1,75 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTreasuryYieldConfigArgs {
+    pub float: u64,
+    pub vault_state: Pubkey, // Marinade state or stake pool of the vault_program
+    pub share_mint: Pubkey,  // mSOL or pool token mint of the vault_state
+}
+
+// SetTreasuryYieldConfig picks the whitelisted yield vault the treasury is swept into, and the float
+// of lamports the treasury keeps on hand. The vault can only change while the treasury holds none
+// of its shares.
+#[derive(Accounts)]
+pub struct SetTreasuryYieldConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: checked against YIELD_VAULT_PROGRAM_WHITELIST
+    #[account(constraint = YIELD_VAULT_PROGRAM_WHITELIST.contains(vault_program.key) @ ErrorCode::InvalidYieldVaultProgram)]
+    vault_program: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=authority,
+        seeds=[
+            PREFIX.as_bytes(),
+            TREASURY_YIELD_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        space=TreasuryYieldConfig::LEN,
+        bump,
+    )]
+    treasury_yield_config: Account<'info, TreasuryYieldConfig>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetTreasuryYieldConfig<'info>>,
+    args: SetTreasuryYieldConfigArgs,
+) -> Result<()> {
+    let treasury_yield_config = &mut ctx.accounts.treasury_yield_config;
+    if treasury_yield_config.shares > 0
+        && (treasury_yield_config.vault_program != ctx.accounts.vault_program.key()
+            || treasury_yield_config.vault_state != args.vault_state
+            || treasury_yield_config.share_mint != args.share_mint)
+    {
+        return Err(ErrorCode::InvalidYieldVaultProgram.into());
+    }
+    if args.float < MIN_TREASURY_LEFTOVER {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    treasury_yield_config.auction_house_key = ctx.accounts.auction_house.key();
+    treasury_yield_config.vault_program = ctx.accounts.vault_program.key();
+    treasury_yield_config.vault_state = args.vault_state;
+    treasury_yield_config.share_mint = args.share_mint;
+    treasury_yield_config.float = args.float;
+    treasury_yield_config.bump = ctx.bumps.treasury_yield_config;
+    treasury_yield_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "set_treasury_yield_config: {{\"vault_program\":\"{}\",\"vault_state\":\"{}\",\"share_mint\":\"{}\",\"float\":{},\"shares\":{}}}",
+        treasury_yield_config.vault_program,
+        treasury_yield_config.vault_state,
+        treasury_yield_config.share_mint,
+        treasury_yield_config.float,
+        treasury_yield_config.shares,
+    );
+    Ok(())
+}
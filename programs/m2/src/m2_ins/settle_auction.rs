@@ -0,0 +1,404 @@
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{associated_token::AssociatedToken, token::Token},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SettleAuctionArgs {
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+}
+
+// SettleAuction can be called by anyone once the auction ended. The token goes to the highest
+// bidder, or back to the seller if there was no bid or the highest bid didn't reach the reserve
+// price, in which case the highest bid is refunded. The highest bid is royalty and fee inclusive:
+// creators are paid the full royalty out of it through the usual payout path, the platform fee is
+// taken from the seller proceeds, and the rest goes to the seller. A proxy bid that won below its
+// maximum gets the unused part of the maximum back.
+#[derive(Accounts)]
+#[instruction(args: SettleAuctionArgs)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: checked against auction_state
+    #[account(mut, address = auction_state.seller)]
+    seller: UncheckedAccount<'info>,
+    /// CHECK: the highest bidder, or the seller if there was no bid or the reserve wasn't met
+    #[account(mut)]
+    winner: UncheckedAccount<'info>,
+    /// CHECK: checked against auction_state if there was a bid, refunded if the reserve wasn't met
+    #[account(mut)]
+    highest_bidder: UncheckedAccount<'info>,
+    /// CHECK: optional
+    notary: UncheckedAccount<'info>,
+    /// CHECK: checked against auction_state
+    #[account(address = auction_state.token_mint)]
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            PREFIX.as_bytes(),
+            AUCTION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump=auction_state.bump,
+    )]
+    auction_state: Box<Account<'info, AuctionState>>,
+    /// CHECK: checked in seeds
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), AUCTION_ESCROW.as_bytes(), auction_state.key().as_ref()],
+        bump=auction_state.escrow_bump,
+    )]
+    auction_escrow: UncheckedAccount<'info>,
+    /// CHECK: ata of auction_escrow holding the token, checked in transfer_token
+    #[account(mut)]
+    token_vault: UncheckedAccount<'info>,
+    /// CHECK: ata of the winner for token_mint, created if needed
+    #[account(mut)]
+    winner_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    trade_sequence: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    // remaining accounts:
+    // ** IF USING NATIVE SOL **
+    // 0..=4. creators (optional) - same layout as in execute_sale_v2
+    //
+    // ** IF USING SPL **
+    // 0. payment_mint (required) - the payment mint of the auction
+    // 1. payment_source_token_account (required) - ata of auction_escrow holding the highest bid
+    // 2. payment_seller_token_account (required) - token account controlled by seller, or the
+    //                                             highest bidder's ata if the reserve wasn't met
+    // 3. payment_treausry_token_account (required) - token account controlled by auction_house_treasury
+    // 4. payment_winner_token_account (required if a proxy bid won below its maximum) - ata of the winner, created if needed
+    // 4..=13. or 5..=14. creator_token_account (optional) - same layout as in execute_sale_v2
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+    args: SettleAuctionArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let payer = &ctx.accounts.payer;
+    let seller = &ctx.accounts.seller;
+    let winner = &ctx.accounts.winner;
+    let notary = &ctx.accounts.notary;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let auction_escrow = &ctx.accounts.auction_escrow;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let auction_state = &ctx.accounts.auction_state;
+    let auction_state_key = auction_state.key();
+    let is_spl = auction_state.is_spl();
+    let has_bid = auction_state.bid_count > 0;
+    let reserve_met = auction_state.is_reserve_met();
+    let escrowed_bid = auction_state.get_escrowed_bid();
+
+    if Clock::get()?.unix_timestamp < auction_state.get_settle_time() {
+        return Err(ErrorCode::AuctionNotEnded.into());
+    }
+    assert_keys_equal(
+        winner.key,
+        if reserve_met {
+            &auction_state.highest_bidder
+        } else {
+            &auction_state.seller
+        },
+    )?;
+    if has_bid {
+        assert_keys_equal(
+            ctx.accounts.highest_bidder.key,
+            &auction_state.highest_bidder,
+        )?;
+    }
+    if is_spl && has_bid {
+        assert_keys_equal(
+            index_ra!(remaining_accounts, 0).key,
+            &auction_state.payment_mint,
+        )?;
+    }
+
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX.as_bytes(),
+        AUCTION_ESCROW.as_bytes(),
+        auction_state_key.as_ref(),
+        &[auction_state.escrow_bump],
+    ]];
+
+    let mut price = 0;
+    let mut royalty = 0;
+    if has_bid && !reserve_met {
+        let highest_bidder = &ctx.accounts.highest_bidder;
+        if is_spl {
+            transfer_token(
+                &escrowed_bid,
+                payer,
+                auction_escrow,
+                seller,
+                None,
+                DestinationSpecifier::Ai(highest_bidder),
+                index_ra!(remaining_accounts, 0),
+                index_ra!(remaining_accounts, 1),
+                index_ra!(remaining_accounts, 2),
+                token_program,
+                system_program,
+                None,
+                escrow_signer_seeds,
+            )?;
+        } else {
+            invoke_signed(
+                &system_instruction::transfer(auction_escrow.key, highest_bidder.key, escrowed_bid),
+                &[
+                    auction_escrow.to_account_info(),
+                    highest_bidder.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+                escrow_signer_seeds,
+            )?;
+        }
+        msg!(
+            "auction_bid_refunded: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{}}}",
+            auction_state_key,
+            auction_state.highest_bidder,
+            escrowed_bid,
+        );
+    }
+    if reserve_met {
+        assert_metadata_valid(metadata, ctx.accounts.token_mint.key)?;
+        let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+
+        let unused_max_bid = escrowed_bid - auction_state.highest_bid;
+        if unused_max_bid > 0 {
+            if is_spl {
+                transfer_token(
+                    &unused_max_bid,
+                    payer,
+                    auction_escrow,
+                    seller,
+                    None,
+                    DestinationSpecifier::Ai(winner),
+                    index_ra!(remaining_accounts, 0),
+                    index_ra!(remaining_accounts, 1),
+                    index_ra!(remaining_accounts, 4),
+                    token_program,
+                    system_program,
+                    None,
+                    escrow_signer_seeds,
+                )?;
+            } else {
+                invoke_signed(
+                    &system_instruction::transfer(auction_escrow.key, winner.key, unused_max_bid),
+                    &[
+                        auction_escrow.to_account_info(),
+                        winner.to_account_info(),
+                        system_program.to_account_info(),
+                    ],
+                    escrow_signer_seeds,
+                )?;
+            }
+            msg!(
+                "auction_bid_refunded: {{\"auction\":\"{}\",\"bidder\":\"{}\",\"amount\":{}}}",
+                auction_state_key,
+                winner.key(),
+                unused_max_bid,
+            );
+        }
+
+        // the highest bid is royalty inclusive, same as a royalty inclusive collection bid
+        price = get_collection_bid_sale_price(
+            auction_state.highest_bid,
+            true,
+            metadata_parsed.seller_fee_basis_points,
+            10_000,
+        )?;
+        royalty = pay_creator_fees(
+            &mut (if is_spl && unused_max_bid > 0 {
+                remaining_accounts[5..].iter()
+            } else if is_spl {
+                remaining_accounts[4..].iter()
+            } else {
+                remaining_accounts.iter()
+            }),
+            None,
+            &metadata_parsed,
+            &auction_escrow.to_account_info(),
+            escrow_signer_seeds,
+            price,
+            10_000,
+            if is_spl {
+                Some(TransferCreatorSplArgs {
+                    buyer: seller,
+                    payer,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+        )?;
+
+        // nobody takes an auction, the whole platform fee comes out of the seller proceeds
+        let (actual_maker_fee_bp, actual_taker_fee_bp) =
+            get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
+        let platform_fee_bp = (actual_maker_fee_bp as i32 + actual_taker_fee_bp as i32).max(0);
+        transfer_listing_payment(
+            price,
+            platform_fee_bp as i16,
+            0,
+            winner,
+            seller,
+            auction_escrow,
+            auction_house_treasury,
+            if is_spl {
+                Some(TransferListingPaymentSplArgs {
+                    payer,
+                    buyer: seller,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                    payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                    system_program,
+                    token_program,
+                })
+            } else {
+                None
+            },
+            escrow_signer_seeds,
+        )?;
+        if is_spl {
+            // rounding dust and skipped creator shares go to the seller as well
+            let payment_source_token_account = index_ra!(remaining_accounts, 1);
+            if payment_source_token_account.lamports() > 0 {
+                let dust = get_balance_from_token_account(payment_source_token_account)?;
+                if dust > 0 {
+                    transfer_token(
+                        &dust,
+                        payer,
+                        auction_escrow,
+                        seller,
+                        None,
+                        DestinationSpecifier::Ai(seller),
+                        index_ra!(remaining_accounts, 0),
+                        payment_source_token_account,
+                        index_ra!(remaining_accounts, 2),
+                        token_program,
+                        system_program,
+                        None,
+                        escrow_signer_seeds,
+                    )?;
+                }
+            }
+        }
+
+        record_sale(
+            &ctx.accounts.collection_stats,
+            &ctx.accounts.last_sale,
+            &ctx.accounts.trade_sequence,
+            payer,
+            &auction_house.key(),
+            &metadata_parsed,
+            &auction_state.payment_mint,
+            winner.key,
+            seller.key,
+            price,
+            get_payment_decimals(if is_spl {
+                Some(index_ra!(remaining_accounts, 0))
+            } else {
+                None
+            })?,
+        )?;
+    }
+
+    transfer_token(
+        &1,
+        payer,
+        auction_escrow,
+        seller,
+        None,
+        DestinationSpecifier::Ai(winner),
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_vault,
+        &ctx.accounts.winner_receipt_token_account,
+        token_program,
+        system_program,
+        None,
+        escrow_signer_seeds,
+    )?;
+
+    // whatever is left in the escrow is the rent the seller funded, plus rounding dust
+    let escrow_lamports = auction_escrow.lamports();
+    if escrow_lamports > 0 {
+        invoke_signed(
+            &system_instruction::transfer(auction_escrow.key, seller.key, escrow_lamports),
+            &[
+                auction_escrow.to_account_info(),
+                seller.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            escrow_signer_seeds,
+        )?;
+    }
+
+    msg!(
+        "settle_auction: {{\"auction\":\"{}\",\"winner\":\"{}\",\"price\":{},\"royalty\":{},\"highest_bid\":{},\"bid_count\":{},\"reserve_met\":{}}}",
+        auction_state_key,
+        winner.key(),
+        price,
+        royalty,
+        auction_state.highest_bid,
+        auction_state.bid_count,
+        reserve_met,
+    );
+    Ok(())
+}
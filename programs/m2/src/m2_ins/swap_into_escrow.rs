@@ -0,0 +1,166 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+};
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Mint, Token, TokenAccount},
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapIntoEscrowArgs {
+    pub max_amount_in: u64,
+    pub min_amount_out: u64,
+    pub swap_data: Vec<u8>,
+}
+
+// SwapIntoEscrow lets a buyer holding source_mint fund their escrow in the listing's payment mint
+// by routing through a whitelisted swap program in the same transaction as the settlement.
+// The swap route accounts are passed through as remaining accounts, and the contract itself
+// enforces the slippage bound by measuring the escrow balance before and after the swap.
+#[derive(Accounts)]
+pub struct SwapIntoEscrow<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// CHECK: authority
+    authority: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump, has_one=authority)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: escrow_payment_account
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), wallet.key().as_ref()], bump)]
+    escrow_payment_account: UncheckedAccount<'info>,
+    source_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = source_mint,
+        token::authority = wallet,
+    )]
+    source_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: payment mint of the listing, native mint if the listing is priced in SOL
+    destination_mint: UncheckedAccount<'info>,
+    /// CHECK: ata(escrow_payment_account, destination_mint), created if needed
+    #[account(mut, address = get_associated_token_address(&escrow_payment_account.key(), &destination_mint.key()))]
+    destination_token_account: UncheckedAccount<'info>,
+    /// CHECK: checked against SWAP_PROGRAM_WHITELIST
+    #[account(constraint = SWAP_PROGRAM_WHITELIST.contains(swap_program.key) @ ErrorCode::InvalidSwapProgram)]
+    swap_program: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. swap route accounts, passed as-is to swap_program
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapIntoEscrow<'info>>,
+    args: SwapIntoEscrowArgs,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let auction_house = &ctx.accounts.auction_house;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let source_token_account = &mut ctx.accounts.source_token_account;
+    let destination_mint = &ctx.accounts.destination_mint;
+    let destination_token_account = &ctx.accounts.destination_token_account;
+    let swap_program = &ctx.accounts.swap_program;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    let is_native = destination_mint.key() == spl_token::native_mint::id();
+    if !is_native {
+        assert_payment_mint(destination_mint)?;
+    }
+    if args.min_amount_out == 0 {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+
+    if destination_token_account.data_is_empty() {
+        make_ata(
+            destination_token_account.to_account_info(),
+            wallet.to_account_info(),
+            escrow_payment_account.to_account_info(),
+            destination_mint.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+        )?;
+    }
+
+    let source_balance_before = source_token_account.amount;
+    let destination_balance_before = get_balance_from_token_account(destination_token_account)?;
+
+    let swap_accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    invoke(
+        &Instruction {
+            program_id: swap_program.key(),
+            accounts: swap_accounts,
+            data: args.swap_data,
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    source_token_account.reload()?;
+    let amount_in = source_balance_before
+        .checked_sub(source_token_account.amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if amount_in > args.max_amount_in {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+    let amount_out = get_balance_from_token_account(destination_token_account)?
+        .checked_sub(destination_balance_before)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if amount_out < args.min_amount_out {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    if is_native {
+        // unwrap SOL into the escrow so it can be used for SOL denominated listings
+        let auction_house_key = auction_house.key();
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                token_program.key,
+                destination_token_account.key,
+                escrow_payment_account.key,
+                escrow_payment_account.key,
+                &[],
+            )?,
+            &[
+                destination_token_account.to_account_info(),
+                escrow_payment_account.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            &[&[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                wallet.key.as_ref(),
+                &[ctx.bumps.escrow_payment_account],
+            ]],
+        )?;
+    }
+
+    msg!(
+        "swap_into_escrow: {{\"source_mint\":\"{}\",\"destination_mint\":\"{}\",\"amount_in\":{},\"amount_out\":{}}}",
+        ctx.accounts.source_mint.key(),
+        destination_mint.key(),
+        amount_in,
+        amount_out,
+    );
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{
+        prelude::*, solana_program::program::invoke_signed, AnchorDeserialize, AnchorSerialize,
+    },
+    anchor_spl::token::Token,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SweepTreasuryArgs {
+    pub deposit: bool, // true sweeps into the vault, false pulls back from it
+    pub amount: u64,   // lamports to deposit, or shares to withdraw
+}
+
+// SweepTreasury lets the auction house authority deposit the treasury balance above the configured
+// float into the yield vault, or pull it back on demand. The vault instruction is built here for
+// the configured vault_state, with the treasury as signer and its share account as the only
+// account receiving or giving up shares. The contract measures the treasury balance and shares
+// before and after, so a deposit can never dig into the float or leave the treasury without shares.
+#[derive(Accounts)]
+pub struct SweepTreasury<'info> {
+    authority: Signer<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=auction_house_treasury,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump=auction_house.treasury_bump)]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            TREASURY_YIELD_CONFIG.as_bytes(),
+            auction_house.key().as_ref(),
+        ],
+        bump=treasury_yield_config.bump,
+    )]
+    treasury_yield_config: Box<Account<'info, TreasuryYieldConfig>>,
+    /// CHECK: checked against treasury_yield_config
+    #[account(address = treasury_yield_config.vault_program @ ErrorCode::InvalidYieldVaultProgram)]
+    vault_program: UncheckedAccount<'info>,
+    /// CHECK: checked against treasury_yield_config
+    #[account(mut, address = treasury_yield_config.vault_state @ ErrorCode::InvalidYieldVaultProgram)]
+    vault_state: UncheckedAccount<'info>,
+    /// CHECK: checked against treasury_yield_config
+    #[account(mut, address = treasury_yield_config.share_mint @ ErrorCode::InvalidYieldVaultProgram)]
+    share_mint: UncheckedAccount<'info>,
+    /// CHECK: ata of auction_house_treasury for share_mint, checked in get_treasury_share_balance
+    #[account(mut)]
+    treasury_share_account: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. vault accounts, see get_treasury_vault_instruction
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SweepTreasury<'info>>,
+    args: SweepTreasuryArgs,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let treasury_share_account = &ctx.accounts.treasury_share_account;
+    let treasury_yield_config = &mut ctx.accounts.treasury_yield_config;
+    let share_mint = treasury_yield_config.share_mint;
+
+    let shares_before = get_treasury_share_balance(
+        treasury_share_account,
+        auction_house_treasury.key,
+        &share_mint,
+    )?;
+    let balance_before = auction_house_treasury.lamports();
+    let vault_accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| a.key())
+        .collect::<Vec<_>>();
+    let instruction = get_treasury_vault_instruction(
+        ctx.accounts.vault_program.key,
+        args.deposit,
+        args.amount,
+        ctx.accounts.vault_state.key,
+        &share_mint,
+        auction_house_treasury.key,
+        treasury_share_account.key,
+        &vault_accounts,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.vault_state.to_account_info(),
+        ctx.accounts.share_mint.to_account_info(),
+        auction_house_treasury.to_account_info(),
+        treasury_share_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    ];
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+    let auction_house_key = auction_house.key();
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            TREASURY.as_bytes(),
+            &[auction_house.treasury_bump],
+        ]],
+    )?;
+
+    let shares_after = get_treasury_share_balance(
+        treasury_share_account,
+        auction_house_treasury.key,
+        &share_mint,
+    )?;
+    let balance_after = auction_house_treasury.lamports();
+    if args.deposit {
+        if shares_after <= shares_before
+            || balance_after >= balance_before
+            || balance_after < treasury_yield_config.float
+        {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+    } else if shares_after >= shares_before || balance_after <= balance_before {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    treasury_yield_config.shares = shares_after;
+    treasury_yield_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "sweep_treasury: {{\"deposit\":{},\"amount\":{},\"shares\":{},\"treasury_balance\":{}}}",
+        args.deposit,
+        balance_before.abs_diff(balance_after),
+        shares_after,
+        balance_after,
+    );
+    Ok(())
+}
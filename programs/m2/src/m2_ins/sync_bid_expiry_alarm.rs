@@ -0,0 +1,114 @@
+use anchor_lang::Discriminator;
+
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// SyncBidExpiryAlarm is a permissionless instruction that registers bids in the optional
+// BidExpiryAlarm of the hour they expire in, so crankers can derive the alarms of the next window
+// and fetch the bids expiring in it instead of scanning all program accounts. Live bids passed in
+// remaining accounts are upserted, closed bids are removed. A bucket is split into pages when it
+// fills up, and an emptied alarm of a past bucket is closed into the payer.
+#[derive(Accounts)]
+#[instruction(bucket: i64, page: u8)]
+pub struct SyncBidExpiryAlarm<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    #[account(
+        init_if_needed,
+        payer=payer,
+        seeds=[
+            PREFIX.as_bytes(),
+            BID_EXPIRY_ALARM.as_bytes(),
+            auction_house.key().as_ref(),
+            bucket.to_le_bytes().as_ref(),
+            &[page],
+        ],
+        space=BidExpiryAlarm::LEN,
+        bump,
+    )]
+    bid_expiry_alarm: Box<Account<'info, BidExpiryAlarm>>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. buyer_trade_state - buyer trade states (v1 and v2) expiring in bucket to add, refresh or remove
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SyncBidExpiryAlarm<'info>>,
+    bucket: i64,
+    page: u8,
+) -> Result<()> {
+    if BidExpiryAlarm::get_bucket(bucket) != bucket {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let bid_expiry_alarm = &mut ctx.accounts.bid_expiry_alarm;
+    let clock = Clock::get()?;
+
+    bid_expiry_alarm.auction_house_key = auction_house_key;
+    bid_expiry_alarm.bucket = bucket;
+    bid_expiry_alarm.page = page;
+    bid_expiry_alarm.bump = ctx.bumps.bid_expiry_alarm;
+    bid_expiry_alarm.updated_at = clock.unix_timestamp;
+
+    for buyer_trade_state in ctx.remaining_accounts.iter() {
+        if !is_live_buyer_trade_state(buyer_trade_state)? {
+            bid_expiry_alarm.remove(buyer_trade_state.key);
+            continue;
+        }
+
+        let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
+        if bid_args.auction_house_key != auction_house_key {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        let derived_key = Pubkey::create_program_address(
+            &[
+                PREFIX.as_bytes(),
+                bid_args.buyer.as_ref(),
+                bid_args.auction_house_key.as_ref(),
+                bid_args.token_mint.as_ref(),
+                &[bid_args.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+        if derived_key != *buyer_trade_state.key {
+            return Err(ErrorCode::DerivedKeyInvalid.into());
+        }
+        // bids without an expiry never show up in an alarm
+        if bid_args.expiry.abs() <= 1 || BidExpiryAlarm::get_bucket(bid_args.expiry.abs()) != bucket
+        {
+            return Err(ErrorCode::InvalidExpiry.into());
+        }
+
+        bid_expiry_alarm.upsert(BidExpiryAlarmEntry {
+            buyer_trade_state: buyer_trade_state.key(),
+            buyer: bid_args.buyer,
+            token_mint: bid_args.token_mint,
+            expiry: bid_args.expiry.abs(),
+        })?;
+    }
+
+    msg!(
+        "sync_bid_expiry_alarm: {{\"bucket\":{},\"page\":{},\"entries\":{}}}",
+        bucket,
+        page,
+        bid_expiry_alarm.entries.len(),
+    );
+
+    if bid_expiry_alarm.entries.is_empty()
+        && bucket.saturating_add(BID_EXPIRY_BUCKET_SECONDS) <= clock.unix_timestamp
+    {
+        bid_expiry_alarm.close(ctx.accounts.payer.to_account_info())?;
+    }
+    Ok(())
+}
+
+fn is_live_buyer_trade_state(info: &AccountInfo) -> Result<bool> {
+    if info.owner != &crate::ID || info.data_len() < 8 {
+        return Ok(false);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(data[0..8] == BuyerTradeState::discriminator()
+        || data[0..8] == BuyerTradeStateV2::discriminator())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::Discriminator;
+
+use {
+    crate::constants::*, crate::errors::ErrorCode, crate::states::*, crate::utils::*,
+    anchor_lang::prelude::*, anchor_spl::token::Mint,
+};
+
+// SyncMintListingIndex is a permissionless instruction that keeps the optional per-mint
+// MintListingIndex in sync with the seller trade states (v1 and v2) passed in remaining accounts.
+// Live listings are upserted, closed or expired listings are removed, so wallets and other programs
+// can find every listing of a mint with a single account fetch. sell, cancel_sell and
+// execute_sale_v2 update an index that already exists, this catches up with the other instructions.
+#[derive(Accounts)]
+pub struct SyncMintListingIndex<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    token_mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer=payer,
+        seeds=[PREFIX.as_bytes(), MINT_LISTING_INDEX.as_bytes(), token_mint.key().as_ref()],
+        space=MintListingIndex::LEN,
+        bump,
+    )]
+    mint_listing_index: Box<Account<'info, MintListingIndex>>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0..N. seller_trade_state - seller trade states of token_mint to add, refresh or remove
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, SyncMintListingIndex<'info>>) -> Result<()> {
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let mint_listing_index = &mut ctx.accounts.mint_listing_index;
+    let clock = Clock::get()?;
+
+    mint_listing_index.token_mint = token_mint_key;
+    mint_listing_index.bump = ctx.bumps.mint_listing_index;
+    mint_listing_index.updated_at = clock.unix_timestamp;
+
+    for seller_trade_state in ctx.remaining_accounts.iter() {
+        if !is_live_seller_trade_state(seller_trade_state)? {
+            mint_listing_index.remove(seller_trade_state.key);
+            continue;
+        }
+
+        let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+        if sell_args.token_mint != token_mint_key {
+            return Err(ErrorCode::InvalidTokenMint.into());
+        }
+        let derived_key = Pubkey::create_program_address(
+            &[
+                PREFIX.as_bytes(),
+                sell_args.seller.as_ref(),
+                sell_args.auction_house_key.as_ref(),
+                sell_args.token_account.as_ref(),
+                sell_args.token_mint.as_ref(),
+                &[sell_args.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+        if derived_key != *seller_trade_state.key {
+            return Err(ErrorCode::DerivedKeyInvalid.into());
+        }
+
+        if is_trade_state_expired(sell_args.expiry, clock.unix_timestamp) {
+            mint_listing_index.remove(seller_trade_state.key);
+            continue;
+        }
+
+        mint_listing_index.upsert(
+            MintListingEntry {
+                seller_trade_state: seller_trade_state.key(),
+                auction_house_key: sell_args.auction_house_key,
+                seller: sell_args.seller,
+                buyer_price: sell_args.buyer_price,
+                expiry: sell_args.expiry,
+                payment_mint: sell_args.payment_mint,
+            },
+            clock.unix_timestamp,
+        );
+    }
+
+    msg!(
+        "sync_mint_listing_index: {{\"token_mint\":\"{}\",\"listings\":{}}}",
+        token_mint_key,
+        mint_listing_index.listings.len(),
+    );
+    Ok(())
+}
+
+fn is_live_seller_trade_state(info: &AccountInfo) -> Result<bool> {
+    if info.owner != &crate::ID || info.data_len() < 8 {
+        return Ok(false);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(data[0..8] == SellerTradeState::discriminator()
+        || data[0..8] == SellerTradeStateV2::discriminator())
+}
@@ -68,7 +68,7 @@ pub fn handle<'info>(
         &[escrow_payment_bump],
     ]];
 
-    if ctx.remaining_accounts.is_empty() {
+    let (escrow_balance, payment_mint) = if ctx.remaining_accounts.is_empty() {
         invoke_signed(
             &system_instruction::transfer(&escrow_payment_account.key(), &wallet.key(), amount),
             &[
@@ -78,6 +78,7 @@ pub fn handle<'info>(
             ],
             escrow_signer_seeds,
         )?;
+        (escrow_payment_account.lamports(), Pubkey::default())
     } else {
         assert_keys_equal(index_ra!(remaining_accounts, 3).key, &spl_token::id())?;
         transfer_token(
@@ -95,7 +96,24 @@ pub fn handle<'info>(
             None,
             escrow_signer_seeds,
         )?;
-    }
+        // the source token account is closed by transfer_token once it's empty
+        let payment_source_token_account = index_ra!(remaining_accounts, 1);
+        (
+            if payment_source_token_account.data_is_empty() {
+                0
+            } else {
+                get_balance_from_token_account(payment_source_token_account)?
+            },
+            index_ra!(remaining_accounts, 0).key(),
+        )
+    };
 
+    msg!(
+        "withdraw: {{\"wallet\":\"{}\",\"payment_mint\":\"{}\",\"amount\":{},\"escrow_balance\":{}}}",
+        wallet.key(),
+        payment_mint,
+        amount,
+        escrow_balance,
+    );
     Ok(())
 }
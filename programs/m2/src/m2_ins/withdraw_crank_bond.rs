@@ -0,0 +1,51 @@
+use {crate::constants::*, crate::errors::ErrorCode, crate::states::*, anchor_lang::prelude::*};
+
+// WithdrawCrankBond pays lamports of the CrankBond back to the cranker. Withdrawing all of it
+// closes the bond and returns its rent too.
+#[derive(Accounts)]
+pub struct WithdrawCrankBond<'info> {
+    #[account(mut)]
+    cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), CRANK_BOND.as_bytes(), cranker.key().as_ref()],
+        bump=crank_bond.bump,
+        has_one=cranker,
+    )]
+    crank_bond: Account<'info, CrankBond>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawCrankBond<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let cranker = &ctx.accounts.cranker;
+    let crank_bond = &mut ctx.accounts.crank_bond;
+    let remaining = crank_bond
+        .amount
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientCrankBond)?;
+
+    if remaining == 0 {
+        crank_bond.close(cranker.to_account_info())?;
+    } else {
+        let crank_bond_info = crank_bond.to_account_info();
+        **crank_bond_info.lamports.borrow_mut() = crank_bond_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        **cranker.lamports.borrow_mut() = cranker
+            .lamports()
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        crank_bond.amount = remaining;
+    }
+
+    msg!(
+        "withdraw_crank_bond: {{\"cranker\":\"{}\",\"amount\":{},\"bond\":{}}}",
+        cranker.key(),
+        amount,
+        remaining,
+    );
+    Ok(())
+}
@@ -1,5 +1,3 @@
-use solana_program::native_token::LAMPORTS_PER_SOL;
-
 use {
     crate::constants::*,
     crate::errors::ErrorCode,
@@ -10,8 +8,6 @@ use {
     },
 };
 
-const MIN_LEFTOVER: u64 = LAMPORTS_PER_SOL; // 1 SOL
-
 // WithdrawFromTreasury becomes a permissionless instruction
 // that can be called by anyone. As long as the treasury_withdrawal_destination and amount is set correctly
 #[derive(Accounts)]
@@ -45,11 +41,11 @@ pub fn handle<'info>(
     let auction_house = &ctx.accounts.auction_house;
     let system_program = &ctx.accounts.system_program;
 
-    // need to keep at least MIN_LEFTOVER in the treasury
+    // need to keep at least MIN_TREASURY_LEFTOVER in the treasury
     if amount
         > (auction_house_treasury
             .lamports()
-            .checked_sub(MIN_LEFTOVER)
+            .checked_sub(MIN_TREASURY_LEFTOVER)
             .ok_or(ErrorCode::NumericalOverflow)?)
     {
         return Err(ErrorCode::InvalidAccountState.into());
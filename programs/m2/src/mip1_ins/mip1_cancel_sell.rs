@@ -8,7 +8,10 @@ use mpl_token_metadata::{
 use solana_program::sysvar;
 use spl_associated_token_account::get_associated_token_address;
 
-use crate::utils::{assert_is_ata, check_programmable, close_account_anchor};
+use crate::utils::{
+    assert_is_ata, check_programmable, close_account_anchor, split_optional_accounts,
+    uncount_open_order, OrderSide,
+};
 use {
     crate::constants::*,
     crate::errors::ErrorCode,
@@ -104,6 +107,10 @@ pub struct MIP1CancelSell<'info> {
     associated_token_program: Program<'info, AssociatedToken>,
     token_program: Program<'info, Token>,
     system_program: Program<'info, System>,
+    // remaining accounts:
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. wallet_order_counter - WalletOrderCounter of the wallet, required if the counted_order exists
+    // 1. counted_order (required) - CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
 }
 
 pub fn handle_mip1_cancel_sell<'info>(
@@ -300,6 +307,15 @@ pub fn handle_mip1_cancel_sell<'info>(
     assert_is_ata(token_account, wallet.key, token_mint.key, wallet.key)?;
 
     close_account_anchor(seller_trade_state, wallet)?;
+    let (_, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    uncount_open_order(
+        optional_accounts.get(0),
+        optional_accounts.require(1)?,
+        &seller_trade_state.key(),
+        wallet,
+        &ctx.accounts.auction_house.key(),
+        OrderSide::Listing,
+    )?;
 
     msg!(
         "mip1_cancel_sell: {{\"seller_trade_state\":\"{}\",\"token_account\":\"{}\"}}",
@@ -1,14 +1,11 @@
 use std::collections::HashMap;
 
 use mpl_token_metadata::{
-    accounts::Metadata,
     instructions::TransferBuilder,
     types::{AuthorizationData, Payload, PayloadType, SeedsVec, TransferArgs},
 };
 use solana_program::{program::invoke_signed, sysvar};
 
-use crate::index_ra;
-
 use {
     crate::constants::*,
     crate::errors::ErrorCode,
@@ -126,16 +123,16 @@ pub struct MIP1ExecuteSaleV2<'info> {
     /// CHECK: checked by address and in CPI
     #[account(address = mpl_token_metadata::ID)]
     token_metadata_program: UncheckedAccount<'info>,
-    /// CHECK: checked in CPI
+    /// CHECK: checked in get_pnft_transfer_accounts
     edition: UncheckedAccount<'info>,
-    /// CHECK: checked in CPI
+    /// CHECK: checked in get_pnft_transfer_accounts
     authorization_rules_program: UncheckedAccount<'info>,
-    /// CHECK: checked in CPI
+    /// CHECK: checked in get_pnft_transfer_accounts
     authorization_rules: UncheckedAccount<'info>,
-    /// CHECK: checked in CPI
+    /// CHECK: checked in get_pnft_transfer_accounts
     #[account(mut)]
     owner_token_record: UncheckedAccount<'info>,
-    /// CHECK: checked in CPI
+    /// CHECK: checked in get_pnft_transfer_accounts
     #[account(mut)]
     destination_token_record: UncheckedAccount<'info>,
     /// CHECK: check in cpi
@@ -149,6 +146,7 @@ pub struct MIP1ExecuteSaleV2<'info> {
     // remaining accounts:
     // ** IF USING NATIVE SOL **
     // 0..=4. creators (optional) - if the buyer is paying in SOL, these are the creators of the token
+    //                                 a creator that can't receive lamports is followed by its collection's royalty_fallback and recipient
     //
     // ** IF USING SPL **
     // 0. payment_mint (required) - if the buyer is paying in a token, this is the mint of that token
@@ -158,77 +156,111 @@ pub struct MIP1ExecuteSaleV2<'info> {
     // 4..=13. creator_token_account (optional) - if the buyer is paying in a SPL token, these are the creator token accounts,
     //                                            if the creator token accounts are not initialized, the creator itself needs to be
     //                                            included, in the format of creator_1_ATA, creator_1, creator_2_ATA, creator_2, ...
+    //
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. seller_status (required) - SellerStatus of the seller, derivation checked in assert_seller_not_suspended
+    // 2. buyer_status (required) - BuyerStatus of the buyer, derivation checked in assert_buyer_not_suspended
+    // 3. price_band_config (required) - PriceBandConfig of the collection, derivation checked in assert_price_attested
+    // 4. price_attestation - PriceAttestation of the token, required if the price_band_config is enabled
+    // 5. collection_stats - CollectionStats of the collection, required for floor pegged listings, created if needed
+    // 6. last_sale (required) - LastSale of the token, created if needed
+    // 7. trade_sequence - TradeSequence, created if needed, the sale is only recorded on the collection_stats and trade_sequence with both of them
+    // 8. frontend_config - FrontendConfig of the frontend that routed the sale, checked in attribute_frontend_sale
+    // 9. taker_fee_config - TakerFeeConfig of the auction house, derivation checked in get_taker_fee_bp
+    // 10. price_feed - pyth SOL/USD price account, required if the taker_fee_config is enabled
+    // 11. royalty_subsidy - RoyaltySubsidy of the collection, derivation checked in get_active_royalty_subsidy
+    // 12. rebate_epoch - RebateEpoch of the current epoch, derivation checked in enter_rebate_draw
+    // 13. fill_proof - FillProof of the buyer and the token, derivation checked in record_fill_proof
+    // 14. insurance_fund - InsuranceFund of the auction house, derivation checked in fund_insurance
+    // 15. buyer_order_counter - WalletOrderCounter of the buyer, required if the buyer_counted_order exists
+    // 16. seller_order_counter - WalletOrderCounter of the seller, required if the seller_counted_order exists
+    // 17. buyer_counted_order (required) - CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    // 18. seller_counted_order (required) - CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
 }
 
 pub fn handle_mip1_execute_sale<'info>(
     ctx: Context<'_, '_, '_, 'info, MIP1ExecuteSaleV2<'info>>,
     args: MIP1ExecuteSaleV2Args,
 ) -> Result<()> {
+    assert_compute_budget(&ctx.accounts.instructions, MIN_SETTLEMENT_COMPUTE_UNITS)?;
     let payer = &ctx.accounts.payer;
     let buyer = &ctx.accounts.buyer;
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
     let seller = &ctx.accounts.seller;
     let token_mint = &ctx.accounts.token_mint;
     let metadata = &ctx.accounts.metadata;
-    let notary = &ctx.accounts.notary;
-    let seller_trade_state = &ctx.accounts.seller_trade_state;
-    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
-    let buyer_escrow_payment_account = &ctx.accounts.buyer_escrow_payment_account;
-    let auction_house = &ctx.accounts.auction_house;
-    let auction_house_key = auction_house.key();
-    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
     let token_account = &ctx.accounts.token_account;
     let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
 
     let program_as_signer = &ctx.accounts.program_as_signer;
-    let edition = &ctx.accounts.edition;
-    let authorization_rules_program = &ctx.accounts.authorization_rules_program;
-    let authorization_rules = &ctx.accounts.authorization_rules;
-    let owner_token_record = &ctx.accounts.owner_token_record;
-    let destination_token_record = &ctx.accounts.destination_token_record;
 
     let associated_token_program = &ctx.accounts.associated_token_program;
     let token_program = &ctx.accounts.token_program;
     let system_program = &ctx.accounts.system_program;
     let instructions = &ctx.accounts.instructions;
-    let remaining_accounts = ctx.remaining_accounts;
 
     if !buyer.is_signer && !seller.is_signer {
         return Err(ErrorCode::SaleRequiresSigner.into());
     }
     let taker = if buyer.is_signer { buyer } else { seller };
 
-    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
-    let is_spl = bid_args.payment_mint != Pubkey::default();
-    bid_args.check_args(
-        ctx.accounts.buyer_referral.key,
-        args.price,
-        &token_mint.key(),
-        1,
-        if is_spl {
-            index_ra!(remaining_accounts, 0).key
-        } else {
-            &bid_args.payment_mint
-        },
-    )?;
-    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
-    sell_args.check_args(
-        ctx.accounts.seller_referral.key,
-        &bid_args.buyer_price,
-        &bid_args.token_mint,
-        &1,
-        &bid_args.payment_mint,
+    let settlement = SettlementContext {
+        auction_house: &ctx.accounts.auction_house,
+        auction_house_treasury: &ctx.accounts.auction_house_treasury,
+        notary: &ctx.accounts.notary,
+        buyer,
+        seller,
+        taker,
+        payer,
+        buyer_trade_state: &ctx.accounts.buyer_trade_state,
+        seller_trade_state: &ctx.accounts.seller_trade_state,
+        buyer_referral: &ctx.accounts.buyer_referral,
+        seller_referral: &ctx.accounts.seller_referral,
+        escrow_payment_account: &ctx.accounts.buyer_escrow_payment_account,
+        escrow_payment_bump: ctx.bumps.buyer_escrow_payment_account,
+        program_as_signer: &ctx.accounts.program_as_signer,
+        program_as_signer_bump: ctx.bumps.program_as_signer,
+        metadata,
+        collection_config,
+        seller_status: optional_accounts.require(1)?,
+        buyer_status: optional_accounts.require(2)?,
+        price_band_config: optional_accounts.require(3)?,
+        price_attestation: optional_accounts.get(4),
+        collection_stats: optional_accounts.get(5),
+        last_sale: optional_accounts.require(6)?,
+        trade_sequence: optional_accounts.get(7),
+        frontend_config: optional_accounts.get(8),
+        taker_fee_config: optional_accounts.get(9),
+        price_feed: optional_accounts.get(10),
+        royalty_subsidy: optional_accounts.get(11),
+        rebate_epoch: optional_accounts.get(12),
+        fill_proof: optional_accounts.get(13),
+        insurance_fund: optional_accounts.get(14),
+        buyer_order_counter: optional_accounts.get(15),
+        seller_order_counter: optional_accounts.get(16),
+        buyer_counted_order: optional_accounts.require(17)?,
+        seller_counted_order: optional_accounts.require(18)?,
+        ocp_policy: None,
+        t22_mint: None,
+        token_program,
+        system_program,
+        remaining_accounts,
+    };
+    let terms = settlement.validate(&token_mint.key(), 1, args.price)?;
+    check_programmable(&terms.metadata_parsed)?;
+    let pnft = get_pnft_transfer_accounts(
+        &terms.metadata_parsed,
+        &token_account.key(),
+        buyer_receipt_token_account.key,
+        &ctx.accounts.edition,
+        &ctx.accounts.owner_token_record,
+        &ctx.accounts.destination_token_record,
+        &ctx.accounts.authorization_rules_program,
+        &ctx.accounts.authorization_rules,
     )?;
 
-    let clock = Clock::get()?;
-    if bid_args.expiry.abs() > 1 && clock.unix_timestamp > bid_args.expiry.abs() {
-        return Err(ErrorCode::InvalidExpiry.into());
-    }
-    if sell_args.expiry.abs() > 1 && clock.unix_timestamp > sell_args.expiry.abs() {
-        return Err(ErrorCode::InvalidExpiry.into());
-    }
-
-    assert_metadata_valid(metadata, &token_mint.key())?;
-
     let program_as_signer_seeds = &[
         PREFIX.as_bytes(),
         SIGNER.as_bytes(),
@@ -249,109 +281,43 @@ pub fn handle_mip1_execute_sale<'info>(
         .destination_owner(buyer.key())
         .mint(token_mint.key())
         .metadata(metadata.key())
-        .edition(Some(edition.key()))
-        .token_record(Some(owner_token_record.key()))
-        .destination_token_record(Some(destination_token_record.key()))
+        .edition(Some(pnft.edition.key()))
+        .token_record(Some(pnft.owner_token_record.key()))
+        .destination_token_record(Some(pnft.destination_token_record.key()))
         .authority(program_as_signer.key())
         .payer(payer.key())
         .system_program(system_program.key())
         .sysvar_instructions(instructions.key())
         .spl_token_program(token_program.key())
         .spl_ata_program(associated_token_program.key())
-        .authorization_rules_program(Some(authorization_rules_program.key()))
-        .authorization_rules(Some(authorization_rules.key()))
+        .authorization_rules_program(pnft.authorization_rules_program.map(|a| a.key()))
+        .authorization_rules(pnft.authorization_rules.map(|a| a.key()))
         .transfer_args(TransferArgs::V1 {
             authorization_data: Some(AuthorizationData { payload }),
             amount: 1,
         })
         .instruction();
 
-    invoke_signed(
-        &ins,
-        &[
-            program_as_signer.to_account_info(),
-            token_account.to_account_info(),
-            buyer_receipt_token_account.to_account_info(),
-            buyer.to_account_info(),
-            payer.to_account_info(),
-            token_mint.to_account_info(),
-            metadata.to_account_info(),
-            edition.to_account_info(),
-            token_program.to_account_info(),
-            associated_token_program.to_account_info(),
-            system_program.to_account_info(),
-            instructions.to_account_info(),
-            authorization_rules_program.to_account_info(),
-            authorization_rules.to_account_info(),
-            owner_token_record.to_account_info(),
-            destination_token_record.to_account_info(),
-            seller.to_account_info(), // might not be needed, but skips an unnecessary branch
-        ],
-        &[program_as_signer_seeds],
-    )?;
-
-    let buyer_escrow_signer_seeds: &[&[&[u8]]] = &[&[
-        PREFIX.as_bytes(),
-        auction_house_key.as_ref(),
-        buyer.key.as_ref(),
-        &[ctx.bumps.buyer_escrow_payment_account],
-    ]];
+    let mut account_infos = vec![
+        program_as_signer.to_account_info(),
+        token_account.to_account_info(),
+        buyer_receipt_token_account.to_account_info(),
+        buyer.to_account_info(),
+        payer.to_account_info(),
+        token_mint.to_account_info(),
+        metadata.to_account_info(),
+        token_program.to_account_info(),
+        associated_token_program.to_account_info(),
+        system_program.to_account_info(),
+        instructions.to_account_info(),
+        ctx.accounts.token_metadata_program.to_account_info(), // in place of absent auth rules
+        seller.to_account_info(), // might not be needed, but skips an unnecessary branch
+    ];
+    account_infos.extend(pnft.to_account_infos());
+    invoke_signed(&ins, &account_infos, &[program_as_signer_seeds])?;
 
     // buyer pays creator royalties
-    let metadata_parsed = &Metadata::safe_deserialize(&metadata.data.borrow()).unwrap();
-    let royalty = pay_creator_fees(
-        &mut (if is_spl {
-            remaining_accounts[4..].iter()
-        } else {
-            remaining_accounts.iter()
-        }),
-        None,
-        metadata_parsed,
-        &buyer_escrow_payment_account.to_account_info(),
-        buyer_escrow_signer_seeds,
-        args.price,
-        10_000,
-        if is_spl {
-            Some(TransferCreatorSplArgs {
-                buyer,
-                payer,
-                mint: index_ra!(remaining_accounts, 0),
-                payment_source_token_account: index_ra!(remaining_accounts, 1),
-                system_program,
-                token_program,
-            })
-        } else {
-            None
-        },
-    )?;
-    check_programmable(metadata_parsed)?;
-
-    let (actual_maker_fee_bp, actual_taker_fee_bp) =
-        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
-    let (maker_fee, taker_fee) = transfer_listing_payment(
-        args.price,
-        actual_maker_fee_bp,
-        actual_taker_fee_bp,
-        taker,
-        seller,
-        buyer_escrow_payment_account,
-        auction_house_treasury,
-        if is_spl {
-            Some(TransferListingPaymentSplArgs {
-                payer,
-                buyer,
-                mint: index_ra!(remaining_accounts, 0),
-                payment_source_token_account: index_ra!(remaining_accounts, 1),
-                payment_seller_token_account: index_ra!(remaining_accounts, 2),
-                payment_treasury_token_account: index_ra!(remaining_accounts, 3),
-                system_program,
-                token_program,
-            })
-        } else {
-            None
-        },
-        buyer_escrow_signer_seeds,
-    )?;
+    let payout = settlement.pay(&terms, args.maker_fee_bp, args.taker_fee_bp, 10_000)?;
 
     // close token account
     if token_account.amount == 1 && token_account.owner == program_as_signer.key() {
@@ -380,24 +346,17 @@ pub fn handle_mip1_execute_sale<'info>(
         &buyer.key(),
     )?;
 
-    try_close_buyer_escrow(
-        buyer_escrow_payment_account,
-        buyer,
-        system_program,
-        buyer_escrow_signer_seeds,
-    )?;
-
-    // we don't need to zero out buyer_trade_state, just copy zero discriminator to it and then close
-    close_account_anchor(buyer_trade_state, buyer)?;
-    close_account_anchor(seller_trade_state, seller)?;
+    settlement.close(&terms, &payout)?;
     msg!(
-        "{{\"maker_fee\":{},\"taker_fee\":{},\"royalty\":{},\"price\":{},\"seller_expiry\":{},\"buyer_expiry\":{}}}",
-        maker_fee,
-        taker_fee,
-        royalty,
-        args.price,
-        sell_args.expiry,
-        bid_args.expiry,
+        "{{\"maker_fee\":{},\"taker_fee\":{},\"royalty\":{},\"royalty_subsidy\":{},\"price\":{},\"seller_expiry\":{},\"buyer_expiry\":{},\"creator_listing\":{}}}",
+        payout.maker_fee,
+        payout.taker_fee,
+        payout.royalty,
+        payout.royalty_subsidy,
+        terms.price,
+        terms.sell_args.expiry,
+        terms.bid_args.expiry,
+        terms.sell_args.creator_listing,
     );
 
     Ok(())
@@ -0,0 +1,31 @@
+use {super::mip1_sell::*, anchor_lang::prelude::*};
+
+// MIP1MigrateAndList lists a pNFT that program_as_signer was left the utility delegate of, locked
+// or not, in one transaction: the stale delegate is unlocked and revoked first, then the token is
+// escrowed and listed as by mip1_sell. The seller's rental listing PDA of the token is passed to
+// prove the delegate isn't backing a rental listing any more.
+#[derive(Accounts)]
+pub struct MIP1MigrateAndList<'info> {
+    sell: MIP1Sell<'info>,
+    /// CHECK: derivation checked in release_stale_utility_delegate, must not exist
+    rental_listing: UncheckedAccount<'info>,
+}
+
+pub fn handle_mip1_migrate_and_list<'info>(
+    ctx: Context<'_, '_, '_, 'info, MIP1MigrateAndList<'info>>,
+    args: &MIP1SellArgs,
+) -> Result<()> {
+    ctx.accounts.sell.release_stale_utility_delegate(
+        &ctx.accounts.rental_listing,
+        ctx.bumps.sell.program_as_signer,
+    )?;
+    handle_mip1_sell(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.sell,
+            ctx.remaining_accounts,
+            ctx.bumps.sell,
+        ),
+        args,
+    )
+}
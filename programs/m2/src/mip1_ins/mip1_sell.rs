@@ -2,13 +2,16 @@ use std::collections::HashMap;
 
 use mpl_token_metadata::{
     accounts::{Metadata, TokenRecord},
-    instructions::TransferBuilder,
+    instructions::{RevokeUtilityV1Builder, TransferBuilder, UnlockV1Builder},
     types::{
         AuthorizationData, Payload, PayloadType, SeedsVec, TokenDelegateRole, TokenState,
         TransferArgs,
     },
 };
-use solana_program::{program::invoke, sysvar};
+use solana_program::{
+    program::{invoke, invoke_signed},
+    sysvar,
+};
 use spl_associated_token_account::get_associated_token_address;
 
 use crate::index_ra;
@@ -18,9 +21,12 @@ use {
     crate::errors::ErrorCode,
     crate::states::*,
     crate::utils::{
-        assert_is_ata, assert_payment_mint, check_programmable, close_account_anchor,
-        create_or_realloc_seller_trade_state, get_delegate_info_and_token_state_from_token_record,
-        split_payer_from_remaining_accounts,
+        assert_collection_not_paused, assert_collection_price_on_tick, assert_derivation,
+        assert_hold_period_elapsed, assert_is_ata, assert_payment_mint, assert_price_precision,
+        check_programmable, close_account_anchor, count_open_order,
+        create_or_realloc_seller_trade_state, get_collection_key,
+        get_delegate_info_and_token_state_from_token_record, get_payment_decimals, get_ui_price,
+        split_optional_accounts, split_payer_from_remaining_accounts, OrderSide,
     },
     anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
     anchor_spl::{
@@ -133,15 +139,125 @@ pub struct MIP1Sell<'info> {
     // remaining accounts:
     // 0. payment_mint (optional) - if the seller wants payment in a SPL token, this is the mint of that token
     // ...
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. last_sale (required) - LastSale of the token, derivation checked in assert_hold_period_elapsed
+    // 2. order_limit_config (required) - OrderLimitConfig of the auction house, derivation checked in count_open_order
+    // 3. wallet_order_counter - WalletOrderCounter of the wallet, required if the order_limit_config has a limit, created if needed
+    // 4. counted_order - CountedOrder of the seller_trade_state, required if the order_limit_config has a limit, created if needed
     // -1. payer (optional) - this wallet will try to pay for sts rent
 }
 
+impl<'info> MIP1Sell<'info> {
+    /// Unlocks and revokes a utility delegate program_as_signer was left with on the seller's
+    /// pNFT, so the token can be escrowed by handle_mip1_sell. Only delegates without a rental
+    /// listing of the seller behind them are stale, rental_listing has to be the seller's rental
+    /// listing PDA of the token and must not exist. Other delegates are left for mip1_sell to
+    /// reject.
+    pub fn release_stale_utility_delegate(
+        &self,
+        rental_listing: &AccountInfo<'info>,
+        program_as_signer_bump: u8,
+    ) -> Result<()> {
+        let (delegate, delegate_role, token_state) =
+            get_delegate_info_and_token_state_from_token_record(&self.owner_token_record)?;
+        if delegate != Some(self.program_as_signer.key())
+            || delegate_role != Some(TokenDelegateRole::Utility)
+        {
+            return Ok(());
+        }
+        assert_derivation(
+            &crate::ID,
+            rental_listing,
+            &[
+                PREFIX.as_bytes(),
+                RENTAL_LISTING.as_bytes(),
+                self.auction_house.key().as_ref(),
+                self.wallet.key.as_ref(),
+                self.token_mint.key().as_ref(),
+            ],
+        )?;
+        if !rental_listing.data_is_empty() {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+
+        let account_infos = [
+            self.program_as_signer.to_account_info(),
+            self.wallet.to_account_info(),
+            self.token_account.to_account_info(),
+            self.token_mint.to_account_info(),
+            self.metadata.to_account_info(),
+            self.edition.to_account_info(),
+            self.owner_token_record.to_account_info(),
+            self.system_program.to_account_info(),
+            self.instructions.to_account_info(),
+            self.token_program.to_account_info(),
+            self.authorization_rules_program.to_account_info(),
+            self.authorization_rules.to_account_info(),
+            self.token_metadata_program.to_account_info(),
+        ];
+        if token_state == TokenState::Locked {
+            let ins = UnlockV1Builder::new()
+                .authority(self.program_as_signer.key())
+                .token_owner(Some(self.wallet.key()))
+                .token(self.token_account.key())
+                .mint(self.token_mint.key())
+                .metadata(self.metadata.key())
+                .edition(Some(self.edition.key()))
+                .token_record(Some(self.owner_token_record.key()))
+                .payer(self.wallet.key())
+                .system_program(self.system_program.key())
+                .sysvar_instructions(self.instructions.key())
+                .spl_token_program(Some(self.token_program.key()))
+                .authorization_rules_program(Some(self.authorization_rules_program.key()))
+                .authorization_rules(Some(self.authorization_rules.key()))
+                .instruction();
+            invoke_signed(
+                &ins,
+                &account_infos,
+                &[&[
+                    PREFIX.as_bytes(),
+                    SIGNER.as_bytes(),
+                    &[program_as_signer_bump],
+                ]],
+            )?;
+        }
+
+        let ins = RevokeUtilityV1Builder::new()
+            .delegate(self.program_as_signer.key())
+            .metadata(self.metadata.key())
+            .master_edition(Some(self.edition.key()))
+            .token_record(Some(self.owner_token_record.key()))
+            .mint(self.token_mint.key())
+            .token(self.token_account.key())
+            .authority(self.wallet.key())
+            .payer(self.wallet.key())
+            .system_program(self.system_program.key())
+            .sysvar_instructions(self.instructions.key())
+            .spl_token_program(Some(self.token_program.key()))
+            .authorization_rules_program(Some(self.authorization_rules_program.key()))
+            .authorization_rules(Some(self.authorization_rules.key()))
+            .instruction();
+        invoke(&ins, &account_infos)?;
+
+        msg!(
+            "stale_delegate_released: {{\"token_mint\":\"{}\",\"wallet\":\"{}\",\"was_locked\":{}}}",
+            self.token_mint.key(),
+            self.wallet.key(),
+            token_state == TokenState::Locked,
+        );
+        Ok(())
+    }
+}
+
 pub fn handle_mip1_sell<'info>(
     ctx: Context<'_, '_, '_, 'info, MIP1Sell<'info>>,
     args: &MIP1SellArgs,
 ) -> Result<()> {
     let (remaining_accounts, possible_payer) =
         split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
     let wallet = &ctx.accounts.wallet;
     let payer = if let Some(p) = possible_payer {
         p
@@ -239,8 +355,30 @@ pub fn handle_mip1_sell<'info>(
     {
         return Err(ErrorCode::InvalidAccountState.into());
     }
+    // neither the listing nor the migration listing existed yet
+    if sell_args.seller == Pubkey::default() && migration_sell_args.seller == Pubkey::default() {
+        count_open_order(
+            optional_accounts.require(2)?,
+            optional_accounts.get(3),
+            optional_accounts.get(4),
+            seller_trade_state.key,
+            payer,
+            auction_house.key,
+            wallet.key,
+            OrderSide::Listing,
+        )?;
+    }
 
-    check_programmable(&Metadata::safe_deserialize(&metadata.data.borrow()).unwrap())?;
+    let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    check_programmable(&metadata_parsed)?;
+    assert_collection_not_paused(collection_config, auction_house.key, &metadata_parsed)?;
+    assert_hold_period_elapsed(
+        collection_config,
+        optional_accounts.require(1)?,
+        auction_house.key,
+        &metadata_parsed,
+        wallet.key,
+    )?;
 
     let (sts_to_modify, sts_to_modify_bump, sts_to_close, escrow_account_key) =
         if token_account.owner == *program_as_signer.key {
@@ -382,8 +520,15 @@ pub fn handle_mip1_sell<'info>(
 
     let payment_mint = if remaining_accounts.len() == 1 {
         assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.price, index_ra!(remaining_accounts, 0))?;
         index_ra!(remaining_accounts, 0).key()
     } else {
+        assert_collection_price_on_tick(
+            collection_config,
+            auction_house.key,
+            &get_collection_key(&metadata_parsed),
+            args.price,
+        )?;
         Pubkey::default()
     };
     let sts = SellerTradeStateV2 {
@@ -397,6 +542,17 @@ pub fn handle_mip1_sell<'info>(
         bump: sts_to_modify_bump,
         expiry: args.expiry,
         payment_mint,
+        floor_premium_bp: None,
+        creator_listing: false,
+        dutch_end_price: 0,
+        dutch_start_time: 0,
+        dutch_decay_interval: 0,
+        allowed_buyer: Pubkey::default(),
+        auto_renew_period: 0,
+        auto_renew_bump_bp: 0,
+        secondary_payment_mint: Pubkey::default(),
+        secondary_price: 0,
+        not_before: 0,
     };
     let sts_v2_serialized = sts.try_to_vec()?;
     sts_to_modify.try_borrow_mut_data()?[8..8 + sts_v2_serialized.len()]
@@ -407,10 +563,17 @@ pub fn handle_mip1_sell<'info>(
         sts_to_modify.key(),
         escrow_account_key
     );
+    let payment_decimals = get_payment_decimals(if remaining_accounts.len() == 1 {
+        Some(index_ra!(remaining_accounts, 0))
+    } else {
+        None
+    })?;
     msg!(
-        "{{\"price\":{},\"seller_expiry\":{}}}",
+        "{{\"price\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
         sts.buyer_price,
-        sts.expiry
+        sts.expiry,
+        payment_decimals,
+        get_ui_price(sts.buyer_price, payment_decimals),
     );
 
     if sts_to_close.key != sts_to_modify.key {
@@ -9,3 +9,6 @@ pub use mip1_execute_sale_v2::*;
 
 pub mod mip1_cancel_sell;
 pub use mip1_cancel_sell::*;
+
+pub mod mip1_migrate_and_list;
+pub use mip1_migrate_and_list::*;
@@ -4,6 +4,7 @@ use {
     crate::constants::*,
     crate::errors::ErrorCode,
     crate::states::*,
+    crate::utils::{split_optional_accounts, uncount_open_order, OrderSide},
     anchor_lang::prelude::*,
     anchor_spl::token::{Mint, Token, TokenAccount},
 };
@@ -77,6 +78,10 @@ pub struct OCPCancelSell<'info> {
     token_program: Program<'info, Token>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
+    // remaining accounts:
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. wallet_order_counter - WalletOrderCounter of the wallet, required if the counted_order exists
+    // 1. counted_order (required) - CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
 }
 
 pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, OCPCancelSell<'info>>) -> Result<()> {
@@ -132,6 +137,16 @@ pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, OCPCancelSell<'info>>) -> R
         ))?;
     }
 
+    let (_, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    uncount_open_order(
+        optional_accounts.get(0),
+        optional_accounts.require(1)?,
+        &seller_trade_state.key(),
+        wallet,
+        &ctx.accounts.auction_house.key(),
+        OrderSide::Listing,
+    )?;
+
     msg!(
         "{{\"price\":{},\"seller_expiry\":{}}}",
         seller_trade_state.buyer_price,
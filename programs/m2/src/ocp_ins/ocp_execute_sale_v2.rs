@@ -1,5 +1,4 @@
 use anchor_spl::associated_token::AssociatedToken;
-use mpl_token_metadata::accounts::Metadata;
 use open_creator_protocol::state::Policy;
 use solana_program::sysvar;
 
@@ -109,10 +108,10 @@ pub struct OCPExecuteSaleV2<'info> {
     )]
     pub buyer_escrow_payment_account: UncheckedAccount<'info>,
 
-    /// CHECK: check with contraints
+    /// CHECK: must match the buyer_referral stored in buyer_trade_state
     #[account(mut)]
     buyer_referral: UncheckedAccount<'info>,
-    /// CHECK: check with contraints
+    /// CHECK: must match the seller_referral stored in seller_trade_state
     #[account(mut)]
     seller_referral: UncheckedAccount<'info>,
 
@@ -137,53 +136,89 @@ pub struct OCPExecuteSaleV2<'info> {
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    // remaining accounts:
+    // 0..=4. creators (optional) - these are the creators of the token
+    //
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. seller_status (required) - SellerStatus of the seller, derivation checked in assert_seller_not_suspended
+    // 2. buyer_status (required) - BuyerStatus of the buyer, derivation checked in assert_buyer_not_suspended
+    // 3. price_band_config (required) - PriceBandConfig of the collection, derivation checked in assert_price_attested
+    // 4. price_attestation - PriceAttestation of the token, required if the price_band_config is enabled
+    // 5. collection_stats - CollectionStats of the collection, required for floor pegged listings, created if needed
+    // 6. last_sale (required) - LastSale of the token, created if needed
+    // 7. trade_sequence - TradeSequence, created if needed, the sale is only recorded on the collection_stats and trade_sequence with both of them
+    // 8. frontend_config - FrontendConfig of the frontend that routed the sale, checked in attribute_frontend_sale
+    // 9. taker_fee_config - TakerFeeConfig of the auction house, derivation checked in get_taker_fee_bp
+    // 10. price_feed - pyth SOL/USD price account, required if the taker_fee_config is enabled
+    // 11. royalty_subsidy - RoyaltySubsidy of the collection, derivation checked in get_active_royalty_subsidy
+    // 12. rebate_epoch - RebateEpoch of the current epoch, derivation checked in enter_rebate_draw
+    // 13. fill_proof - FillProof of the buyer and the token, derivation checked in record_fill_proof
+    // 14. insurance_fund - InsuranceFund of the auction house, derivation checked in fund_insurance
+    // 15. buyer_order_counter - WalletOrderCounter of the buyer, required if the buyer_counted_order exists
+    // 16. seller_order_counter - WalletOrderCounter of the seller, required if the seller_counted_order exists
+    // 17. buyer_counted_order (required) - CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    // 18. seller_counted_order (required) - CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
 }
 
 pub fn handle<'info>(
     ctx: Context<'_, '_, '_, 'info, OCPExecuteSaleV2<'info>>,
     args: OCPExecuteSaleV2Args,
 ) -> Result<()> {
-    let payer = &ctx.accounts.payer;
+    assert_compute_budget(&ctx.accounts.instructions, MIN_SETTLEMENT_COMPUTE_UNITS)?;
     let buyer = &ctx.accounts.buyer;
+    let (remaining_accounts, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
     let seller = &ctx.accounts.seller;
-    let token_mint = &ctx.accounts.token_mint;
-    let metadata = &ctx.accounts.metadata;
-    let notary = &ctx.accounts.notary;
-    let seller_trade_state = &ctx.accounts.seller_trade_state;
-    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
-    let buyer_escrow_payment_account = &ctx.accounts.buyer_escrow_payment_account;
-    let auction_house = &ctx.accounts.auction_house;
-    let auction_house_key = auction_house.key();
-    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
-    let system_program = &ctx.accounts.system_program;
 
-    let bid_args = BidArgs::from_account_info(buyer_trade_state)?;
-    bid_args.check_args(
-        &bid_args.buyer_referral,
-        args.price,
-        &token_mint.key(),
-        1,
-        &Pubkey::default(),
-    )?;
-    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
-    sell_args.check_args(
-        &sell_args.seller_referral,
-        &args.price,
-        &token_mint.key(),
-        &1,
-        &Pubkey::default(),
-    )?;
-
-    let clock = Clock::get()?;
-    if bid_args.expiry.abs() > 1 && clock.unix_timestamp > bid_args.expiry.abs() {
-        return Err(ErrorCode::InvalidExpiry.into());
-    }
-    if sell_args.expiry.abs() > 1 && clock.unix_timestamp > sell_args.expiry.abs() {
-        return Err(ErrorCode::InvalidExpiry.into());
+    let settlement = SettlementContext {
+        auction_house: &ctx.accounts.auction_house,
+        auction_house_treasury: &ctx.accounts.auction_house_treasury,
+        notary: &ctx.accounts.notary,
+        buyer,
+        seller,
+        taker: &ctx.accounts.payer,
+        payer: &ctx.accounts.payer,
+        buyer_trade_state: &ctx.accounts.buyer_trade_state,
+        seller_trade_state: &ctx.accounts.seller_trade_state,
+        buyer_referral: &ctx.accounts.buyer_referral,
+        seller_referral: &ctx.accounts.seller_referral,
+        escrow_payment_account: &ctx.accounts.buyer_escrow_payment_account,
+        escrow_payment_bump: ctx.bumps.buyer_escrow_payment_account,
+        program_as_signer: &ctx.accounts.program_as_signer,
+        program_as_signer_bump: ctx.bumps.program_as_signer,
+        metadata: &ctx.accounts.metadata,
+        collection_config,
+        seller_status: optional_accounts.require(1)?,
+        buyer_status: optional_accounts.require(2)?,
+        price_band_config: optional_accounts.require(3)?,
+        price_attestation: optional_accounts.get(4),
+        collection_stats: optional_accounts.get(5),
+        last_sale: optional_accounts.require(6)?,
+        trade_sequence: optional_accounts.get(7),
+        frontend_config: optional_accounts.get(8),
+        taker_fee_config: optional_accounts.get(9),
+        price_feed: optional_accounts.get(10),
+        royalty_subsidy: optional_accounts.get(11),
+        rebate_epoch: optional_accounts.get(12),
+        fill_proof: optional_accounts.get(13),
+        insurance_fund: optional_accounts.get(14),
+        buyer_order_counter: optional_accounts.get(15),
+        seller_order_counter: optional_accounts.get(16),
+        buyer_counted_order: optional_accounts.require(17)?,
+        seller_counted_order: optional_accounts.require(18)?,
+        ocp_policy: Some(&ctx.accounts.ocp_policy),
+        t22_mint: None,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+        remaining_accounts,
+    };
+    let terms = settlement.validate(&ctx.accounts.token_mint.key(), 1, args.price)?;
+    // ocp sales are paid in SOL, remaining accounts are all creators
+    if terms.is_spl {
+        return Err(ErrorCode::InvalidTokenMint.into());
     }
 
-    assert_metadata_valid(metadata, &token_mint.key())?;
-
     open_creator_protocol::cpi::unlock(CpiContext::new_with_signer(
         ctx.accounts.ocp_program.to_account_info(),
         open_creator_protocol::cpi::accounts::UnlockCtx {
@@ -246,58 +281,19 @@ pub fn handle<'info>(
         ]],
     ))?;
 
-    let buyer_escrow_signer_seeds: &[&[&[u8]]] = &[&[
-        PREFIX.as_bytes(),
-        auction_house_key.as_ref(),
-        buyer.key.as_ref(),
-        &[ctx.bumps.buyer_escrow_payment_account],
-    ]];
-
     // buyer pays creator royalties
-    let metadata_parsed = &Metadata::safe_deserialize(&metadata.data.borrow()).unwrap();
-    let royalty = pay_creator_fees(
-        &mut ctx.remaining_accounts.iter(),
-        Some(&ctx.accounts.ocp_policy),
-        metadata_parsed,
-        &buyer_escrow_payment_account.to_account_info(),
-        buyer_escrow_signer_seeds,
-        args.price,
-        10_000,
-        None,
-    )?;
-
-    let (actual_maker_fee_bp, actual_taker_fee_bp) =
-        get_actual_maker_taker_fee_bp(notary, args.maker_fee_bp, args.taker_fee_bp);
-    let (maker_fee, taker_fee) = transfer_listing_payment(
-        args.price,
-        actual_maker_fee_bp,
-        actual_taker_fee_bp,
-        payer,
-        seller,
-        buyer_escrow_payment_account,
-        auction_house_treasury,
-        None,
-        buyer_escrow_signer_seeds,
-    )?;
-
-    try_close_buyer_escrow(
-        buyer_escrow_payment_account,
-        buyer,
-        system_program,
-        buyer_escrow_signer_seeds,
-    )?;
-
-    // we don't need to zero out buyer_trade_state, just copy zero discriminator to it and then close
-    close_account_anchor(buyer_trade_state, buyer)?;
-    close_account_anchor(seller_trade_state, seller)?;
+    let payout = settlement.pay(&terms, args.maker_fee_bp, args.taker_fee_bp, 10_000)?;
+    settlement.close(&terms, &payout)?;
     msg!(
-        "{{\"maker_fee\":{},\"taker_fee\":{},\"royalty\":{},\"price\":{},\"seller_expiry\":{},\"buyer_expiry\":{}}}",
-        maker_fee,
-        taker_fee,
-        royalty,
-        args.price,
-        sell_args.expiry,
-        bid_args.expiry,
+        "{{\"maker_fee\":{},\"taker_fee\":{},\"royalty\":{},\"royalty_subsidy\":{},\"price\":{},\"seller_expiry\":{},\"buyer_expiry\":{},\"creator_listing\":{}}}",
+        payout.maker_fee,
+        payout.taker_fee,
+        payout.royalty,
+        payout.royalty_subsidy,
+        terms.price,
+        terms.sell_args.expiry,
+        terms.bid_args.expiry,
+        terms.sell_args.creator_listing,
     );
 
     Ok(())
@@ -1,3 +1,4 @@
+use mpl_token_metadata::accounts::Metadata;
 use open_creator_protocol::state::MintState;
 use solana_program::sysvar;
 
@@ -5,6 +6,10 @@ use {
     crate::constants::*,
     crate::errors::ErrorCode,
     crate::states::*,
+    crate::utils::{
+        assert_collection_not_paused, assert_collection_price_on_tick, assert_hold_period_elapsed,
+        count_open_order, get_collection_key, get_ui_price, split_optional_accounts, OrderSide,
+    },
     anchor_lang::{prelude::*, AnchorDeserialize},
     anchor_spl::token::{Mint, Token, TokenAccount},
 };
@@ -91,6 +96,13 @@ pub struct OCPSell<'info> {
     token_program: Program<'info, Token>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
+    // remaining accounts:
+    // ** OPTIONAL ACCOUNTS, after this program's id as a marker, see OptionalAccounts **
+    // 0. collection_config (required) - CollectionConfig of the collection, derivation checked in assert_collection_not_paused
+    // 1. last_sale (required) - LastSale of the token, derivation checked in assert_hold_period_elapsed
+    // 2. order_limit_config (required) - OrderLimitConfig of the auction house, derivation checked in count_open_order
+    // 3. wallet_order_counter - WalletOrderCounter of the wallet, required if the order_limit_config has a limit, created if needed
+    // 4. counted_order - CountedOrder of the seller_trade_state, required if the order_limit_config has a limit, created if needed
 }
 
 pub fn handle<'info>(
@@ -110,6 +122,24 @@ pub fn handle<'info>(
     let wallet_key = wallet.key();
     let token_mint_key = token_mint.key();
     let token_ata_key = token_ata.key();
+    let (_, optional_accounts) = split_optional_accounts(ctx.remaining_accounts);
+    let collection_config = optional_accounts.require(0)?;
+
+    let metadata_parsed = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+    assert_collection_not_paused(collection_config, &auction_house.key(), &metadata_parsed)?;
+    assert_hold_period_elapsed(
+        collection_config,
+        optional_accounts.require(1)?,
+        &auction_house.key(),
+        &metadata_parsed,
+        wallet.key,
+    )?;
+    assert_collection_price_on_tick(
+        collection_config,
+        &auction_house.key(),
+        &get_collection_key(&metadata_parsed),
+        args.price,
+    )?;
 
     // can't set the existing seller_trade_state to another auction house
     if seller_trade_state.auction_house_key.ne(&Pubkey::default())
@@ -119,6 +149,19 @@ pub fn handle<'info>(
     {
         return Err(ErrorCode::InvalidAccountState.into());
     }
+    // seller_trade_state was just created by init_if_needed
+    if seller_trade_state.seller == Pubkey::default() {
+        count_open_order(
+            optional_accounts.require(2)?,
+            optional_accounts.get(3),
+            optional_accounts.get(4),
+            &seller_trade_state.key(),
+            &wallet,
+            &auction_house.key(),
+            &wallet_key,
+            OrderSide::Listing,
+        )?;
+    }
 
     match ctx.accounts.ocp_mint_state.locked_by {
         None => {
@@ -174,10 +217,13 @@ pub fn handle<'info>(
     seller_trade_state.bump = ctx.bumps.seller_trade_state;
     seller_trade_state.expiry = args.expiry; // negative number means non-movable listing mode
 
+    // ocp listings are paid in SOL
     msg!(
-        "{{\"price\":{},\"seller_expiry\":{}}}",
+        "{{\"price\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
         seller_trade_state.buyer_price,
-        seller_trade_state.expiry
+        seller_trade_state.expiry,
+        SOL_DECIMALS,
+        get_ui_price(seller_trade_state.buyer_price, SOL_DECIMALS),
     );
     Ok(())
 }
@@ -1,6 +1,15 @@
-use anchor_lang::{prelude::*, AnchorDeserialize, Discriminator};
+use anchor_lang::{prelude::*, solana_program::keccak, AnchorDeserialize, Discriminator};
 
-use crate::{errors::ErrorCode, utils::assert_owned_by};
+use crate::{
+    constants::{
+        BID_EXPIRY_BUCKET_SECONDS, FLOOR_WINDOW_SECONDS, MAX_BID_EXPIRY_ALARM_ENTRIES,
+        MAX_BUNDLE_ITEMS, MAX_MINT_LISTING_INDEX_ENTRIES, MAX_ORDER_BOOK_LEVELS,
+        MAX_ORDER_BOOK_LEVEL_ORDERS, MAX_PORTFOLIO_BID_MINTS, MAX_REBATE_TICKETS,
+        MAX_UNSTAKE_DATA_LEN, TWAP_SLOTS,
+    },
+    errors::ErrorCode,
+    utils::{assert_owned_by, is_trade_state_expired},
+};
 
 #[account]
 #[derive(Default, Copy)]
@@ -75,6 +84,17 @@ pub struct SellerTradeStateV2 {
     pub bump: u8,
     pub expiry: i64, // in unix timestamp in seconds
     pub payment_mint: Pubkey,
+    pub floor_premium_bp: Option<i16>, // if set, price is pegged to the collection floor and buyer_price is the minimum
+    pub creator_listing: bool, // listed by the update authority or a verified creator, no maker fee
+    pub dutch_end_price: u64,  // dutch listings decay from buyer_price down to this price
+    pub dutch_start_time: i64,
+    pub dutch_decay_interval: i64, // seconds from dutch_start_time to reach dutch_end_price, 0 for no dutch pricing
+    pub allowed_buyer: Pubkey,     // private sale to this wallet only, default pubkey for anyone
+    pub auto_renew_period: i64, // renew_listing extends an expired listing by this many seconds, 0 to let it expire
+    pub auto_renew_bump_bp: u16, // renew_listing raises buyer_price by this, 0 to keep it
+    pub secondary_payment_mint: Pubkey, // SPL leg paid on top of a SOL price, default pubkey for none
+    pub secondary_price: u64,           // price of a single token in secondary_payment_mint
+    pub not_before: i64, // unix timestamp the listing can be filled from, 0 for any time
 }
 
 impl SellerTradeStateV2 {
@@ -89,7 +109,18 @@ impl SellerTradeStateV2 {
         1 + // bump
         8 + // expiry
         32 + // payment_mint
-        159; // padding
+        3 + // floor_premium_bp
+        1 + // creator_listing
+        8 + // dutch_end_price
+        8 + // dutch_start_time
+        8 + // dutch_decay_interval
+        32 + // allowed_buyer
+        8 + // auto_renew_period
+        2 + // auto_renew_bump_bp
+        32 + // secondary_payment_mint
+        8 + // secondary_price
+        8 + // not_before
+        41; // padding
 
     pub fn from_sell_args(args: &SellArgs) -> Self {
         SellerTradeStateV2 {
@@ -103,6 +134,17 @@ impl SellerTradeStateV2 {
             bump: args.bump,
             expiry: args.expiry,
             payment_mint: args.payment_mint,
+            floor_premium_bp: args.floor_premium_bp,
+            creator_listing: args.creator_listing,
+            dutch_end_price: args.dutch_end_price,
+            dutch_start_time: args.dutch_start_time,
+            dutch_decay_interval: args.dutch_decay_interval,
+            allowed_buyer: args.allowed_buyer,
+            auto_renew_period: 0,
+            auto_renew_bump_bp: 0,
+            secondary_payment_mint: args.secondary_payment_mint,
+            secondary_price: args.secondary_price,
+            not_before: args.not_before,
         }
     }
 }
@@ -152,6 +194,9 @@ pub struct BuyerTradeStateV2 {
     pub expiry: i64,
     pub buyer_creator_royalty_bp: u16,
     pub payment_mint: Pubkey,
+    pub escrowless: bool, // paid out of a payment token account delegated to program_as_signer
+    pub take_profit_price: u64, // relist the bought token at this price, 0 to keep it
+    pub target_seller: Pubkey, // offer to this holder only, default pubkey for any seller
 }
 
 impl BuyerTradeStateV2 {
@@ -166,7 +211,10 @@ impl BuyerTradeStateV2 {
     8 + // expiry
     2 + // buyer_creator_ryoalty_bp
     32 + // payment_mint
-    125; // padding to 320 bytes
+    1 + // escrowless
+    8 + // take_profit_price
+    32 + // target_seller
+    84; // padding to 320 bytes
 
     pub fn from_bid_args(args: &BidArgs) -> Self {
         BuyerTradeStateV2 {
@@ -180,6 +228,9 @@ impl BuyerTradeStateV2 {
             expiry: args.expiry,
             buyer_creator_royalty_bp: args.buyer_creator_royalty_bp,
             payment_mint: args.payment_mint,
+            escrowless: args.escrowless,
+            take_profit_price: args.take_profit_price,
+            target_seller: args.target_seller,
         }
     }
 }
@@ -195,9 +246,20 @@ pub struct BidArgs {
     pub expiry: i64, // in unix timestamp in seconds
     pub buyer_creator_royalty_bp: u16,
     pub payment_mint: Pubkey,
+    pub escrowless: bool,
+    pub take_profit_price: u64,
+    pub target_seller: Pubkey,
 }
 
 impl BidArgs {
+    // targeted offers can only be filled by their target_seller
+    pub fn assert_target_seller(&self, seller: &Pubkey) -> Result<()> {
+        if self.target_seller != Pubkey::default() && self.target_seller != *seller {
+            return Err(ErrorCode::SellerNotTargeted.into());
+        }
+        Ok(())
+    }
+
     pub fn check_args(
         &self,
         buyer_referral: &Pubkey,
@@ -206,8 +268,10 @@ impl BidArgs {
         token_size: u64,
         payment_mint: &Pubkey,
     ) -> Result<()> {
-        if self.buyer_referral != *buyer_referral
-            || self.buyer_price != buyer_price
+        if self.buyer_referral != *buyer_referral {
+            return Err(ErrorCode::InvalidReferral.into());
+        }
+        if self.buyer_price != buyer_price
             || self.token_mint != *token_mint
             || self.token_size != token_size
             || self.payment_mint != *payment_mint
@@ -235,6 +299,9 @@ impl BidArgs {
                 expiry: bts.expiry,
                 buyer_creator_royalty_bp: 0,
                 payment_mint: Pubkey::default(),
+                escrowless: false,
+                take_profit_price: 0,
+                target_seller: Pubkey::default(),
             })
         } else if discrimantor == BuyerTradeStateV2::discriminator() {
             let bts = BuyerTradeStateV2::try_deserialize(&mut account_data)?;
@@ -249,6 +316,9 @@ impl BidArgs {
                 expiry: bts.expiry,
                 buyer_creator_royalty_bp: bts.buyer_creator_royalty_bp,
                 payment_mint: bts.payment_mint,
+                escrowless: bts.escrowless,
+                take_profit_price: bts.take_profit_price,
+                target_seller: bts.target_seller,
             })
         } else {
             Err(ErrorCode::InvalidDiscriminator.into())
@@ -268,9 +338,32 @@ pub struct SellArgs {
     pub bump: u8,
     pub expiry: i64, // in unix timestamp in seconds
     pub payment_mint: Pubkey,
+    pub floor_premium_bp: Option<i16>,
+    pub creator_listing: bool,
+    pub dutch_end_price: u64,
+    pub dutch_start_time: i64,
+    pub dutch_decay_interval: i64,
+    pub allowed_buyer: Pubkey,
+    pub secondary_payment_mint: Pubkey,
+    pub secondary_price: u64,
+    pub not_before: i64,
 }
 
 impl SellArgs {
+    // floor pegged and dutch listings are priced at settlement, their buyer_price is not the
+    // settlement price so the bid price can't be checked against it
+    pub fn has_dynamic_price(&self) -> bool {
+        self.floor_premium_bp.is_some() || self.dutch_decay_interval > 0
+    }
+
+    // private listings can only be bought by their allowed_buyer
+    pub fn assert_allowed_buyer(&self, buyer: &Pubkey) -> Result<()> {
+        if self.allowed_buyer != Pubkey::default() && self.allowed_buyer != *buyer {
+            return Err(ErrorCode::BuyerNotAllowed.into());
+        }
+        Ok(())
+    }
+
     pub fn check_args(
         &self,
         seller_referral: &Pubkey,
@@ -279,8 +372,10 @@ impl SellArgs {
         token_size: &u64,
         payment_mint: &Pubkey,
     ) -> Result<()> {
-        if self.seller_referral != *seller_referral
-            || self.buyer_price != *buyer_price
+        if self.seller_referral != *seller_referral {
+            return Err(ErrorCode::InvalidReferral.into());
+        }
+        if self.buyer_price != *buyer_price
             || self.token_mint != *token_mint
             || self.token_size != *token_size
             || self.payment_mint != *payment_mint
@@ -308,6 +403,15 @@ impl SellArgs {
                 token_account: sts.token_account,
                 expiry: sts.expiry,
                 payment_mint: Pubkey::default(),
+                floor_premium_bp: None,
+                creator_listing: false,
+                dutch_end_price: 0,
+                dutch_start_time: 0,
+                dutch_decay_interval: 0,
+                allowed_buyer: Pubkey::default(),
+                secondary_payment_mint: Pubkey::default(),
+                secondary_price: 0,
+                not_before: 0,
             })
         } else if discriminator == SellerTradeStateV2::discriminator() {
             let sts = SellerTradeStateV2::try_deserialize(&mut account_data)?;
@@ -322,9 +426,1465 @@ impl SellArgs {
                 token_account: sts.token_account,
                 expiry: sts.expiry,
                 payment_mint: sts.payment_mint,
+                floor_premium_bp: sts.floor_premium_bp,
+                creator_listing: sts.creator_listing,
+                dutch_end_price: sts.dutch_end_price,
+                dutch_start_time: sts.dutch_start_time,
+                dutch_decay_interval: sts.dutch_decay_interval,
+                allowed_buyer: sts.allowed_buyer,
+                secondary_payment_mint: sts.secondary_payment_mint,
+                secondary_price: sts.secondary_price,
+                not_before: sts.not_before,
             })
         } else {
             Err(ErrorCode::InvalidDiscriminator.into())
         }
     }
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct MintListingEntry {
+    pub seller_trade_state: Pubkey,
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub buyer_price: u64,
+    pub expiry: i64,
+    pub payment_mint: Pubkey,
+}
+
+impl MintListingEntry {
+    pub const LEN: usize = 32 + // seller_trade_state
+        32 + // auction_house_key
+        32 + // seller
+        8 + // buyer_price
+        8 + // expiry
+        32; // payment_mint
+}
+
+// MintListingIndex lists the live listings of a mint across trade state versions. It's best effort:
+// sell, cancel_sell and execute_sale_v2 keep an existing index up to date when it's passed as an
+// optional account, every other listing instruction and expiries are only picked up by
+// sync_mint_listing_index, so readers should check the listed seller_trade_state before relying on it
+#[account]
+#[derive(Default)]
+pub struct MintListingIndex {
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    pub updated_at: i64,
+    pub listings: Vec<MintListingEntry>,
+}
+
+impl MintListingIndex {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // token_mint
+        1 + // bump
+        8 + // updated_at
+        4 + MintListingEntry::LEN * MAX_MINT_LISTING_INDEX_ENTRIES; // listings
+
+    // a full index makes room by evicting an expired entry, or else the one added first, so that
+    // listing a mint never fails on its index
+    pub fn upsert(&mut self, entry: MintListingEntry, now: i64) {
+        if let Some(existing) = self
+            .listings
+            .iter_mut()
+            .find(|l| l.seller_trade_state == entry.seller_trade_state)
+        {
+            *existing = entry;
+            return;
+        }
+        if self.listings.len() >= MAX_MINT_LISTING_INDEX_ENTRIES {
+            let evicted = self
+                .listings
+                .iter()
+                .position(|l| is_trade_state_expired(l.expiry, now))
+                .unwrap_or(0);
+            self.listings.remove(evicted);
+        }
+        self.listings.push(entry);
+    }
+
+    pub fn remove(&mut self, seller_trade_state: &Pubkey) {
+        self.listings
+            .retain(|l| l.seller_trade_state != *seller_trade_state);
+    }
+}
+
+// CollectionConfig holds the per collection settings of an auction house, set by
+// set_collection_pause, set_price_tick and set_hold_period. It was named CollectionPause before it
+// grew the tick and hold period, migrate_collection_pause moves those accounts over
+#[account]
+#[derive(Default)]
+pub struct CollectionConfig {
+    pub auction_house_key: Pubkey,
+    pub collection: Pubkey, // verified collection mint, or the token mint itself if there's no verified collection
+    pub paused: bool,
+    pub bump: u8,
+    pub updated_at: i64,
+    pub tick_size: u64, // SOL prices of the collection have to be a multiple of it, 0 for any, SPL prices aren't ticked
+    pub hold_period: i64, // seconds a buyer has to hold a token of the collection before listing it, 0 for none
+}
+
+impl CollectionConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // collection
+        1 + // paused
+        1 + // bump
+        8 + // updated_at
+        8 + // tick_size
+        8 + // hold_period
+        48; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct CollectionStats {
+    pub auction_house_key: Pubkey,
+    pub collection: Pubkey, // verified collection mint, or the token mint itself if there's no verified collection
+    pub payment_mint: Pubkey, // Pubkey::default() for SOL
+    pub bump: u8,
+    pub sale_count: u64,
+    pub volume: u128,
+    pub last_sale_price: u64,
+    pub last_sale_at: i64,
+    pub floor_window_start: i64,
+    pub floor_price: u64, // lowest sale price within the current floor window
+    pub twap_prices: [u64; TWAP_SLOTS], // ring buffer of the most recent sale prices
+    pub twap_sale_times: [i64; TWAP_SLOTS], // sale times of twap_prices, 0 if the slot is unused
+    pub twap_head: u8,    // slot the next sale is written to
+    pub twap_price: u64,  // time weighted average of twap_prices as of the latest sale
+}
+
+impl CollectionStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // collection
+        32 + // payment_mint
+        1 + // bump
+        8 + // sale_count
+        16 + // volume
+        8 + // last_sale_price
+        8 + // last_sale_at
+        8 + // floor_window_start
+        8 + // floor_price
+        8 * TWAP_SLOTS + // twap_prices
+        8 * TWAP_SLOTS + // twap_sale_times
+        1 + // twap_head
+        8 + // twap_price
+        23; // padding
+
+    pub fn record_sale(&mut self, price: u64, now: i64) -> Result<()> {
+        self.sale_count = self
+            .sale_count
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        self.volume = self
+            .volume
+            .checked_add(price as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        self.last_sale_price = price;
+        self.last_sale_at = now;
+        if self.floor_price == 0
+            || now.saturating_sub(self.floor_window_start) > FLOOR_WINDOW_SECONDS
+        {
+            // start a new floor window with this sale
+            self.floor_price = price;
+            self.floor_window_start = now;
+        } else if price < self.floor_price {
+            self.floor_price = price;
+        }
+
+        let head = self.twap_head as usize % TWAP_SLOTS;
+        self.twap_prices[head] = price;
+        self.twap_sale_times[head] = now;
+        self.twap_head = ((head + 1) % TWAP_SLOTS) as u8;
+        self.twap_price = self.get_twap(now)?;
+        Ok(())
+    }
+
+    /// Time weighted average price of the recent sales as of now: every sale's price is weighted
+    /// by how long it stood before the next sale, the latest one up to now. A burst of sales
+    /// within a few seconds barely moves it. Falls back to the latest sale price while no time
+    /// has passed since the oldest recorded sale, and is 0 before the first sale.
+    pub fn get_twap(&self, now: i64) -> Result<u64> {
+        let mut weighted_sum: u128 = 0;
+        let mut total_weight: u128 = 0;
+        let mut prev: Option<(u64, i64)> = None;
+        for i in 0..TWAP_SLOTS {
+            let slot = (self.twap_head as usize + i) % TWAP_SLOTS;
+            let sale_time = self.twap_sale_times[slot];
+            if sale_time == 0 {
+                continue;
+            }
+            if let Some((prev_price, prev_time)) = prev {
+                let weight = sale_time.saturating_sub(prev_time).max(0) as u128;
+                weighted_sum = weighted_sum
+                    .checked_add(
+                        (prev_price as u128)
+                            .checked_mul(weight)
+                            .ok_or(ErrorCode::NumericalOverflow)?,
+                    )
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                total_weight += weight;
+            }
+            prev = Some((self.twap_prices[slot], sale_time));
+        }
+        let (last_price, last_time) = match prev {
+            Some(last) => last,
+            None => return Ok(0),
+        };
+        let weight = now.saturating_sub(last_time).max(0) as u128;
+        weighted_sum = weighted_sum
+            .checked_add(
+                (last_price as u128)
+                    .checked_mul(weight)
+                    .ok_or(ErrorCode::NumericalOverflow)?,
+            )
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        total_weight += weight;
+        if total_weight == 0 {
+            return Ok(last_price);
+        }
+        // an average of u64 prices always fits in a u64
+        Ok((weighted_sum / total_weight) as u64)
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct LastSale {
+    pub token_mint: Pubkey,
+    pub auction_house_key: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub sold_at: i64,
+    pub bump: u8,
+    pub trade_seq: u64, // TradeSequence seq of the sale, 0 if it wasn't recorded on the TradeSequence
+}
+
+impl LastSale {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // token_mint
+        32 + // auction_house_key
+        32 + // buyer
+        32 + // seller
+        32 + // payment_mint
+        8 + // price
+        8 + // sold_at
+        1 + // bump
+        8 + // trade_seq
+        56; // padding
+}
+
+// Marketplace-wide sequence of settled sales, across all auction houses. Every recorded sale gets
+// the next seq, so a gap in the seqs seen by an indexer is a missed fill.
+#[account]
+#[derive(Default)]
+pub struct TradeSequence {
+    pub seq: u64, // seq of the latest sale, 0 before the first one
+    pub bump: u8,
+}
+
+impl TradeSequence {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // seq
+        1 + // bump
+        64; // padding
+}
+
+// Proof of a fill for other programs to read instead of trusting an indexer: a FillProof derived
+// from [PREFIX, FILL_PROOF, buyer, token_mint] and owned by this program means buyer bought
+// token_mint in the settlement it describes. Created at settlement if the taker opts in, it can be
+// closed by anyone FILL_PROOF_LIFETIME_SLOTS after the fill.
+#[account]
+#[derive(Default)]
+pub struct FillProof {
+    pub auction_house_key: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64, // price of a single token
+    pub token_size: u64,
+    pub trade_seq: u64, // TradeSequence seq of the fill
+    pub slot: u64,      // slot of the fill
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl FillProof {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // buyer
+        32 + // seller
+        32 + // token_mint
+        32 + // payment_mint
+        8 + // price
+        8 + // token_size
+        8 + // trade_seq
+        8 + // slot
+        32 + // rent_payer
+        1 + // bump
+        32; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct SellerStatus {
+    pub seller: Pubkey,
+    pub suspended: bool, // if set, none of the seller's listings can be filled
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl SellerStatus {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // seller
+        1 + // suspended
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct BuyerStatus {
+    pub buyer: Pubkey,
+    pub suspended: bool, // if set, none of the buyer's bids can be filled
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl BuyerStatus {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // buyer
+        1 + // suspended
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct FrontendConfig {
+    pub auction_house_key: Pubkey,
+    pub frontend: Pubkey, // frontend identifier, also the only wallet that can claim the fee share
+    pub fee_share_bp: u16, // share of the platform fee of attributed SOL sales
+    pub enabled: bool,
+    pub bump: u8,
+    pub sale_count: u64,
+    pub volume: u128,
+    pub fees_earned: u64,
+}
+
+impl FrontendConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // frontend
+        2 + // fee_share_bp
+        1 + // enabled
+        1 + // bump
+        8 + // sale_count
+        16 + // volume
+        8 + // fees_earned
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct ListingOperator {
+    pub seller: Pubkey,
+    pub operator: Pubkey, // can cancel and reprice the seller's listings, but never move the token or funds
+    pub enabled: bool,
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl ListingOperator {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // seller
+        32 + // operator
+        1 + // enabled
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct CollectionBidState {
+    pub auction_house_key: Pubkey,
+    pub buyer: Pubkey,
+    pub buyer_referral: Pubkey,
+    pub collection: Pubkey, // verified metaplex collection or token group any filled token has to belong to
+    pub buyer_price: u64,   // price per token
+    pub quantity: u16,      // tokens still wanted, the bid is closed once it's filled
+    pub filled: u16,
+    pub payment_mint: Pubkey,
+    pub buyer_creator_royalty_bp: u16,
+    pub royalty_inclusive: bool, // buyer_price already includes the royalty paid by the buyer
+    pub expiry: i64,
+    pub bump: u8,
+    pub trait_hash: [u8; 32], // hash of required attributes, filled tokens are attested by the notary, zero for none
+    pub allowed_auction_houses: [Pubkey; 2], // other auction houses of the same authority that can fill the bid, default for none
+}
+
+impl CollectionBidState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // buyer
+        32 + // buyer_referral
+        32 + // collection
+        8 + // buyer_price
+        2 + // quantity
+        2 + // filled
+        32 + // payment_mint
+        2 + // buyer_creator_royalty_bp
+        1 + // royalty_inclusive
+        8 + // expiry
+        1 + // bump
+        32 + // trait_hash
+        64; // allowed_auction_houses
+}
+
+impl CollectionBidState {
+    // the funds stay in the escrow of auction_house_key, other auction houses have to be allowed
+    pub fn assert_fillable_by(&self, auction_house: &Pubkey) -> Result<()> {
+        if self.auction_house_key != *auction_house
+            && !self.allowed_auction_houses.contains(auction_house)
+        {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct RoyaltyFallback {
+    pub collection: Pubkey,
+    pub recipient: Pubkey, // receives the royalty share of creators that can't receive lamports
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl RoyaltyFallback {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // collection
+        32 + // recipient
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct AuctionState {
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub start_price: u64,      // minimum first bid
+    pub min_increment_bp: u16, // every bid has to beat the highest bid by this much
+    pub start_time: i64,
+    pub end_time: i64,
+    pub highest_bid: u64, // royalty and fee inclusive amount held by the auction escrow
+    pub highest_bidder: Pubkey,
+    pub bid_count: u32,
+    pub bump: u8,
+    pub escrow_bump: u8,
+    pub extension_window: i64, // a bid placed this close to end_time extends the auction
+    pub extension_seconds: i64, // by this much
+    pub reserve_price: u64,    // the auction only sells if the highest bid reaches it
+    pub reveal_end_time: i64,  // sealed auctions take commits until end_time and reveals until then
+    pub commit_count: u32,
+    pub buy_now_price: u64, // a bid of this amount wins the auction right away, 0 if disabled
+    pub tick_size: u64,     // tick of the collection when the auction was created, 0 for none
+    pub highest_max_bid: u64, // proxy maximum of the highest bidder, the escrow holds it in full
+}
+
+impl AuctionState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // seller
+        32 + // token_mint
+        32 + // payment_mint
+        8 + // start_price
+        2 + // min_increment_bp
+        8 + // start_time
+        8 + // end_time
+        8 + // highest_bid
+        32 + // highest_bidder
+        4 + // bid_count
+        1 + // bump
+        1 + // escrow_bump
+        8 + // extension_window
+        8 + // extension_seconds
+        8 + // reserve_price
+        8 + // reveal_end_time
+        4 + // commit_count
+        8 + // buy_now_price
+        8 + // tick_size
+        8 + // highest_max_bid
+        68; // padding
+
+    pub fn is_spl(&self) -> bool {
+        self.payment_mint != Pubkey::default()
+    }
+
+    // amount held by the auction escrow for the highest bidder, auctions from before proxy bids
+    // have a highest_max_bid of 0
+    pub fn get_escrowed_bid(&self) -> u64 {
+        self.highest_bid.max(self.highest_max_bid)
+    }
+
+    // end_time after a bid placed at now, pushed back if the bid lands in the extension window
+    pub fn get_extended_end_time(&self, now: i64) -> Result<i64> {
+        if self.extension_seconds == 0 || self.end_time.saturating_sub(now) > self.extension_window
+        {
+            return Ok(self.end_time);
+        }
+        Ok(self
+            .end_time
+            .checked_add(self.extension_seconds)
+            .ok_or(ErrorCode::NumericalOverflow)?)
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.reveal_end_time > 0
+    }
+
+    // sealed auctions can only be settled once the reveal window closed
+    pub fn get_settle_time(&self) -> i64 {
+        if self.is_sealed() {
+            self.reveal_end_time
+        } else {
+            self.end_time
+        }
+    }
+
+    pub fn is_reserve_met(&self) -> bool {
+        self.bid_count > 0 && self.highest_bid >= self.reserve_price
+    }
+
+    // smallest amount the next bid has to offer, rounded up to the tick
+    pub fn get_min_bid(&self) -> Result<u64> {
+        if self.bid_count == 0 {
+            return Ok(self.start_price);
+        }
+        self.get_min_raise(self.highest_bid)
+    }
+
+    // smallest amount beating a bid of amount by min_increment_bp, rounded up to the tick
+    pub fn get_min_raise(&self, amount: u64) -> Result<u64> {
+        let increment = (amount as u128)
+            .checked_mul(self.min_increment_bp as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::NumericalOverflow)? as u64;
+        self.round_up_to_tick(
+            amount
+                .checked_add(increment.max(1))
+                .ok_or(ErrorCode::NumericalOverflow)?,
+        )
+    }
+
+    fn round_up_to_tick(&self, amount: u64) -> Result<u64> {
+        if self.tick_size == 0 {
+            return Ok(amount);
+        }
+        Ok(amount
+            .checked_add(self.tick_size - 1)
+            .ok_or(ErrorCode::NumericalOverflow)?
+            / self.tick_size
+            * self.tick_size)
+    }
+
+    // standing bid of a proxy bidder that has to offer at least min_amount: the proxy bids no more
+    // than needed, but jumps to the reserve price if its max_bid covers it. max_bid sits on the tick
+    pub fn get_proxy_bid(&self, max_bid: u64, min_amount: u64) -> Result<u64> {
+        let amount = min_amount.min(max_bid);
+        if amount < self.reserve_price && max_bid >= self.reserve_price {
+            return Ok(self.round_up_to_tick(self.reserve_price)?.min(max_bid));
+        }
+        Ok(amount)
+    }
+
+    // a bid reaching the buy now price is filled at exactly the buy now price
+    pub fn is_buy_now(&self, amount: u64) -> bool {
+        self.buy_now_price > 0 && amount >= self.buy_now_price
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct TakerFeeConfig {
+    pub auction_house_key: Pubkey,
+    pub price_feed: Pubkey,      // pyth SOL/USD price account
+    pub flat_fee_micro_usd: u64, // taker fee of SOL sales in USD with 6 decimals
+    pub max_fee_bp: u16,         // cap of the converted taker fee
+    pub max_staleness: i64,      // seconds
+    pub enabled: bool,
+    pub bump: u8,
+    pub prev_flat_fee_micro_usd: u64, // fee the config ramps from after an update
+    pub prev_max_fee_bp: u16,
+    pub updated_at: i64,
+    pub ramp_seconds: i64, // 0 applies updates at once
+}
+
+impl TakerFeeConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // price_feed
+        8 + // flat_fee_micro_usd
+        2 + // max_fee_bp
+        8 + // max_staleness
+        1 + // enabled
+        1 + // bump
+        8 + // prev_flat_fee_micro_usd
+        2 + // prev_max_fee_bp
+        8 + // updated_at
+        8 + // ramp_seconds
+        38; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct BuybackConfig {
+    pub auction_house_key: Pubkey,
+    pub collection: Pubkey, // verified collection mint the treasury buys back
+    pub max_price: u64,     // royalty exclusive price cap of a single buyback
+    pub budget: u64,        // total lamports the treasury may spend, royalties included
+    pub spent: u64,
+    pub burned: u32,
+    pub bump: u8,
+}
+
+impl BuybackConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // collection
+        8 + // max_price
+        8 + // budget
+        8 + // spent
+        4 + // burned
+        1 + // bump
+        64; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct BidExpiryAlarmEntry {
+    pub buyer_trade_state: Pubkey,
+    pub buyer: Pubkey,
+    pub token_mint: Pubkey,
+    pub expiry: i64,
+}
+
+impl BidExpiryAlarmEntry {
+    pub const LEN: usize = 32 + // buyer_trade_state
+        32 + // buyer
+        32 + // token_mint
+        8; // expiry
+}
+
+#[account]
+#[derive(Default)]
+pub struct BidExpiryAlarm {
+    pub auction_house_key: Pubkey,
+    pub bucket: i64, // start of the BID_EXPIRY_BUCKET_SECONDS window the entries expire in
+    pub page: u8,
+    pub bump: u8,
+    pub updated_at: i64,
+    pub entries: Vec<BidExpiryAlarmEntry>,
+}
+
+impl BidExpiryAlarm {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        8 + // bucket
+        1 + // page
+        1 + // bump
+        8 + // updated_at
+        4 + BidExpiryAlarmEntry::LEN * MAX_BID_EXPIRY_ALARM_ENTRIES; // entries
+
+    pub fn get_bucket(expiry: i64) -> i64 {
+        expiry - expiry.rem_euclid(BID_EXPIRY_BUCKET_SECONDS)
+    }
+
+    pub fn upsert(&mut self, entry: BidExpiryAlarmEntry) -> Result<()> {
+        if let Some(existing) = self
+            .entries
+            .iter_mut()
+            .find(|e| e.buyer_trade_state == entry.buyer_trade_state)
+        {
+            *existing = entry;
+            return Ok(());
+        }
+        if self.entries.len() >= MAX_BID_EXPIRY_ALARM_ENTRIES {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, buyer_trade_state: &Pubkey) {
+        self.entries
+            .retain(|e| e.buyer_trade_state != *buyer_trade_state);
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct SealedBid {
+    pub auction_state: Pubkey,
+    pub bidder: Pubkey,
+    pub commitment: [u8; 32], // keccak of auction_state, bidder, price and salt
+    pub deposit: u64,         // held by this account until the reveal, covers the hidden price
+    pub reveal_end_time: i64, // of the auction the commit was made in
+    pub bump: u8,
+}
+
+impl SealedBid {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_state
+        32 + // bidder
+        32 + // commitment
+        8 + // deposit
+        8 + // reveal_end_time
+        1 + // bump
+        64; // padding
+
+    pub fn get_commitment(
+        auction_state: &Pubkey,
+        bidder: &Pubkey,
+        price: u64,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        keccak::hashv(&[
+            auction_state.as_ref(),
+            bidder.as_ref(),
+            &price.to_le_bytes(),
+            salt,
+        ])
+        .to_bytes()
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct PriceBandConfig {
+    pub auction_house_key: Pubkey,
+    pub collection: Pubkey, // verified collection mint, or the token mint itself if there's no verified collection
+    pub enabled: bool,      // sales of the collection need a fresh PriceAttestation
+    pub max_age: i64,       // seconds an attestation stays valid
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl PriceBandConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // collection
+        1 + // enabled
+        8 + // max_age
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct PriceAttestation {
+    pub auction_house_key: Pubkey,
+    pub token_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub min_price: u64, // fair price band attested by the notary
+    pub max_price: u64,
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl PriceAttestation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // token_mint
+        32 + // payment_mint
+        8 + // min_price
+        8 + // max_price
+        8 + // attested_at
+        1 + // bump
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct TreasuryYieldConfig {
+    pub auction_house_key: Pubkey,
+    pub vault_program: Pubkey, // one of YIELD_VAULT_PROGRAM_WHITELIST
+    pub vault_state: Pubkey,   // Marinade state or stake pool the treasury is swept into
+    pub share_mint: Pubkey,    // mSOL or pool token mint of the vault_state
+    pub float: u64,            // lamports the treasury keeps when sweeping
+    pub shares: u64, // share_mint balance of the treasury's share account as of the last sweep
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl TreasuryYieldConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // vault_program
+        32 + // vault_state
+        32 + // share_mint
+        8 + // float
+        8 + // shares
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct BundleItem {
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey, // ata of the seller, owned by program_as_signer while listed
+}
+
+impl BundleItem {
+    pub const LEN: usize = 32 + // token_mint
+        32; // token_account
+}
+
+#[account]
+#[derive(Default)]
+pub struct BundleSellerState {
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub seller_referral: Pubkey,
+    pub bundle_id: u64,
+    pub buyer_price: u64, // lamports for all the items
+    pub expiry: i64,
+    pub sealed: bool, // no more items can be appended, and the bundle can be sold
+    pub bump: u8,
+    pub items: Vec<BundleItem>,
+}
+
+impl BundleSellerState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // seller
+        32 + // seller_referral
+        8 + // bundle_id
+        8 + // buyer_price
+        8 + // expiry
+        1 + // sealed
+        1 + // bump
+        4 + BundleItem::LEN * MAX_BUNDLE_ITEMS; // items
+}
+
+#[account]
+#[derive(Default)]
+pub struct SwapOffer {
+    pub auction_house_key: Pubkey,
+    pub proposer: Pubkey,
+    pub offered_mint: Pubkey,
+    pub offered_token_account: Pubkey, // ata of the proposer, owned by program_as_signer while proposed
+    pub counterparty: Pubkey,
+    pub requested_mint: Pubkey,
+    pub sweetener: u64, // lamports paid to the counterparty out of the proposer's escrow
+    pub expiry: i64,    // 0 never expires
+    pub bump: u8,
+}
+
+impl SwapOffer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // proposer
+        32 + // offered_mint
+        32 + // offered_token_account
+        32 + // counterparty
+        32 + // requested_mint
+        8 + // sweetener
+        8 + // expiry
+        1 + // bump
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct RentalListing {
+    pub auction_house_key: Pubkey,
+    pub owner: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey, // stays with the owner, program_as_signer is its utility delegate
+    pub fee: u64,              // lamports the renter pays the owner for one term
+    pub term: i64,             // seconds
+    pub renter: Pubkey,        // default while the listing isn't rented
+    pub rented_until: i64,     // the token is locked until then
+    pub bump: u8,
+}
+
+impl RentalListing {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // owner
+        32 + // token_mint
+        32 + // token_account
+        8 + // fee
+        8 + // term
+        32 + // renter
+        8 + // rented_until
+        1 + // bump
+        64; // padding
+
+    pub fn is_rented(&self) -> bool {
+        self.renter != Pubkey::default()
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct EscrowDepositor {
+    pub auction_house_key: Pubkey,
+    pub buyer: Pubkey,
+    pub depositor: Pubkey, // can top up the buyer's escrow, but never withdraw from it
+    pub enabled: bool,
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl EscrowDepositor {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // buyer
+        32 + // depositor
+        1 + // enabled
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct RoyaltySubsidy {
+    pub auction_house_key: Pubkey,
+    pub collection: Pubkey, // verified collection mint whose royalties are subsidized
+    pub subsidy_bp: u16,    // share of the buyer's royalty the treasury pays instead
+    pub start_time: i64,
+    pub end_time: i64,
+    pub budget: u64, // total lamports the treasury may pay to creators
+    pub spent: u64,
+    pub bump: u8,
+}
+
+impl RoyaltySubsidy {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // collection
+        2 + // subsidy_bp
+        8 + // start_time
+        8 + // end_time
+        8 + // budget
+        8 + // spent
+        1 + // bump
+        64; // padding
+
+    /// Whether the subsidy runs at now and has budget left
+    pub fn is_active(&self, now: i64) -> bool {
+        self.start_time <= now && now <= self.end_time && self.spent < self.budget
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct PortfolioBidState {
+    pub auction_house_key: Pubkey,
+    pub buyer: Pubkey,
+    pub buyer_referral: Pubkey,
+    pub bid_id: u64,
+    pub buyer_price: u64,
+    pub payment_mint: Pubkey,
+    pub buyer_creator_royalty_bp: u16,
+    pub expiry: i64,
+    pub bump: u8,
+    pub mints: Vec<Pubkey>, // any one of them fills the bid, which is closed then
+}
+
+impl PortfolioBidState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // buyer
+        32 + // buyer_referral
+        8 + // bid_id
+        8 + // buyer_price
+        32 + // payment_mint
+        2 + // buyer_creator_royalty_bp
+        8 + // expiry
+        1 + // bump
+        4 + 32 * MAX_PORTFOLIO_BID_MINTS; // mints
+}
+
+#[account]
+#[derive(Default)]
+pub struct CompressedSellerTradeState {
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub seller_referral: Pubkey,
+    pub asset_id: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub index: u32,
+    pub data_hash: [u8; 32], // leaf hashes don't change with the delegate, so settlement only
+    pub creator_hash: [u8; 32], // needs a fresh root and proof
+    pub buyer_price: u64,
+    pub payment_mint: Pubkey,
+    pub expiry: i64,
+    pub bump: u8,
+}
+
+impl CompressedSellerTradeState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // seller
+        32 + // seller_referral
+        32 + // asset_id
+        32 + // merkle_tree
+        8 + // nonce
+        4 + // index
+        32 + // data_hash
+        32 + // creator_hash
+        8 + // buyer_price
+        32 + // payment_mint
+        8 + // expiry
+        1; // bump
+}
+
+#[account]
+#[derive(Default)]
+pub struct CoreSellerTradeState {
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub seller_referral: Pubkey,
+    pub asset: Pubkey,
+    pub buyer_price: u64,
+    pub payment_mint: Pubkey,
+    pub expiry: i64,
+    pub bump: u8,
+}
+
+impl CoreSellerTradeState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // seller
+        32 + // seller_referral
+        32 + // asset
+        8 + // buyer_price
+        32 + // payment_mint
+        8 + // expiry
+        1; // bump
+}
+
+#[account]
+#[derive(Default)]
+pub struct CustodialPlatform {
+    pub auction_house_key: Pubkey,
+    pub platform: Pubkey,       // buyer wallet of the checkout provider
+    pub custody_wallet: Pubkey, // owner of the omnibus token accounts bought tokens are delivered to
+    pub enabled: bool,
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl CustodialPlatform {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // platform
+        32 + // custody_wallet
+        1 + // enabled
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+// RebateCampaign runs a commit-reveal fee rebate draw every epoch, with the randomness_authority
+// trusted not to steer it or withhold reveals, see set_rebate_campaign
+#[account]
+#[derive(Default)]
+pub struct RebateCampaign {
+    pub auction_house_key: Pubkey,
+    pub randomness_authority: Pubkey, // commits to and reveals the seed of every epoch's draw
+    pub prize: u64,                   // lamports the treasury pays the winner of an epoch
+    pub enabled: bool,
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl RebateCampaign {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // randomness_authority
+        8 + // prize
+        1 + // enabled
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct RebateEpoch {
+    pub auction_house_key: Pubkey,
+    pub epoch: u64,
+    pub commitment: [u8; 32], // keccak of the seed, committed before the epoch starts
+    pub entries_hash: [u8; 32], // running hash of the entered settlements, unknown at commit time
+    pub ticket_count: u32,    // settlements of the epoch, the ones past MAX_REBATE_TICKETS included
+    pub winner: Pubkey,       // default until drawn, and if nobody was entered
+    pub prize: u64,
+    pub drawn: bool, // a drawn epoch with a winner is closed when claimed, one without when drawn
+    pub bump: u8,
+    pub tickets: Vec<Pubkey>, // takers of the first MAX_REBATE_TICKETS settlements
+}
+
+impl RebateEpoch {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        8 + // epoch
+        32 + // commitment
+        32 + // entries_hash
+        4 + // ticket_count
+        32 + // winner
+        8 + // prize
+        1 + // drawn
+        1 + // bump
+        4 + 32 * MAX_REBATE_TICKETS; // tickets
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct BookOrder {
+    pub owner: Pubkey,
+    pub size: u64, // left to fill, in base units of the token
+    pub seq: u64,  // position in the order book's time priority
+}
+
+impl BookOrder {
+    pub const LEN: usize = 32 + // owner
+        8 + // size
+        8; // seq
+}
+
+#[account]
+#[derive(Default)]
+pub struct OrderBook {
+    pub auction_house_key: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    pub next_seq: u64,
+    pub bid_prices: Vec<u64>, // prices with resting bids, best (highest) first
+    pub ask_prices: Vec<u64>, // prices with resting asks, best (lowest) first
+}
+
+impl OrderBook {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // token_mint
+        1 + // bump
+        8 + // next_seq
+        4 + 8 * MAX_ORDER_BOOK_LEVELS + // bid_prices
+        4 + 8 * MAX_ORDER_BOOK_LEVELS; // ask_prices
+
+    pub fn get_best_price(&self, is_bid: bool) -> Option<u64> {
+        if is_bid {
+            self.bid_prices.first().copied()
+        } else {
+            self.ask_prices.first().copied()
+        }
+    }
+
+    pub fn take_seq(&mut self) -> Result<u64> {
+        let seq = self.next_seq;
+        self.next_seq = seq.checked_add(1).ok_or(ErrorCode::NumericalOverflow)?;
+        Ok(seq)
+    }
+
+    pub fn add_price(&mut self, is_bid: bool, price: u64) -> Result<()> {
+        let prices = if is_bid {
+            &mut self.bid_prices
+        } else {
+            &mut self.ask_prices
+        };
+        let index = prices
+            .iter()
+            .position(|p| if is_bid { *p <= price } else { *p >= price })
+            .unwrap_or(prices.len());
+        if prices.get(index) == Some(&price) {
+            return Ok(());
+        }
+        if prices.len() >= MAX_ORDER_BOOK_LEVELS {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        prices.insert(index, price);
+        Ok(())
+    }
+
+    pub fn remove_price(&mut self, is_bid: bool, price: u64) {
+        if is_bid {
+            self.bid_prices.retain(|p| *p != price);
+        } else {
+            self.ask_prices.retain(|p| *p != price);
+        }
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct OrderBookLevel {
+    pub order_book: Pubkey,
+    pub is_bid: bool,
+    pub price: u64, // lamports per base unit of the token
+    pub bump: u8,
+    pub orders: Vec<BookOrder>, // resting orders at the price, oldest first
+}
+
+impl OrderBookLevel {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // order_book
+        1 + // is_bid
+        8 + // price
+        1 + // bump
+        4 + BookOrder::LEN * MAX_ORDER_BOOK_LEVEL_ORDERS; // orders
+
+    pub fn push(&mut self, order: BookOrder) -> Result<()> {
+        if self.orders.len() >= MAX_ORDER_BOOK_LEVEL_ORDERS {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+        self.orders.push(order);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, owner: &Pubkey, seq: u64) -> Result<BookOrder> {
+        let index = self
+            .orders
+            .iter()
+            .position(|o| o.owner == *owner && o.seq == seq)
+            .ok_or(ErrorCode::InvalidAccountState)?;
+        Ok(self.orders.remove(index))
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct EditionSale {
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub master_mint: Pubkey,
+    pub price: u64,      // lamports per printed edition
+    pub max_prints: u64, // 0 to print until the master edition's max supply
+    pub printed: u64,
+    pub bump: u8,
+}
+
+impl EditionSale {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // seller
+        32 + // master_mint
+        8 + // price
+        8 + // max_prints
+        8 + // printed
+        1 + // bump
+        32; // padding
+
+    pub fn is_sold_out(&self) -> bool {
+        self.max_prints > 0 && self.printed >= self.max_prints
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct InsuranceFund {
+    pub auction_house_key: Pubkey,
+    pub taker_fee_share_bp: u16, // share of the taker fee of every SOL settlement paid into the fund
+    pub enabled: bool,
+    pub bump: u8,
+    pub total_funded: u64,
+    pub total_paid_out: u64,
+    pub payout_recipient: Pubkey, // queued payout, default pubkey for none
+    pub payout_amount: u64,
+    pub payout_available_at: i64,
+    pub updated_at: i64,
+}
+
+impl InsuranceFund {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        2 + // taker_fee_share_bp
+        1 + // enabled
+        1 + // bump
+        8 + // total_funded
+        8 + // total_paid_out
+        32 + // payout_recipient
+        8 + // payout_amount
+        8 + // payout_available_at
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct StakedListing {
+    pub auction_house_key: Pubkey,
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey, // token account of the seller the staking program keeps locked
+    pub staking_program: Pubkey,
+    pub price: u64, // lamports
+    pub expiry: i64,
+    pub bump: u8,
+    pub unstake_data: Vec<u8>, // staking program instruction releasing the token to the buyer
+}
+
+impl StakedListing {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // seller
+        32 + // token_mint
+        32 + // token_account
+        32 + // staking_program
+        8 + // price
+        8 + // expiry
+        1 + // bump
+        4 + MAX_UNSTAKE_DATA_LEN; // unstake_data
+}
+
+#[account]
+#[derive(Default)]
+pub struct PermanentDelegateApproval {
+    pub auction_house_key: Pubkey,
+    pub mint: Pubkey,
+    pub permanent_delegate: Pubkey, // delegate of the mint at the time of the approval
+    pub approved: bool,
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl PermanentDelegateApproval {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // mint
+        32 + // permanent_delegate
+        1 + // approved
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct OrderLimitConfig {
+    pub auction_house_key: Pubkey,
+    pub max_open_listings: u32, // per wallet, 0 for no limit
+    pub max_open_bids: u32,     // per wallet, 0 for no limit
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl OrderLimitConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        4 + // max_open_listings
+        4 + // max_open_bids
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct WalletOrderCounter {
+    pub auction_house_key: Pubkey,
+    pub wallet: Pubkey,
+    pub open_listings: u32,
+    pub open_bids: u32,
+    pub bump: u8,
+}
+
+impl WalletOrderCounter {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // wallet
+        4 + // open_listings
+        4 + // open_bids
+        1 + // bump
+        32; // padding
+}
+
+// CountedOrder marks a trade state as counted on its wallet's WalletOrderCounter, so closing an
+// order that was opened without being counted doesn't free up a counted one
+#[account]
+#[derive(Default)]
+pub struct CountedOrder {
+    pub trade_state: Pubkey,
+    pub bump: u8,
+}
+
+impl CountedOrder {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // trade_state
+        1; // bump
+}
+
+#[account]
+#[derive(Default)]
+pub struct PermanentDelegateConfig {
+    pub auction_house_key: Pubkey,
+    pub flag_only: bool, // list unapproved mints with a permanent delegate and flag them in the logs
+    pub bump: u8,
+    pub updated_at: i64,
+}
+
+impl PermanentDelegateConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        1 + // flag_only
+        1 + // bump
+        8 + // updated_at
+        64; // padding
+}
+
+#[account]
+#[derive(Default)]
+pub struct CancellationSnapshot {
+    pub auction_house_key: Pubkey,
+    pub merkle_root: [u8; 32], // root of the trade states to force-cancel, zero for none
+    pub order_count: u32,      // leaves of the tree, for indexers
+    pub published_at: i64,
+    pub bump: u8,
+}
+
+impl CancellationSnapshot {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_house_key
+        32 + // merkle_root
+        4 + // order_count
+        8 + // published_at
+        1 + // bump
+        64; // padding
+}
+
+// CrankBond holds the lamports a cranker posts to call the permissionless cranks, each invalid crank
+// call slashes CRANK_BOND_LAMPORTS of it. Valid calls leave it untouched, and the cranker can
+// withdraw what's left at any time
+#[account]
+#[derive(Default)]
+pub struct CrankBond {
+    pub cranker: Pubkey,
+    pub amount: u64, // lamports posted on top of the rent
+    pub bump: u8,
+}
+
+impl CrankBond {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // cranker
+        8 + // amount
+        1; // bump
+}
@@ -0,0 +1,8 @@
+pub mod t22_sell;
+pub use t22_sell::*;
+
+pub mod t22_cancel_sell;
+pub use t22_cancel_sell::*;
+
+pub mod t22_execute_sale_v2;
+pub use t22_execute_sale_v2::*;
@@ -0,0 +1,105 @@
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022::{revoke, Revoke, Token2022},
+        token_interface::TokenAccount,
+    },
+    solana_program::program_option::COption,
+};
+
+// T22CancelSell cancels a t22_sell listing and revokes program_as_signer's delegation, if the
+// seller still holds the token in token_account.
+#[derive(Accounts)]
+pub struct T22CancelSell<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: notary is not dangerous because we don't read or write from this account
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: token_account of the listing, might have been closed since
+    #[account(mut)]
+    token_account: UncheckedAccount<'info>,
+    /// CHECK: token_mint, checked against sell_args
+    token_mint: UncheckedAccount<'info>,
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()], bump=auction_house.bump)]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: seeds check and check sell_args
+    #[account(
+        mut,
+        seeds=[
+          PREFIX.as_bytes(),
+          wallet.key().as_ref(),
+          auction_house.key().as_ref(),
+          token_account.key().as_ref(),
+          token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    seller_trade_state: AccountInfo<'info>,
+    /// CHECK: WalletOrderCounter of the wallet, derivation checked in uncount_open_order
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    token_program: Program<'info, Token2022>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, T22CancelSell<'info>>) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let token_account = &ctx.accounts.token_account;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let auction_house = &ctx.accounts.auction_house;
+
+    if seller_trade_state.data_is_empty() {
+        return Err(ErrorCode::EmptyTradeState.into());
+    }
+    let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+    if sell_args.auction_house_key != auction_house.key()
+        || sell_args.token_mint != ctx.accounts.token_mint.key()
+    {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    assert_valid_notary(
+        auction_house,
+        &ctx.accounts.notary,
+        100u8, // 100% enforced cosign
+    )?;
+
+    if *token_account.owner == ctx.accounts.token_program.key() {
+        let token_account_data =
+            TokenAccount::try_deserialize(&mut &token_account.data.borrow()[..])?;
+        if token_account_data.owner == wallet.key()
+            && token_account_data.delegate == COption::Some(ctx.accounts.program_as_signer.key())
+        {
+            revoke(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Revoke {
+                    source: token_account.to_account_info(),
+                    authority: wallet.to_account_info(),
+                },
+            ))?;
+        }
+    }
+    close_account_anchor(seller_trade_state, wallet)?;
+    uncount_open_order(
+        Some(&ctx.accounts.wallet_order_counter),
+        &ctx.accounts.counted_order,
+        &seller_trade_state.key(),
+        wallet,
+        &auction_house.key(),
+        OrderSide::Listing,
+    )?;
+
+    msg!(
+        "t22_cancel_sell: {{\"seller_trade_state\":\"{}\"}}",
+        seller_trade_state.key(),
+    );
+    Ok(())
+}
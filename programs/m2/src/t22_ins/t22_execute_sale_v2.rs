@@ -0,0 +1,315 @@
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::{create_idempotent, AssociatedToken, Create},
+        token::Token,
+        token_2022::Token2022,
+        token_interface::{Mint, TokenAccount},
+    },
+    solana_program::program_option::COption,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct T22ExecuteSaleV2Args {
+    pub price: u64,      // bid price of the whole fill
+    pub token_size: u64, // any part of the listed size
+    pub maker_fee_bp: i16,
+    pub taker_fee_bp: u16,
+}
+
+// T22ExecuteSaleV2 settles a t22_sell listing against a v2 bid, moving the token with
+// program_as_signer's delegation. If the mint has a transfer fee, the buyer pays for the amount
+// actually received: the price, and with it the royalty base and the seller's proceeds, shrink in
+// proportion to the tokens the fee withholds. The accounts of a transfer hook are passed through to
+// the transfer from the end of the remaining accounts.
+#[derive(Accounts)]
+#[instruction(args: T22ExecuteSaleV2Args)]
+pub struct T22ExecuteSaleV2<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: buyer. Either buyer or the seller has to be the signer
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+    /// CHECK: seller. Either buyer or the seller has to be the signer
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: optional
+    pub notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = seller,
+        constraint = token_account.delegate == COption::Some(program_as_signer.key()) @ ErrorCode::InvalidAccountState,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: ata(buyer, token_mint) of the Token-2022 program, created if needed
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&buyer.key(), &token_mint.key(), &asset_token_program.key()),
+    )]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+    #[account(
+        constraint = *token_mint.to_account_info().owner == asset_token_program.key() @ ErrorCode::InvalidTokenMint,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+        bump=auction_house.bump,
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: auction_house_treasury
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), TREASURY.as_bytes()], bump)]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+    /// CHECK: check seeds and check sell_args
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub seller_trade_state: AccountInfo<'info>,
+    /// CHECK: check seeds and check bid_args
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            buyer.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub buyer_trade_state: AccountInfo<'info>,
+    /// CHECK: check with contraints
+    #[account(
+        mut,
+        seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), buyer.key().as_ref()],
+        constraint= args.price > 0,
+        constraint= args.maker_fee_bp <= MAX_MAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.maker_fee_bp >= -(args.taker_fee_bp as i16) @ ErrorCode::InvalidPlatformFeeBp,
+        constraint= args.taker_fee_bp <= MAX_TAKER_FEE_BP @ ErrorCode::InvalidPlatformFeeBp,
+        bump,
+    )]
+    pub buyer_escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: check with contraints
+    #[account(mut)]
+    buyer_referral: UncheckedAccount<'info>,
+    /// CHECK: check with contraints
+    #[account(mut)]
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    pub collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_seller_not_suspended
+    pub seller_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_buyer_not_suspended
+    pub buyer_status: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    pub price_band_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_price_attested
+    pub price_attestation: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    pub collection_stats: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    pub last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in record_sale, created if needed
+    #[account(mut)]
+    pub trade_sequence: UncheckedAccount<'info>,
+    /// CHECK: FrontendConfig of the frontend that routed the sale, checked in attribute_frontend_sale
+    #[account(mut)]
+    pub frontend_config: UncheckedAccount<'info>,
+    /// CHECK: TakerFeeConfig of the auction house, derivation checked in get_taker_fee_bp
+    pub taker_fee_config: UncheckedAccount<'info>,
+    /// CHECK: pyth SOL/USD price account, checked against taker_fee_config if it's enabled
+    pub price_feed: UncheckedAccount<'info>,
+    /// CHECK: RoyaltySubsidy of the collection, derivation checked in get_active_royalty_subsidy
+    #[account(mut)]
+    pub royalty_subsidy: UncheckedAccount<'info>,
+    /// CHECK: RebateEpoch of the current epoch, derivation checked in enter_rebate_draw
+    #[account(mut)]
+    pub rebate_epoch: UncheckedAccount<'info>,
+    /// CHECK: FillProof of the buyer and the token, or any other account to skip it, derivation checked in record_fill_proof
+    #[account(mut)]
+    pub fill_proof: UncheckedAccount<'info>,
+    /// CHECK: InsuranceFund of the auction house, derivation checked in fund_insurance
+    #[account(mut)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the buyer, derivation checked in uncount_open_order
+    #[account(mut)]
+    pub buyer_order_counter: UncheckedAccount<'info>,
+    /// CHECK: WalletOrderCounter of the seller, derivation checked in uncount_open_order
+    #[account(mut)]
+    pub seller_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the buyer_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    pub buyer_counted_order: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the seller_trade_state, derivation checked in uncount_open_order
+    #[account(mut)]
+    pub seller_counted_order: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>, // token program of SPL payments
+    pub asset_token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    // remaining accounts: same as execute_sale_v2, followed by
+    // the transfer hook program, its ExtraAccountMetaList and its extra accounts (if the mint has a transfer hook)
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, T22ExecuteSaleV2<'info>>,
+    args: T22ExecuteSaleV2Args,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let token_mint = &ctx.accounts.token_mint;
+    let token_mint_ai = token_mint.to_account_info();
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let asset_token_program = &ctx.accounts.asset_token_program;
+
+    if !buyer.is_signer && !seller.is_signer {
+        return Err(ErrorCode::SaleRequiresSigner.into());
+    }
+    let taker = if buyer.is_signer { buyer } else { seller };
+
+    let settlement = SettlementContext {
+        auction_house: &ctx.accounts.auction_house,
+        auction_house_treasury: &ctx.accounts.auction_house_treasury,
+        notary: &ctx.accounts.notary,
+        buyer,
+        seller,
+        taker,
+        payer,
+        buyer_trade_state: &ctx.accounts.buyer_trade_state,
+        seller_trade_state: &ctx.accounts.seller_trade_state,
+        buyer_referral: &ctx.accounts.buyer_referral,
+        seller_referral: &ctx.accounts.seller_referral,
+        escrow_payment_account: &ctx.accounts.buyer_escrow_payment_account,
+        escrow_payment_bump: ctx.bumps.buyer_escrow_payment_account,
+        program_as_signer,
+        program_as_signer_bump: ctx.bumps.program_as_signer,
+        metadata: &ctx.accounts.metadata,
+        collection_config: &ctx.accounts.collection_config,
+        seller_status: &ctx.accounts.seller_status,
+        buyer_status: &ctx.accounts.buyer_status,
+        price_band_config: &ctx.accounts.price_band_config,
+        price_attestation: Some(&ctx.accounts.price_attestation),
+        collection_stats: Some(&ctx.accounts.collection_stats),
+        last_sale: &ctx.accounts.last_sale,
+        trade_sequence: Some(&ctx.accounts.trade_sequence),
+        frontend_config: Some(&ctx.accounts.frontend_config),
+        taker_fee_config: Some(&ctx.accounts.taker_fee_config),
+        price_feed: Some(&ctx.accounts.price_feed),
+        royalty_subsidy: Some(&ctx.accounts.royalty_subsidy),
+        rebate_epoch: Some(&ctx.accounts.rebate_epoch),
+        fill_proof: Some(&ctx.accounts.fill_proof),
+        insurance_fund: Some(&ctx.accounts.insurance_fund),
+        buyer_order_counter: Some(&ctx.accounts.buyer_order_counter),
+        seller_order_counter: Some(&ctx.accounts.seller_order_counter),
+        buyer_counted_order: &ctx.accounts.buyer_counted_order,
+        seller_counted_order: &ctx.accounts.seller_counted_order,
+        ocp_policy: None,
+        t22_mint: Some(&token_mint_ai),
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    let mut terms = settlement.validate(&token_mint.key(), args.token_size, args.price)?;
+    // take profit bids relist the bought token, which only execute_sale_v2 does
+    if terms.bid_args.take_profit_price > 0 {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+
+    let transfer_fee = get_t22_transfer_fee(&token_mint_ai, terms.token_size)?;
+    let received = terms.token_size.saturating_sub(transfer_fee);
+    if received == 0 {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    terms.price = get_received_price(terms.price, terms.token_size, received)?;
+    terms.secondary_price = get_received_price(terms.secondary_price, terms.token_size, received)?;
+
+    if buyer_receipt_token_account.data_is_empty() {
+        create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: payer.to_account_info(),
+                associated_token: buyer_receipt_token_account.to_account_info(),
+                authority: buyer.to_account_info(),
+                mint: token_mint_ai.clone(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: asset_token_program.to_account_info(),
+            },
+        ))?;
+    }
+    let buyer_rec_acct =
+        TokenAccount::try_deserialize(&mut &buyer_receipt_token_account.data.borrow()[..])?;
+    if let COption::Some(_) = buyer_rec_acct.delegate {
+        return Err(ErrorCode::BuyerATACannotHaveDelegate.into());
+    }
+    // the mint's transfer hook is the only program outside our control that runs mid-settlement,
+    // with the trade states still open. It can't re-enter m2: the runtime rejects a CPI back into
+    // a program already on the invoke stack, and Token-2022 passes the hook the transfer's
+    // accounts read-only, so the trade states don't need an in-progress flag
+    transfer_t22_checked(
+        &asset_token_program.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &token_mint_ai,
+        buyer_receipt_token_account,
+        program_as_signer,
+        ctx.remaining_accounts,
+        terms.token_size,
+        token_mint.decimals,
+        &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[ctx.bumps.program_as_signer],
+        ]],
+    )?;
+
+    let payout = settlement.pay(
+        &terms,
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        terms.bid_args.buyer_creator_royalty_bp,
+    )?;
+    settlement.close(&terms, &payout)?;
+
+    msg!(
+        "t22_execute_sale_v2: {{\"price\":{},\"token_size\":{},\"received\":{},\"seller_expiry\":{},\"buyer_expiry\":{},\"royalty\":{},\"royalty_subsidy\":{}}}",
+        terms.price,
+        terms.token_size,
+        received,
+        terms.sell_args.expiry,
+        terms.bid_args.expiry,
+        payout.royalty,
+        payout.royalty_subsidy,
+    );
+    Ok(())
+}
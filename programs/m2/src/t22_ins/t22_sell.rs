@@ -0,0 +1,250 @@
+use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::index_ra;
+
+use {
+    crate::constants::*,
+    crate::errors::ErrorCode,
+    crate::states::*,
+    crate::utils::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    anchor_spl::{
+        token_2022::{approve, Approve, Token2022},
+        token_interface::{Mint, TokenAccount},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct T22SellArgs {
+    pub price: u64,      // price of a single token
+    pub token_size: u64, // listings of a semi-fungible mint can be filled partially
+    pub expiry: i64,
+}
+
+// T22Sell lists a token of a Token-2022 mint. The token stays in the seller's token account, which
+// delegates token_size to program_as_signer until the sale or t22_cancel_sell. Listing again
+// changes the price and size. Mints with the non-transferable extension are rejected up front, and
+// mints with a permanent delegate unless the auction house approved them or only flags them.
+// Without a verified Metaplex collection, the mint's token group counts as its collection.
+#[derive(Accounts)]
+pub struct T22Sell<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: optional
+    notary: UncheckedAccount<'info>,
+    /// CHECK: program_as_signer
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    program_as_signer: UncheckedAccount<'info>,
+    #[account(mut, token::mint = token_mint, token::authority = wallet)]
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        constraint = token_mint.decimals == 0 @ ErrorCode::InvalidTokenMint,
+        constraint = *token_mint.to_account_info().owner == token_program.key() @ ErrorCode::InvalidTokenMint,
+    )]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: metadata
+    #[account(
+    seeds = [
+        "metadata".as_bytes(),
+        mpl_token_metadata::ID.as_ref(),
+        token_mint.key().as_ref(),
+    ],
+    bump,
+    seeds::program = mpl_token_metadata::ID,
+    )]
+    metadata: UncheckedAccount<'info>,
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref()],
+        constraint = auction_house.notary == notary.key() @ ErrorCode::InvalidNotary,
+        bump=auction_house.bump,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: seeds check and discriminator check
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    seller_trade_state: AccountInfo<'info>,
+    /// CHECK: seller_referral
+    seller_referral: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_collection_not_paused
+    collection_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_hold_period_elapsed
+    last_sale: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in count_open_order
+    order_limit_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in count_open_order, created if needed
+    #[account(mut)]
+    wallet_order_counter: UncheckedAccount<'info>,
+    /// CHECK: CountedOrder of the seller_trade_state, derivation checked in count_open_order, created if needed
+    #[account(mut)]
+    counted_order: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_t22_permanent_delegate_allowed
+    permanent_delegate_config: UncheckedAccount<'info>,
+    /// CHECK: derivation checked in assert_t22_permanent_delegate_allowed
+    permanent_delegate_approval: UncheckedAccount<'info>,
+    token_program: Program<'info, Token2022>,
+    system_program: Program<'info, System>,
+    // remaining accounts:
+    // 0. payment_mint (optional) - if the seller wants payment in a SPL token, this is the mint of that token
+    // ...
+    // -1. payer (optional) - this wallet will try to pay for sts rent
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, T22Sell<'info>>,
+    args: T22SellArgs,
+) -> Result<()> {
+    let (remaining_accounts, possible_payer) =
+        split_payer_from_remaining_accounts(ctx.remaining_accounts);
+    let wallet = &ctx.accounts.wallet;
+    let payer = if let Some(p) = possible_payer {
+        p
+    } else {
+        wallet
+    };
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+
+    if args.price > MAX_PRICE || args.price == 0 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    if args.expiry >= 0 {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    if args.token_size > token_account.amount || args.token_size == 0 {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    if !seller_trade_state.data_is_empty() {
+        let discriminator_ai = seller_trade_state.try_borrow_data()?;
+        if discriminator_ai[..8] != SellerTradeStateV2::discriminator() {
+            return Err(ErrorCode::InvalidDiscriminator.into());
+        }
+        drop(discriminator_ai);
+        // can't set the existing seller_trade_state to another auction house
+        let sell_args = SellArgs::from_account_info(seller_trade_state)?;
+        if sell_args.auction_house_key != auction_house.key() {
+            return Err(ErrorCode::InvalidAccountState.into());
+        }
+    }
+    assert_metadata_valid(metadata, &token_mint.key())?;
+    assert_t22_transferable(&token_mint.to_account_info())?;
+    assert_t22_permanent_delegate_allowed(
+        &token_mint.to_account_info(),
+        &ctx.accounts.permanent_delegate_config,
+        &ctx.accounts.permanent_delegate_approval,
+        &auction_house.key(),
+    )?;
+    let mut metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    apply_t22_group_collection(&mut metadata_parsed, &token_mint.to_account_info())?;
+    assert_collection_not_paused(
+        &ctx.accounts.collection_config,
+        &auction_house.key(),
+        &metadata_parsed,
+    )?;
+    assert_hold_period_elapsed(
+        &ctx.accounts.collection_config,
+        &ctx.accounts.last_sale,
+        &auction_house.key(),
+        &metadata_parsed,
+        wallet.key,
+    )?;
+    let payment_mint = if remaining_accounts.len() == 1 {
+        assert_payment_mint(index_ra!(remaining_accounts, 0))?;
+        assert_price_precision(args.price, index_ra!(remaining_accounts, 0))?;
+        index_ra!(remaining_accounts, 0).key()
+    } else {
+        assert_collection_price_on_tick(
+            &ctx.accounts.collection_config,
+            &auction_house.key(),
+            &get_collection_key(&metadata_parsed),
+            args.price,
+        )?;
+        Pubkey::default()
+    };
+
+    approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: token_account.to_account_info(),
+                delegate: program_as_signer.to_account_info(),
+                authority: wallet.to_account_info(),
+            },
+        ),
+        args.token_size,
+    )?;
+
+    if seller_trade_state.data_is_empty() {
+        count_open_order(
+            &ctx.accounts.order_limit_config,
+            Some(&ctx.accounts.wallet_order_counter),
+            Some(&ctx.accounts.counted_order),
+            seller_trade_state.key,
+            payer,
+            &auction_house.key(),
+            wallet.key,
+            OrderSide::Listing,
+        )?;
+    }
+    create_or_realloc_seller_trade_state(
+        seller_trade_state,
+        payer,
+        &[
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            token_mint.key().as_ref(),
+            &[ctx.bumps.seller_trade_state],
+        ],
+    )?;
+    let sts = SellerTradeStateV2 {
+        auction_house_key: auction_house.key(),
+        seller: wallet.key(),
+        seller_referral: ctx.accounts.seller_referral.key(),
+        buyer_price: args.price,
+        token_mint: token_mint.key(),
+        token_account: token_account.key(),
+        token_size: args.token_size,
+        bump: ctx.bumps.seller_trade_state,
+        expiry: args.expiry,
+        payment_mint,
+        ..Default::default()
+    };
+    let sts_v2_serialized = sts.try_to_vec()?;
+    seller_trade_state.try_borrow_mut_data()?[8..8 + sts_v2_serialized.len()]
+        .copy_from_slice(&sts_v2_serialized);
+
+    msg!(
+        "t22_sell: {{\"seller_trade_state\":\"{}\",\"token_account\":\"{}\"}}",
+        seller_trade_state.key(),
+        token_account.key(),
+    );
+    let payment_decimals = get_payment_decimals(if remaining_accounts.len() == 1 {
+        Some(index_ra!(remaining_accounts, 0))
+    } else {
+        None
+    })?;
+    msg!(
+        "{{\"price\":{},\"token_size\":{},\"seller_expiry\":{},\"decimals\":{},\"ui_price\":\"{}\"}}",
+        sts.buyer_price,
+        sts.token_size,
+        sts.expiry,
+        payment_decimals,
+        get_ui_price(sts.buyer_price, payment_decimals),
+    );
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Names the check and the account behind a failing result. With the diagnostics feature the
+/// program logs a compact diagnostic before returning the error, so a wallet simulating a failing
+/// transaction knows which check to fix without mapping the error code back to the instruction.
+/// Without the feature, results pass through untouched.
+pub trait Diagnose<T> {
+    fn diagnose(self, check: &str, account: &str) -> Result<T>;
+}
+
+impl<T> Diagnose<T> for Result<T> {
+    fn diagnose(self, check: &str, account: &str) -> Result<T> {
+        if cfg!(feature = "diagnostics") && self.is_err() {
+            msg!(
+                "diagnostic: {{\"check\":\"{}\",\"account\":\"{}\"}}",
+                check,
+                account
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorCode;
+
+    #[test]
+    fn diagnose_passes_results_through() {
+        let ok: Result<u64> = Ok(1);
+        assert_eq!(ok.diagnose("check", "account").unwrap(), 1);
+        let err: Result<u64> = Err(ErrorCode::InvalidPrice.into());
+        assert_eq!(
+            err.diagnose("check", "account").unwrap_err(),
+            ErrorCode::InvalidPrice.into()
+        );
+    }
+}
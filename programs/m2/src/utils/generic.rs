@@ -1,13 +1,23 @@
 use anchor_lang::Discriminator;
 use mpl_token_metadata::{
     accounts::{Metadata, TokenRecord},
-    types::{TokenDelegateRole, TokenStandard, TokenState},
+    types::{
+        Collection, Creator, Key as MetadataKey, TokenDelegateRole, TokenStandard, TokenState,
+    },
 };
 use spl_associated_token_account::instruction;
 
 use crate::constants::{
-    DEFAULT_BID_EXPIRY_SECONDS_AFTER_NOW, DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP,
-    VALID_PAYMENT_MINTS,
+    BUYER_STATUS, COLLECTION_CONFIG, COMPUTE_BUDGET_PROGRAM_ID, CORE_ROYALTIES_PLUGIN,
+    CORE_TRANSFER_DELEGATE_PLUGIN, COUNTED_ORDER, CRANK_BOND_LAMPORTS, CUSTODIAL_PLATFORM,
+    CUSTODY_VAULT, DEFAULT_BID_EXPIRY_SECONDS_AFTER_NOW, DEFAULT_INSTRUCTION_COMPUTE_UNITS,
+    DEFAULT_MAKER_FEE_BP, DEFAULT_TAKER_FEE_BP, LAST_SALE, LISTING_OPERATOR,
+    MARINADE_DEPOSIT_IX_DISCRIMINATOR, MARINADE_LIQUID_UNSTAKE_IX_DISCRIMINATOR, MAX_PRICE,
+    MAX_SPL_PRICE_DECIMALS, MAX_TRANSACTION_COMPUTE_UNITS, MINT_LISTING_INDEX, MIN_SPL_PRICE,
+    MPL_CORE_PROGRAM_ID, ORDER_LIMIT_CONFIG, PREFIX, PRICE_ATTESTATION, PRICE_BAND_CONFIG,
+    ROYALTY_FALLBACK, ROYALTY_SUBSIDY, SELLER_STATUS, SOL_DECIMALS, STAKE_POOL_DEPOSIT_SOL_IX,
+    STAKE_POOL_WITHDRAW_SOL_IX, VALID_PAYMENT_MINTS, WALLET_ORDER_COUNTER,
+    YIELD_VAULT_PROGRAM_WHITELIST,
 };
 
 use {
@@ -16,11 +26,13 @@ use {
     anchor_lang::{
         prelude::*,
         solana_program::{
+            instruction::{AccountMeta, Instruction},
             program::invoke,
             program::invoke_signed,
             program_option::COption,
             program_pack::{IsInitialized, Pack},
             system_instruction,
+            sysvar::instructions::load_instruction_at_checked,
         },
     },
     anchor_spl::token::Mint,
@@ -97,10 +109,10 @@ pub fn make_ata<'a>(
     Ok(())
 }
 
-pub fn assert_metadata_valid(metadata: &UncheckedAccount, token_mint: &Pubkey) -> Result<()> {
+pub fn assert_metadata_valid(metadata: &AccountInfo, token_mint: &Pubkey) -> Result<()> {
     assert_derivation(
         &mpl_token_metadata::ID,
-        &metadata.to_account_info(),
+        metadata,
         &[
             Metadata::PREFIX,
             mpl_token_metadata::ID.as_ref(),
@@ -202,6 +214,54 @@ pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
     }
 }
 
+/// Returns true if token_account is the custody vault of the mint used by custody_sell
+pub fn is_custody_vault(token_account: &Pubkey, mint: &Pubkey) -> bool {
+    let (custody_vault, _) = Pubkey::find_program_address(
+        &[PREFIX.as_bytes(), CUSTODY_VAULT.as_bytes(), mint.as_ref()],
+        &crate::ID,
+    );
+    custody_vault == *token_account
+}
+
+/// Checks that an SPL price is settleable in the payment mint: not dust that rounds every fee
+/// and royalty down to zero, and not more precise than MAX_SPL_PRICE_DECIMALS
+pub fn assert_price_precision(price: u64, payment_mint: &AccountInfo) -> Result<()> {
+    let mint: spl_token::state::Mint = assert_initialized(payment_mint)?;
+    check_price_precision(price, mint.decimals)
+}
+
+pub fn check_price_precision(price: u64, decimals: u8) -> Result<()> {
+    if price < MIN_SPL_PRICE {
+        return Err(ErrorCode::PriceBelowMintMinimum.into());
+    }
+    let tick = 10u64
+        .checked_pow(decimals.saturating_sub(MAX_SPL_PRICE_DECIMALS) as u32)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if !price.is_multiple_of(tick) {
+        return Err(ErrorCode::InvalidPricePrecision.into());
+    }
+    Ok(())
+}
+
+/// Returns the decimals of a payment mint, or of SOL if payment_mint is None
+pub fn get_payment_decimals(payment_mint: Option<&AccountInfo>) -> Result<u8> {
+    match payment_mint {
+        Some(mint_ai) => {
+            let mint: spl_token::state::Mint = assert_initialized(mint_ai)?;
+            Ok(mint.decimals)
+        }
+        None => Ok(SOL_DECIMALS),
+    }
+}
+
+/// Formats a price in base units as whole units of its payment mint, e.g. "1.5" for
+/// 1_500_000_000 lamports, so event consumers can display prices without resolving the mint
+pub fn get_ui_price(price: u64, decimals: u8) -> String {
+    spl_token::amount_to_ui_amount_string_trimmed(price, decimals)
+}
+
+// only classic SPL mints are accepted for payment, Token-2022 payment mints with a permanent
+// delegate that could claw back settled proceeds never get this far
 pub fn assert_payment_mint(mint_ai: &AccountInfo) -> Result<()> {
     if !VALID_PAYMENT_MINTS.contains(mint_ai.key) || mint_ai.owner != &spl_token::id() {
         Err(ErrorCode::InvalidTokenMint.into())
@@ -310,6 +370,45 @@ pub fn split_payer_from_remaining_accounts<'a, 'info>(
     }
 }
 
+/// Accounts an instruction took on after it shipped are passed at the end of its remaining
+/// accounts, after this program's id as a marker and in the order the instruction lists them, so
+/// the account layout of existing clients doesn't change. Passing the program id in place of one
+/// of them, or leaving out the ones at the end, skips it the same as a PDA that doesn't exist,
+/// except for the ones an instruction requires, which fail it with MissingRemainingAccount.
+/// Accounts the instruction writes to have to be passed writable
+pub struct OptionalAccounts<'a, 'info>(&'a [AccountInfo<'info>]);
+
+impl<'a, 'info> OptionalAccounts<'a, 'info> {
+    pub fn get(&self, index: usize) -> Option<&'a AccountInfo<'info>> {
+        self.0
+            .get(index)
+            .filter(|account| account.key != &crate::ID)
+    }
+
+    /// Returns the account at index, for the accounts an instruction can't do without
+    pub fn require(&self, index: usize) -> Result<&'a AccountInfo<'info>> {
+        self.get(index)
+            .ok_or_else(|| ErrorCode::MissingRemainingAccount.into())
+    }
+}
+
+/// Splits the OptionalAccounts off remaining_accounts, after split_payer_from_remaining_accounts
+/// as the payer goes last
+pub fn split_optional_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> (&'a [AccountInfo<'info>], OptionalAccounts<'a, 'info>) {
+    match remaining_accounts
+        .iter()
+        .position(|account| account.key == &crate::ID)
+    {
+        Some(marker) => (
+            &remaining_accounts[..marker],
+            OptionalAccounts(&remaining_accounts[marker + 1..]),
+        ),
+        None => (remaining_accounts, OptionalAccounts(&[])),
+    }
+}
+
 /// Cheap method to just grab mint Pubkey from token account, instead of deserializing entire thing
 #[allow(dead_code)]
 pub fn get_mint_from_token_account(token_account_info: &AccountInfo) -> Result<Pubkey> {
@@ -332,7 +431,6 @@ pub fn get_delegate_from_token_account(token_account_info: &AccountInfo) -> Resu
     }
 }
 
-#[allow(dead_code)]
 pub fn get_balance_from_token_account(token_account_info: &AccountInfo) -> Result<u64> {
     // TokeAccount layout:   mint(32), owner(32), ...
     let data = token_account_info.try_borrow_data()?;
@@ -343,7 +441,6 @@ pub fn get_balance_from_token_account(token_account_info: &AccountInfo) -> Resul
 /// Create account almost from scratch, lifted from
 /// https://github.com/solana-labs/solana-program-library/blob/7d4873c61721aca25464d42cc5ef651a7923ca79/associated-token-account/program/src/processor.rs#L51-L98
 #[inline(always)]
-#[allow(dead_code)]
 pub fn create_or_allocate_account_raw<'a>(
     program_id: &Pubkey,
     new_account_info: &AccountInfo<'a>,
@@ -359,7 +456,7 @@ pub fn create_or_allocate_account_raw<'a>(
         )?;
     }
 
-    let accounts = &[new_account_info.clone()];
+    let accounts = std::slice::from_ref(new_account_info);
     invoke_signed(
         &system_instruction::allocate(new_account_info.key, (*size).try_into().unwrap()),
         accounts,
@@ -375,6 +472,39 @@ pub fn create_or_allocate_account_raw<'a>(
     Ok(())
 }
 
+/// Deserializes a program owned account, or creates it with the given space and returns the default
+/// if it doesn't exist yet. Use save_program_account to write the discriminator and data back.
+pub fn load_or_create_program_account<'a, T: AccountDeserialize + Default>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<T> {
+    if account.data_is_empty() {
+        let required_lamports = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account.lamports());
+        create_or_allocate_account_raw(
+            &crate::ID,
+            account,
+            payer,
+            &required_lamports,
+            &space,
+            seeds,
+        )?;
+        Ok(T::default())
+    } else {
+        assert_owned_by(account, &crate::ID)?;
+        T::try_deserialize(&mut &account.data.borrow()[..])
+    }
+}
+
+pub fn save_program_account<T: AccountSerialize>(account: &AccountInfo, data: &T) -> Result<()> {
+    let mut account_data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut account_data[..];
+    data.try_serialize(&mut writer)
+}
+
 pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u8]]) -> Result<u8> {
     let (key, bump) = Pubkey::find_program_address(path, program_id);
     if key != *account.key {
@@ -411,6 +541,48 @@ pub fn try_close_buyer_escrow<'info>(
     }
 }
 
+/// Token standard of an asset, picks the execute instruction execute_sale_auto routes to
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AssetStandard {
+    Legacy,       // SPL Token mint
+    Programmable, // SPL Token mint of a pNFT
+    Token2022,
+    Core,
+    Compressed, // asset id of a compressed NFT, which has no account
+}
+
+/// Returns the token standard of asset from its owner, and for SPL Token mints from the token
+/// standard in their metadata. metadata isn't read for other standards
+pub fn get_asset_standard(asset: &AccountInfo, metadata: &AccountInfo) -> Result<AssetStandard> {
+    if *asset.owner == MPL_CORE_PROGRAM_ID {
+        return Ok(AssetStandard::Core);
+    }
+    if *asset.owner == anchor_spl::token_2022::ID {
+        return Ok(AssetStandard::Token2022);
+    }
+    if *asset.owner == spl_token::id() {
+        assert_metadata_valid(metadata, asset.key)?;
+        let metadata_parsed = Metadata::safe_deserialize(&metadata.data.borrow())?;
+        return Ok(
+            if metadata_parsed.token_standard == Some(TokenStandard::ProgrammableNonFungible) {
+                AssetStandard::Programmable
+            } else {
+                AssetStandard::Legacy
+            },
+        );
+    }
+    if asset.data_is_empty() && *asset.owner == solana_program::system_program::ID {
+        return Ok(AssetStandard::Compressed);
+    }
+    Err(ErrorCode::UnsupportedAssetStandard.into())
+}
+
+/// Checks that metadata is the valid metadata account of a pNFT mint
+pub fn assert_programmable_metadata(metadata: &AccountInfo, token_mint: &Pubkey) -> Result<()> {
+    assert_metadata_valid(metadata, token_mint)?;
+    check_programmable(&Metadata::safe_deserialize(&metadata.data.borrow())?)
+}
+
 pub fn check_programmable(metadata_parsed: &Metadata) -> Result<()> {
     match metadata_parsed.token_standard {
         None => return Err(ErrorCode::InvalidTokenStandard.into()),
@@ -423,209 +595,1549 @@ pub fn check_programmable(metadata_parsed: &Metadata) -> Result<()> {
     Ok(())
 }
 
-pub fn close_account_anchor(info: &AccountInfo, dest: &AccountInfo) -> Result<()> {
-    let curr_lamp = info.lamports();
-    **info.lamports.borrow_mut() = 0;
-    **dest.lamports.borrow_mut() = dest
-        .lamports()
-        .checked_add(curr_lamp)
-        .ok_or(ErrorCode::NumericalOverflow)?;
-    info.try_borrow_mut_data()?[0..8].copy_from_slice(&[0; 8]);
-    Ok(())
+/// Returns the maker fee bp of a sale, creator listings filled by the buyer don't pay a maker fee
+pub fn get_creator_listing_maker_fee_bp(
+    sell_args: &SellArgs,
+    seller_is_taker: bool,
+    maker_fee_bp: i16,
+) -> i16 {
+    if sell_args.creator_listing && !seller_is_taker {
+        // keep maker rebates
+        maker_fee_bp.min(0)
+    } else {
+        maker_fee_bp
+    }
 }
 
-pub fn get_delegate_info_and_token_state_from_token_record(
-    info: &AccountInfo,
-) -> Result<(Option<Pubkey>, Option<TokenDelegateRole>, TokenState)> {
-    let token_record = TokenRecord::safe_deserialize(&info.data.borrow())?;
-    Ok((
-        token_record.delegate,
-        token_record.delegate_role,
-        token_record.state,
-    ))
+/// Returns true if wallet is the update authority or a verified creator of the token
+pub fn is_update_authority_or_verified_creator(
+    metadata_parsed: &Metadata,
+    wallet: &Pubkey,
+) -> bool {
+    metadata_parsed.update_authority == *wallet
+        || metadata_parsed
+            .creators
+            .as_ref()
+            .is_some_and(|creators| creators.iter().any(|c| c.verified && c.address == *wallet))
 }
 
-pub fn create_or_realloc_seller_trade_state<'a>(
-    sts: &AccountInfo<'a>,
-    payer: &AccountInfo<'a>,
-    sts_seeds: &[&[u8]],
-) -> Result<()> {
-    let rent = Rent::get()?;
-    let required_lamports = rent
-        .minimum_balance(SellerTradeStateV2::LEN)
-        .saturating_sub(sts.lamports());
-    if sts.data_is_empty() {
-        // brand new account, need to create it with correct length
-        invoke_signed(
-            &system_instruction::create_account(
-                payer.key,
-                sts.key,
-                required_lamports,
-                SellerTradeStateV2::LEN as u64,
-                &crate::ID,
-            ),
-            &[payer.clone(), sts.clone()],
-            &[sts_seeds],
-        )?;
+/// Creator of a compressed NFT as attested by the notary, the leaf only has a hash of these
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
 
-        sts.try_borrow_mut_data()?[..8].copy_from_slice(&SellerTradeStateV2::discriminator());
-        Ok(())
-    } else if sts.data_len() == SellerTradeState::LEN {
-        // old buyer trade state that we want to migrate
-        // zero out original data
-        sts.try_borrow_mut_data()?
-            .copy_from_slice(&[0; SellerTradeState::LEN]);
-        // reallocate new space
-        sts.realloc(SellerTradeStateV2::LEN, true)?;
-        // transfer lamports so become rent exempt
-        if required_lamports > 0 {
-            invoke(
-                &system_instruction::transfer(payer.key, sts.key, required_lamports),
-                &[payer.clone(), sts.clone()],
-            )?;
-        }
+/// Returns the creator hash Bubblegum keeps in the leaf of a compressed NFT with these creators
+pub fn hash_compressed_creators(creators: &[CompressedCreator]) -> [u8; 32] {
+    let creator_data = creators
+        .iter()
+        .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
+        .collect::<Vec<_>>();
+    anchor_lang::solana_program::keccak::hashv(
+        &creator_data
+            .iter()
+            .map(|c| c.as_slice())
+            .collect::<Vec<_>>(),
+    )
+    .to_bytes()
+}
 
-        // write discriminator
-        sts.try_borrow_mut_data()?[0..8].copy_from_slice(&SellerTradeStateV2::discriminator());
-        Ok(())
-    } else if sts.try_borrow_data()?[0..8] == SellerTradeStateV2::discriminator() {
-        Ok(())
-    } else {
-        Err(ErrorCode::InvalidAccountState.into())
+/// Builds the Metadata a compressed NFT would have from its notary attested royalty, collection
+/// and creators, so royalties, pauses and sale records treat it like any other token. The asset
+/// id stands in for the mint
+pub fn get_compressed_metadata(
+    asset_id: &Pubkey,
+    seller_fee_basis_points: u16,
+    collection: Option<Pubkey>,
+    creators: &[CompressedCreator],
+) -> Metadata {
+    Metadata {
+        key: MetadataKey::MetadataV1,
+        update_authority: Pubkey::default(),
+        mint: *asset_id,
+        name: String::new(),
+        symbol: String::new(),
+        uri: String::new(),
+        seller_fee_basis_points,
+        creators: Some(
+            creators
+                .iter()
+                .map(|c| Creator {
+                    address: c.address,
+                    verified: c.verified,
+                    share: c.share,
+                })
+                .collect(),
+        ),
+        primary_sale_happened: true,
+        is_mutable: false,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::NonFungible),
+        collection: collection.map(|key| Collection {
+            verified: true,
+            key,
+        }),
+        uses: None,
+        collection_details: None,
+        programmable_config: None,
     }
 }
 
-#[macro_export]
-macro_rules! index_ra {
-    ($iter:ident, $i:expr) => {
-        $iter.get($i).ok_or(ErrorCode::MissingRemainingAccount)?
-    };
+const CORE_ASSET_KEY: u8 = 1; // Key of mpl core accounts
+const CORE_PLUGIN_HEADER_KEY: u8 = 3;
+const CORE_PLUGIN_REGISTRY_KEY: u8 = 4;
+const CORE_COLLECTION_KEY: u8 = 5;
+
+/// Authority of a plugin of an mpl core asset
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum CoreAuthority {
+    None,
+    Owner,
+    UpdateAuthority,
+    Address { address: Pubkey },
 }
 
-pub fn create_or_realloc_buyer_trade_state<'a>(
-    bts: &AccountInfo<'a>,
-    payer: &AccountInfo<'a>,
-    bts_seeds: &[&[u8]],
-) -> Result<()> {
-    let rent = Rent::get()?;
-    let required_lamports = rent
-        .minimum_balance(BuyerTradeStateV2::LEN)
-        .saturating_sub(bts.lamports());
-    if bts.data_is_empty() {
-        // brand new account, need to create it with correct length
-        invoke_signed(
-            &system_instruction::create_account(
-                payer.key,
-                bts.key,
-                required_lamports,
-                BuyerTradeStateV2::LEN as u64,
-                &crate::ID,
-            ),
-            &[payer.clone(), bts.clone()],
-            &[bts_seeds],
-        )?;
+#[derive(AnchorSerialize, AnchorDeserialize)]
+enum CoreUpdateAuthority {
+    None,
+    Address(Pubkey),
+    Collection(Pubkey),
+}
 
-        bts.try_borrow_mut_data()?[..8].copy_from_slice(&BuyerTradeStateV2::discriminator());
-        Ok(())
-    } else if bts.data_len() == BuyerTradeState::LEN {
-        // old buyer trade state that we want to migrate
-        // zero out original data
-        bts.try_borrow_mut_data()?
-            .copy_from_slice(&[0; BuyerTradeState::LEN]);
-        // reallocate new space
-        bts.realloc(BuyerTradeStateV2::LEN, true)?;
-        // transfer lamports so become rent exempt
-        if required_lamports > 0 {
-            invoke(
-                &system_instruction::transfer(payer.key, bts.key, required_lamports),
-                &[payer.clone(), bts.clone()],
-            )?;
-        }
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CoreBaseAsset {
+    key: u8,
+    owner: Pubkey,
+    update_authority: CoreUpdateAuthority,
+    name: String,
+    uri: String,
+    seq: Option<u64>,
+}
 
-        // write discriminator
-        bts.try_borrow_mut_data()?[0..8].copy_from_slice(&BuyerTradeStateV2::discriminator());
-        Ok(())
-    } else if bts.try_borrow_data()?[0..8] == BuyerTradeStateV2::discriminator() {
-        Ok(())
-    } else {
-        Err(ErrorCode::InvalidAccountState.into())
-    }
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CoreBaseCollection {
+    key: u8,
+    update_authority: Pubkey,
+    name: String,
+    uri: String,
+    num_minted: u32,
+    current_size: u32,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CorePluginHeader {
+    key: u8,
+    plugin_registry_offset: u64,
+}
 
-    #[test]
-    fn assert_keys_equal_returns_ok_when_keys_are_equal() -> Result<()> {
-        let pubkey = Pubkey::new_from_array([1; 32]);
-        let same_pubkey = Pubkey::new_from_array([1; 32]);
-        assert_keys_equal(&pubkey, &same_pubkey)
-    }
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CoreRegistryRecord {
+    plugin_type: u8,
+    authority: CoreAuthority,
+    offset: u64,
+}
 
-    #[test]
-    fn assert_owned_by_returns_ok_when_given_account_is_owned_by_given_owner() -> Result<()> {
-        let mut lamports: u64 = 1;
-        let mut data = [1];
-        let owner = Pubkey::new_unique();
-        let account = AccountInfo::new(
-            &owner,
-            false,
-            false,
-            &mut lamports,
-            &mut data,
-            &owner,
-            false,
-            4,
-        );
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CorePluginRegistry {
+    key: u8,
+    registry: Vec<CoreRegistryRecord>, // external plugins follow, they don't matter for trading
+}
 
-        assert_owned_by(&account, &owner)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CoreRoyaltyCreator {
+    pub address: Pubkey,
+    pub percentage: u8,
+}
+
+/// Rule set of a royalties plugin, the programs allowed or denied to take part in a transfer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum CoreRuleSet {
+    None,
+    ProgramAllowList(Vec<Pubkey>),
+    ProgramDenyList(Vec<Pubkey>),
+}
+
+/// Royalties plugin of an mpl core asset or collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CoreRoyalties {
+    pub basis_points: u16,
+    pub creators: Vec<CoreRoyaltyCreator>,
+    pub rule_set: CoreRuleSet,
+}
+
+impl CoreRoyalties {
+    /// Royalties with a rule set are enforced, they're always paid in full
+    pub fn is_enforced(&self) -> bool {
+        !matches!(self.rule_set, CoreRuleSet::None)
     }
 
-    #[test]
-    fn assert_initialized_returns_ok_when_account_is_frozen() {
-        let mut buffer = vec![0; spl_token::state::Account::get_packed_len()];
-        let mut lamports: u64 = 1;
-        let owner = Pubkey::new_unique();
-        let spl_token_account = spl_token::state::Account {
-            mint: Pubkey::new_unique(),
-            owner,
-            amount: 1,
-            delegate: COption::None,
-            state: spl_token::state::AccountState::Frozen,
-            is_native: COption::None,
-            delegated_amount: 0,
-            close_authority: COption::None,
+    /// Checks the programs taking part in a fill against the rule set
+    pub fn assert_programs_allowed(&self, programs: &[&Pubkey]) -> Result<()> {
+        let allowed = match &self.rule_set {
+            CoreRuleSet::None => true,
+            CoreRuleSet::ProgramAllowList(list) => programs.iter().all(|p| list.contains(p)),
+            CoreRuleSet::ProgramDenyList(list) => !programs.iter().any(|p| list.contains(p)),
         };
+        if !allowed {
+            return Err(ErrorCode::CoreRuleSetViolation.into());
+        }
+        Ok(())
+    }
+}
 
-        spl_token::state::Account::pack(spl_token_account, &mut buffer)
-            .expect("Could not pack SPL token account into buffer");
+#[derive(Default)]
+pub struct CorePlugins {
+    pub transfer_delegate: Option<CoreAuthority>,
+    pub royalties: Option<CoreRoyalties>,
+}
 
-        let account_info = AccountInfo::new(
-            &owner,
-            false,
-            false,
-            &mut lamports,
-            &mut buffer,
-            &owner,
-            false,
-            4,
-        );
+/// Parts of an mpl core asset that trading depends on
+pub struct CoreAsset {
+    pub owner: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub plugins: CorePlugins,
+}
 
-        match assert_initialized::<spl_token::state::Account>(&account_info) {
-            Ok(result) => assert_eq!(result, spl_token_account),
-            _ => panic!("expected Ok(spl_token_account)"),
+impl CoreAsset {
+    /// Royalties in effect for the asset, its own royalties plugin takes precedence over the
+    /// collection's
+    pub fn get_royalties<'a>(
+        &'a self,
+        collection_royalties: Option<&'a CoreRoyalties>,
+    ) -> Option<&'a CoreRoyalties> {
+        self.plugins.royalties.as_ref().or(collection_royalties)
+    }
+}
+
+fn get_core_plugins(data: &[u8], base_len: usize) -> Result<CorePlugins> {
+    let mut plugins = CorePlugins::default();
+    if data.len() <= base_len {
+        return Ok(plugins);
+    }
+    let header = CorePluginHeader::deserialize(&mut &data[base_len..])
+        .map_err(|_| ErrorCode::InvalidTokenStandard)?;
+    let mut registry_data = data
+        .get(header.plugin_registry_offset as usize..)
+        .ok_or(ErrorCode::InvalidTokenStandard)?;
+    let registry = CorePluginRegistry::deserialize(&mut registry_data)
+        .map_err(|_| ErrorCode::InvalidTokenStandard)?;
+    if header.key != CORE_PLUGIN_HEADER_KEY || registry.key != CORE_PLUGIN_REGISTRY_KEY {
+        return Err(ErrorCode::InvalidTokenStandard.into());
+    }
+    for record in registry.registry {
+        match record.plugin_type {
+            CORE_TRANSFER_DELEGATE_PLUGIN => plugins.transfer_delegate = Some(record.authority),
+            CORE_ROYALTIES_PLUGIN => {
+                // plugins are stored as the Plugin enum of mpl core, which starts with Royalties
+                let mut plugin_data = data
+                    .get(record.offset as usize..)
+                    .ok_or(ErrorCode::InvalidTokenStandard)?;
+                if u8::deserialize(&mut plugin_data).ok() != Some(CORE_ROYALTIES_PLUGIN) {
+                    return Err(ErrorCode::InvalidTokenStandard.into());
+                }
+                plugins.royalties = Some(
+                    CoreRoyalties::deserialize(&mut plugin_data)
+                        .map_err(|_| ErrorCode::InvalidTokenStandard)?,
+                );
+            }
+            _ => {}
         }
     }
+    Ok(plugins)
+}
 
-    #[test]
-    fn assert_is_ata_returns_ok_when_account_is_ata() -> Result<()> {
-        let mut buffer = vec![0; spl_token::state::Account::get_packed_len()];
-        let mut lamports: u64 = 1;
-        let owner = spl_token::id();
-        let mint = Pubkey::new_unique();
-        let spl_token_account = spl_token::state::Account {
-            mint,
+pub fn get_core_asset(data: &[u8]) -> Result<CoreAsset> {
+    let mut base_data = data;
+    let base =
+        CoreBaseAsset::deserialize(&mut base_data).map_err(|_| ErrorCode::InvalidTokenStandard)?;
+    if base.key != CORE_ASSET_KEY {
+        return Err(ErrorCode::InvalidTokenStandard.into());
+    }
+    Ok(CoreAsset {
+        owner: base.owner,
+        collection: match base.update_authority {
+            CoreUpdateAuthority::Collection(collection) => Some(collection),
+            _ => None,
+        },
+        plugins: get_core_plugins(data, data.len() - base_data.len())?,
+    })
+}
+
+/// Counterpart of check_programmable for mpl core assets, which don't have a mint or metadata
+pub fn check_core_asset(asset: &AccountInfo) -> Result<CoreAsset> {
+    if *asset.owner != MPL_CORE_PROGRAM_ID {
+        return Err(ErrorCode::InvalidTokenStandard.into());
+    }
+    get_core_asset(&asset.data.borrow())
+}
+
+/// Checks the collection account passed along with a core asset, which is the core program itself
+/// for assets without a collection, and returns the royalties of the collection
+pub fn get_core_collection_royalties(
+    collection: &AccountInfo,
+    asset: &CoreAsset,
+) -> Result<Option<CoreRoyalties>> {
+    let collection_key = match asset.collection {
+        Some(collection_key) => collection_key,
+        None => {
+            assert_keys_equal(collection.key, &MPL_CORE_PROGRAM_ID)?;
+            return Ok(None);
+        }
+    };
+    assert_keys_equal(collection.key, &collection_key)?;
+    if *collection.owner != MPL_CORE_PROGRAM_ID {
+        return Err(ErrorCode::InvalidTokenStandard.into());
+    }
+    let data = collection.data.borrow();
+    let mut base_data = &data[..];
+    let base = CoreBaseCollection::deserialize(&mut base_data)
+        .map_err(|_| ErrorCode::InvalidTokenStandard)?;
+    if base.key != CORE_COLLECTION_KEY {
+        return Err(ErrorCode::InvalidTokenStandard.into());
+    }
+    Ok(get_core_plugins(&data, data.len() - base_data.len())?.royalties)
+}
+
+/// Metadata standing in for the metadata account of a core asset in fee distribution, with the
+/// asset's royalties plugin taking precedence over the collection's
+pub fn get_core_metadata(
+    asset_key: &Pubkey,
+    asset: &CoreAsset,
+    collection_royalties: Option<&CoreRoyalties>,
+) -> Metadata {
+    let (seller_fee_basis_points, creators) = match asset.get_royalties(collection_royalties) {
+        Some(royalties) => (
+            royalties.basis_points,
+            royalties
+                .creators
+                .iter()
+                .map(|c| CompressedCreator {
+                    address: c.address,
+                    verified: true,
+                    share: c.percentage,
+                })
+                .collect(),
+        ),
+        None => (0, vec![]),
+    };
+    get_compressed_metadata(
+        asset_key,
+        seller_fee_basis_points,
+        asset.collection,
+        &creators,
+    )
+}
+
+/// Returns the share of the creator fee a core sale pays. Enforced royalties are paid in full
+/// whatever the bid offered, and the programs owning the transfer authority and the new owner have
+/// to satisfy their rule set, the same check the core program makes on the transfer.
+pub fn get_core_royalty_bp(
+    asset: &CoreAsset,
+    collection_royalties: Option<&CoreRoyalties>,
+    buyer_creator_royalty_bp: u16,
+    programs: &[&Pubkey],
+) -> Result<u16> {
+    match asset.get_royalties(collection_royalties) {
+        Some(royalties) if royalties.is_enforced() => {
+            royalties.assert_programs_allowed(programs)?;
+            Ok(10_000)
+        }
+        _ => Ok(buyer_creator_royalty_bp),
+    }
+}
+
+/// Returns the verified collection of the token, or the token mint itself if there's none
+pub fn get_collection_key(metadata_parsed: &Metadata) -> Pubkey {
+    match &metadata_parsed.collection {
+        Some(c) if c.verified => c.key,
+        _ => metadata_parsed.mint,
+    }
+}
+
+// TLV layout of Token-2022 mints. spl-token-2022 0.9 predates the group extensions, so their
+// entries are read by hand
+const T22_ACCOUNT_TYPE_OFFSET: usize = 165; // mints with extensions are padded to a token account
+const T22_ACCOUNT_TYPE_MINT: u8 = 1;
+const T22_GROUP_MEMBER_POINTER_EXTENSION: u16 = 22; // { authority, member_address }
+const T22_TOKEN_GROUP_MEMBER_EXTENSION: u16 = 23; // { mint, group, member_number }
+
+fn get_t22_extension_data(data: &[u8], extension_type: u16) -> Option<&[u8]> {
+    if data.get(T22_ACCOUNT_TYPE_OFFSET) != Some(&T22_ACCOUNT_TYPE_MINT) {
+        return None;
+    }
+    let mut offset = T22_ACCOUNT_TYPE_OFFSET + 1;
+    while let Some(header) = data.get(offset..offset + 4) {
+        let entry_type = u16::from_le_bytes([header[0], header[1]]);
+        let len = u16::from_le_bytes([header[2], header[3]]) as usize;
+        if entry_type == 0 {
+            return None; // the rest is uninitialized
+        }
+        if entry_type == extension_type {
+            return data.get(offset + 4..offset + 4 + len);
+        }
+        offset += 4 + len;
+    }
+    None
+}
+
+/// Returns the token group of a Token-2022 mint from its raw data, None if it isn't a member of
+/// one. Only a TokenGroupMember on the mint itself counts: Token-2022 initializes it with the
+/// group's update authority signing, the same guarantee as a verified Metaplex collection, while
+/// a member account kept by another program proves nothing
+pub fn get_t22_group_from_data(mint: &Pubkey, data: &[u8]) -> Option<Pubkey> {
+    let pointer = get_t22_extension_data(data, T22_GROUP_MEMBER_POINTER_EXTENSION)?;
+    if pointer.get(32..64)? != mint.as_ref() {
+        return None;
+    }
+    let member = get_t22_extension_data(data, T22_TOKEN_GROUP_MEMBER_EXTENSION)?;
+    if member.len() < 64 || &member[0..32] != mint.as_ref() {
+        return None;
+    }
+    Some(Pubkey::new_from_array(*array_ref![member, 32, 32]))
+}
+
+/// Uses the token group of a Token-2022 mint as the verified collection of tokens without a
+/// verified Metaplex collection, so collection bids, pauses, ticks, price bands, stats and royalty
+/// subsidies treat group members like the tokens of a collection. Does nothing for other mints
+pub fn apply_t22_group_collection(
+    metadata_parsed: &mut Metadata,
+    mint: &AccountInfo,
+) -> Result<()> {
+    if mint.owner != &anchor_spl::token_2022::ID
+        || matches!(&metadata_parsed.collection, Some(c) if c.verified)
+    {
+        return Ok(());
+    }
+    if let Some(group) = get_t22_group_from_data(mint.key, &mint.try_borrow_data()?) {
+        metadata_parsed.collection = Some(Collection {
+            verified: true,
+            key: group,
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_collection_not_paused(
+    collection_config: &AccountInfo,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+) -> Result<()> {
+    assert_collection_key_not_paused(
+        collection_config,
+        auction_house_key,
+        &get_collection_key(metadata_parsed),
+    )
+}
+
+/// Checks that the collection isn't paused on the auction house. collection_config is required,
+/// a CollectionConfig that doesn't exist yet means the collection isn't paused
+pub fn assert_collection_key_not_paused(
+    collection_config: &AccountInfo,
+    auction_house_key: &Pubkey,
+    collection: &Pubkey,
+) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        collection_config,
+        &[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+            collection.as_ref(),
+        ],
+    )?;
+    if collection_config.data_is_empty() || collection_config.owner != &crate::ID {
+        return Ok(());
+    }
+    let config = CollectionConfig::try_deserialize(&mut &collection_config.data.borrow()[..])?;
+    if config.paused {
+        return Err(ErrorCode::CollectionPaused.into());
+    }
+    Ok(())
+}
+
+/// Returns the tick size of the collection from its CollectionConfig, 0 if it has none.
+/// collection_config is required, a CollectionConfig that doesn't exist yet has no tick
+pub fn get_collection_tick_size(
+    collection_config: &AccountInfo,
+    auction_house_key: &Pubkey,
+    collection: &Pubkey,
+) -> Result<u64> {
+    assert_derivation(
+        &crate::ID,
+        collection_config,
+        &[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+            collection.as_ref(),
+        ],
+    )?;
+    if collection_config.data_is_empty() || collection_config.owner != &crate::ID {
+        return Ok(0);
+    }
+    Ok(CollectionConfig::try_deserialize(&mut &collection_config.data.borrow()[..])?.tick_size)
+}
+
+/// Checks that price is a multiple of tick_size, a tick_size of 0 accepts any price
+pub fn assert_price_on_tick(price: u64, tick_size: u64) -> Result<()> {
+    if tick_size > 0 && !price.is_multiple_of(tick_size) {
+        return Err(ErrorCode::PriceOffTick.into());
+    }
+    Ok(())
+}
+
+/// Checks that a SOL price sits on the tick of the collection. SPL prices aren't held to the
+/// tick, which is in lamports, but to the precision of their mint by assert_price_precision
+pub fn assert_collection_price_on_tick(
+    collection_config: &AccountInfo,
+    auction_house_key: &Pubkey,
+    collection: &Pubkey,
+    price: u64,
+) -> Result<()> {
+    assert_price_on_tick(
+        price,
+        get_collection_tick_size(collection_config, auction_house_key, collection)?,
+    )
+}
+
+/// Returns the hold period of the collection from its CollectionConfig, 0 if it has none
+pub fn get_collection_hold_period(
+    collection_config: &AccountInfo,
+    auction_house_key: &Pubkey,
+    collection: &Pubkey,
+) -> Result<i64> {
+    assert_derivation(
+        &crate::ID,
+        collection_config,
+        &[
+            PREFIX.as_bytes(),
+            COLLECTION_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+            collection.as_ref(),
+        ],
+    )?;
+    if collection_config.data_is_empty() || collection_config.owner != &crate::ID {
+        return Ok(0);
+    }
+    Ok(CollectionConfig::try_deserialize(&mut &collection_config.data.borrow()[..])?.hold_period)
+}
+
+/// Returns the RoyaltySubsidy of the token's collection if it's running at now and has budget left
+pub fn get_active_royalty_subsidy(
+    royalty_subsidy: &AccountInfo,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+    now: i64,
+) -> Result<Option<RoyaltySubsidy>> {
+    assert_derivation(
+        &crate::ID,
+        royalty_subsidy,
+        &[
+            PREFIX.as_bytes(),
+            ROYALTY_SUBSIDY.as_bytes(),
+            auction_house_key.as_ref(),
+            get_collection_key(metadata_parsed).as_ref(),
+        ],
+    )?;
+    if royalty_subsidy.data_is_empty() || royalty_subsidy.owner != &crate::ID {
+        return Ok(None);
+    }
+    let subsidy = RoyaltySubsidy::try_deserialize(&mut &royalty_subsidy.data.borrow()[..])?;
+    Ok(if subsidy.is_active(now) {
+        Some(subsidy)
+    } else {
+        None
+    })
+}
+
+/// Returns the CustodialPlatform registered for the buyer, which has to be enabled
+pub fn get_custodial_platform(
+    custodial_platform: &AccountInfo,
+    auction_house_key: &Pubkey,
+    buyer: &Pubkey,
+) -> Result<CustodialPlatform> {
+    assert_derivation(
+        &crate::ID,
+        custodial_platform,
+        &[
+            PREFIX.as_bytes(),
+            CUSTODIAL_PLATFORM.as_bytes(),
+            auction_house_key.as_ref(),
+            buyer.as_ref(),
+        ],
+    )?;
+    if custodial_platform.data_is_empty() || custodial_platform.owner != &crate::ID {
+        return Err(ErrorCode::InvalidCustodialPlatform.into());
+    }
+    let platform = CustodialPlatform::try_deserialize(&mut &custodial_platform.data.borrow()[..])?;
+    if !platform.enabled {
+        return Err(ErrorCode::InvalidCustodialPlatform.into());
+    }
+    Ok(platform)
+}
+
+/// Returns the price of a listing after an auto renewal raised it by bump_bp
+pub fn get_renewed_price(price: u64, bump_bp: u16) -> Result<u64> {
+    let renewed_price = (price as u128)
+        .checked_mul(10000 + bump_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        / 10000;
+    if renewed_price > MAX_PRICE as u128 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    Ok(renewed_price as u64)
+}
+
+pub fn is_within_hold_period(sold_at: i64, hold_period: i64, now: i64) -> bool {
+    hold_period > 0 && now < sold_at.saturating_add(hold_period)
+}
+
+/// Checks that wallet didn't buy the token within the hold period of its collection, from the
+/// LastSale of the token. Tokens that were never sold through the program can always be listed.
+/// collection_config and last_sale are required, whether the collection has a hold period or not
+pub fn assert_hold_period_elapsed(
+    collection_config: &AccountInfo,
+    last_sale: &AccountInfo,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+    wallet: &Pubkey,
+) -> Result<()> {
+    let hold_period = get_collection_hold_period(
+        collection_config,
+        auction_house_key,
+        &get_collection_key(metadata_parsed),
+    )?;
+    assert_derivation(
+        &crate::ID,
+        last_sale,
+        &[
+            PREFIX.as_bytes(),
+            LAST_SALE.as_bytes(),
+            metadata_parsed.mint.as_ref(),
+        ],
+    )?;
+    if hold_period == 0 || last_sale.data_is_empty() || last_sale.owner != &crate::ID {
+        return Ok(());
+    }
+    let last_sale = LastSale::try_deserialize(&mut &last_sale.data.borrow()[..])?;
+    if last_sale.buyer == *wallet
+        && is_within_hold_period(last_sale.sold_at, hold_period, Clock::get()?.unix_timestamp)
+    {
+        return Err(ErrorCode::HoldPeriodNotElapsed.into());
+    }
+    Ok(())
+}
+
+/// Builds the yield vault instruction sweep_treasury invokes with the auction house treasury as
+/// signer: a deposit of `amount` lamports of the treasury, minting shares into the
+/// treasury_share_account, or a withdrawal burning `amount` shares out of it and paying the
+/// lamports back to the treasury. Only the vault accounts that aren't the treasury's come from the
+/// caller, and the vault program checks them against its vault_state
+///
+/// # Arguments
+/// * `vault_accounts` - for Marinade deposits liq_pool_sol_leg_pda, liq_pool_msol_leg,
+///   liq_pool_msol_leg_authority, reserve_pda and msol_mint_authority, for Marinade withdrawals
+///   liq_pool_sol_leg_pda, liq_pool_msol_leg and treasury_msol_account. For stake pool deposits
+///   withdraw_authority, reserve_stake and manager_fee_account, withdrawals add the clock and
+///   stake_history sysvars and the stake program
+#[allow(clippy::too_many_arguments)]
+pub fn get_treasury_vault_instruction(
+    vault_program: &Pubkey,
+    deposit: bool,
+    amount: u64,
+    vault_state: &Pubkey,
+    share_mint: &Pubkey,
+    auction_house_treasury: &Pubkey,
+    treasury_share_account: &Pubkey,
+    vault_accounts: &[Pubkey],
+) -> Result<Instruction> {
+    let vault_account = |index: usize| -> Result<Pubkey> {
+        vault_accounts
+            .get(index)
+            .copied()
+            .ok_or_else(|| ErrorCode::MissingRemainingAccount.into())
+    };
+    let (accounts, data) = if *vault_program == YIELD_VAULT_PROGRAM_WHITELIST[0] {
+        let (accounts, discriminator) = if deposit {
+            (
+                vec![
+                    AccountMeta::new(*vault_state, false),
+                    AccountMeta::new(*share_mint, false),
+                    AccountMeta::new(vault_account(0)?, false),
+                    AccountMeta::new(vault_account(1)?, false),
+                    AccountMeta::new_readonly(vault_account(2)?, false),
+                    AccountMeta::new(vault_account(3)?, false),
+                    AccountMeta::new(*auction_house_treasury, true),
+                    AccountMeta::new(*treasury_share_account, false),
+                    AccountMeta::new_readonly(vault_account(4)?, false),
+                    AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                ],
+                MARINADE_DEPOSIT_IX_DISCRIMINATOR,
+            )
+        } else {
+            (
+                vec![
+                    AccountMeta::new(*vault_state, false),
+                    AccountMeta::new(*share_mint, false),
+                    AccountMeta::new(vault_account(0)?, false),
+                    AccountMeta::new(vault_account(1)?, false),
+                    AccountMeta::new(vault_account(2)?, false),
+                    AccountMeta::new(*treasury_share_account, false),
+                    AccountMeta::new(*auction_house_treasury, true),
+                    AccountMeta::new(*auction_house_treasury, true),
+                    AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                ],
+                MARINADE_LIQUID_UNSTAKE_IX_DISCRIMINATOR,
+            )
+        };
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+        (accounts, data)
+    } else if *vault_program == YIELD_VAULT_PROGRAM_WHITELIST[1] {
+        let (accounts, tag) = if deposit {
+            (
+                vec![
+                    AccountMeta::new(*vault_state, false),
+                    AccountMeta::new_readonly(vault_account(0)?, false),
+                    AccountMeta::new(vault_account(1)?, false),
+                    AccountMeta::new(*auction_house_treasury, true),
+                    AccountMeta::new(*treasury_share_account, false),
+                    AccountMeta::new(vault_account(2)?, false),
+                    // the treasury is its own referrer, so referral fees come back to it
+                    AccountMeta::new(*treasury_share_account, false),
+                    AccountMeta::new(*share_mint, false),
+                    AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                ],
+                STAKE_POOL_DEPOSIT_SOL_IX,
+            )
+        } else {
+            assert_keys_equal(&vault_account(3)?, &solana_program::sysvar::clock::ID)?;
+            assert_keys_equal(
+                &vault_account(4)?,
+                &solana_program::sysvar::stake_history::ID,
+            )?;
+            assert_keys_equal(&vault_account(5)?, &solana_program::stake::program::ID)?;
+            (
+                vec![
+                    AccountMeta::new(*vault_state, false),
+                    AccountMeta::new_readonly(vault_account(0)?, false),
+                    AccountMeta::new_readonly(*auction_house_treasury, true),
+                    AccountMeta::new(*treasury_share_account, false),
+                    AccountMeta::new(vault_account(1)?, false),
+                    AccountMeta::new(*auction_house_treasury, true),
+                    AccountMeta::new(vault_account(2)?, false),
+                    AccountMeta::new(*share_mint, false),
+                    AccountMeta::new_readonly(vault_account(3)?, false),
+                    AccountMeta::new_readonly(vault_account(4)?, false),
+                    AccountMeta::new_readonly(vault_account(5)?, false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                ],
+                STAKE_POOL_WITHDRAW_SOL_IX,
+            )
+        };
+        let mut data = vec![tag];
+        data.extend_from_slice(&amount.to_le_bytes());
+        (accounts, data)
+    } else {
+        return Err(ErrorCode::InvalidYieldVaultProgram.into());
+    };
+    Ok(Instruction {
+        program_id: *vault_program,
+        accounts,
+        data,
+    })
+}
+
+/// Returns the share balance of the account sweep_treasury mints yield vault shares into, which
+/// has to be the ata of the auction house treasury for the share mint, with no delegate or close
+/// authority, so only the treasury can move the shares
+pub fn get_treasury_share_balance(
+    treasury_share_account: &AccountInfo,
+    auction_house_treasury: &Pubkey,
+    share_mint: &Pubkey,
+) -> Result<u64> {
+    let account = assert_is_ata(
+        treasury_share_account,
+        auction_house_treasury,
+        share_mint,
+        auction_house_treasury,
+    )?;
+    if account.delegate.is_some() || account.close_authority.is_some() {
+        return Err(ErrorCode::InvalidAccountState.into());
+    }
+    Ok(account.amount)
+}
+
+// sales of a collection with an enabled PriceBandConfig need a PriceAttestation of the token that is
+// younger than max_age and whose band contains the price
+pub fn assert_price_attested(
+    price_band_config: &AccountInfo,
+    price_attestation: Option<&AccountInfo>,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+    payment_mint: &Pubkey,
+    price: u64,
+) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        price_band_config,
+        &[
+            PREFIX.as_bytes(),
+            PRICE_BAND_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+            get_collection_key(metadata_parsed).as_ref(),
+        ],
+    )?;
+    if price_band_config.data_is_empty() || price_band_config.owner != &crate::ID {
+        return Ok(());
+    }
+    let config = PriceBandConfig::try_deserialize(&mut &price_band_config.data.borrow()[..])?;
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let price_attestation = price_attestation.ok_or(ErrorCode::PriceOutsideAttestedBand)?;
+    assert_derivation(
+        &crate::ID,
+        price_attestation,
+        &[
+            PREFIX.as_bytes(),
+            PRICE_ATTESTATION.as_bytes(),
+            auction_house_key.as_ref(),
+            metadata_parsed.mint.as_ref(),
+        ],
+    )?;
+    if price_attestation.data_is_empty() || price_attestation.owner != &crate::ID {
+        return Err(ErrorCode::PriceOutsideAttestedBand.into());
+    }
+    let attestation = PriceAttestation::try_deserialize(&mut &price_attestation.data.borrow()[..])?;
+    if attestation.payment_mint != *payment_mint
+        || Clock::get()?
+            .unix_timestamp
+            .saturating_sub(attestation.attested_at)
+            > config.max_age
+        || price < attestation.min_price
+        || price > attestation.max_price
+    {
+        return Err(ErrorCode::PriceOutsideAttestedBand.into());
+    }
+    Ok(())
+}
+
+// checks that recipient is the fallback royalty recipient of the token's collection, the
+// royalty_fallback account has to be the collection's RoyaltyFallback PDA
+pub fn assert_royalty_fallback(
+    royalty_fallback: &AccountInfo,
+    recipient: &AccountInfo,
+    metadata_parsed: &Metadata,
+) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        royalty_fallback,
+        &[
+            PREFIX.as_bytes(),
+            ROYALTY_FALLBACK.as_bytes(),
+            get_collection_key(metadata_parsed).as_ref(),
+        ],
+    )?;
+    assert_owned_by(royalty_fallback, &crate::ID)?;
+    let fallback = RoyaltyFallback::try_deserialize(&mut &royalty_fallback.data.borrow()[..])?;
+    assert_keys_equal(&fallback.recipient, recipient.key)
+}
+
+pub fn assert_seller_not_suspended(seller_status: &AccountInfo, seller: &Pubkey) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        seller_status,
+        &[PREFIX.as_bytes(), SELLER_STATUS.as_bytes(), seller.as_ref()],
+    )?;
+    if seller_status.data_is_empty() || seller_status.owner != &crate::ID {
+        return Ok(());
+    }
+    let status = SellerStatus::try_deserialize(&mut &seller_status.data.borrow()[..])?;
+    if status.suspended {
+        return Err(ErrorCode::SellerSuspended.into());
+    }
+    Ok(())
+}
+
+pub fn assert_buyer_not_suspended(buyer_status: &AccountInfo, buyer: &Pubkey) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        buyer_status,
+        &[PREFIX.as_bytes(), BUYER_STATUS.as_bytes(), buyer.as_ref()],
+    )?;
+    if buyer_status.data_is_empty() || buyer_status.owner != &crate::ID {
+        return Ok(());
+    }
+    let status = BuyerStatus::try_deserialize(&mut &buyer_status.data.borrow()[..])?;
+    if status.suspended {
+        return Err(ErrorCode::BuyerSuspended.into());
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum OrderSide {
+    Listing,
+    Bid,
+}
+
+/// Counts a new open order of wallet on its WalletOrderCounter, creating the counter if needed,
+/// and fails with OpenOrderLimitReached if the wallet already has as many open orders of the side
+/// as the OrderLimitConfig of the auction house allows. order_limit_config is required, orders
+/// aren't counted while the auction house has no OrderLimitConfig or no limit for the side. A
+/// counted order gets a CountedOrder, which uncount_open_order closes, and is only counted once
+///
+/// # Arguments
+/// * `order_limit_config` - OrderLimitConfig pda of the auction house, may not exist yet
+/// * `wallet_order_counter` - WalletOrderCounter pda of the wallet on the auction house, required if the side has a limit
+/// * `counted_order` - CountedOrder pda of the trade state, required if the side has a limit
+/// * `trade_state` - Trade state of the order
+/// * `payer` - Payer account, will pay for rent if the counter or the CountedOrder need to be created
+/// * `auction_house_key` - Auction house of the order
+/// * `wallet` - Seller of the listing or buyer of the bid
+/// * `side` - Side of the order
+#[allow(clippy::too_many_arguments)]
+pub fn count_open_order<'a>(
+    order_limit_config: &AccountInfo<'a>,
+    wallet_order_counter: Option<&AccountInfo<'a>>,
+    counted_order: Option<&AccountInfo<'a>>,
+    trade_state: &Pubkey,
+    payer: &AccountInfo<'a>,
+    auction_house_key: &Pubkey,
+    wallet: &Pubkey,
+    side: OrderSide,
+) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        order_limit_config,
+        &[
+            PREFIX.as_bytes(),
+            ORDER_LIMIT_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+        ],
+    )?;
+    if order_limit_config.data_is_empty() || order_limit_config.owner != &crate::ID {
+        return Ok(());
+    }
+    let config = OrderLimitConfig::try_deserialize(&mut &order_limit_config.data.borrow()[..])?;
+    let max_open_orders = match side {
+        OrderSide::Listing => config.max_open_listings,
+        OrderSide::Bid => config.max_open_bids,
+    };
+    if max_open_orders == 0 {
+        return Ok(());
+    }
+    let wallet_order_counter = wallet_order_counter.ok_or(ErrorCode::MissingRemainingAccount)?;
+    let counted_order = counted_order.ok_or(ErrorCode::MissingRemainingAccount)?;
+
+    let counted_order_seeds: &[&[u8]] = &[
+        PREFIX.as_bytes(),
+        COUNTED_ORDER.as_bytes(),
+        trade_state.as_ref(),
+    ];
+    let counted_order_bump = assert_derivation(&crate::ID, counted_order, counted_order_seeds)?;
+    if is_counted_order(counted_order) {
+        return Ok(());
+    }
+    let counter_seeds: &[&[u8]] = &[
+        PREFIX.as_bytes(),
+        WALLET_ORDER_COUNTER.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet.as_ref(),
+    ];
+    let counter_bump = assert_derivation(&crate::ID, wallet_order_counter, counter_seeds)?;
+    let mut counter: WalletOrderCounter = load_or_create_program_account(
+        wallet_order_counter,
+        payer,
+        WalletOrderCounter::LEN,
+        &[counter_seeds, &[&[counter_bump]]].concat(),
+    )?;
+    counter.auction_house_key = *auction_house_key;
+    counter.wallet = *wallet;
+    counter.bump = counter_bump;
+    let open_orders = match side {
+        OrderSide::Listing => &mut counter.open_listings,
+        OrderSide::Bid => &mut counter.open_bids,
+    };
+    if *open_orders >= max_open_orders {
+        return Err(ErrorCode::OpenOrderLimitReached.into());
+    }
+    *open_orders += 1;
+    save_program_account(wallet_order_counter, &counter)?;
+
+    let _: CountedOrder = load_or_create_program_account(
+        counted_order,
+        payer,
+        CountedOrder::LEN,
+        &[counted_order_seeds, &[&[counted_order_bump]]].concat(),
+    )?;
+    save_program_account(
+        counted_order,
+        &CountedOrder {
+            trade_state: *trade_state,
+            bump: counted_order_bump,
+        },
+    )
+}
+
+/// Uncounts a closed order of wallet on its WalletOrderCounter and closes its CountedOrder into the
+/// wallet. counted_order is required, orders without a CountedOrder were never counted, e.g.
+/// opened before the auction house had a limit, and leave the counter alone
+///
+/// # Arguments
+/// * `wallet_order_counter` - WalletOrderCounter pda of the wallet on the auction house, required if the order is counted
+/// * `counted_order` - CountedOrder pda of the trade state
+/// * `trade_state` - Trade state of the closed order
+/// * `wallet` - Seller of the listing or buyer of the bid, receives the rent of the CountedOrder
+/// * `auction_house_key` - Auction house of the order
+/// * `side` - Side of the order
+pub fn uncount_open_order<'a>(
+    wallet_order_counter: Option<&AccountInfo<'a>>,
+    counted_order: &AccountInfo<'a>,
+    trade_state: &Pubkey,
+    wallet: &AccountInfo<'a>,
+    auction_house_key: &Pubkey,
+    side: OrderSide,
+) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        counted_order,
+        &[
+            PREFIX.as_bytes(),
+            COUNTED_ORDER.as_bytes(),
+            trade_state.as_ref(),
+        ],
+    )?;
+    if !is_counted_order(counted_order) {
+        return Ok(());
+    }
+    let wallet_order_counter = wallet_order_counter.ok_or(ErrorCode::MissingRemainingAccount)?;
+    assert_derivation(
+        &crate::ID,
+        wallet_order_counter,
+        &[
+            PREFIX.as_bytes(),
+            WALLET_ORDER_COUNTER.as_bytes(),
+            auction_house_key.as_ref(),
+            wallet.key.as_ref(),
+        ],
+    )?;
+    assert_owned_by(wallet_order_counter, &crate::ID)?;
+    let mut counter =
+        WalletOrderCounter::try_deserialize(&mut &wallet_order_counter.data.borrow()[..])?;
+    let open_orders = match side {
+        OrderSide::Listing => &mut counter.open_listings,
+        OrderSide::Bid => &mut counter.open_bids,
+    };
+    *open_orders = open_orders
+        .checked_sub(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    save_program_account(wallet_order_counter, &counter)?;
+    close_account_anchor(counted_order, wallet)
+}
+
+/// Upserts entry into the MintListingIndex of token_mint, or without an entry removes
+/// seller_trade_state from it. An index that doesn't exist yet is left to sync_mint_listing_index,
+/// a full one evicts an entry, see MintListingIndex::upsert
+pub fn update_mint_listing_index(
+    mint_listing_index: &AccountInfo,
+    token_mint: &Pubkey,
+    seller_trade_state: &Pubkey,
+    entry: Option<MintListingEntry>,
+) -> Result<()> {
+    assert_derivation(
+        &crate::ID,
+        mint_listing_index,
+        &[
+            PREFIX.as_bytes(),
+            MINT_LISTING_INDEX.as_bytes(),
+            token_mint.as_ref(),
+        ],
+    )?;
+    if mint_listing_index.owner != &crate::ID || mint_listing_index.data_is_empty() {
+        return Ok(());
+    }
+    let mut index = MintListingIndex::try_deserialize(&mut &mint_listing_index.data.borrow()[..])?;
+    let now = Clock::get()?.unix_timestamp;
+    match entry {
+        Some(entry) => index.upsert(entry, now),
+        None => index.remove(seller_trade_state),
+    }
+    index.updated_at = now;
+    save_program_account(mint_listing_index, &index)
+}
+
+fn is_counted_order(counted_order: &AccountInfo) -> bool {
+    counted_order.owner == &crate::ID
+        && counted_order.data_len() >= 8
+        && counted_order.data.borrow()[..8] == CountedOrder::discriminator()
+}
+
+/// Whether a listing is still backed by its token account: the token can only leave the account
+/// through the program, or the seller still holds the listed size with program_as_signer as the
+/// delegate of it. SPL Token and Token-2022 accounts are read alike, t22_sell listings are
+/// delegated the same way as legacy ones
+pub fn is_listing_backed(
+    token_account: &AccountInfo,
+    sell_args: &SellArgs,
+    program_as_signer: &Pubkey,
+) -> Result<bool> {
+    if token_account.data_is_empty()
+        || (token_account.owner != &spl_token::id()
+            && token_account.owner != &anchor_spl::token_2022::ID)
+    {
+        return Ok(false);
+    }
+    let account = anchor_spl::token_interface::TokenAccount::try_deserialize(
+        &mut &token_account.data.borrow()[..],
+    )?;
+    if account.mint != sell_args.token_mint || account.amount < sell_args.token_size {
+        return Ok(false);
+    }
+    if account.owner == *program_as_signer {
+        return Ok(true);
+    }
+    Ok(account.owner == sell_args.seller
+        && account.delegate == COption::Some(*program_as_signer)
+        && account.delegated_amount >= sell_args.token_size)
+}
+
+/// Slashes CRANK_BOND_LAMPORTS out of the CrankBond of a permissionless crank call that turned out
+/// to be invalid, paying them to the party whose account was cranked. The call itself succeeds so
+/// the slash sticks. The cranks only take a bond that covers the slash.
+pub fn slash_crank_bond<'info>(
+    crank_bond: &mut Account<'info, CrankBond>,
+    recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    crank_bond.amount = crank_bond
+        .amount
+        .checked_sub(CRANK_BOND_LAMPORTS)
+        .ok_or(ErrorCode::InsufficientCrankBond)?;
+    let crank_bond_info = crank_bond.to_account_info();
+    **crank_bond_info.lamports.borrow_mut() = crank_bond_info
+        .lamports()
+        .checked_sub(CRANK_BOND_LAMPORTS)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    **recipient.lamports.borrow_mut() = recipient
+        .lamports()
+        .checked_add(CRANK_BOND_LAMPORTS)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    msg!(
+        "crank_bond_slashed: {{\"cranker\":\"{}\",\"recipient\":\"{}\",\"amount\":{},\"bond\":{}}}",
+        crank_bond.cranker,
+        recipient.key,
+        CRANK_BOND_LAMPORTS,
+        crank_bond.amount,
+    );
+    Ok(())
+}
+
+/// Returns true if operator signed and is an enabled ListingOperator of the seller
+pub fn is_listing_operator(
+    listing_operator: &AccountInfo,
+    operator: &AccountInfo,
+    seller: &Pubkey,
+) -> Result<bool> {
+    if !operator.is_signer {
+        return Ok(false);
+    }
+    assert_derivation(
+        &crate::ID,
+        listing_operator,
+        &[
+            PREFIX.as_bytes(),
+            LISTING_OPERATOR.as_bytes(),
+            seller.as_ref(),
+            operator.key.as_ref(),
+        ],
+    )?;
+    if listing_operator.data_is_empty() || listing_operator.owner != &crate::ID {
+        return Ok(false);
+    }
+    let listing_operator =
+        ListingOperator::try_deserialize(&mut &listing_operator.data.borrow()[..])?;
+    Ok(listing_operator.enabled)
+}
+
+pub fn close_account_anchor(info: &AccountInfo, dest: &AccountInfo) -> Result<()> {
+    let curr_lamp = info.lamports();
+    **info.lamports.borrow_mut() = 0;
+    **dest.lamports.borrow_mut() = dest
+        .lamports()
+        .checked_add(curr_lamp)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    info.try_borrow_mut_data()?[0..8].copy_from_slice(&[0; 8]);
+    Ok(())
+}
+
+pub fn get_delegate_info_and_token_state_from_token_record(
+    info: &AccountInfo,
+) -> Result<(Option<Pubkey>, Option<TokenDelegateRole>, TokenState)> {
+    let token_record = TokenRecord::safe_deserialize(&info.data.borrow())?;
+    Ok((
+        token_record.delegate,
+        token_record.delegate_role,
+        token_record.state,
+    ))
+}
+
+pub fn create_or_realloc_seller_trade_state<'a>(
+    sts: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    sts_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let required_lamports = rent
+        .minimum_balance(SellerTradeStateV2::LEN)
+        .saturating_sub(sts.lamports());
+    if sts.data_is_empty() {
+        // brand new account, need to create it with correct length
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                sts.key,
+                required_lamports,
+                SellerTradeStateV2::LEN as u64,
+                &crate::ID,
+            ),
+            &[payer.clone(), sts.clone()],
+            &[sts_seeds],
+        )?;
+
+        sts.try_borrow_mut_data()?[..8].copy_from_slice(&SellerTradeStateV2::discriminator());
+        Ok(())
+    } else if sts.data_len() == SellerTradeState::LEN {
+        // old seller trade state that we want to migrate
+        let old_sts = SellerTradeState::try_deserialize_unchecked(&mut &sts.data.borrow()[..])?;
+        msg!(
+            "trade_state_migrated: {{\"trade_state\":\"{}\",\"kind\":\"seller\",\"old_size\":{},\"new_size\":{},\"wallet\":\"{}\",\"token_mint\":\"{}\",\"price\":{},\"token_size\":{},\"expiry\":{}}}",
+            sts.key,
+            SellerTradeState::LEN,
+            SellerTradeStateV2::LEN,
+            old_sts.seller,
+            old_sts.token_mint,
+            old_sts.buyer_price,
+            old_sts.token_size,
+            old_sts.expiry,
+        );
+        // zero out original data
+        sts.try_borrow_mut_data()?
+            .copy_from_slice(&[0; SellerTradeState::LEN]);
+        // reallocate new space
+        sts.realloc(SellerTradeStateV2::LEN, true)?;
+        // transfer lamports so become rent exempt
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, sts.key, required_lamports),
+                &[payer.clone(), sts.clone()],
+            )?;
+        }
+
+        // write discriminator
+        sts.try_borrow_mut_data()?[0..8].copy_from_slice(&SellerTradeStateV2::discriminator());
+        Ok(())
+    } else if sts.try_borrow_data()?[0..8] == SellerTradeStateV2::discriminator() {
+        Ok(())
+    } else {
+        Err(ErrorCode::InvalidAccountState.into())
+    }
+}
+
+#[macro_export]
+macro_rules! index_ra {
+    ($iter:ident, $i:expr) => {
+        $iter.get($i).ok_or(ErrorCode::MissingRemainingAccount)?
+    };
+}
+
+pub fn create_or_realloc_buyer_trade_state<'a>(
+    bts: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    bts_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let required_lamports = rent
+        .minimum_balance(BuyerTradeStateV2::LEN)
+        .saturating_sub(bts.lamports());
+    if bts.data_is_empty() {
+        // brand new account, need to create it with correct length
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                bts.key,
+                required_lamports,
+                BuyerTradeStateV2::LEN as u64,
+                &crate::ID,
+            ),
+            &[payer.clone(), bts.clone()],
+            &[bts_seeds],
+        )?;
+
+        bts.try_borrow_mut_data()?[..8].copy_from_slice(&BuyerTradeStateV2::discriminator());
+        Ok(())
+    } else if bts.data_len() == BuyerTradeState::LEN {
+        // old buyer trade state that we want to migrate
+        let old_bts = BuyerTradeState::try_deserialize_unchecked(&mut &bts.data.borrow()[..])?;
+        msg!(
+            "trade_state_migrated: {{\"trade_state\":\"{}\",\"kind\":\"buyer\",\"old_size\":{},\"new_size\":{},\"wallet\":\"{}\",\"token_mint\":\"{}\",\"price\":{},\"token_size\":{},\"expiry\":{}}}",
+            bts.key,
+            BuyerTradeState::LEN,
+            BuyerTradeStateV2::LEN,
+            old_bts.buyer,
+            old_bts.token_mint,
+            old_bts.buyer_price,
+            old_bts.token_size,
+            old_bts.expiry,
+        );
+        // zero out original data
+        bts.try_borrow_mut_data()?
+            .copy_from_slice(&[0; BuyerTradeState::LEN]);
+        // reallocate new space
+        bts.realloc(BuyerTradeStateV2::LEN, true)?;
+        // transfer lamports so become rent exempt
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, bts.key, required_lamports),
+                &[payer.clone(), bts.clone()],
+            )?;
+        }
+
+        // write discriminator
+        bts.try_borrow_mut_data()?[0..8].copy_from_slice(&BuyerTradeStateV2::discriminator());
+        Ok(())
+    } else if bts.try_borrow_data()?[0..8] == BuyerTradeStateV2::discriminator() {
+        Ok(())
+    } else {
+        Err(ErrorCode::InvalidAccountState.into())
+    }
+}
+
+// sale price of a collection bid fill, royalty inclusive bids quote the sale price plus the
+// royalty, so the royalty has to be taken out of the quote to get the sale price
+pub fn get_collection_bid_sale_price(
+    bid_price: u64,
+    royalty_inclusive: bool,
+    seller_fee_basis_points: u16,
+    buyer_creator_royalty_bp: u16,
+) -> Result<u64> {
+    if !royalty_inclusive {
+        return Ok(bid_price);
+    }
+    let royalty_bp_squared = (seller_fee_basis_points as u128)
+        .checked_mul(buyer_creator_royalty_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    Ok((bid_price as u128)
+        .checked_mul(10000 * 10000)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(
+            royalty_bp_squared
+                .checked_add(10000 * 10000)
+                .ok_or(ErrorCode::NumericalOverflow)?,
+        )
+        .ok_or(ErrorCode::NumericalOverflow)? as u64)
+}
+
+// fill between the heads of the best bid and ask levels of an order book as (price, size), the
+// order that rested first sets the price
+pub fn get_order_book_fill(
+    bid_price: u64,
+    bid: &BookOrder,
+    ask_price: u64,
+    ask: &BookOrder,
+) -> Result<(u64, u64)> {
+    if bid_price < ask_price {
+        return Err(ErrorCode::OrderBookNotCrossed.into());
+    }
+    let price = if bid.seq < ask.seq {
+        bid_price
+    } else {
+        ask_price
+    };
+    Ok((price, bid.size.min(ask.size)))
+}
+
+// lamports of size base units of a token at price lamports each
+pub fn get_order_book_amount(price: u64, size: u64) -> Result<u64> {
+    let amount = (price as u128)
+        .checked_mul(size as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if amount > MAX_PRICE as u128 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    Ok(amount as u64)
+}
+
+// compute budget the transaction asked for, given (program_id, data) of all its instructions
+pub fn get_requested_compute_units<'a>(
+    instructions: impl Iterator<Item = (Pubkey, &'a [u8])>,
+) -> u32 {
+    let mut other_instructions: u32 = 0;
+    let mut limit: Option<u32> = None;
+    for (program_id, data) in instructions {
+        if program_id != COMPUTE_BUDGET_PROGRAM_ID {
+            other_instructions = other_instructions.saturating_add(1);
+            continue;
+        }
+        // ComputeBudgetInstruction::SetComputeUnitLimit(u32)
+        if data.len() == 5 && data[0] == 2 {
+            limit = Some(u32::from_le_bytes(*array_ref![data, 1, 4]));
+        }
+    }
+    limit
+        .unwrap_or_else(|| other_instructions.saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNITS))
+        .min(MAX_TRANSACTION_COMPUTE_UNITS)
+}
+
+// fail fast when the transaction can't have enough compute units for a settlement,
+// instead of running out of compute half way through the CPIs
+pub fn assert_compute_budget(instructions_sysvar: &AccountInfo, min_units: u32) -> Result<()> {
+    let mut instructions = vec![];
+    let mut index = 0;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        instructions.push(ix);
+        index += 1;
+    }
+    let requested = get_requested_compute_units(
+        instructions
+            .iter()
+            .map(|ix| (ix.program_id, ix.data.as_slice())),
+    );
+    if requested < min_units {
+        msg!(
+            "requested {} compute units, need at least {}",
+            requested,
+            min_units
+        );
+        return Err(ErrorCode::InsufficientComputeBudget.into());
+    }
+    Ok(())
+}
+
+/// Returns the leaf of an order in a cancellation merkle tree, the keccak hash of its trade state
+pub fn get_cancellation_leaf(trade_state: &Pubkey) -> [u8; 32] {
+    anchor_lang::solana_program::keccak::hash(trade_state.as_ref()).to_bytes()
+}
+
+/// Returns whether proof proves leaf is in the merkle tree with root. Nodes hash their children in
+/// sorted order, so the proof doesn't need to encode left and right
+pub fn verify_merkle_proof(leaf: [u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        anchor_lang::solana_program::keccak::hashv(&[&left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{CLOCK_SKEW_SECONDS, MAX_MINT_LISTING_INDEX_ENTRIES};
+    use anchor_spl::token_2022::spl_token_2022;
+    use mpl_token_metadata::types::Key;
+
+    fn test_metadata(mint: Pubkey, collection: Option<Collection>) -> Metadata {
+        Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::new_unique(),
+            mint,
+            name: String::new(),
+            symbol: String::new(),
+            uri: String::new(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        }
+    }
+
+    #[test]
+    fn assert_keys_equal_returns_ok_when_keys_are_equal() -> Result<()> {
+        let pubkey = Pubkey::new_from_array([1; 32]);
+        let same_pubkey = Pubkey::new_from_array([1; 32]);
+        assert_keys_equal(&pubkey, &same_pubkey)
+    }
+
+    #[test]
+    fn assert_owned_by_returns_ok_when_given_account_is_owned_by_given_owner() -> Result<()> {
+        let mut lamports: u64 = 1;
+        let mut data = [1];
+        let owner = Pubkey::new_unique();
+        let account = AccountInfo::new(
+            &owner,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            4,
+        );
+
+        assert_owned_by(&account, &owner)
+    }
+
+    #[test]
+    fn assert_initialized_returns_ok_when_account_is_frozen() {
+        let mut buffer = vec![0; spl_token::state::Account::get_packed_len()];
+        let mut lamports: u64 = 1;
+        let owner = Pubkey::new_unique();
+        let spl_token_account = spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount: 1,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Frozen,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+
+        spl_token::state::Account::pack(spl_token_account, &mut buffer)
+            .expect("Could not pack SPL token account into buffer");
+
+        let account_info = AccountInfo::new(
+            &owner,
+            false,
+            false,
+            &mut lamports,
+            &mut buffer,
+            &owner,
+            false,
+            4,
+        );
+
+        match assert_initialized::<spl_token::state::Account>(&account_info) {
+            Ok(result) => assert_eq!(result, spl_token_account),
+            _ => panic!("expected Ok(spl_token_account)"),
+        }
+    }
+
+    #[test]
+    fn assert_is_ata_returns_ok_when_account_is_ata() -> Result<()> {
+        let mut buffer = vec![0; spl_token::state::Account::get_packed_len()];
+        let mut lamports: u64 = 1;
+        let owner = spl_token::id();
+        let mint = Pubkey::new_unique();
+        let spl_token_account = spl_token::state::Account {
+            mint,
             owner,
             amount: 1,
             delegate: COption::None,
@@ -727,4 +2239,768 @@ mod tests {
             _ => panic!("expected Ok(balance)"),
         }
     }
+
+    #[test]
+    fn check_price_precision_rejects_dust_and_extra_precision() {
+        // USDC, 6 decimals
+        assert!(check_price_precision(9_999, 6).is_err());
+        assert!(check_price_precision(10_000, 6).is_ok());
+        assert!(check_price_precision(1_234_567, 6).is_ok());
+        // 9 decimals, prices are limited to 6 decimals
+        assert!(check_price_precision(1_000_000_001, 9).is_err());
+        assert!(check_price_precision(1_000_001_000, 9).is_ok());
+    }
+
+    fn t22_mint_data(extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0; T22_ACCOUNT_TYPE_OFFSET];
+        data.push(T22_ACCOUNT_TYPE_MINT);
+        for (extension_type, value) in extensions {
+            data.extend_from_slice(&extension_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    #[test]
+    fn get_t22_group_from_data_reads_members_on_the_mint() {
+        let mint = Pubkey::new_unique();
+        let group = Pubkey::new_unique();
+        let pointer = |member_address: Pubkey| {
+            (
+                T22_GROUP_MEMBER_POINTER_EXTENSION,
+                [Pubkey::new_unique().to_bytes(), member_address.to_bytes()].concat(),
+            )
+        };
+        let member = (
+            T22_TOKEN_GROUP_MEMBER_EXTENSION,
+            [mint.to_bytes(), group.to_bytes(), [0; 32]].concat()[..72].to_vec(),
+        );
+        let transfer_fee = (1, vec![0; 108]);
+
+        let data = t22_mint_data(&[transfer_fee.clone(), pointer(mint), member.clone()]);
+        assert_eq!(get_t22_group_from_data(&mint, &data), Some(group));
+        // a member account outside the mint
+        let data = t22_mint_data(&[pointer(Pubkey::new_unique()), member.clone()]);
+        assert_eq!(get_t22_group_from_data(&mint, &data), None);
+        // a pointer without an initialized member
+        let data = t22_mint_data(&[pointer(mint)]);
+        assert_eq!(get_t22_group_from_data(&mint, &data), None);
+        // no extensions at all
+        assert_eq!(get_t22_group_from_data(&mint, &[0; 82]), None);
+    }
+
+    #[test]
+    fn get_asset_standard_routes_by_owner() {
+        let key = Pubkey::new_unique();
+        let metadata_key = Pubkey::new_unique();
+        let (mut lamports, mut metadata_lamports) = (1, 1);
+        let mut metadata_data = [0; 1];
+        let metadata = AccountInfo::new(
+            &metadata_key,
+            false,
+            false,
+            &mut metadata_lamports,
+            &mut metadata_data,
+            &metadata_key,
+            false,
+            0,
+        );
+        let cases = [
+            (MPL_CORE_PROGRAM_ID, 1, Some(AssetStandard::Core)),
+            (
+                anchor_spl::token_2022::ID,
+                82,
+                Some(AssetStandard::Token2022),
+            ),
+            (
+                solana_program::system_program::ID,
+                0,
+                Some(AssetStandard::Compressed),
+            ),
+            (solana_program::system_program::ID, 8, None),
+            (Pubkey::new_unique(), 8, None),
+        ];
+        for (owner, len, expected) in cases {
+            let mut data = vec![0; len];
+            let asset = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+            assert_eq!(get_asset_standard(&asset, &metadata).ok(), expected);
+        }
+    }
+
+    #[test]
+    fn assert_programmable_metadata_rejects_other_token_standards() {
+        let mint = Pubkey::new_unique();
+        let metadata_key = Metadata::find_pda(&mint).0;
+        let mut lamports = 1;
+        let mut pnft = test_metadata(mint, None);
+        pnft.token_standard = Some(TokenStandard::ProgrammableNonFungible);
+        let mut data = pnft.try_to_vec().unwrap();
+        let metadata = AccountInfo::new(
+            &metadata_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &mpl_token_metadata::ID,
+            false,
+            0,
+        );
+        assert!(assert_programmable_metadata(&metadata, &mint).is_ok());
+        assert!(assert_programmable_metadata(&metadata, &Pubkey::new_unique()).is_err());
+
+        for token_standard in [None, Some(TokenStandard::NonFungible)] {
+            let mut lamports = 1;
+            let mut nft = test_metadata(mint, None);
+            nft.token_standard = token_standard;
+            let mut data = nft.try_to_vec().unwrap();
+            let metadata = AccountInfo::new(
+                &metadata_key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &mpl_token_metadata::ID,
+                false,
+                0,
+            );
+            assert_eq!(
+                assert_programmable_metadata(&metadata, &mint),
+                Err(ErrorCode::InvalidTokenStandard.into())
+            );
+        }
+    }
+
+    #[test]
+    fn get_collection_key_returns_verified_collection_or_mint() {
+        let mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+
+        let verified = test_metadata(
+            mint,
+            Some(Collection {
+                verified: true,
+                key: collection,
+            }),
+        );
+        assert_eq!(get_collection_key(&verified), collection);
+
+        let unverified = test_metadata(
+            mint,
+            Some(Collection {
+                verified: false,
+                key: collection,
+            }),
+        );
+        assert_eq!(get_collection_key(&unverified), mint);
+        assert_eq!(get_collection_key(&test_metadata(mint, None)), mint);
+    }
+
+    #[test]
+    fn compressed_creators_hash_and_metadata() {
+        let creators = vec![
+            CompressedCreator {
+                address: Pubkey::new_from_array([1; 32]),
+                verified: true,
+                share: 60,
+            },
+            CompressedCreator {
+                address: Pubkey::new_from_array([2; 32]),
+                verified: false,
+                share: 40,
+            },
+        ];
+        let mut creator_data = vec![1u8; 32];
+        creator_data.extend_from_slice(&[1, 60]);
+        creator_data.extend_from_slice(&[2u8; 32]);
+        creator_data.extend_from_slice(&[0, 40]);
+        assert_eq!(
+            hash_compressed_creators(&creators),
+            anchor_lang::solana_program::keccak::hash(&creator_data).to_bytes()
+        );
+
+        let asset_id = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let metadata = get_compressed_metadata(&asset_id, 500, Some(collection), &creators);
+        assert_eq!(get_collection_key(&metadata), collection);
+        assert_eq!(metadata.seller_fee_basis_points, 500);
+        assert_eq!(metadata.creators.unwrap()[1].share, 40);
+        let metadata = get_compressed_metadata(&asset_id, 500, None, &creators);
+        assert_eq!(get_collection_key(&metadata), asset_id);
+    }
+
+    #[test]
+    fn core_asset_plugins_and_metadata() {
+        let owner = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mut data = CoreBaseAsset {
+            key: CORE_ASSET_KEY,
+            owner,
+            update_authority: CoreUpdateAuthority::Collection(collection),
+            name: "asset".to_string(),
+            uri: "https://example.com".to_string(),
+            seq: None,
+        }
+        .try_to_vec()
+        .unwrap();
+        let asset_key = Pubkey::new_unique();
+        let asset = get_core_asset(&data).unwrap();
+        assert_eq!(asset.owner, owner);
+        assert_eq!(asset.collection, Some(collection));
+        assert!(asset.plugins.transfer_delegate.is_none());
+        let metadata = get_core_metadata(&asset_key, &asset, None);
+        assert_eq!(metadata.seller_fee_basis_points, 0);
+        assert_eq!(get_collection_key(&metadata), collection);
+
+        // header, royalties plugin, registry
+        let header_offset = data.len();
+        let royalties_offset = header_offset + 9;
+        let mut royalties = vec![CORE_ROYALTIES_PLUGIN];
+        royalties.extend(
+            CoreRoyalties {
+                basis_points: 500,
+                creators: vec![CoreRoyaltyCreator {
+                    address: creator,
+                    percentage: 100,
+                }],
+                rule_set: CoreRuleSet::None,
+            }
+            .try_to_vec()
+            .unwrap(),
+        );
+        let registry_offset = royalties_offset + royalties.len();
+        data.extend(
+            CorePluginHeader {
+                key: CORE_PLUGIN_HEADER_KEY,
+                plugin_registry_offset: registry_offset as u64,
+            }
+            .try_to_vec()
+            .unwrap(),
+        );
+        data.extend(royalties);
+        data.extend(
+            CorePluginRegistry {
+                key: CORE_PLUGIN_REGISTRY_KEY,
+                registry: vec![
+                    CoreRegistryRecord {
+                        plugin_type: CORE_ROYALTIES_PLUGIN,
+                        authority: CoreAuthority::UpdateAuthority,
+                        offset: royalties_offset as u64,
+                    },
+                    CoreRegistryRecord {
+                        plugin_type: CORE_TRANSFER_DELEGATE_PLUGIN,
+                        authority: CoreAuthority::Address { address: delegate },
+                        offset: 0,
+                    },
+                ],
+            }
+            .try_to_vec()
+            .unwrap(),
+        );
+        data.extend([0u8; 4]); // no external plugins
+
+        let asset = get_core_asset(&data).unwrap();
+        assert_eq!(
+            asset.plugins.transfer_delegate,
+            Some(CoreAuthority::Address { address: delegate })
+        );
+        let collection_royalties = CoreRoyalties {
+            basis_points: 1000,
+            creators: vec![],
+            rule_set: CoreRuleSet::None,
+        };
+        let metadata = get_core_metadata(&asset_key, &asset, Some(&collection_royalties));
+        assert_eq!(metadata.seller_fee_basis_points, 500);
+        let creators = metadata.creators.unwrap();
+        assert_eq!(creators[0].address, creator);
+        assert_eq!(creators[0].share, 100);
+
+        data[0] = CORE_COLLECTION_KEY;
+        assert!(get_core_asset(&data).is_err());
+    }
+
+    #[test]
+    fn core_royalty_bp_enforced_by_rule_set() {
+        let allowed = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let asset = CoreAsset {
+            owner: Pubkey::new_unique(),
+            collection: None,
+            plugins: CorePlugins::default(),
+        };
+        let royalties = |rule_set| CoreRoyalties {
+            basis_points: 500,
+            creators: vec![],
+            rule_set,
+        };
+
+        // no plugin or no rule set, the bid decides
+        assert_eq!(get_core_royalty_bp(&asset, None, 0, &[&other]).unwrap(), 0);
+        let optional = royalties(CoreRuleSet::None);
+        assert_eq!(
+            get_core_royalty_bp(&asset, Some(&optional), 2500, &[&other]).unwrap(),
+            2500
+        );
+
+        let allow_list = royalties(CoreRuleSet::ProgramAllowList(vec![allowed]));
+        assert_eq!(
+            get_core_royalty_bp(&asset, Some(&allow_list), 0, &[&allowed, &allowed]).unwrap(),
+            10_000
+        );
+        assert!(get_core_royalty_bp(&asset, Some(&allow_list), 0, &[&allowed, &other]).is_err());
+
+        let deny_list = royalties(CoreRuleSet::ProgramDenyList(vec![other]));
+        assert_eq!(
+            get_core_royalty_bp(&asset, Some(&deny_list), 0, &[&allowed]).unwrap(),
+            10_000
+        );
+        assert!(get_core_royalty_bp(&asset, Some(&deny_list), 0, &[&allowed, &other]).is_err());
+
+        // the asset's own plugin takes precedence over the collection's
+        let asset = CoreAsset {
+            plugins: CorePlugins {
+                transfer_delegate: None,
+                royalties: Some(royalties(CoreRuleSet::None)),
+            },
+            ..asset
+        };
+        assert_eq!(
+            get_core_royalty_bp(&asset, Some(&deny_list), 0, &[&other]).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_get_requested_compute_units() {
+        let program = Pubkey::new_unique();
+        let mut set_limit = vec![2];
+        set_limit.extend_from_slice(&400_000u32.to_le_bytes());
+        let set_price = [3, 1, 0, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!(
+            get_requested_compute_units(vec![(program, &[][..])].into_iter()),
+            200_000
+        );
+        assert_eq!(
+            get_requested_compute_units(
+                vec![
+                    (COMPUTE_BUDGET_PROGRAM_ID, &set_price[..]),
+                    (program, &[][..]),
+                    (program, &[][..]),
+                ]
+                .into_iter()
+            ),
+            400_000
+        );
+        assert_eq!(
+            get_requested_compute_units(
+                vec![
+                    (COMPUTE_BUDGET_PROGRAM_ID, &set_limit[..]),
+                    (program, &[][..]),
+                ]
+                .into_iter()
+            ),
+            400_000
+        );
+        assert_eq!(
+            get_requested_compute_units((0..10).map(|_| (program, &[][..]))),
+            MAX_TRANSACTION_COMPUTE_UNITS
+        );
+    }
+
+    #[test]
+    fn test_get_order_book_fill() {
+        let order = |size, seq| BookOrder {
+            owner: Pubkey::new_unique(),
+            size,
+            seq,
+        };
+        // the resting ask sets the price for a later bid, and the other way around
+        assert_eq!(
+            get_order_book_fill(120, &order(5, 2), 100, &order(3, 1)).unwrap(),
+            (100, 3)
+        );
+        assert_eq!(
+            get_order_book_fill(120, &order(5, 1), 100, &order(8, 2)).unwrap(),
+            (120, 5)
+        );
+        assert_eq!(
+            get_order_book_fill(100, &order(5, 1), 100, &order(8, 2)).unwrap(),
+            (100, 5)
+        );
+        assert!(get_order_book_fill(99, &order(5, 1), 100, &order(8, 2)).is_err());
+
+        assert_eq!(get_order_book_amount(1_000, 7).unwrap(), 7_000);
+        assert!(get_order_book_amount(MAX_PRICE, 2).is_err());
+        assert!(get_order_book_amount(u64::MAX, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_get_collection_bid_sale_price() {
+        assert_eq!(
+            get_collection_bid_sale_price(1_000_000, false, 500, 10000).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            get_collection_bid_sale_price(1_050_000, true, 500, 10000).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            get_collection_bid_sale_price(1_025_000, true, 500, 5000).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            get_collection_bid_sale_price(1_000_000, true, 500, 0).unwrap(),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn assert_price_on_tick_checks_multiples() {
+        let tick = 10_000_000; // 0.01 SOL
+        assert!(assert_price_on_tick(1_230_000_000, tick).is_ok());
+        assert!(assert_price_on_tick(1_234_567_890, tick).is_err());
+        // no tick accepts any price
+        assert!(assert_price_on_tick(1_234_567_890, 0).is_ok());
+    }
+
+    #[test]
+    fn get_renewed_price_bumps_by_bp() {
+        assert_eq!(get_renewed_price(1_000_000, 0).unwrap(), 1_000_000);
+        assert_eq!(get_renewed_price(1_000_000, 250).unwrap(), 1_025_000);
+        assert_eq!(get_renewed_price(999, 100).unwrap(), 1_008);
+        assert!(get_renewed_price(MAX_PRICE, 1).is_err());
+    }
+
+    #[test]
+    fn is_within_hold_period_until_it_elapses() {
+        let day = 60 * 60 * 24;
+        assert!(is_within_hold_period(1_000, day, 1_000));
+        assert!(is_within_hold_period(1_000, day, 1_000 + day - 1));
+        assert!(!is_within_hold_period(1_000, day, 1_000 + day));
+        // no hold period
+        assert!(!is_within_hold_period(1_000, 0, 1_000));
+    }
+
+    #[test]
+    fn get_ui_price_formats_whole_units() {
+        assert_eq!(get_ui_price(1_500_000_000, SOL_DECIMALS), "1.5");
+        assert_eq!(get_ui_price(2_000_000, 6), "2");
+        assert_eq!(get_ui_price(1, 6), "0.000001");
+        assert_eq!(get_ui_price(7, 0), "7");
+    }
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        anchor_lang::solana_program::keccak::hashv(&[&left, &right]).to_bytes()
+    }
+
+    #[test]
+    fn verify_merkle_proof_checks_membership() {
+        let leaves: Vec<[u8; 32]> = (1..=4u8)
+            .map(|i| get_cancellation_leaf(&Pubkey::new_from_array([i; 32])))
+            .collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(verify_merkle_proof(leaves[0], &[leaves[1], right], &root));
+        assert!(verify_merkle_proof(leaves[3], &[leaves[2], left], &root));
+        // wrong sibling or a leaf that isn't in the tree
+        assert!(!verify_merkle_proof(leaves[0], &[leaves[2], right], &root));
+        let outsider = get_cancellation_leaf(&Pubkey::new_from_array([5; 32]));
+        assert!(!verify_merkle_proof(outsider, &[leaves[1], right], &root));
+        // a single leaf tree is its own root
+        assert!(verify_merkle_proof(leaves[0], &[], &leaves[0]));
+    }
+
+    fn t22_token_account_data(account: spl_token_2022::state::Account) -> Vec<u8> {
+        use spl_token_2022::extension::{
+            immutable_owner::ImmutableOwner, ExtensionType, StateWithExtensionsMut,
+        };
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+            ExtensionType::ImmutableOwner,
+        ])
+        .unwrap();
+        let mut data = vec![0; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(
+                &mut data,
+            )
+            .unwrap();
+        state.init_extension::<ImmutableOwner>(true).unwrap();
+        state.base = account;
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn is_listing_backed_reads_t22_delegated_listings() {
+        let program_as_signer = Pubkey::new_unique();
+        let sell_args = SellArgs {
+            seller: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            token_size: 1,
+            ..Default::default()
+        };
+        let listed = spl_token_2022::state::Account {
+            mint: sell_args.token_mint,
+            owner: sell_args.seller,
+            amount: 1,
+            delegate: COption::Some(program_as_signer),
+            state: spl_token_2022::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 1,
+            close_authority: COption::None,
+        };
+        let key = Pubkey::new_unique();
+        let t22_program = anchor_spl::token_2022::ID;
+        let is_backed = |account: spl_token_2022::state::Account, owner: &Pubkey| {
+            let mut data = t22_token_account_data(account);
+            let mut lamports = 1;
+            let account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                owner,
+                false,
+                0,
+            );
+            is_listing_backed(&account_info, &sell_args, &program_as_signer).unwrap()
+        };
+
+        assert!(is_backed(listed, &t22_program));
+        // revoked, or the token moved away
+        assert!(!is_backed(
+            spl_token_2022::state::Account {
+                delegate: COption::None,
+                delegated_amount: 0,
+                ..listed
+            },
+            &t22_program,
+        ));
+        assert!(!is_backed(
+            spl_token_2022::state::Account {
+                amount: 0,
+                ..listed
+            },
+            &t22_program,
+        ));
+        // not a token account
+        assert!(!is_backed(listed, &Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn split_optional_accounts_reads_accounts_after_the_marker() {
+        let creator = Pubkey::new_unique();
+        let first = Pubkey::new_unique();
+        let third = Pubkey::new_unique();
+        let keys = [creator, crate::ID, first, crate::ID, third];
+        let mut lamports = [0u64; 5];
+        let mut data: [[u8; 0]; 5] = [[]; 5];
+        let owner = solana_program::system_program::ID;
+        let accounts = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+            })
+            .collect::<Vec<_>>();
+
+        let (rest, optional_accounts) = split_optional_accounts(&accounts);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].key, &creator);
+        assert_eq!(optional_accounts.get(0).map(|a| *a.key), Some(first));
+        // the program id in a slot skips it, and so does leaving it out
+        assert!(optional_accounts.get(1).is_none());
+        assert_eq!(optional_accounts.get(2).map(|a| *a.key), Some(third));
+        assert!(optional_accounts.get(3).is_none());
+
+        let (rest, optional_accounts) = split_optional_accounts(&accounts[..1]);
+        assert_eq!(rest.len(), 1);
+        assert!(optional_accounts.get(0).is_none());
+    }
+
+    #[test]
+    fn is_counted_order_only_accepts_program_owned_markers() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = CountedOrder::discriminator().to_vec();
+        data.extend_from_slice(&[0; 33]);
+        let counted_order = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(is_counted_order(&counted_order));
+
+        // an order opened before the counter has no marker to uncount
+        let mut lamports = 0;
+        let mut data = [];
+        let system_program = solana_program::system_program::ID;
+        let uncounted_order = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &system_program,
+            false,
+            0,
+        );
+        assert!(!is_counted_order(&uncounted_order));
+
+        let mut lamports = 0;
+        let mut data = WalletOrderCounter::discriminator().to_vec();
+        let other_account = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(!is_counted_order(&other_account));
+    }
+
+    #[test]
+    fn mint_listing_index_upsert_evicts_when_full() {
+        let entry = |expiry| MintListingEntry {
+            seller_trade_state: Pubkey::new_unique(),
+            expiry,
+            ..Default::default()
+        };
+        let now = 1_000;
+        let mut index = MintListingIndex::default();
+        let listings: Vec<_> = (0..MAX_MINT_LISTING_INDEX_ENTRIES)
+            .map(|_| entry(now * 2))
+            .collect();
+        for listing in &listings {
+            index.upsert(*listing, now);
+        }
+
+        // without an expired entry the one added first makes room
+        let newest = entry(now * 2);
+        index.upsert(newest, now);
+        assert_eq!(index.listings.len(), MAX_MINT_LISTING_INDEX_ENTRIES);
+        assert!(!index.listings.contains(&listings[0]));
+        assert!(index.listings.contains(&newest));
+
+        // an expired entry is evicted before older live ones
+        let expired = entry(now - CLOCK_SKEW_SECONDS - 1);
+        index.listings[2] = expired;
+        let latest = entry(now * 2);
+        index.upsert(latest, now);
+        assert!(!index.listings.contains(&expired));
+        assert!(index.listings.contains(&listings[1]));
+        assert!(index.listings.contains(&latest));
+
+        // an existing entry is updated in place
+        let repriced = MintListingEntry {
+            buyer_price: 1,
+            ..latest
+        };
+        index.upsert(repriced, now);
+        assert_eq!(index.listings.len(), MAX_MINT_LISTING_INDEX_ENTRIES);
+        assert!(index.listings.contains(&repriced));
+    }
+
+    #[test]
+    fn get_treasury_vault_instruction_moves_shares_of_the_treasury_only() -> Result<()> {
+        let vault_state = Pubkey::new_unique();
+        let share_mint = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let treasury_share_account = Pubkey::new_unique();
+        let vault_accounts = (0..5).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+        let deposit = get_treasury_vault_instruction(
+            &YIELD_VAULT_PROGRAM_WHITELIST[0],
+            true,
+            1_000,
+            &vault_state,
+            &share_mint,
+            &treasury,
+            &treasury_share_account,
+            &vault_accounts,
+        )?;
+        assert_eq!(deposit.data[..8], MARINADE_DEPOSIT_IX_DISCRIMINATOR);
+        assert_eq!(deposit.data[8..], 1_000u64.to_le_bytes());
+        let signers = deposit
+            .accounts
+            .iter()
+            .filter(|a| a.is_signer)
+            .collect::<Vec<_>>();
+        assert_eq!(signers.len(), 1);
+        assert_eq!(signers[0].pubkey, treasury);
+        // mint_to
+        assert_eq!(deposit.accounts[7].pubkey, treasury_share_account);
+
+        let mut withdraw_accounts = vault_accounts[..3].to_vec();
+        withdraw_accounts.extend([
+            solana_program::sysvar::clock::ID,
+            solana_program::sysvar::stake_history::ID,
+            solana_program::stake::program::ID,
+        ]);
+        let withdraw = get_treasury_vault_instruction(
+            &YIELD_VAULT_PROGRAM_WHITELIST[1],
+            false,
+            500,
+            &vault_state,
+            &share_mint,
+            &treasury,
+            &treasury_share_account,
+            &withdraw_accounts,
+        )?;
+        assert_eq!(withdraw.data[0], STAKE_POOL_WITHDRAW_SOL_IX);
+        assert_eq!(withdraw.data[1..], 500u64.to_le_bytes());
+        // burns from the treasury's share account and pays the lamports to the treasury
+        assert_eq!(withdraw.accounts[3].pubkey, treasury_share_account);
+        assert_eq!(withdraw.accounts[5].pubkey, treasury);
+
+        assert!(get_treasury_vault_instruction(
+            &YIELD_VAULT_PROGRAM_WHITELIST[1],
+            false,
+            500,
+            &vault_state,
+            &share_mint,
+            &treasury,
+            &treasury_share_account,
+            &vault_accounts,
+        )
+        .is_err());
+        assert!(get_treasury_vault_instruction(
+            &Pubkey::new_unique(),
+            true,
+            1_000,
+            &vault_state,
+            &share_mint,
+            &treasury,
+            &treasury_share_account,
+            &vault_accounts,
+        )
+        .is_err());
+        Ok(())
+    }
 }
@@ -3,3 +3,15 @@ pub use generic::*;
 
 pub mod transfer;
 pub use transfer::*;
+
+pub mod sale_record;
+pub use sale_record::*;
+
+pub mod oracle;
+pub use oracle::*;
+
+pub mod settlement;
+pub use settlement::*;
+
+pub mod diagnostics;
+pub use diagnostics::*;
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+
+use super::{assert_derivation, assert_keys_equal};
+use crate::constants::{PREFIX, TAKER_FEE_CONFIG};
+use crate::errors::ErrorCode;
+use crate::states::TakerFeeConfig;
+
+// pyth v2 price account layout
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+const PYTH_EXPO_OFFSET: usize = 20;
+const PYTH_TIMESTAMP_OFFSET: usize = 96;
+const PYTH_AGG_PRICE_OFFSET: usize = 208;
+const PYTH_AGG_STATUS_OFFSET: usize = 224;
+const PYTH_STATUS_TRADING: u32 = 1;
+
+/// Reads the aggregate price and exponent of a pyth price account, failing if the price is not
+/// trading, not positive or older than max_staleness seconds
+pub fn get_pyth_price(data: &[u8], now: i64, max_staleness: i64) -> Result<(i64, i32)> {
+    if data.len() < PYTH_AGG_STATUS_OFFSET + 4
+        || u32::from_le_bytes(*array_ref![data, 0, 4]) != PYTH_MAGIC
+    {
+        return Err(ErrorCode::InvalidPriceFeed.into());
+    }
+    let expo = i32::from_le_bytes(*array_ref![data, PYTH_EXPO_OFFSET, 4]);
+    let timestamp = i64::from_le_bytes(*array_ref![data, PYTH_TIMESTAMP_OFFSET, 8]);
+    let price = i64::from_le_bytes(*array_ref![data, PYTH_AGG_PRICE_OFFSET, 8]);
+    let status = u32::from_le_bytes(*array_ref![data, PYTH_AGG_STATUS_OFFSET, 4]);
+    if status != PYTH_STATUS_TRADING || price <= 0 || now.saturating_sub(timestamp) > max_staleness
+    {
+        return Err(ErrorCode::InvalidPriceFeed.into());
+    }
+    Ok((price, expo))
+}
+
+/// Converts a flat fee in micro USD into a taker fee bp of a SOL price, rounding up and capping
+/// at max_fee_bp. sol_usd_price * 10^expo is the USD price of one SOL.
+pub fn get_flat_taker_fee_bp(
+    price: u64,
+    flat_fee_micro_usd: u64,
+    max_fee_bp: u16,
+    sol_usd_price: i64,
+    expo: i32,
+) -> Result<u16> {
+    if price == 0 {
+        return Ok(max_fee_bp);
+    }
+    // fee_lamports = flat_fee_micro_usd * 10^(9 - 6) / (sol_usd_price * 10^expo)
+    let mut numerator = (flat_fee_micro_usd as u128)
+        .checked_mul(1_000)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let mut denominator = sol_usd_price as u128;
+    if expo < 0 {
+        numerator = numerator
+            .checked_mul(10u128.pow(expo.unsigned_abs()))
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    } else {
+        denominator = denominator
+            .checked_mul(10u128.pow(expo as u32))
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    }
+    // fee_bp = fee_lamports * 10000 / price, rounded up
+    let denominator = denominator
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let fee_bp = numerator
+        .checked_mul(10000)
+        .and_then(|n| n.checked_add(denominator - 1))
+        .ok_or(ErrorCode::NumericalOverflow)?
+        / denominator;
+    Ok(fee_bp.min(max_fee_bp as u128) as u16)
+}
+
+/// Resolves the taker fee bp of a SOL sale. If the auction house has an enabled TakerFeeConfig,
+/// the taker fee is its flat USD fee converted through the pyth SOL/USD feed, otherwise it's
+/// taker_fee_bp. The fee never goes below what a negative maker fee rebates.
+pub fn get_taker_fee_bp(
+    taker_fee_config: &AccountInfo,
+    price_feed: Option<&AccountInfo>,
+    auction_house_key: &Pubkey,
+    price: u64,
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+) -> Result<u16> {
+    assert_derivation(
+        &crate::ID,
+        taker_fee_config,
+        &[
+            PREFIX.as_bytes(),
+            TAKER_FEE_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+        ],
+    )?;
+    if taker_fee_config.data_is_empty() || taker_fee_config.owner != &crate::ID {
+        return Ok(taker_fee_bp);
+    }
+    let config = TakerFeeConfig::try_deserialize(&mut &taker_fee_config.data.borrow()[..])?;
+    if !config.enabled {
+        return Ok(taker_fee_bp);
+    }
+    let price_feed = price_feed.ok_or(ErrorCode::MissingRemainingAccount)?;
+    assert_keys_equal(price_feed.key, &config.price_feed)?;
+    let now = Clock::get()?.unix_timestamp;
+    let (sol_usd_price, expo) =
+        get_pyth_price(&price_feed.data.borrow(), now, config.max_staleness)?;
+    let (flat_fee_micro_usd, max_fee_bp) = get_ramped_taker_fee(&config, now);
+    let fee_bp = get_flat_taker_fee_bp(price, flat_fee_micro_usd, max_fee_bp, sol_usd_price, expo)?;
+    Ok(fee_bp.max(maker_fee_bp.min(0).unsigned_abs()))
+}
+
+/// Linear ramp from `from` to `to` over ramp_seconds, elapsed seconds in
+pub fn get_ramped_value(from: u64, to: u64, elapsed: i64, ramp_seconds: i64) -> u64 {
+    if ramp_seconds <= 0 || elapsed >= ramp_seconds {
+        return to;
+    }
+    let elapsed = elapsed.max(0) as i128;
+    let (from, to) = (from as i128, to as i128);
+    (from + (to - from) * elapsed / ramp_seconds as i128) as u64
+}
+
+/// (flat_fee_micro_usd, max_fee_bp) of the config at now, ramping from the values before its last
+/// update so in-flight orders don't see a sudden fee change
+pub fn get_ramped_taker_fee(config: &TakerFeeConfig, now: i64) -> (u64, u16) {
+    let elapsed = now.saturating_sub(config.updated_at);
+    (
+        get_ramped_value(
+            config.prev_flat_fee_micro_usd,
+            config.flat_fee_micro_usd,
+            elapsed,
+            config.ramp_seconds,
+        ),
+        get_ramped_value(
+            config.prev_max_fee_bp as u64,
+            config.max_fee_bp as u64,
+            elapsed,
+            config.ramp_seconds,
+        ) as u16,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_flat_taker_fee_bp_converts_and_caps() {
+        // 1 SOL = $100 (10_000_000_000 * 10^-8), flat fee $1 = 0.01 SOL
+        let (sol_usd_price, expo) = (10_000_000_000, -8);
+        // 0.01 SOL on a 1 SOL sale is 100bp
+        assert_eq!(
+            get_flat_taker_fee_bp(1_000_000_000, 1_000_000, 500, sol_usd_price, expo).unwrap(),
+            100
+        );
+        // capped on micro priced sales
+        assert_eq!(
+            get_flat_taker_fee_bp(10_000_000, 1_000_000, 500, sol_usd_price, expo).unwrap(),
+            500
+        );
+        // rounded up on high priced sales
+        assert_eq!(
+            get_flat_taker_fee_bp(1_000_000_000_000, 1_000_000, 500, sol_usd_price, expo).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn get_ramped_value_interpolates_linearly() {
+        assert_eq!(get_ramped_value(100, 200, 0, 3600), 100);
+        assert_eq!(get_ramped_value(100, 200, 1800, 3600), 150);
+        assert_eq!(get_ramped_value(200, 100, 900, 3600), 175);
+        assert_eq!(get_ramped_value(100, 200, 3600, 3600), 200);
+        assert_eq!(get_ramped_value(100, 200, -5, 3600), 100);
+        // no ramp
+        assert_eq!(get_ramped_value(100, 200, 0, 0), 200);
+    }
+
+    #[test]
+    fn get_ramped_taker_fee_ramps_from_previous_values() {
+        let config = TakerFeeConfig {
+            flat_fee_micro_usd: 2_000_000,
+            max_fee_bp: 300,
+            prev_flat_fee_micro_usd: 1_000_000,
+            prev_max_fee_bp: 100,
+            updated_at: 1_000,
+            ramp_seconds: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(get_ramped_taker_fee(&config, 1_500), (1_500_000, 200));
+        assert_eq!(get_ramped_taker_fee(&config, 5_000), (2_000_000, 300));
+    }
+}
@@ -0,0 +1,757 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+use mpl_token_metadata::accounts::Metadata;
+
+use super::{
+    assert_derivation, assert_owned_by, get_collection_key, get_ui_price,
+    load_or_create_program_account, save_program_account,
+};
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::constants::{
+    COLLECTION_STATS, FILL_PROOF, FRONTEND_CONFIG, INSURANCE_FUND, LAST_SALE, MAX_PRICE,
+    MAX_REBATE_TICKETS, PREFIX, REBATE_EPOCH, TRADE_SEQUENCE, TREASURY,
+};
+use crate::errors::ErrorCode;
+use crate::states::{
+    AuctionHouse, BidArgs, CollectionStats, FillProof, FrontendConfig, InsuranceFund, LastSale,
+    RebateEpoch, SellArgs, SellerTradeStateV2, TradeSequence,
+};
+
+/// Records a settled sale on the CollectionStats of (auction_house, collection, payment_mint)
+/// and on the LastSale of the mint, creating any of them if needed. The sale takes the next seq of
+/// the marketplace-wide TradeSequence, which is stored on the LastSale, logged and returned
+///
+/// # Arguments
+/// * `collection_stats` - CollectionStats pda of the sold token's collection
+/// * `last_sale` - LastSale pda of the sold token
+/// * `trade_sequence` - TradeSequence pda
+/// * `payer` - Payer account, will pay for rent if the records need to be created
+/// * `auction_house_key` - Auction house of the sale
+/// * `metadata_parsed` - Metadata of the sold token
+/// * `payment_mint` - Payment mint of the sale, Pubkey::default() for SOL
+/// * `buyer` - Buyer of the sale
+/// * `seller` - Seller of the sale
+/// * `price` - Price the sale was settled at
+/// * `payment_decimals` - Decimals of the payment mint, logged with the price for display
+#[allow(clippy::too_many_arguments)]
+pub fn record_sale<'info>(
+    collection_stats: &AccountInfo<'info>,
+    last_sale: &AccountInfo<'info>,
+    trade_sequence: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+    payment_mint: &Pubkey,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    price: u64,
+    payment_decimals: u8,
+) -> Result<u64> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let collection = get_collection_key(metadata_parsed);
+    let stats_seeds: &[&[u8]] = &[
+        PREFIX.as_bytes(),
+        COLLECTION_STATS.as_bytes(),
+        auction_house_key.as_ref(),
+        collection.as_ref(),
+        payment_mint.as_ref(),
+    ];
+    let stats_bump = assert_derivation(&crate::ID, collection_stats, stats_seeds)?;
+    let mut stats: CollectionStats = load_or_create_program_account(
+        collection_stats,
+        payer,
+        CollectionStats::LEN,
+        &[stats_seeds, &[&[stats_bump]]].concat(),
+    )?;
+    stats.auction_house_key = *auction_house_key;
+    stats.collection = collection;
+    stats.payment_mint = *payment_mint;
+    stats.bump = stats_bump;
+    stats.record_sale(price, now)?;
+    save_program_account(collection_stats, &stats)?;
+
+    let trade_seq = next_trade_seq(trade_sequence, payer)?;
+    record_last_sale(
+        last_sale,
+        payer,
+        auction_house_key,
+        &metadata_parsed.mint,
+        payment_mint,
+        buyer,
+        seller,
+        price,
+        trade_seq,
+    )?;
+
+    msg!(
+        "trade_seq: {{\"seq\":{},\"token_mint\":\"{}\",\"price\":{},\"payment_mint\":\"{}\",\"decimals\":{},\"ui_price\":\"{}\"}}",
+        trade_seq,
+        metadata_parsed.mint,
+        price,
+        payment_mint,
+        payment_decimals,
+        get_ui_price(price, payment_decimals),
+    );
+    Ok(trade_seq)
+}
+
+/// Writes the LastSale of the mint, creating it if needed. Settlements write it even when they
+/// don't record the sale on CollectionStats and the TradeSequence, the hold period of the buyer
+/// is read from it
+///
+/// # Arguments
+/// * `last_sale` - LastSale pda of the sold token
+/// * `payer` - Payer account, will pay for rent if the LastSale needs to be created
+/// * `auction_house_key` - Auction house of the sale
+/// * `token_mint` - Mint of the sold token
+/// * `payment_mint` - Payment mint of the sale, Pubkey::default() for SOL
+/// * `buyer` - Buyer of the sale
+/// * `seller` - Seller of the sale
+/// * `price` - Price the sale was settled at
+/// * `trade_seq` - TradeSequence seq of the sale, 0 if it didn't take one
+#[allow(clippy::too_many_arguments)]
+pub fn record_last_sale<'info>(
+    last_sale: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    auction_house_key: &Pubkey,
+    token_mint: &Pubkey,
+    payment_mint: &Pubkey,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    price: u64,
+    trade_seq: u64,
+) -> Result<()> {
+    let last_sale_seeds: &[&[u8]] = &[PREFIX.as_bytes(), LAST_SALE.as_bytes(), token_mint.as_ref()];
+    let last_sale_bump = assert_derivation(&crate::ID, last_sale, last_sale_seeds)?;
+    let mut last_sale_data: LastSale = load_or_create_program_account(
+        last_sale,
+        payer,
+        LastSale::LEN,
+        &[last_sale_seeds, &[&[last_sale_bump]]].concat(),
+    )?;
+    last_sale_data.token_mint = *token_mint;
+    last_sale_data.auction_house_key = *auction_house_key;
+    last_sale_data.buyer = *buyer;
+    last_sale_data.seller = *seller;
+    last_sale_data.payment_mint = *payment_mint;
+    last_sale_data.price = price;
+    last_sale_data.sold_at = Clock::get()?.unix_timestamp;
+    last_sale_data.bump = last_sale_bump;
+    last_sale_data.trade_seq = trade_seq;
+    save_program_account(last_sale, &last_sale_data)
+}
+
+/// Writes the FillProof of (buyer, token_mint) for a settled fill, creating it if needed. This is
+/// opt in like frontend attribution, any account but the derived FillProof is skipped. A FillProof
+/// left from an earlier fill is overwritten, its rent stays owed to whoever paid for it
+///
+/// # Arguments
+/// * `fill_proof` - FillProof pda of the buyer and the token, or any other account to skip it
+/// * `payer` - Payer account, will pay for rent if the FillProof needs to be created
+/// * `auction_house_key` - Auction house of the fill
+/// * `token_mint` - Mint of the bought token
+/// * `payment_mint` - Payment mint of the fill, Pubkey::default() for SOL
+/// * `buyer` - Buyer of the fill
+/// * `seller` - Seller of the fill
+/// * `price` - Price of a single token
+/// * `token_size` - Size of the fill
+/// * `trade_seq` - TradeSequence seq of the fill, as returned by record_sale
+#[allow(clippy::too_many_arguments)]
+pub fn record_fill_proof<'info>(
+    fill_proof: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    auction_house_key: &Pubkey,
+    token_mint: &Pubkey,
+    payment_mint: &Pubkey,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    price: u64,
+    token_size: u64,
+    trade_seq: u64,
+) -> Result<()> {
+    let fill_proof_seeds: &[&[u8]] = &[
+        PREFIX.as_bytes(),
+        FILL_PROOF.as_bytes(),
+        buyer.as_ref(),
+        token_mint.as_ref(),
+    ];
+    let fill_proof_bump = match assert_derivation(&crate::ID, fill_proof, fill_proof_seeds) {
+        Ok(bump) => bump,
+        Err(_) => return Ok(()),
+    };
+    let proof: FillProof = load_or_create_program_account(
+        fill_proof,
+        payer,
+        FillProof::LEN,
+        &[fill_proof_seeds, &[&[fill_proof_bump]]].concat(),
+    )?;
+    let slot = Clock::get()?.slot;
+    save_program_account(
+        fill_proof,
+        &FillProof {
+            auction_house_key: *auction_house_key,
+            buyer: *buyer,
+            seller: *seller,
+            token_mint: *token_mint,
+            payment_mint: *payment_mint,
+            price,
+            token_size,
+            trade_seq,
+            slot,
+            rent_payer: if proof.rent_payer == Pubkey::default() {
+                *payer.key
+            } else {
+                proof.rent_payer
+            },
+            bump: fill_proof_bump,
+        },
+    )?;
+
+    msg!(
+        "fill_proof: {{\"buyer\":\"{}\",\"token_mint\":\"{}\",\"trade_seq\":{},\"slot\":{}}}",
+        buyer,
+        token_mint,
+        trade_seq,
+        slot,
+    );
+    Ok(())
+}
+
+/// Records a zero-price gift of a token on the marketplace-wide TradeSequence, so indexers see it
+/// in the same ordered log as sales. Gifts are left out of CollectionStats and LastSale, they
+/// neither move the floor nor count as a sale. Returns the gift's seq
+///
+/// # Arguments
+/// * `trade_sequence` - TradeSequence pda
+/// * `payer` - Payer account, will pay for rent if the TradeSequence needs to be created
+/// * `token_mint` - Mint of the gifted token
+pub fn record_gift<'info>(
+    trade_sequence: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_mint: &Pubkey,
+) -> Result<u64> {
+    let trade_seq = next_trade_seq(trade_sequence, payer)?;
+    msg!(
+        "trade_seq: {{\"seq\":{},\"token_mint\":\"{}\",\"price\":0}}",
+        trade_seq,
+        token_mint,
+    );
+    Ok(trade_seq)
+}
+
+fn next_trade_seq<'info>(
+    trade_sequence: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+) -> Result<u64> {
+    let trade_sequence_seeds: &[&[u8]] = &[PREFIX.as_bytes(), TRADE_SEQUENCE.as_bytes()];
+    let trade_sequence_bump = assert_derivation(&crate::ID, trade_sequence, trade_sequence_seeds)?;
+    let mut trade_sequence_data: TradeSequence = load_or_create_program_account(
+        trade_sequence,
+        payer,
+        TradeSequence::LEN,
+        &[trade_sequence_seeds, &[&[trade_sequence_bump]]].concat(),
+    )?;
+    trade_sequence_data.seq = trade_sequence_data
+        .seq
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    trade_sequence_data.bump = trade_sequence_bump;
+    save_program_account(trade_sequence, &trade_sequence_data)?;
+    Ok(trade_sequence_data.seq)
+}
+
+/// Attributes a settled sale to the frontend that routed it, and pays the frontend's share of the
+/// platform fee from the treasury into its FrontendConfig for SOL sales, to be claimed with
+/// claim_frontend_fees. The frontend is only trusted when the notary signed the transaction, sales
+/// without a registered and enabled FrontendConfig (e.g. any non program owned account) are skipped.
+///
+/// # Arguments
+/// * `frontend_config` - FrontendConfig of the frontend, or any non program owned account
+/// * `notary` - Notary of the auction house
+/// * `auction_house` - Auction house of the sale
+/// * `auction_house_treasury` - Treasury the platform fee was paid into
+/// * `system_program` - System program
+/// * `price` - Price the sale was settled at
+/// * `platform_fee` - Maker fee + taker fee paid into the treasury
+/// * `is_spl` - Whether the sale was paid in a SPL token, only SOL sales pay a fee share
+#[allow(clippy::too_many_arguments)]
+pub fn attribute_frontend_sale<'info>(
+    frontend_config: &AccountInfo<'info>,
+    notary: &AccountInfo<'info>,
+    auction_house: &Account<'info, AuctionHouse>,
+    auction_house_treasury: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    price: u64,
+    platform_fee: u64,
+    is_spl: bool,
+) -> Result<()> {
+    if frontend_config.owner != &crate::ID || !notary.is_signer {
+        return Ok(());
+    }
+    let mut config = FrontendConfig::try_deserialize(&mut &frontend_config.data.borrow()[..])?;
+    let auction_house_key = auction_house.key();
+    let derived_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            FRONTEND_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+            config.frontend.as_ref(),
+            &[config.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+    if derived_key != *frontend_config.key {
+        return Err(ErrorCode::DerivedKeyInvalid.into());
+    }
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let fee_share = if is_spl {
+        0
+    } else {
+        (platform_fee as u128)
+            .checked_mul(config.fee_share_bp as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::NumericalOverflow)? as u64
+    };
+    if fee_share > 0 {
+        invoke_signed(
+            &system_instruction::transfer(
+                auction_house_treasury.key,
+                frontend_config.key,
+                fee_share,
+            ),
+            &[
+                auction_house_treasury.clone(),
+                frontend_config.clone(),
+                system_program.clone(),
+            ],
+            &[&[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                TREASURY.as_bytes(),
+                &[auction_house.treasury_bump],
+            ]],
+        )?;
+    }
+
+    config.sale_count = config
+        .sale_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    config.volume = config
+        .volume
+        .checked_add(price as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    config.fees_earned = config
+        .fees_earned
+        .checked_add(fee_share)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    save_program_account(frontend_config, &config)?;
+
+    msg!(
+        "frontend_sale: {{\"frontend\":\"{}\",\"price\":{},\"fee_share\":{}}}",
+        config.frontend,
+        price,
+        fee_share,
+    );
+    Ok(())
+}
+
+/// Pays the InsuranceFund's share of the taker fee of a SOL settlement from the treasury into the
+/// fund. Unlike frontend attribution the fund can't be skipped by passing another account, it has
+/// to be the derived InsuranceFund of the auction house, which is only skipped until the authority
+/// sets it up with set_insurance_fund.
+///
+/// # Arguments
+/// * `insurance_fund` - InsuranceFund pda of the auction house
+/// * `auction_house` - Auction house of the sale
+/// * `auction_house_treasury` - Treasury the taker fee was paid into
+/// * `system_program` - System program
+/// * `taker_fee` - Taker fee paid into the treasury
+/// * `is_spl` - Whether the sale was paid in a SPL token, only SOL sales fund insurance
+pub fn fund_insurance<'info>(
+    insurance_fund: &AccountInfo<'info>,
+    auction_house: &Account<'info, AuctionHouse>,
+    auction_house_treasury: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    taker_fee: u64,
+    is_spl: bool,
+) -> Result<()> {
+    let auction_house_key = auction_house.key();
+    assert_derivation(
+        &crate::ID,
+        insurance_fund,
+        &[
+            PREFIX.as_bytes(),
+            INSURANCE_FUND.as_bytes(),
+            auction_house_key.as_ref(),
+        ],
+    )?;
+    if insurance_fund.owner != &crate::ID || is_spl {
+        return Ok(());
+    }
+    let mut fund = InsuranceFund::try_deserialize(&mut &insurance_fund.data.borrow()[..])?;
+    if !fund.enabled {
+        return Ok(());
+    }
+
+    let share = (taker_fee as u128)
+        .checked_mul(fund.taker_fee_share_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    if share == 0 {
+        return Ok(());
+    }
+    invoke_signed(
+        &system_instruction::transfer(auction_house_treasury.key, insurance_fund.key, share),
+        &[
+            auction_house_treasury.clone(),
+            insurance_fund.clone(),
+            system_program.clone(),
+        ],
+        &[&[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            TREASURY.as_bytes(),
+            &[auction_house.treasury_bump],
+        ]],
+    )?;
+    fund.total_funded = fund
+        .total_funded
+        .checked_add(share)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    save_program_account(insurance_fund, &fund)?;
+
+    msg!(
+        "insurance_funded: {{\"auction_house\":\"{}\",\"taker_fee\":{},\"share\":{}}}",
+        auction_house_key,
+        taker_fee,
+        share,
+    );
+    Ok(())
+}
+
+/// Enters the taker of a settlement into the fee rebate draw of the current epoch. Like frontend
+/// attribution this is opt in: anything but the committed RebateEpoch of the auction house for
+/// the current epoch is skipped, and so are settlements past MAX_REBATE_TICKETS, which still
+/// count towards ticket_count
+pub fn enter_rebate_draw(
+    rebate_epoch: &AccountInfo,
+    auction_house_key: &Pubkey,
+    taker: &Pubkey,
+) -> Result<()> {
+    if rebate_epoch.owner != &crate::ID {
+        return Ok(());
+    }
+    let mut draw = RebateEpoch::try_deserialize(&mut &rebate_epoch.data.borrow()[..])?;
+    let clock = Clock::get()?;
+    if draw.auction_house_key != *auction_house_key || draw.epoch != clock.epoch || draw.drawn {
+        return Ok(());
+    }
+    let derived_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            REBATE_EPOCH.as_bytes(),
+            auction_house_key.as_ref(),
+            draw.epoch.to_le_bytes().as_ref(),
+            &[draw.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::DerivedKeyInvalid)?;
+    if derived_key != *rebate_epoch.key {
+        return Err(ErrorCode::DerivedKeyInvalid.into());
+    }
+
+    draw.ticket_count = draw
+        .ticket_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    draw.entries_hash = keccak::hashv(&[
+        &draw.entries_hash,
+        taker.as_ref(),
+        &clock.slot.to_le_bytes(),
+    ])
+    .to_bytes();
+    if draw.tickets.len() < MAX_REBATE_TICKETS {
+        draw.tickets.push(*taker);
+    }
+    save_program_account(rebate_epoch, &draw)?;
+
+    msg!(
+        "rebate_entry: {{\"epoch\":{},\"taker\":\"{}\",\"ticket\":{}}}",
+        draw.epoch,
+        taker,
+        draw.ticket_count,
+    );
+    Ok(())
+}
+
+/// Returns the ticket that wins a rebate draw, from the revealed seed and the entries_hash the
+/// settlements of the epoch left. Takers don't know the seed, the randomness authority does and is
+/// trusted not to steer the entries_hash with its own settlements
+pub fn get_rebate_winner_index(seed: &[u8; 32], entries_hash: &[u8; 32], tickets: usize) -> usize {
+    let hash = keccak::hashv(&[seed, entries_hash]).to_bytes();
+    let mut le_bytes = [0u8; 8];
+    le_bytes.copy_from_slice(&hash[..8]);
+    (u64::from_le_bytes(le_bytes) % tickets as u64) as usize
+}
+
+/// Returns the current floor of (auction_house, collection, payment_mint) from its CollectionStats
+pub fn get_floor_price(
+    collection_stats: &AccountInfo,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+    payment_mint: &Pubkey,
+) -> Result<u64> {
+    let collection = get_collection_key(metadata_parsed);
+    assert_derivation(
+        &crate::ID,
+        collection_stats,
+        &[
+            PREFIX.as_bytes(),
+            COLLECTION_STATS.as_bytes(),
+            auction_house_key.as_ref(),
+            collection.as_ref(),
+            payment_mint.as_ref(),
+        ],
+    )?;
+    if collection_stats.data_is_empty() {
+        return Err(ErrorCode::FloorPriceUnavailable.into());
+    }
+    assert_owned_by(collection_stats, &crate::ID)?;
+    let stats = CollectionStats::try_deserialize(&mut &collection_stats.data.borrow()[..])?;
+    if stats.floor_price == 0 {
+        return Err(ErrorCode::FloorPriceUnavailable.into());
+    }
+    Ok(stats.floor_price)
+}
+
+/// Resolves the settlement price of a listing. Fixed price listings settle at the bid price,
+/// floor pegged and dutch listings settle at their current price as long as the bid price covers it.
+/// collection_stats is only read for floor pegged listings, which can't settle without it.
+pub fn get_listing_price(
+    sell_args: &SellArgs,
+    bid_price: u64,
+    collection_stats: Option<&AccountInfo>,
+    auction_house_key: &Pubkey,
+    metadata_parsed: &Metadata,
+    payment_mint: &Pubkey,
+) -> Result<u64> {
+    if sell_args.dutch_decay_interval > 0 {
+        let dutch_price = get_dutch_price(
+            sell_args.buyer_price,
+            sell_args.dutch_end_price,
+            sell_args.dutch_start_time,
+            sell_args.dutch_decay_interval,
+            Clock::get()?.unix_timestamp,
+        )?;
+        if dutch_price > bid_price {
+            return Err(ErrorCode::InvalidPrice.into());
+        }
+        return Ok(dutch_price);
+    }
+    let floor_premium_bp = match sell_args.floor_premium_bp {
+        Some(bp) => bp,
+        None => return Ok(bid_price),
+    };
+    let floor_price = get_floor_price(
+        collection_stats.ok_or(ErrorCode::FloorPriceUnavailable)?,
+        auction_house_key,
+        metadata_parsed,
+        payment_mint,
+    )?;
+    let pegged_price =
+        get_floor_pegged_price(floor_price, floor_premium_bp, sell_args.buyer_price)?;
+    if pegged_price > bid_price {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    Ok(pegged_price)
+}
+
+/// Resolves the price of a floor pegged listing, never going below the seller's min_price
+pub fn get_floor_pegged_price(
+    floor_price: u64,
+    floor_premium_bp: i16,
+    min_price: u64,
+) -> Result<u64> {
+    let pegged_price = (floor_price as i128)
+        .checked_mul(10000 + floor_premium_bp as i128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let price = std::cmp::max(pegged_price, min_price as i128);
+    if price <= 0 || price > MAX_PRICE as i128 {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    Ok(price as u64)
+}
+
+/// Resolves the price of a dutch listing, decaying linearly from start_price at start_time to
+/// end_price at start_time + decay_interval
+pub fn get_dutch_price(
+    start_price: u64,
+    end_price: u64,
+    start_time: i64,
+    decay_interval: i64,
+    now: i64,
+) -> Result<u64> {
+    if decay_interval <= 0 || end_price > start_price {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    let elapsed = now.saturating_sub(start_time).clamp(0, decay_interval);
+    let decay = ((start_price - end_price) as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(decay_interval as u128)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    Ok(start_price - decay)
+}
+
+/// Returns the listing a take profit bid relists its bought token with: a non-movable listing of
+/// the buyer at the take_profit_price, in the payment mint of the bid
+///
+/// # Arguments
+/// * `bid_args` - Bid the token was bought with, take_profit_price > 0
+/// * `token_account` - Token account of the buyer the token was delivered to
+/// * `token_size` - Size of the fill
+/// * `bump` - Bump of the take profit seller_trade_state
+pub fn get_take_profit_listing(
+    bid_args: &BidArgs,
+    token_account: &Pubkey,
+    token_size: u64,
+    bump: u8,
+) -> SellerTradeStateV2 {
+    SellerTradeStateV2 {
+        auction_house_key: bid_args.auction_house_key,
+        seller: bid_args.buyer,
+        seller_referral: bid_args.buyer_referral,
+        buyer_price: bid_args.take_profit_price,
+        token_mint: bid_args.token_mint,
+        token_account: *token_account,
+        token_size,
+        bump,
+        expiry: -1,
+        payment_mint: bid_args.payment_mint,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_rebate_winner_index_depends_on_seed_and_entries() {
+        let entries_hash = [7u8; 32];
+        for seed in 0..20u8 {
+            assert!(get_rebate_winner_index(&[seed; 32], &entries_hash, 3) < 3);
+        }
+        assert_eq!(get_rebate_winner_index(&[1; 32], &entries_hash, 1), 0);
+        let winners = (0..20u8)
+            .map(|seed| get_rebate_winner_index(&[seed; 32], &entries_hash, 1_000))
+            .collect::<Vec<_>>();
+        assert!(winners.iter().any(|w| *w != winners[0]));
+        assert_eq!(
+            get_rebate_winner_index(&[1; 32], &entries_hash, 1_000),
+            get_rebate_winner_index(&[1; 32], &entries_hash, 1_000)
+        );
+    }
+
+    #[test]
+    fn get_floor_pegged_price_applies_premium_and_min_price() {
+        assert_eq!(get_floor_pegged_price(1_000, 500, 1).unwrap(), 1_050);
+        assert_eq!(get_floor_pegged_price(1_000, -1_000, 1).unwrap(), 900);
+        assert_eq!(get_floor_pegged_price(1_000, -1_000, 950).unwrap(), 950);
+        assert!(get_floor_pegged_price(0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn collection_stats_record_sale_tracks_window_floor() {
+        let mut stats = CollectionStats::default();
+        stats.record_sale(100, 1_000).unwrap();
+        stats.record_sale(80, 2_000).unwrap();
+        stats.record_sale(120, 3_000).unwrap();
+        assert_eq!(stats.floor_price, 80);
+        assert_eq!(stats.sale_count, 3);
+        assert_eq!(stats.volume, 300);
+        assert_eq!(stats.last_sale_price, 120);
+
+        // a sale after the window is over starts a new window
+        stats
+            .record_sale(150, 1_000 + crate::constants::FLOOR_WINDOW_SECONDS + 1)
+            .unwrap();
+        assert_eq!(stats.floor_price, 150);
+    }
+
+    #[test]
+    fn collection_stats_twap_weights_sales_by_time() {
+        let mut stats = CollectionStats::default();
+        assert_eq!(stats.get_twap(1_000).unwrap(), 0);
+        stats.record_sale(100, 1_000).unwrap();
+        assert_eq!(stats.twap_price, 100);
+        // a wash sale seconds after barely moves the twap
+        stats.record_sale(100, 2_000).unwrap();
+        stats.record_sale(10_000, 2_001).unwrap();
+        assert_eq!(stats.twap_price, 100);
+        assert_eq!(stats.get_twap(3_001).unwrap(), 5_047);
+
+        // only the latest TWAP_SLOTS sales are kept
+        for i in 0..crate::constants::TWAP_SLOTS as i64 {
+            stats.record_sale(50, 10_000 + i * 100).unwrap();
+        }
+        assert_eq!(stats.twap_price, 50);
+        assert_eq!(stats.sale_count, 3 + crate::constants::TWAP_SLOTS as u64);
+    }
+
+    #[test]
+    fn get_dutch_price_decays_linearly() {
+        assert_eq!(get_dutch_price(1_000, 200, 100, 80, 50).unwrap(), 1_000);
+        assert_eq!(get_dutch_price(1_000, 200, 100, 80, 100).unwrap(), 1_000);
+        assert_eq!(get_dutch_price(1_000, 200, 100, 80, 140).unwrap(), 600);
+        assert_eq!(get_dutch_price(1_000, 200, 100, 80, 180).unwrap(), 200);
+        assert_eq!(get_dutch_price(1_000, 200, 100, 80, 1_000).unwrap(), 200);
+        assert!(get_dutch_price(200, 1_000, 100, 80, 140).is_err());
+    }
+
+    #[test]
+    fn get_take_profit_listing_lists_the_bought_token_for_the_buyer() {
+        let bid_args = BidArgs {
+            auction_house_key: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            buyer_referral: Pubkey::new_unique(),
+            buyer_price: 1_000,
+            token_mint: Pubkey::new_unique(),
+            token_size: 1,
+            bump: 254,
+            expiry: 0,
+            buyer_creator_royalty_bp: 0,
+            payment_mint: Pubkey::new_unique(),
+            escrowless: false,
+            take_profit_price: 1_500,
+            target_seller: Pubkey::default(),
+        };
+        let token_account = Pubkey::new_unique();
+        let listing = get_take_profit_listing(&bid_args, &token_account, 1, 253);
+        assert_eq!(listing.auction_house_key, bid_args.auction_house_key);
+        assert_eq!(listing.seller, bid_args.buyer);
+        assert_eq!(listing.seller_referral, bid_args.buyer_referral);
+        assert_eq!(listing.buyer_price, 1_500);
+        assert_eq!(listing.token_mint, bid_args.token_mint);
+        assert_eq!(listing.token_account, token_account);
+        assert_eq!(listing.token_size, 1);
+        assert_eq!(listing.bump, 253);
+        // non-movable, the token account is handed to program_as_signer
+        assert_eq!(listing.expiry, -1);
+        assert_eq!(listing.payment_mint, bid_args.payment_mint);
+        assert_eq!(listing.floor_premium_bp, None);
+        assert_eq!(listing.dutch_decay_interval, 0);
+        assert_eq!(listing.not_before, 0);
+    }
+}
@@ -0,0 +1,988 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::accounts::Metadata;
+use open_creator_protocol::state::Policy;
+
+use solana_program::program_option::COption;
+
+use super::{
+    apply_t22_group_collection, assert_buyer_not_suspended, assert_collection_not_paused,
+    assert_initialized, assert_keys_equal, assert_metadata_valid, assert_price_attested,
+    assert_seller_not_suspended, attribute_frontend_sale, close_account_anchor, enter_rebate_draw,
+    fund_insurance, get_active_royalty_subsidy, get_actual_maker_taker_fee_bp,
+    get_creator_listing_maker_fee_bp, get_listing_price, get_payment_decimals, get_taker_fee_bp,
+    pay_creator_fees, record_fill_proof, record_last_sale, record_sale, save_program_account,
+    transfer_listing_payment, try_close_buyer_escrow, uncount_open_order, Diagnose, OrderSide,
+    TransferCreatorSplArgs, TransferListingPaymentSplArgs,
+};
+use crate::constants::{CLOCK_SKEW_SECONDS, MIN_TREASURY_LEFTOVER, PREFIX, SIGNER, TREASURY};
+use crate::errors::ErrorCode;
+use crate::index_ra;
+use crate::states::{AuctionHouse, BidArgs, RoyaltySubsidy, SellArgs, SellerTradeStateV2};
+
+/// Accounts shared by the listing settlement paths. A path fills in a SettlementContext, calls
+/// validate, moves the asset the way its token standard needs it, then calls pay and close, so a
+/// new asset type only has to implement the asset transfer.
+///
+/// If the bid is paid in a SPL token, remaining_accounts starts with payment_mint,
+/// payment_source_token_account, payment_seller_token_account and payment_treasury_token_account,
+/// followed by the creator accounts. The payment_source_token_account of an escrowless bid is the
+/// buyer's token account delegated to program_as_signer, otherwise it's the ata of the escrow.
+/// If a SOL listing has a secondary payment leg, remaining_accounts starts with
+/// secondary_payment_mint, the buyer's secondary token account delegated to program_as_signer,
+/// the seller's and the treasury's secondary token accounts instead, followed by the creators.
+///
+/// The policy and record accounts are optional, the execute instructions that shipped before them
+/// take them as OptionalAccounts. collection_config, seller_status, buyer_status and
+/// price_band_config are required, their PDAs may not exist yet, which doesn't pause the
+/// collection, suspend anyone or require a price attestation. last_sale is required and always
+/// written, and so are the counted orders of both trade states, so an order counted against a
+/// wallet's limit is always uncounted. Any other record whose account isn't passed is skipped.
+/// Accounts a passed one depends on are required: the price_attestation of an enabled
+/// PriceBandConfig, the price_feed of an enabled TakerFeeConfig, the order counter of a counted
+/// order and the collection_stats of a floor pegged listing.
+pub struct SettlementContext<'r, 'info> {
+    pub auction_house: &'r Account<'info, AuctionHouse>,
+    pub auction_house_treasury: &'r AccountInfo<'info>,
+    pub notary: &'r AccountInfo<'info>,
+    pub buyer: &'r AccountInfo<'info>,
+    pub seller: &'r AccountInfo<'info>,
+    pub taker: &'r AccountInfo<'info>,
+    pub payer: &'r AccountInfo<'info>,
+    pub buyer_trade_state: &'r AccountInfo<'info>,
+    pub seller_trade_state: &'r AccountInfo<'info>,
+    pub buyer_referral: &'r AccountInfo<'info>,
+    pub seller_referral: &'r AccountInfo<'info>,
+    pub escrow_payment_account: &'r AccountInfo<'info>,
+    pub escrow_payment_bump: u8,
+    pub program_as_signer: &'r AccountInfo<'info>,
+    pub program_as_signer_bump: u8,
+    pub metadata: &'r AccountInfo<'info>,
+    pub collection_config: &'r AccountInfo<'info>,
+    pub seller_status: &'r AccountInfo<'info>,
+    pub buyer_status: &'r AccountInfo<'info>,
+    pub price_band_config: &'r AccountInfo<'info>,
+    pub price_attestation: Option<&'r AccountInfo<'info>>,
+    pub collection_stats: Option<&'r AccountInfo<'info>>,
+    pub last_sale: &'r AccountInfo<'info>,
+    pub trade_sequence: Option<&'r AccountInfo<'info>>,
+    pub frontend_config: Option<&'r AccountInfo<'info>>,
+    pub taker_fee_config: Option<&'r AccountInfo<'info>>,
+    pub price_feed: Option<&'r AccountInfo<'info>>,
+    pub royalty_subsidy: Option<&'r AccountInfo<'info>>,
+    pub rebate_epoch: Option<&'r AccountInfo<'info>>,
+    pub fill_proof: Option<&'r AccountInfo<'info>>,
+    pub insurance_fund: Option<&'r AccountInfo<'info>>,
+    pub buyer_order_counter: Option<&'r AccountInfo<'info>>,
+    pub seller_order_counter: Option<&'r AccountInfo<'info>>,
+    pub buyer_counted_order: &'r AccountInfo<'info>,
+    pub seller_counted_order: &'r AccountInfo<'info>,
+    pub ocp_policy: Option<&'r Account<'info, Policy>>,
+    pub t22_mint: Option<&'r AccountInfo<'info>>, // its token group stands in for a missing collection
+    pub token_program: &'r AccountInfo<'info>,
+    pub system_program: &'r Program<'info, System>,
+    pub remaining_accounts: &'r [AccountInfo<'info>],
+}
+
+/// Terms both trade states agreed on, returned by SettlementContext::validate
+pub struct SettlementTerms {
+    pub bid_args: BidArgs,
+    pub sell_args: SellArgs,
+    pub metadata_parsed: Metadata,
+    pub price: u64,      // price of the whole fill
+    pub unit_price: u64, // price of a single token
+    pub token_size: u64, // size of the fill
+    pub is_spl: bool,
+    pub secondary_price: u64, // secondary leg of the whole fill, 0 for none
+}
+
+impl SettlementTerms {
+    /// Size the listing has left after this fill
+    pub fn get_remaining_size(&self) -> u64 {
+        self.sell_args.token_size.saturating_sub(self.token_size)
+    }
+
+    /// Index of the first creator account in remaining_accounts
+    pub fn get_creator_accounts_offset(&self) -> usize {
+        if self.is_spl || self.secondary_price > 0 {
+            4
+        } else {
+            0
+        }
+    }
+}
+
+/// Amounts paid out by SettlementContext::pay
+pub struct SettlementPayout {
+    pub royalty: u64,         // paid by the buyer
+    pub royalty_subsidy: u64, // paid by the treasury on top of royalty
+    pub maker_fee: i64,
+    pub taker_fee: u64,
+}
+
+impl SettlementPayout {
+    /// What the buyer paid in total for a fill at price: the price, the royalty and the fee paid
+    /// out of the buyer's funds, which is the taker fee, or the maker fee if the seller is the taker
+    pub fn get_buyer_cost(&self, price: u64, seller_is_taker: bool) -> Result<u64> {
+        let fee = if seller_is_taker {
+            self.maker_fee as i128
+        } else {
+            self.taker_fee as i128
+        };
+        let cost = (price as i128)
+            .checked_add(self.royalty as i128)
+            .and_then(|c| c.checked_add(fee))
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        Ok(cost.max(0) as u64)
+    }
+
+    /// What the seller keeps of a fill at price, see get_seller_net_proceeds
+    pub fn get_seller_proceeds(&self, price: u64, seller_is_taker: bool) -> u64 {
+        get_seller_net_proceeds(price, self.maker_fee, self.taker_fee, seller_is_taker)
+    }
+}
+
+/// What the seller keeps of a sale at price after fees: the price minus the maker fee if the buyer
+/// is the taker, and the price minus the taker fee if the seller takes the bid, as the seller gets
+/// the price plus the maker fee from the buyer then and pays both fees
+pub fn get_seller_net_proceeds(
+    price: u64,
+    maker_fee: i64,
+    taker_fee: u64,
+    seller_is_taker: bool,
+) -> u64 {
+    let fee = if seller_is_taker {
+        taker_fee as i128
+    } else {
+        maker_fee as i128
+    };
+    (price as i128 - fee).max(0) as u64
+}
+
+/// Checks a seller's net proceeds against the seller's min net proceeds, None accepts any
+pub fn assert_min_net_proceeds(proceeds: u64, min_net_proceeds: Option<u64>) -> Result<()> {
+    match min_net_proceeds {
+        Some(min_net_proceeds) if proceeds < min_net_proceeds => {
+            Err(ErrorCode::MinNetProceedsNotMet.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks a buyer's cost against the taker's max price, None accepts any cost
+pub fn assert_max_price(buyer_cost: u64, max_price: Option<u64>) -> Result<()> {
+    match max_price {
+        Some(max_price) if buyer_cost > max_price => Err(ErrorCode::MaxPriceExceeded.into()),
+        _ => Ok(()),
+    }
+}
+
+impl<'r, 'info> SettlementContext<'r, 'info> {
+    /// Checks that the trade states match each other and the requested sale, and that nothing
+    /// (expiry, pauses, suspensions, price bands, private listings) blocks it. Returns the price
+    /// the sale settles at, which differs from requested_price for floor pegged and dutch listings.
+    ///
+    /// A listing's buyer_price is the price of a single token, token_size can be any part of the
+    /// listed size and requested_price is the bid price of the whole fill.
+    pub fn validate(
+        &self,
+        token_mint: &Pubkey,
+        token_size: u64,
+        requested_price: u64,
+    ) -> Result<SettlementTerms> {
+        let remaining_accounts = self.remaining_accounts;
+        if self.buyer_trade_state.data_is_empty() || self.seller_trade_state.data_is_empty() {
+            return Err(ErrorCode::BothPartiesNeedToAgreeToSale.into())
+                .diagnose("trade_states_exist", "buyer_trade_state");
+        }
+        let bid_args = BidArgs::from_account_info(self.buyer_trade_state)
+            .diagnose("bid_args", "buyer_trade_state")?;
+        let is_spl = bid_args.payment_mint != Pubkey::default();
+        bid_args
+            .check_args(
+                self.buyer_referral.key,
+                requested_price,
+                token_mint,
+                token_size,
+                if is_spl {
+                    index_ra!(remaining_accounts, 0).key // mint account
+                } else {
+                    &bid_args.payment_mint
+                },
+            )
+            .diagnose("bid_args_match", "buyer_trade_state")?;
+        assert_metadata_valid(self.metadata, token_mint).diagnose("metadata", "metadata")?;
+        let mut metadata_parsed = Metadata::safe_deserialize(&self.metadata.data.borrow())?;
+        if let Some(t22_mint) = self.t22_mint {
+            apply_t22_group_collection(&mut metadata_parsed, t22_mint)?;
+        }
+
+        let sell_args = SellArgs::from_account_info(self.seller_trade_state)
+            .diagnose("sell_args", "seller_trade_state")?;
+        sell_args
+            .assert_allowed_buyer(self.buyer.key)
+            .diagnose("allowed_buyer", "buyer")?;
+        bid_args
+            .assert_target_seller(self.seller.key)
+            .diagnose("target_seller", "seller")?;
+        if token_size == 0 || token_size > sell_args.token_size {
+            return Err(ErrorCode::InvalidTokenAmount.into())
+                .diagnose("token_size", "seller_trade_state");
+        }
+        let requested_unit_price = get_unit_price(requested_price, token_size)?;
+        let auction_house_key = self.auction_house.key();
+        let unit_price = get_listing_price(
+            &sell_args,
+            requested_unit_price,
+            self.collection_stats,
+            &auction_house_key,
+            &metadata_parsed,
+            &bid_args.payment_mint,
+        )
+        .diagnose("listing_price", "collection_stats")?;
+        sell_args
+            .check_args(
+                self.seller_referral.key,
+                if sell_args.has_dynamic_price() {
+                    &sell_args.buyer_price
+                } else {
+                    &requested_unit_price
+                }, // buyer_price of a floor pegged or dutch listing is not the settlement price
+                token_mint,
+                &sell_args.token_size,  // checked against the fill above
+                &bid_args.payment_mint, // check that mints match, equality is transitive
+            )
+            .diagnose("sell_args_match", "seller_trade_state")?;
+        let price = unit_price
+            .checked_mul(token_size)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let secondary_price = sell_args
+            .secondary_price
+            .checked_mul(token_size)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        if secondary_price > 0 {
+            // the buyer agrees to the secondary leg by taking the listing
+            if self.taker.key != self.buyer.key {
+                return Err(ErrorCode::SecondaryLegNeedsBuyerTaker.into())
+                    .diagnose("secondary_leg", "taker");
+            }
+            assert_keys_equal(
+                index_ra!(remaining_accounts, 0).key,
+                &sell_args.secondary_payment_mint,
+            )
+            .diagnose("secondary_payment_mint", "remaining_accounts[0]")?;
+            assert_delegated_payment(
+                index_ra!(remaining_accounts, 1),
+                self.buyer.key,
+                self.program_as_signer.key,
+                &sell_args.secondary_payment_mint,
+                secondary_price,
+            )
+            .diagnose("secondary_delegated_payment", "remaining_accounts[1]")?;
+        }
+        if bid_args.escrowless {
+            assert_delegated_payment(
+                index_ra!(remaining_accounts, 1),
+                self.buyer.key,
+                self.program_as_signer.key,
+                &bid_args.payment_mint,
+                price,
+            )
+            .diagnose("delegated_payment", "remaining_accounts[1]")?;
+        }
+        let now = Clock::get()?.unix_timestamp;
+        assert_settlement_not_expired(bid_args.expiry, sell_args.expiry, now)
+            .diagnose("expiry", "trade_states")?;
+        assert_listing_active(sell_args.not_before, now)
+            .diagnose("not_before", "seller_trade_state")?;
+
+        assert_collection_not_paused(self.collection_config, &auction_house_key, &metadata_parsed)
+            .diagnose("collection_config", "collection_config")?;
+        assert_price_attested(
+            self.price_band_config,
+            self.price_attestation,
+            &auction_house_key,
+            &metadata_parsed,
+            &bid_args.payment_mint,
+            unit_price,
+        )
+        .diagnose("price_attestation", "price_attestation")?;
+        assert_seller_not_suspended(self.seller_status, self.seller.key)
+            .diagnose("seller_status", "seller_status")?;
+        assert_buyer_not_suspended(self.buyer_status, self.buyer.key)
+            .diagnose("buyer_status", "buyer_status")?;
+
+        Ok(SettlementTerms {
+            bid_args,
+            sell_args,
+            metadata_parsed,
+            price,
+            unit_price,
+            token_size,
+            is_spl,
+            secondary_price,
+        })
+    }
+
+    /// Pays creator royalties at royalty_bp of the creator fee, then the seller and the treasury
+    /// out of the buyer escrow, or for escrowless bids out of the buyer's delegated token account.
+    /// While a RoyaltySubsidy of the collection is active, the treasury pays its share of the
+    /// royalty of SOL sales instead of the buyer, as long as its budget and the treasury cover it.
+    pub fn pay(
+        &self,
+        terms: &SettlementTerms,
+        maker_fee_bp: i16,
+        taker_fee_bp: u16,
+        royalty_bp: u16,
+    ) -> Result<SettlementPayout> {
+        let remaining_accounts = self.remaining_accounts;
+        let auction_house_key = self.auction_house.key();
+        let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            self.buyer.key.as_ref(),
+            &[self.escrow_payment_bump],
+        ]];
+        let program_as_signer_seeds: &[&[&[u8]]] = &[&[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes(),
+            &[self.program_as_signer_bump],
+        ]];
+        let (payment_authority, payment_signer_seeds) = if terms.bid_args.escrowless {
+            (self.program_as_signer, program_as_signer_seeds)
+        } else {
+            (self.escrow_payment_account, escrow_signer_seeds)
+        };
+
+        let mut subsidy = match self.royalty_subsidy {
+            Some(royalty_subsidy) if !terms.is_spl && royalty_bp > 0 => get_active_royalty_subsidy(
+                royalty_subsidy,
+                &auction_house_key,
+                &terms.metadata_parsed,
+                Clock::get()?.unix_timestamp,
+            )?,
+            _ => None,
+        };
+        let (buyer_royalty_bp, treasury_royalty_bp) = match &subsidy {
+            Some(s) => {
+                let (buyer_royalty_bp, treasury_royalty_bp) =
+                    split_subsidized_royalty_bp(royalty_bp, s.subsidy_bp);
+                let estimate = get_creator_royalty(
+                    terms.price,
+                    terms.metadata_parsed.seller_fee_basis_points,
+                    treasury_royalty_bp,
+                )?;
+                // a subsidy the budget or the treasury can't cover is skipped, the sale goes through
+                if can_treasury_subsidize(s, self.auction_house_treasury.lamports(), estimate) {
+                    (buyer_royalty_bp, treasury_royalty_bp)
+                } else {
+                    (royalty_bp, 0)
+                }
+            }
+            None => (royalty_bp, 0),
+        };
+
+        let royalty = if buyer_royalty_bp == 0 {
+            0
+        } else {
+            pay_creator_fees(
+                &mut remaining_accounts[terms.get_creator_accounts_offset()..].iter(),
+                self.ocp_policy,
+                &terms.metadata_parsed,
+                payment_authority,
+                payment_signer_seeds,
+                terms.price,
+                buyer_royalty_bp,
+                if terms.is_spl {
+                    Some(TransferCreatorSplArgs {
+                        buyer: self.buyer,
+                        payer: self.payer,
+                        mint: index_ra!(remaining_accounts, 0),
+                        payment_source_token_account: index_ra!(remaining_accounts, 1),
+                        system_program: self.system_program,
+                        token_program: self.token_program,
+                    })
+                } else {
+                    None
+                },
+            )?
+        };
+        let royalty_subsidy = match subsidy.as_mut() {
+            Some(s) if treasury_royalty_bp > 0 => {
+                let treasury_signer_seeds: &[&[&[u8]]] = &[&[
+                    PREFIX.as_bytes(),
+                    auction_house_key.as_ref(),
+                    TREASURY.as_bytes(),
+                    &[self.auction_house.treasury_bump],
+                ]];
+                let paid = pay_creator_fees(
+                    &mut remaining_accounts[terms.get_creator_accounts_offset()..].iter(),
+                    self.ocp_policy,
+                    &terms.metadata_parsed,
+                    self.auction_house_treasury,
+                    treasury_signer_seeds,
+                    terms.price,
+                    treasury_royalty_bp,
+                    None,
+                )?;
+                s.spent = s
+                    .spent
+                    .checked_add(paid)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                if s.spent > s.budget
+                    || self.auction_house_treasury.lamports() < MIN_TREASURY_LEFTOVER
+                {
+                    return Err(ErrorCode::InvalidAccountState.into());
+                }
+                if let Some(royalty_subsidy) = self.royalty_subsidy {
+                    save_program_account(royalty_subsidy, s)?;
+                }
+                msg!(
+                    "royalty_subsidy: {{\"collection\":\"{}\",\"amount\":{},\"spent\":{}}}",
+                    s.collection,
+                    paid,
+                    s.spent,
+                );
+                paid
+            }
+            _ => 0,
+        };
+
+        let (actual_maker_fee_bp, actual_taker_fee_bp) =
+            get_actual_maker_taker_fee_bp(self.notary, maker_fee_bp, taker_fee_bp);
+        // the secondary leg is charged the bp fees, the flat SOL taker fee applies once
+        let secondary_taker_fee_bp = actual_taker_fee_bp;
+        // the flat taker fee is quoted in USD through the SOL/USD feed
+        let actual_taker_fee_bp = match self.taker_fee_config {
+            Some(taker_fee_config) if !terms.is_spl => get_taker_fee_bp(
+                taker_fee_config,
+                self.price_feed,
+                &auction_house_key,
+                terms.price,
+                actual_maker_fee_bp,
+                actual_taker_fee_bp,
+            )?,
+            _ => actual_taker_fee_bp,
+        };
+        let actual_maker_fee_bp = get_creator_listing_maker_fee_bp(
+            &terms.sell_args,
+            self.taker.key == self.seller.key,
+            actual_maker_fee_bp,
+        );
+        let (maker_fee, taker_fee) = transfer_listing_payment(
+            terms.price,
+            actual_maker_fee_bp,
+            actual_taker_fee_bp,
+            self.taker,
+            self.seller,
+            payment_authority,
+            self.auction_house_treasury,
+            if terms.is_spl {
+                Some(TransferListingPaymentSplArgs {
+                    payer: self.payer,
+                    buyer: self.buyer,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                    payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                    system_program: self.system_program,
+                    token_program: self.token_program,
+                })
+            } else {
+                None
+            },
+            payment_signer_seeds,
+        )?;
+        if terms.secondary_price > 0 {
+            let (secondary_maker_fee, secondary_taker_fee) = transfer_listing_payment(
+                terms.secondary_price,
+                actual_maker_fee_bp,
+                secondary_taker_fee_bp,
+                self.taker,
+                self.seller,
+                self.program_as_signer,
+                self.auction_house_treasury,
+                Some(TransferListingPaymentSplArgs {
+                    payer: self.payer,
+                    buyer: self.buyer,
+                    mint: index_ra!(remaining_accounts, 0),
+                    payment_source_token_account: index_ra!(remaining_accounts, 1),
+                    payment_seller_token_account: index_ra!(remaining_accounts, 2),
+                    payment_treasury_token_account: index_ra!(remaining_accounts, 3),
+                    system_program: self.system_program,
+                    token_program: self.token_program,
+                }),
+                program_as_signer_seeds,
+            )?;
+            msg!(
+                "secondary_leg: {{\"payment_mint\":\"{}\",\"price\":{},\"maker_fee\":{},\"taker_fee\":{}}}",
+                terms.sell_args.secondary_payment_mint,
+                terms.secondary_price,
+                secondary_maker_fee,
+                secondary_taker_fee,
+            );
+        }
+
+        Ok(SettlementPayout {
+            royalty,
+            royalty_subsidy,
+            maker_fee,
+            taker_fee,
+        })
+    }
+
+    /// Records the sale and its fill proof, shares the platform fee with the frontend and closes
+    /// the trade states, and the buyer escrow if only its rent is left. A partially filled listing
+    /// stays open with its remaining size. Sales are recorded at the unit price, so floors stay comparable.
+    pub fn close(&self, terms: &SettlementTerms, payout: &SettlementPayout) -> Result<()> {
+        let auction_house_key = self.auction_house.key();
+        if let Some(frontend_config) = self.frontend_config {
+            attribute_frontend_sale(
+                frontend_config,
+                self.notary,
+                self.auction_house,
+                self.auction_house_treasury,
+                self.system_program,
+                terms.price,
+                payout
+                    .maker_fee
+                    .saturating_add(payout.taker_fee as i64)
+                    .max(0) as u64,
+                terms.is_spl,
+            )?;
+        }
+        if let Some(insurance_fund) = self.insurance_fund {
+            fund_insurance(
+                insurance_fund,
+                self.auction_house,
+                self.auction_house_treasury,
+                self.system_program,
+                payout.taker_fee,
+                terms.is_spl,
+            )?;
+        }
+        // the sale takes a trade_seq when all of its records are passed, the LastSale the hold
+        // period is read from is written either way
+        if let (Some(collection_stats), Some(trade_sequence)) =
+            (self.collection_stats, self.trade_sequence)
+        {
+            let trade_seq = record_sale(
+                collection_stats,
+                self.last_sale,
+                trade_sequence,
+                self.payer,
+                &auction_house_key,
+                &terms.metadata_parsed,
+                &terms.bid_args.payment_mint,
+                self.buyer.key,
+                self.seller.key,
+                terms.unit_price,
+                get_payment_decimals(if terms.is_spl {
+                    self.remaining_accounts.first()
+                } else {
+                    None
+                })?,
+            )?;
+            if let Some(fill_proof) = self.fill_proof {
+                record_fill_proof(
+                    fill_proof,
+                    self.payer,
+                    &auction_house_key,
+                    &terms.metadata_parsed.mint,
+                    &terms.bid_args.payment_mint,
+                    self.buyer.key,
+                    self.seller.key,
+                    terms.unit_price,
+                    terms.token_size,
+                    trade_seq,
+                )?;
+            }
+        } else {
+            record_last_sale(
+                self.last_sale,
+                self.payer,
+                &auction_house_key,
+                &terms.metadata_parsed.mint,
+                &terms.bid_args.payment_mint,
+                self.buyer.key,
+                self.seller.key,
+                terms.unit_price,
+                0,
+            )?;
+        }
+        if let Some(rebate_epoch) = self.rebate_epoch {
+            enter_rebate_draw(rebate_epoch, &auction_house_key, self.taker.key)?;
+        }
+
+        // we don't need to zero out buyer_trade_state, just copy zero discriminator to it and then close
+        close_account_anchor(self.buyer_trade_state, self.buyer)?;
+        uncount_open_order(
+            self.buyer_order_counter,
+            self.buyer_counted_order,
+            self.buyer_trade_state.key,
+            self.buyer,
+            &auction_house_key,
+            OrderSide::Bid,
+        )?;
+        let remaining_size = terms.get_remaining_size();
+        if remaining_size == 0 {
+            close_account_anchor(self.seller_trade_state, self.seller)?;
+            uncount_open_order(
+                self.seller_order_counter,
+                self.seller_counted_order,
+                self.seller_trade_state.key,
+                self.seller,
+                &auction_house_key,
+                OrderSide::Listing,
+            )?;
+        } else {
+            // only v2 listings can list more than one token
+            let mut sts = SellerTradeStateV2::try_deserialize(
+                &mut &self.seller_trade_state.data.borrow()[..],
+            )?;
+            sts.token_size = remaining_size;
+            save_program_account(self.seller_trade_state, &sts)?;
+        }
+
+        try_close_buyer_escrow(
+            self.escrow_payment_account,
+            self.buyer,
+            self.system_program,
+            &[&[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                self.buyer.key.as_ref(),
+                &[self.escrow_payment_bump],
+            ]],
+        )
+    }
+}
+
+/// Expiries of -1, 0 and 1 never expire, any other expiry is a unix timestamp, negative for
+/// non-movable trade states
+pub fn assert_settlement_not_expired(bid_expiry: i64, sell_expiry: i64, now: i64) -> Result<()> {
+    if is_trade_state_expired(bid_expiry, now) || is_trade_state_expired(sell_expiry, now) {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    Ok(())
+}
+
+/// Time locked listings can't be filled before not_before, 0 doesn't lock the listing
+pub fn assert_listing_active(not_before: i64, now: i64) -> Result<()> {
+    if !is_start_reached(not_before, now) {
+        return Err(ErrorCode::ListingNotYetActive.into());
+    }
+    Ok(())
+}
+
+/// Trade state expiries are unix timestamps, negative for some listing modes, -1, 0 and 1 never
+/// expire
+pub fn is_trade_state_expired(expiry: i64, now: i64) -> bool {
+    expiry.abs() > 1 && is_expiry_passed(expiry.abs(), now)
+}
+
+/// Every expiry check goes through here, so that all legs of a multi-instruction flow agree on
+/// whether an expiry passed: it only counts as passed CLOCK_SKEW_SECONDS after the fact,
+/// which absorbs validator clock drift between the transactions
+pub fn is_expiry_passed(expiry: i64, now: i64) -> bool {
+    now > expiry.saturating_add(CLOCK_SKEW_SECONDS)
+}
+
+/// The counterpart of is_expiry_passed for not_before and auction start times, which count as
+/// reached CLOCK_SKEW_SECONDS early. Auction end and rental deadlines use the raw clock
+pub fn is_start_reached(start: i64, now: i64) -> bool {
+    now >= start.saturating_sub(CLOCK_SKEW_SECONDS)
+}
+
+/// Checks that token_account of owner is delegated to delegate for at least amount of mint
+pub fn assert_delegated_payment(
+    token_account: &AccountInfo,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let parsed: spl_token::state::Account = assert_initialized(token_account)?;
+    if parsed.owner != *owner || parsed.mint != *mint {
+        return Err(ErrorCode::IncorrectOwner.into());
+    }
+    if parsed.delegate != COption::Some(*delegate)
+        || parsed.delegated_amount < amount
+        || parsed.amount < amount
+    {
+        return Err(ErrorCode::InsufficientDelegatedAmount.into());
+    }
+    Ok(())
+}
+
+/// Returns the price of a single token of a fill, the bid price has to split evenly over the fill
+pub fn get_unit_price(price: u64, token_size: u64) -> Result<u64> {
+    if token_size == 0 || !price.is_multiple_of(token_size) {
+        return Err(ErrorCode::InvalidPrice.into());
+    }
+    Ok(price / token_size)
+}
+
+/// Returns the part of a fill's price for what the buyer actually receives, when a token transfer
+/// fee withholds some of the token_size sent. Rounds down, in favor of the buyer
+pub fn get_received_price(price: u64, token_size: u64, received: u64) -> Result<u64> {
+    if token_size == 0 || received > token_size {
+        return Err(ErrorCode::InvalidTokenAmount.into());
+    }
+    Ok(((price as u128) * (received as u128) / (token_size as u128)) as u64)
+}
+
+/// Splits royalty_bp of the creator fee into the part the buyer pays and the subsidy_bp share of it
+/// the treasury pays, as (buyer_royalty_bp, treasury_royalty_bp)
+pub fn split_subsidized_royalty_bp(royalty_bp: u16, subsidy_bp: u16) -> (u16, u16) {
+    let treasury_royalty_bp = (royalty_bp as u32 * subsidy_bp.min(10000) as u32 / 10000) as u16;
+    (royalty_bp - treasury_royalty_bp, treasury_royalty_bp)
+}
+
+/// Royalty paid to the creators at royalty_bp of the creator fee of seller_fee_bp
+pub fn get_creator_royalty(price: u64, seller_fee_bp: u16, royalty_bp: u16) -> Result<u64> {
+    Ok((price as u128)
+        .checked_mul(seller_fee_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_mul(royalty_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64)
+}
+
+/// Whether the budget of the subsidy and the treasury above MIN_TREASURY_LEFTOVER both cover
+/// the estimated subsidy of a sale
+pub fn can_treasury_subsidize(
+    subsidy: &RoyaltySubsidy,
+    treasury_lamports: u64,
+    estimate: u64,
+) -> bool {
+    subsidy.spent.saturating_add(estimate) <= subsidy.budget
+        && treasury_lamports.saturating_sub(estimate) >= MIN_TREASURY_LEFTOVER
+}
+
+/// Returns (maker_fee, taker_fee, seller_amount) of a listing sale. The seller gets the price
+/// plus the maker fee from the buyer escrow if the seller is the taker and pays both fees, and the
+/// price minus the maker fee otherwise.
+pub fn get_listing_payment_amounts(
+    price: u64,
+    maker_fee_bp: i16,
+    taker_fee_bp: u16,
+    seller_is_taker: bool,
+) -> Result<(i64, u64, u64)> {
+    let maker_fee = (price as i128)
+        .checked_mul(maker_fee_bp as i128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as i64;
+    let taker_fee = (price as u128)
+        .checked_mul(taker_fee_bp as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    let seller_amount = if seller_is_taker {
+        (price as i64)
+            .checked_add(maker_fee)
+            .ok_or(ErrorCode::NumericalOverflow)?
+    } else {
+        (price as i64)
+            .checked_sub(maker_fee)
+            .ok_or(ErrorCode::NumericalOverflow)?
+    } as u64;
+    Ok((maker_fee, taker_fee, seller_amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::program_pack::Pack;
+
+    #[test]
+    fn assert_delegated_payment_checks_delegation() {
+        let (owner, delegate, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut data = [0; spl_token::state::Account::LEN];
+        spl_token::state::Account::pack(
+            spl_token::state::Account {
+                mint,
+                owner,
+                amount: 1_000,
+                delegate: COption::Some(delegate),
+                state: spl_token::state::AccountState::Initialized,
+                delegated_amount: 500,
+                ..Default::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let token_account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &spl_token::ID,
+            false,
+            0,
+        );
+
+        assert!(assert_delegated_payment(&token_account, &owner, &delegate, &mint, 500).is_ok());
+        assert!(assert_delegated_payment(&token_account, &owner, &delegate, &mint, 501).is_err());
+        assert!(assert_delegated_payment(&token_account, &delegate, &delegate, &mint, 1).is_err());
+        assert!(assert_delegated_payment(&token_account, &owner, &owner, &mint, 1).is_err());
+    }
+
+    #[test]
+    fn assert_settlement_not_expired_ignores_unset_expiries() -> Result<()> {
+        for expiry in [-1, 0, 1] {
+            assert_settlement_not_expired(expiry, expiry, i64::MAX)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn is_trade_state_expired_after_expiry() {
+        let late = 100 + CLOCK_SKEW_SECONDS;
+        assert!(!is_trade_state_expired(100, 101));
+        assert!(!is_trade_state_expired(100, late));
+        assert!(is_trade_state_expired(100, late + 1));
+        assert!(is_trade_state_expired(-100, late + 1));
+        assert!(!is_trade_state_expired(-1, i64::MAX));
+        assert!(!is_expiry_passed(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn assert_listing_active_waits_for_not_before() {
+        let early = 1_000 - CLOCK_SKEW_SECONDS;
+        assert!(assert_listing_active(0, 1_000).is_ok());
+        assert!(assert_listing_active(1_000, early - 1).is_err());
+        assert!(assert_listing_active(1_000, early).is_ok());
+        assert!(assert_listing_active(1_000, 1_000).is_ok());
+        assert!(is_start_reached(i64::MIN, i64::MIN));
+    }
+
+    #[test]
+    fn assert_settlement_not_expired_checks_both_trade_states() {
+        let late = 100 + CLOCK_SKEW_SECONDS;
+        assert!(assert_settlement_not_expired(100, -200, late).is_ok());
+        assert!(assert_settlement_not_expired(100, -200, late + 1).is_err());
+        assert!(assert_settlement_not_expired(200, -100, late + 1).is_err());
+        assert!(assert_settlement_not_expired(0, -100, late).is_ok());
+    }
+
+    #[test]
+    fn get_unit_price_splits_fill_price() -> Result<()> {
+        assert_eq!(get_unit_price(1_000, 1)?, 1_000);
+        assert_eq!(get_unit_price(1_000, 4)?, 250);
+        assert!(get_unit_price(1_000, 3).is_err());
+        assert!(get_unit_price(1_000, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn get_received_price_prorates_withheld_tokens() -> Result<()> {
+        assert_eq!(get_received_price(1_000, 10, 10)?, 1_000);
+        assert_eq!(get_received_price(1_000, 10, 9)?, 900);
+        assert_eq!(get_received_price(1_000, 3, 2)?, 666);
+        assert_eq!(get_received_price(u64::MAX, 2, 1)?, u64::MAX / 2);
+        assert!(get_received_price(1_000, 10, 11).is_err());
+        assert!(get_received_price(1_000, 0, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn get_listing_payment_amounts_when_buyer_is_taker() -> Result<()> {
+        assert_eq!(
+            get_listing_payment_amounts(1_000_000, 100, 200, false)?,
+            (10_000, 20_000, 990_000)
+        );
+        // maker rebate
+        assert_eq!(
+            get_listing_payment_amounts(1_000_000, -100, 200, false)?,
+            (-10_000, 20_000, 1_010_000)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn get_buyer_cost_adds_the_buyers_fee_and_royalty() -> Result<()> {
+        let payout = SettlementPayout {
+            royalty: 50_000,
+            royalty_subsidy: 0,
+            maker_fee: -10_000,
+            taker_fee: 20_000,
+        };
+        assert_eq!(payout.get_buyer_cost(1_000_000, false)?, 1_070_000);
+        // maker rebates lower the buyer's cost when the seller takes the bid
+        assert_eq!(payout.get_buyer_cost(1_000_000, true)?, 1_040_000);
+        Ok(())
+    }
+
+    #[test]
+    fn split_subsidized_royalty_bp_moves_the_subsidy_to_the_treasury() {
+        assert_eq!(split_subsidized_royalty_bp(10000, 5000), (5000, 5000));
+        assert_eq!(split_subsidized_royalty_bp(10000, 0), (10000, 0));
+        assert_eq!(split_subsidized_royalty_bp(5000, 10000), (0, 5000));
+        assert_eq!(split_subsidized_royalty_bp(3333, 5000), (1667, 1666));
+    }
+
+    #[test]
+    fn can_treasury_subsidize_checks_budget_and_leftover() {
+        let subsidy = RoyaltySubsidy {
+            budget: 1_000,
+            spent: 400,
+            ..Default::default()
+        };
+        let treasury = MIN_TREASURY_LEFTOVER + 1_000;
+        assert!(can_treasury_subsidize(&subsidy, treasury, 600));
+        assert!(!can_treasury_subsidize(&subsidy, treasury, 601));
+        assert!(!can_treasury_subsidize(
+            &subsidy,
+            MIN_TREASURY_LEFTOVER + 500,
+            600
+        ));
+    }
+
+    #[test]
+    fn get_seller_proceeds_deducts_the_sellers_fee() {
+        let payout = SettlementPayout {
+            royalty: 50_000,
+            royalty_subsidy: 0,
+            maker_fee: -10_000,
+            taker_fee: 20_000,
+        };
+        // maker rebate on top of the price when the buyer takes the listing
+        assert_eq!(payout.get_seller_proceeds(1_000_000, false), 1_010_000);
+        assert_eq!(payout.get_seller_proceeds(1_000_000, true), 980_000);
+        assert_eq!(get_seller_net_proceeds(100, 0, 200, true), 0);
+    }
+
+    #[test]
+    fn assert_min_net_proceeds_rejects_proceeds_below_it() {
+        assert!(assert_min_net_proceeds(1_000, Some(1_000)).is_ok());
+        assert!(assert_min_net_proceeds(999, Some(1_000)).is_err());
+        assert!(assert_min_net_proceeds(0, None).is_ok());
+    }
+
+    #[test]
+    fn assert_max_price_rejects_costs_above_it() {
+        assert!(assert_max_price(1_000, Some(1_000)).is_ok());
+        assert!(assert_max_price(1_001, Some(1_000)).is_err());
+        assert!(assert_max_price(u64::MAX, None).is_ok());
+    }
+
+    #[test]
+    fn get_listing_payment_amounts_when_seller_is_taker() -> Result<()> {
+        assert_eq!(
+            get_listing_payment_amounts(1_000_000, 100, 200, true)?,
+            (10_000, 20_000, 1_010_000)
+        );
+        assert_eq!(get_listing_payment_amounts(3, 100, 200, true)?, (0, 0, 3));
+        Ok(())
+    }
+}
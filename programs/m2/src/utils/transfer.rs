@@ -1,15 +1,43 @@
 use std::slice::Iter;
 
 use anchor_lang::prelude::*;
-use mpl_token_metadata::accounts::Metadata;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        non_transferable::NonTransferable, permanent_delegate::PermanentDelegate,
+        transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+};
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata, TokenRecord},
+    types::ProgrammableConfig,
+};
 use open_creator_protocol::state::Policy;
+use solana_program::instruction::Instruction;
 use solana_program::{
     program::{invoke, invoke_signed},
     system_instruction,
 };
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address, onchain::add_cpi_accounts_for_execute,
+};
 
-use super::{assert_initialized, assert_is_ata, assert_keys_equal, is_token_owner, make_ata};
-use crate::errors::ErrorCode;
+use super::{
+    assert_derivation, assert_initialized, assert_is_ata, assert_keys_equal,
+    assert_royalty_fallback, get_balance_from_token_account, get_listing_payment_amounts,
+    is_token_owner, make_ata, CoreAuthority,
+};
+use crate::{
+    constants::{
+        BUBBLEGUM_PROGRAM_ID, CORE_TRANSFER_DELEGATE_PLUGIN, MPL_CORE_PROGRAM_ID,
+        MPL_TOKEN_AUTH_RULES_PROGRAM_ID, PERMANENT_DELEGATE_APPROVAL, PERMANENT_DELEGATE_CONFIG,
+        PREFIX,
+    },
+    errors::ErrorCode,
+    index_ra,
+    states::{BidArgs, PermanentDelegateApproval, PermanentDelegateConfig},
+};
 
 pub enum DestinationSpecifier<'refs, 'a> {
     Key(&'refs Pubkey),
@@ -180,25 +208,12 @@ pub fn transfer_listing_payment<'info>(
     //   seller gets (args.price - maker_fee) from buyer
     // royalty is also paid ON TOP of the price
 
-    let maker_fee = (buyer_price as i128)
-        .checked_mul(actual_maker_fee_bp as i128)
-        .ok_or(ErrorCode::NumericalOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::NumericalOverflow)? as i64;
-    let taker_fee = (buyer_price as u128)
-        .checked_mul(actual_taker_fee_bp as u128)
-        .ok_or(ErrorCode::NumericalOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::NumericalOverflow)? as u64;
-    let seller_will_get_from_buyer = if taker.key.eq(seller.key) {
-        (buyer_price as i64)
-            .checked_add(maker_fee)
-            .ok_or(ErrorCode::NumericalOverflow)?
-    } else {
-        (buyer_price as i64)
-            .checked_sub(maker_fee)
-            .ok_or(ErrorCode::NumericalOverflow)?
-    } as u64;
+    let (maker_fee, taker_fee, seller_will_get_from_buyer) = get_listing_payment_amounts(
+        buyer_price,
+        actual_maker_fee_bp,
+        actual_taker_fee_bp,
+        taker.key.eq(seller.key),
+    )?;
     let total_platform_fee = (maker_fee
         .checked_add(taker_fee as i64)
         .ok_or(ErrorCode::NumericalOverflow)?) as u64;
@@ -399,14 +414,26 @@ pub fn pay_creator_fees<'r, 'a>(
             )?;
         } else {
             assert_keys_equal(&creator.address, current_creator_info.key)?;
-            if creator_fee + current_creator_info.lamports() >= Rent::get()?.minimum_balance(0) {
+            // creators that can't receive lamports (executable or read-only accounts) are paid to
+            // the collection's royalty fallback, passed as the next two accounts: the
+            // RoyaltyFallback PDA and its recipient
+            let recipient_info =
+                if current_creator_info.executable || !current_creator_info.is_writable {
+                    let royalty_fallback = next_account_info(creator_accounts)?;
+                    let fallback_recipient = next_account_info(creator_accounts)?;
+                    assert_royalty_fallback(royalty_fallback, fallback_recipient, metadata)?;
+                    fallback_recipient
+                } else {
+                    current_creator_info
+                };
+            if creator_fee + recipient_info.lamports() >= Rent::get()?.minimum_balance(0) {
                 invoke_signed(
                     &system_instruction::transfer(
                         escrow_payment_account.key,
-                        current_creator_info.key,
+                        recipient_info.key,
                         creator_fee,
                     ),
-                    &[escrow_payment_account.clone(), current_creator_info.clone()],
+                    &[escrow_payment_account.clone(), recipient_info.clone()],
                     signer_seeds,
                 )?;
                 total_fee_paid = total_fee_paid
@@ -418,3 +445,655 @@ pub fn pay_creator_fees<'r, 'a>(
 
     Ok(total_fee_paid)
 }
+
+/// Pays the proceeds of a primary sale from payer to the creators of metadata by their shares and
+/// returns the amount paid. Creators are passed in the order of the metadata, a share that would
+/// leave its creator below rent exemption is skipped and left to the caller.
+pub fn pay_primary_sale_proceeds<'r, 'a>(
+    creator_accounts: &mut Iter<'r, AccountInfo<'a>>,
+    metadata: &'r Metadata,
+    payer: &AccountInfo<'a>,
+    proceeds: u64,
+) -> Result<u64> {
+    let creators = match &metadata.creators {
+        Some(creators) => creators,
+        None => return Ok(0),
+    };
+
+    let mut total_paid = 0u64;
+    for creator in creators {
+        let share = (creator.share as u128)
+            .checked_mul(proceeds as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?
+            .checked_div(100)
+            .ok_or(ErrorCode::NumericalOverflow)? as u64;
+        let creator_info = next_account_info(creator_accounts)?;
+        assert_keys_equal(&creator.address, creator_info.key)?;
+        if share == 0 || share + creator_info.lamports() < Rent::get()?.minimum_balance(0) {
+            continue;
+        }
+        invoke(
+            &system_instruction::transfer(payer.key, creator_info.key, share),
+            &[payer.clone(), creator_info.clone()],
+        )?;
+        total_paid = total_paid
+            .checked_add(share)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    }
+
+    Ok(total_paid)
+}
+
+/// Refunds up to the bid price from the buyer's escrow to the buyer's wallet and returns the
+/// refunded amount. Escrowless bids have nothing escrowed and aren't refunded. SPL bids expect
+/// payment_mint, the escrow's payment token account and the wallet's payment token account as
+/// the first remaining accounts, the wallet's one is created by payer if needed.
+#[allow(clippy::too_many_arguments)]
+pub fn refund_bid_escrow<'info>(
+    bid_args: &BidArgs,
+    payer: &AccountInfo<'info>,
+    wallet: &AccountInfo<'info>,
+    escrow_payment_account: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    escrow_signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    if bid_args.escrowless {
+        return Ok(0);
+    }
+    if bid_args.payment_mint == Pubkey::default() {
+        let refund = bid_args.buyer_price.min(escrow_payment_account.lamports());
+        if refund > 0 {
+            invoke_signed(
+                &system_instruction::transfer(escrow_payment_account.key, wallet.key, refund),
+                &[
+                    escrow_payment_account.clone(),
+                    wallet.clone(),
+                    system_program.clone(),
+                ],
+                escrow_signer_seeds,
+            )?;
+        }
+        return Ok(refund);
+    }
+
+    let payment_mint = index_ra!(remaining_accounts, 0);
+    let payment_source_token_account = index_ra!(remaining_accounts, 1);
+    assert_keys_equal(payment_mint.key, &bid_args.payment_mint)?;
+    let refund = if payment_source_token_account.data_is_empty() {
+        0
+    } else {
+        bid_args.buyer_price.min(get_balance_from_token_account(
+            payment_source_token_account,
+        )?)
+    };
+    if refund > 0 {
+        transfer_token(
+            &refund,
+            payer,
+            escrow_payment_account,
+            wallet,
+            None,
+            DestinationSpecifier::Ai(wallet),
+            payment_mint,
+            payment_source_token_account,
+            index_ra!(remaining_accounts, 2),
+            token_program,
+            system_program,
+            None,
+            escrow_signer_seeds,
+        )?;
+    }
+    Ok(refund)
+}
+
+/// Returns the part of amount a transfer of a Token-2022 mint withholds for its transfer fee
+/// extension in the current epoch, 0 for mints without one
+pub fn get_t22_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => Ok(transfer_fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::NumericalOverflow)?),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Fails with NonTransferableAsset for Token-2022 mints with the non-transferable extension, their
+/// tokens are soulbound and a listing of them could never be filled
+pub fn assert_t22_transferable(mint: &AccountInfo) -> Result<()> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    if mint_state.get_extension::<NonTransferable>().is_ok() {
+        return Err(ErrorCode::NonTransferableAsset.into());
+    }
+    Ok(())
+}
+
+/// Returns the permanent delegate of a Token-2022 mint, None for mints without one. A permanent
+/// delegate can transfer or burn any token of the mint, including sold ones
+pub fn get_t22_permanent_delegate(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match mint_state.get_extension::<PermanentDelegate>() {
+        Ok(permanent_delegate) => Ok(Option::<Pubkey>::from(permanent_delegate.delegate)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Checks a Token-2022 mint with a permanent delegate against the PermanentDelegateConfig of the
+/// auction house. Mints the authority approved in set_permanent_delegate_approval pass, others fail
+/// with PermanentDelegateNotApproved, or only get flagged in the logs if the auction house is set to
+/// flag_only. permanent_delegate_config and permanent_delegate_approval are only read for such mints
+pub fn assert_t22_permanent_delegate_allowed(
+    mint: &AccountInfo,
+    permanent_delegate_config: &AccountInfo,
+    permanent_delegate_approval: &AccountInfo,
+    auction_house_key: &Pubkey,
+) -> Result<()> {
+    let permanent_delegate = match get_t22_permanent_delegate(mint)? {
+        Some(permanent_delegate) => permanent_delegate,
+        None => return Ok(()),
+    };
+    if is_permanent_delegate_approved(
+        permanent_delegate_approval,
+        auction_house_key,
+        mint.key,
+        &permanent_delegate,
+    )? {
+        return Ok(());
+    }
+
+    assert_derivation(
+        &crate::ID,
+        permanent_delegate_config,
+        &[
+            PREFIX.as_bytes(),
+            PERMANENT_DELEGATE_CONFIG.as_bytes(),
+            auction_house_key.as_ref(),
+        ],
+    )?;
+    // auction houses without a config reject unapproved mints
+    let flag_only = !permanent_delegate_config.data_is_empty()
+        && permanent_delegate_config.owner == &crate::ID
+        && PermanentDelegateConfig::try_deserialize(
+            &mut &permanent_delegate_config.data.borrow()[..],
+        )?
+        .flag_only;
+    if !flag_only {
+        return Err(ErrorCode::PermanentDelegateNotApproved.into());
+    }
+    msg!(
+        "permanent_delegate_flagged: {{\"mint\":\"{}\",\"permanent_delegate\":\"{}\"}}",
+        mint.key,
+        permanent_delegate,
+    );
+    Ok(())
+}
+
+fn is_permanent_delegate_approved(
+    permanent_delegate_approval: &AccountInfo,
+    auction_house_key: &Pubkey,
+    mint: &Pubkey,
+    permanent_delegate: &Pubkey,
+) -> Result<bool> {
+    assert_derivation(
+        &crate::ID,
+        permanent_delegate_approval,
+        &[
+            PREFIX.as_bytes(),
+            PERMANENT_DELEGATE_APPROVAL.as_bytes(),
+            auction_house_key.as_ref(),
+            mint.as_ref(),
+        ],
+    )?;
+    if permanent_delegate_approval.data_is_empty()
+        || permanent_delegate_approval.owner != &crate::ID
+    {
+        return Ok(false);
+    }
+    let approval = PermanentDelegateApproval::try_deserialize(
+        &mut &permanent_delegate_approval.data.borrow()[..],
+    )?;
+    // a delegate changed after the approval has to be approved again
+    Ok(approval.approved && approval.permanent_delegate == *permanent_delegate)
+}
+
+/// Returns the transfer hook program of a Token-2022 mint, None for mints without one
+pub fn get_t22_transfer_hook_program(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match mint_state.get_extension::<TransferHook>() {
+        Ok(transfer_hook) => Ok(Option::<Pubkey>::from(transfer_hook.program_id)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Adds the accounts the transfer hook of a Token-2022 mint needs to a transfer instruction of
+/// the mint: the hook program, its ExtraAccountMetaList and the extra accounts the list resolves
+/// to. They are looked up by key in hook_accounts, so they can be passed in any order.
+/// Does nothing for mints without a transfer hook
+///
+/// # Arguments
+/// * `instruction` - transfer_checked instruction of the mint
+/// * `account_infos` - Account infos of the instruction
+/// * `mint` - Token-2022 mint
+/// * `hook_accounts` - Accounts to look the hook's accounts up in
+pub fn add_t22_transfer_hook_accounts<'info>(
+    instruction: &mut Instruction,
+    account_infos: &mut Vec<AccountInfo<'info>>,
+    mint: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let hook_program = match get_t22_transfer_hook_program(mint)? {
+        Some(hook_program) => hook_program,
+        None => return Ok(()),
+    };
+    let extra_account_metas = get_extra_account_metas_address(mint.key, &hook_program);
+    if !hook_accounts.iter().any(|a| *a.key == hook_program)
+        || !hook_accounts.iter().any(|a| *a.key == extra_account_metas)
+    {
+        return Err(ErrorCode::MissingTransferHookAccounts.into());
+    }
+    add_cpi_accounts_for_execute(
+        instruction,
+        account_infos,
+        mint.key,
+        &hook_program,
+        hook_accounts,
+    )?;
+    Ok(())
+}
+
+/// Transfers amount of a Token-2022 mint with transfer_checked, passing through the accounts of
+/// the mint's transfer hook from hook_accounts
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_t22_checked<'info>(
+    token_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut instruction = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        source.key,
+        mint.key,
+        destination.key,
+        authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![
+        source.clone(),
+        mint.clone(),
+        destination.clone(),
+        authority.clone(),
+    ];
+    add_t22_transfer_hook_accounts(&mut instruction, &mut account_infos, mint, hook_accounts)?;
+    account_infos.push(token_program.clone());
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+pub struct PnftTransferAccounts<'r, 'info> {
+    pub edition: &'r AccountInfo<'info>,
+    pub owner_token_record: &'r AccountInfo<'info>,
+    pub destination_token_record: &'r AccountInfo<'info>,
+    pub authorization_rules_program: Option<&'r AccountInfo<'info>>, // None without a rule set
+    pub authorization_rules: Option<&'r AccountInfo<'info>>,
+}
+
+impl<'r, 'info> PnftTransferAccounts<'r, 'info> {
+    /// Account infos to pass along with the token-metadata Transfer instruction built from self
+    pub fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        let mut account_infos = vec![
+            self.edition.clone(),
+            self.owner_token_record.clone(),
+            self.destination_token_record.clone(),
+        ];
+        if let (Some(program), Some(rules)) =
+            (self.authorization_rules_program, self.authorization_rules)
+        {
+            account_infos.extend_from_slice(&[program.clone(), rules.clone()]);
+        }
+        account_infos
+    }
+}
+
+/// Checks the pNFT accounts of a token-metadata Transfer from token_account to
+/// destination_token_account against their derivations and the rule set in the metadata. The auth
+/// rules accounts are only passed through for a pNFT with a rule set, token-metadata takes its own
+/// program id in their place otherwise
+///
+/// # Arguments
+/// * `metadata_parsed` - Metadata of the transferred pNFT
+/// * `token_account` - Token account the pNFT is transferred from
+/// * `destination_token_account` - Token account the pNFT is transferred to
+/// * `edition` - Master edition of the mint
+/// * `owner_token_record` - Token record of token_account
+/// * `destination_token_record` - Token record of destination_token_account
+/// * `authorization_rules_program` - Auth rules program, unchecked without a rule set
+/// * `authorization_rules` - Rule set of the metadata, unchecked without a rule set
+#[allow(clippy::too_many_arguments)]
+pub fn get_pnft_transfer_accounts<'r, 'info>(
+    metadata_parsed: &Metadata,
+    token_account: &Pubkey,
+    destination_token_account: &Pubkey,
+    edition: &'r AccountInfo<'info>,
+    owner_token_record: &'r AccountInfo<'info>,
+    destination_token_record: &'r AccountInfo<'info>,
+    authorization_rules_program: &'r AccountInfo<'info>,
+    authorization_rules: &'r AccountInfo<'info>,
+) -> Result<PnftTransferAccounts<'r, 'info>> {
+    let mint = &metadata_parsed.mint;
+    assert_keys_equal(edition.key, &MasterEdition::find_pda(mint).0)?;
+    assert_keys_equal(
+        owner_token_record.key,
+        &TokenRecord::find_pda(mint, token_account).0,
+    )?;
+    assert_keys_equal(
+        destination_token_record.key,
+        &TokenRecord::find_pda(mint, destination_token_account).0,
+    )?;
+
+    // the rule set is only compared by key with the metadata m2 parses for the sale anyway, m2
+    // never reads the rule set account. Its evaluation happens inside token-metadata's Transfer,
+    // so a per-mint cache of validated rule sets wouldn't save any compute here
+    let rule_set = match &metadata_parsed.programmable_config {
+        Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        }) => rule_set,
+        _ => {
+            return Ok(PnftTransferAccounts {
+                edition,
+                owner_token_record,
+                destination_token_record,
+                authorization_rules_program: None,
+                authorization_rules: None,
+            })
+        }
+    };
+    if authorization_rules.key != rule_set
+        || authorization_rules_program.key != &MPL_TOKEN_AUTH_RULES_PROGRAM_ID
+    {
+        return Err(ErrorCode::InvalidAuthorizationRules.into());
+    }
+    Ok(PnftTransferAccounts {
+        edition,
+        owner_token_record,
+        destination_token_record,
+        authorization_rules_program: Some(authorization_rules_program),
+        authorization_rules: Some(authorization_rules),
+    })
+}
+
+// Anchor discriminators of the Bubblegum instructions the marketplace calls, as
+// sha256("global:<name>")[..8], Bubblegum isn't a dependency of the program
+const BUBBLEGUM_DELEGATE_DISCRIMINATOR: [u8; 8] = [90, 147, 75, 178, 85, 88, 4, 137];
+const BUBBLEGUM_TRANSFER_DISCRIMINATOR: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+
+/// Leaf of a compressed NFT and the root of the tree it is proven against, laid out the way
+/// Bubblegum's delegate and transfer instructions take them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CompressedLeafArgs {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+pub struct BubblegumAccounts<'r, 'info> {
+    pub bubblegum_program: &'r AccountInfo<'info>,
+    pub tree_authority: &'r AccountInfo<'info>,
+    pub merkle_tree: &'r AccountInfo<'info>,
+    pub log_wrapper: &'r AccountInfo<'info>,
+    pub compression_program: &'r AccountInfo<'info>,
+    pub system_program: &'r AccountInfo<'info>,
+    pub proof: &'r [AccountInfo<'info>], // proof nodes of the leaf above the tree's canopy
+}
+
+/// Returns the asset id Bubblegum derives for the leaf minted with nonce in merkle_tree
+pub fn get_compressed_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            "asset".as_bytes(),
+            merkle_tree.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
+}
+
+fn invoke_bubblegum<'info>(
+    discriminator: [u8; 8],
+    bubblegum: &BubblegumAccounts<'_, 'info>,
+    leaf_accounts: &[(&AccountInfo<'info>, bool)], // (account, is_signer) between tree_authority and merkle_tree
+    leaf: &CompressedLeafArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&leaf.try_to_vec()?);
+    let mut accounts = vec![AccountMeta::new_readonly(
+        bubblegum.tree_authority.key(),
+        false,
+    )];
+    let mut account_infos = vec![bubblegum.tree_authority.clone()];
+    for (account, is_signer) in leaf_accounts {
+        accounts.push(AccountMeta::new_readonly(account.key(), *is_signer));
+        account_infos.push((*account).clone());
+    }
+    accounts.push(AccountMeta::new(bubblegum.merkle_tree.key(), false));
+    accounts.push(AccountMeta::new_readonly(
+        bubblegum.log_wrapper.key(),
+        false,
+    ));
+    accounts.push(AccountMeta::new_readonly(
+        bubblegum.compression_program.key(),
+        false,
+    ));
+    accounts.push(AccountMeta::new_readonly(
+        bubblegum.system_program.key(),
+        false,
+    ));
+    account_infos.extend_from_slice(&[
+        bubblegum.merkle_tree.clone(),
+        bubblegum.log_wrapper.clone(),
+        bubblegum.compression_program.clone(),
+        bubblegum.system_program.clone(),
+    ]);
+    for node in bubblegum.proof {
+        accounts.push(AccountMeta::new_readonly(node.key(), false));
+        account_infos.push(node.clone());
+    }
+    account_infos.push(bubblegum.bubblegum_program.clone());
+    invoke_signed(
+        &Instruction {
+            program_id: BUBBLEGUM_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Sets new_leaf_delegate as the delegate of a compressed NFT. Bubblegum verifies the leaf
+/// against the root with the proof, so this also proves leaf_owner owns the asset
+pub fn delegate_compressed<'info>(
+    bubblegum: &BubblegumAccounts<'_, 'info>,
+    leaf_owner: &AccountInfo<'info>,
+    previous_leaf_delegate: &AccountInfo<'info>,
+    new_leaf_delegate: &AccountInfo<'info>,
+    leaf: &CompressedLeafArgs,
+) -> Result<()> {
+    invoke_bubblegum(
+        BUBBLEGUM_DELEGATE_DISCRIMINATOR,
+        bubblegum,
+        &[
+            (leaf_owner, true),
+            (previous_leaf_delegate, false),
+            (new_leaf_delegate, false),
+        ],
+        leaf,
+        &[],
+    )
+}
+
+/// Transfers a compressed NFT to new_leaf_owner, signed by its leaf_delegate
+pub fn transfer_compressed<'info>(
+    bubblegum: &BubblegumAccounts<'_, 'info>,
+    leaf_owner: &AccountInfo<'info>,
+    leaf_delegate: &AccountInfo<'info>,
+    new_leaf_owner: &AccountInfo<'info>,
+    leaf: &CompressedLeafArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_bubblegum(
+        BUBBLEGUM_TRANSFER_DISCRIMINATOR,
+        bubblegum,
+        &[
+            (leaf_owner, false),
+            (leaf_delegate, true),
+            (new_leaf_owner, false),
+        ],
+        leaf,
+        signer_seeds,
+    )
+}
+
+const CORE_ADD_PLUGIN_DISCRIMINATOR: u8 = 2;
+const CORE_APPROVE_PLUGIN_AUTHORITY_DISCRIMINATOR: u8 = 8;
+const CORE_REVOKE_PLUGIN_AUTHORITY_DISCRIMINATOR: u8 = 10;
+const CORE_TRANSFER_DISCRIMINATOR: u8 = 14;
+
+pub struct CoreAccounts<'r, 'info> {
+    pub core_program: &'r AccountInfo<'info>,
+    pub asset: &'r AccountInfo<'info>,
+    pub collection: &'r AccountInfo<'info>, // the core program for assets without a collection
+    pub payer: &'r AccountInfo<'info>,
+    pub system_program: &'r AccountInfo<'info>,
+}
+
+fn invoke_core<'info>(
+    discriminator: u8,
+    core: &CoreAccounts<'_, 'info>,
+    authority: &AccountInfo<'info>,
+    new_owner: Option<&AccountInfo<'info>>,
+    args: &[u8],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = vec![discriminator];
+    data.extend_from_slice(args);
+    let mut accounts = vec![
+        AccountMeta::new(core.asset.key(), false),
+        if core.collection.is_writable {
+            AccountMeta::new(core.collection.key(), false)
+        } else {
+            AccountMeta::new_readonly(core.collection.key(), false)
+        },
+        AccountMeta::new(core.payer.key(), true),
+        AccountMeta::new_readonly(authority.key(), true),
+    ];
+    let mut account_infos = vec![
+        core.asset.clone(),
+        core.collection.clone(),
+        core.payer.clone(),
+        authority.clone(),
+    ];
+    if let Some(new_owner) = new_owner {
+        accounts.push(AccountMeta::new_readonly(new_owner.key(), false));
+        account_infos.push(new_owner.clone());
+    }
+    accounts.push(AccountMeta::new_readonly(core.system_program.key(), false));
+    // no log_wrapper, optional accounts of mpl core are set to its program id
+    accounts.push(AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false));
+    account_infos.extend_from_slice(&[core.system_program.clone(), core.core_program.clone()]);
+    invoke_signed(
+        &Instruction {
+            program_id: MPL_CORE_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Makes delegate the authority of the transfer delegate plugin of a core asset, adding the
+/// plugin if the asset doesn't have one yet. Signed by the owner of the asset, who is the payer
+pub fn delegate_core<'info>(
+    core: &CoreAccounts<'_, 'info>,
+    transfer_delegate: Option<&CoreAuthority>,
+    delegate: &Pubkey,
+) -> Result<()> {
+    let new_authority = CoreAuthority::Address { address: *delegate };
+    match transfer_delegate {
+        Some(authority) if *authority == new_authority => Ok(()),
+        Some(_) => {
+            let mut args = vec![CORE_TRANSFER_DELEGATE_PLUGIN];
+            args.extend_from_slice(&new_authority.try_to_vec()?);
+            invoke_core(
+                CORE_APPROVE_PLUGIN_AUTHORITY_DISCRIMINATOR,
+                core,
+                core.payer,
+                None,
+                &args,
+                &[],
+            )
+        }
+        None => {
+            // Plugin::TransferDelegate, and the plugin's init_authority
+            let mut args = vec![CORE_TRANSFER_DELEGATE_PLUGIN];
+            args.extend_from_slice(&Some(new_authority).try_to_vec()?);
+            invoke_core(
+                CORE_ADD_PLUGIN_DISCRIMINATOR,
+                core,
+                core.payer,
+                None,
+                &args,
+                &[],
+            )
+        }
+    }
+}
+
+/// Hands the transfer delegate plugin of a core asset back to its owner, who is the payer
+pub fn revoke_core_delegate<'info>(core: &CoreAccounts<'_, 'info>) -> Result<()> {
+    invoke_core(
+        CORE_REVOKE_PLUGIN_AUTHORITY_DISCRIMINATOR,
+        core,
+        core.payer,
+        None,
+        &[CORE_TRANSFER_DELEGATE_PLUGIN],
+        &[],
+    )
+}
+
+/// Transfers a core asset to new_owner, signed by the authority of its transfer delegate plugin
+pub fn transfer_core<'info>(
+    core: &CoreAccounts<'_, 'info>,
+    transfer_delegate: &AccountInfo<'info>,
+    new_owner: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_core(
+        CORE_TRANSFER_DISCRIMINATOR,
+        core,
+        transfer_delegate,
+        Some(new_owner),
+        &[0], // no compression_proof
+        signer_seeds,
+    )
+}
@@ -585,7 +585,7 @@ export type M2 = {
       ]
     },
     {
-      "name": "ocpSell",
+      "name": "migrateBuyerTradeState",
       "accounts": [
         {
           "name": "wallet",
@@ -598,165 +598,226 @@ export type M2 = {
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAta",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "newAuctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
-          "isMut": false,
+          "name": "escrowPaymentAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "newEscrowPaymentAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
-          "isMut": false,
+          "name": "buyerTradeState",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpMintState",
+          "name": "newBuyerTradeState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpPolicy",
-          "isMut": false,
+          "name": "walletOrderCounter",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpFreezeAuthority",
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newOrderLimitConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpProgram",
+          "name": "newWalletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncMintListingIndex",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "cmtProgram",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "mintListingIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setCollectionPause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "OCPSellArgs"
-          }
+          "name": "paused",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "ocpCancelSell",
+      "name": "migrateCollectionPause",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "notary",
+          "name": "auctionHouse",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAta",
+          "name": "collectionPause",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapIntoEscrow",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "metadata",
+          "name": "notary",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
+          "name": "auctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpMintState",
+          "name": "escrowPaymentAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpPolicy",
+          "name": "sourceMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpFreezeAuthority",
-          "isMut": false,
+          "name": "sourceTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpProgram",
+          "name": "destinationMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cmtProgram",
-          "isMut": false,
+          "name": "destinationTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "swapProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -766,133 +827,246 @@ export type M2 = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapIntoEscrowArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "ocpExecuteSaleV2",
+      "name": "setFloorPricing",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "wallet",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "buyer",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "floorPremiumBp",
+          "type": {
+            "option": "i16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "suspendAllListings",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "seller",
+          "name": "sellerStatus",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resumeAllListings",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "programAsSigner",
-          "isMut": false,
+          "name": "sellerStatus",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerTokenAta",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "suspendAllBids",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "buyerTokenAta",
+          "name": "buyerStatus",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resumeAllBids",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "metadata",
-          "isMut": false,
+          "name": "buyerStatus",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStaleListing",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "auctionHouseTreasury",
+          "name": "crankBond",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "seller",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
-          "isMut": true,
+          "name": "tokenAccount",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerEscrowPaymentAccount",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerReferral",
-          "isMut": true,
+          "name": "programAsSigner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
+          "name": "sellerOrderCounter",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpMintState",
+          "name": "sellerCountedOrder",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "custodySell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "ocpPolicy",
+          "name": "notary",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpFreezeAuthority",
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "token_account that holds the token, can be the custody_vault when changing price"
+          ]
+        },
+        {
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpProgram",
+          "name": "metadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cmtProgram",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "custodyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
@@ -916,54 +1090,24 @@ export type M2 = {
         {
           "name": "args",
           "type": {
-            "defined": "OCPExecuteSaleV2Args"
+            "defined": "CustodySellArgs"
           }
         }
       ]
     },
     {
-      "name": "executeSaleV2",
+      "name": "custodyCancelSell",
       "accounts": [
         {
-          "name": "buyer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "notary",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenAccount",
+          "name": "wallet",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "metadata",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "escrowPaymentAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buyerReceiptTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "authority",
           "isMut": false,
@@ -975,32 +1119,32 @@ export type M2 = {
           "isSigner": false
         },
         {
-          "name": "auctionHouseTreasury",
+          "name": "custodyVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
+          "name": "tokenAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerReferral",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
+          "name": "programAsSigner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1008,163 +1152,189 @@ export type M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFrontendConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "ataProgram",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "frontend",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "frontendConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "escrowPaymentBump",
-          "type": "u8"
-        },
-        {
-          "name": "programAsSignerBump",
-          "type": "u8"
-        },
-        {
-          "name": "buyerPrice",
-          "type": "u64"
-        },
-        {
-          "name": "tokenSize",
-          "type": "u64"
-        },
-        {
-          "name": "buyerStateExpiry",
-          "type": "i64"
-        },
-        {
-          "name": "sellerStateExpiry",
-          "type": "i64"
-        },
-        {
-          "name": "makerFeeBp",
-          "type": "i16"
-        },
-        {
-          "name": "takerFeeBp",
-          "type": "u16"
+          "name": "args",
+          "type": {
+            "defined": "SetFrontendConfigArgs"
+          }
         }
       ]
     },
     {
-      "name": "mip1Sell",
+      "name": "claimFrontendFees",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "frontend",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "notary",
-          "isMut": false,
+          "name": "frontendConfig",
+          "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "markCreatorListing",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "programAsSigner",
+          "name": "metadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setListingOperator",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenMint",
+          "name": "operator",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "listingOperator",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "operatorRepriceListing",
+      "accounts": [
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
+          "name": "listingOperator",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "migrationSellerTradeState",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "sellerReferral",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "args",
+          "type": {
+            "defined": "OperatorRepriceListingArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "collectionBid",
+      "accounts": [
         {
-          "name": "tokenAta",
+          "name": "wallet",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "escrow mode for init sell:        we transfer from token_account to token_ata",
-            "escrow mode for change price:     token_account is the same as token_ata",
-            "migration mode for change price:  token_ata is not used, because we only need token_account which is owned by program_as_signer"
-          ]
+          "isSigner": true
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "notary",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
+          "name": "escrowPaymentAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destinationTokenRecord",
+          "name": "collectionBidState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "buyerReferral",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
@@ -1172,27 +1342,22 @@ export type M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "MIP1SellArgs"
+            "defined": "CollectionBidArgs"
           }
         }
       ]
     },
     {
-      "name": "mip1ExecuteSaleV2",
+      "name": "sellIntoCollectionBid",
       "accounts": [
         {
-          "name": "payer",
+          "name": "seller",
           "isMut": true,
           "isSigner": true
         },
@@ -1202,22 +1367,27 @@ export type M2 = {
           "isSigner": false
         },
         {
-          "name": "seller",
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "metadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
+          "name": "escrowPaymentAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -1227,15 +1397,10 @@ export type M2 = {
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "metadata",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "auctionHouse",
           "isMut": false,
@@ -1247,82 +1412,87 @@ export type M2 = {
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
+          "name": "bidAuctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
+          "name": "collectionBidState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerEscrowPaymentAccount",
+          "name": "buyerReferral",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerReferral",
-          "isMut": true,
+          "name": "collectionConfig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
-          "isMut": true,
+          "name": "sellerStatus",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "buyerStatus",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "priceBandConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "priceAttestation",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
-          "isMut": false,
+          "name": "collectionStats",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
+          "name": "lastSale",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
+          "name": "tradeSequence",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "permanentDelegateConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "permanentDelegateApproval",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "assetTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -1331,13 +1501,13 @@ export type M2 = {
         {
           "name": "args",
           "type": {
-            "defined": "MIP1ExecuteSaleV2Args"
+            "defined": "SellIntoCollectionBidArgs"
           }
         }
       ]
     },
     {
-      "name": "mip1CancelSell",
+      "name": "cancelCollectionBid",
       "accounts": [
         {
           "name": "wallet",
@@ -1345,98 +1515,190 @@ export type M2 = {
           "isSigner": true
         },
         {
-          "name": "notary",
+          "name": "authority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAta",
+          "name": "collectionBidState",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRoyaltyFallback",
+      "accounts": [
+        {
+          "name": "updateAuthority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenMint",
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "collectionMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyFallback",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sellDutch",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
         },
         {
           "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellDutchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createAuction",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "tokenAccount",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "should always be ATA of (mint, wallet)"
-          ]
+          "isSigner": false
         },
         {
-          "name": "tokenAccountTemp",
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionState",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "should always be ATA of (mint, program_as_signer)"
-          ]
+          "isSigner": false
         },
         {
-          "name": "tempTokenRecord",
+          "name": "auctionEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "sellerStatus",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateAuctionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "placeAuctionBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
+          "name": "auctionEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instructions",
-          "isMut": false,
+          "name": "previousBidder",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "buyerStatus",
           "isMut": false,
           "isSigner": false
         },
@@ -1449,563 +1711,645 @@ export type M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
     {
-      "name": "buyerTradeState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyer",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerReferral",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenSize",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
-          }
-        ]
-      }
+      "name": "placeAuctionProxyBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxBid",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "sellerTradeState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "seller",
-            "type": "publicKey"
-          },
-          {
-            "name": "sellerReferral",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenAccount",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenSize",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
+      "name": "settleAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "highestBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SettleAuctionArgs"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "sellerTradeStateV2",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "seller",
-            "type": "publicKey"
-          },
-          {
-            "name": "sellerReferral",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenAccount",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenSize",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
-          },
-          {
-            "name": "paymentMint",
-            "type": "publicKey"
+      "name": "setTakerFeeConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "takerFeeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTakerFeeConfigArgs"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "auctionHouse",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "auctionHouseTreasury",
-            "type": "publicKey"
-          },
-          {
-            "name": "treasuryWithdrawalDestination",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "notary",
-            "type": "publicKey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "treasuryBump",
-            "type": "u8"
-          },
-          {
-            "name": "sellerFeeBasisPoints",
-            "type": "u16"
-          },
-          {
-            "name": "buyerReferralBp",
-            "type": "u16"
-          },
-          {
-            "name": "sellerReferralBp",
-            "type": "u16"
-          },
-          {
-            "name": "requiresNotary",
-            "type": "bool"
-          },
-          {
-            "name": "nprob",
-            "type": "u8"
+      "name": "setBuybackConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buybackConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetBuybackConfigArgs"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "buyerTradeStateV2",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyer",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerReferral",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerPrice",
-            "type": "u64"
-          },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenSize",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
-          },
-          {
-            "name": "buyerCreatorRoyaltyBp",
-            "type": "u16"
-          },
-          {
-            "name": "paymentMint",
-            "type": "publicKey"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "name": "buybackListing",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buybackConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
-      "name": "MIP1ExecuteSaleV2Args",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "makerFeeBp",
-            "type": "i16"
-          },
-          {
-            "name": "takerFeeBp",
-            "type": "u16"
-          }
-        ]
-      }
+      "name": "syncBidExpiryAlarm",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidExpiryAlarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bucket",
+          "type": "i64"
+        },
+        {
+          "name": "page",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "MIP1SellArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "OCPExecuteSaleV2Args",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "makerFeeBp",
-            "type": "i16"
-          },
-          {
-            "name": "takerFeeBp",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "OCPSellArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
+      "name": "commitBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CommitBidArgs"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "DestinationSpecifier",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Key",
-            "fields": [
-              {
-                "defined": "&'refsPubkey"
-              }
-            ]
-          },
-          {
-            "name": "Ai",
-            "fields": [
-              {
-                "defined": "&'refsAccountInfo<'a>"
-              }
-            ]
+      "name": "revealBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RevealBidArgs"
           }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "PublicKeyMismatch",
-      "msg": "PublicKeyMismatch"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidMintAuthority",
-      "msg": "InvalidMintAuthority"
-    },
-    {
-      "code": 6002,
-      "name": "UninitializedAccount",
-      "msg": "UninitializedAccount"
-    },
-    {
-      "code": 6003,
-      "name": "IncorrectOwner",
-      "msg": "IncorrectOwner"
+        }
+      ]
     },
     {
-      "code": 6004,
-      "name": "PublicKeysShouldBeUnique",
-      "msg": "PublicKeysShouldBeUnique"
+      "name": "reclaimSealedBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6005,
-      "name": "StatementFalse",
-      "msg": "StatementFalse"
+      "name": "reclaimUnsold",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "highestBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6006,
-      "name": "NotRentExempt",
-      "msg": "NotRentExempt"
+      "name": "setPriceBandConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPriceBandConfigArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6007,
-      "name": "NumericalOverflow",
-      "msg": "NumericalOverflow"
-    },
-    {
-      "code": 6008,
-      "name": "ExpectedSolAccount",
-      "msg": "Expected a sol account but got an spl token account instead"
-    },
-    {
-      "code": 6009,
-      "name": "CannotExchangeSOLForSol",
-      "msg": "Cannot exchange sol for sol"
-    },
-    {
-      "code": 6010,
-      "name": "SOLWalletMustSign",
-      "msg": "If paying with sol, sol wallet must be signer"
-    },
-    {
-      "code": 6011,
-      "name": "CannotTakeThisActionWithoutAuctionHouseSignOff",
-      "msg": "Cannot take this action without auction house signing too"
-    },
-    {
-      "code": 6012,
-      "name": "NoPayerPresent",
-      "msg": "No payer present on this txn"
-    },
-    {
-      "code": 6013,
-      "name": "DerivedKeyInvalid",
-      "msg": "Derived key invalid"
-    },
-    {
-      "code": 6014,
-      "name": "MetadataDoesntExist",
-      "msg": "Metadata doesn't exist"
-    },
-    {
-      "code": 6015,
-      "name": "InvalidTokenAmount",
-      "msg": "Invalid token amount"
-    },
-    {
-      "code": 6016,
-      "name": "BothPartiesNeedToAgreeToSale",
-      "msg": "Both parties need to agree to this sale"
-    },
-    {
-      "code": 6017,
-      "name": "CannotMatchFreeSalesWithoutAuctionHouseOrSellerSignoff",
-      "msg": "Cannot match free sales unless the auction house or seller signs off"
-    },
-    {
-      "code": 6018,
-      "name": "SaleRequiresSigner",
-      "msg": "This sale requires a signer"
-    },
-    {
-      "code": 6019,
-      "name": "OldSellerNotInitialized",
-      "msg": "Old seller not initialized"
-    },
-    {
-      "code": 6020,
-      "name": "SellerATACannotHaveDelegate",
-      "msg": "Seller ata cannot have a delegate set"
-    },
-    {
-      "code": 6021,
-      "name": "BuyerATACannotHaveDelegate",
-      "msg": "Buyer ata cannot have a delegate set"
-    },
-    {
-      "code": 6022,
-      "name": "NoValidSignerPresent",
-      "msg": "No valid signer present"
-    },
-    {
-      "code": 6023,
-      "name": "InvalidBasisPoints",
-      "msg": "Invalid BP"
-    },
-    {
-      "code": 6024,
-      "name": "InvalidNotary",
-      "msg": "Invalid notary"
-    },
-    {
-      "code": 6025,
-      "name": "EmptyTradeState",
-      "msg": "Empty trade state"
-    },
-    {
-      "code": 6026,
-      "name": "InvalidExpiry",
-      "msg": "Invalid expiry"
-    },
-    {
-      "code": 6027,
-      "name": "InvalidPrice",
-      "msg": "Invalid price"
-    },
-    {
-      "code": 6028,
-      "name": "InvalidRemainingAccountsWithoutProgramAsSigner",
-      "msg": "Invalid remainning accounts without program_as_signer"
-    },
-    {
-      "code": 6029,
-      "name": "InvalidBump",
-      "msg": "Invalid bump"
-    },
-    {
-      "code": 6030,
-      "name": "InvalidCreateAuctionHouseNonce",
-      "msg": "Invalid create auction house nonce"
-    },
-    {
-      "code": 6031,
-      "name": "InvalidAccountState",
-      "msg": "Invalid account state"
-    },
-    {
-      "code": 6032,
-      "name": "InvalidDiscriminator",
-      "msg": "Invalid discriminator"
-    },
-    {
-      "code": 6033,
-      "name": "InvalidPlatformFeeBp",
-      "msg": "Invalid platform fee bp"
-    },
-    {
-      "code": 6034,
-      "name": "InvalidTokenMint",
-      "msg": "Invalid token mint"
-    },
-    {
-      "code": 6035,
-      "name": "InvalidTokenStandard",
-      "msg": "Invalid token standard"
-    },
-    {
-      "code": 6036,
-      "name": "Deprecated",
-      "msg": "Deprecated"
-    },
-    {
-      "code": 6037,
-      "name": "MissingRemainingAccount",
-      "msg": "Missing remaining account"
-    }
-  ]
-};
-
-export const IDL: M2 = {
-  "version": "0.1.0",
-  "name": "m2",
-  "instructions": [
-    {
-      "name": "withdrawFromTreasury",
+      "name": "attestPrice",
       "accounts": [
         {
-          "name": "treasuryWithdrawalDestination",
+          "name": "notary",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "auctionHouseTreasury",
-          "isMut": true,
+          "name": "auctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "priceAttestation",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -2014,41 +2358,33 @@ export const IDL: M2 = {
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "args",
+          "type": {
+            "defined": "AttestPriceArgs"
+          }
         }
       ]
     },
     {
-      "name": "updateAuctionHouse",
+      "name": "setTreasuryYieldConfig",
       "accounts": [
-        {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "notary",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "authority",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "newAuthority",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "treasuryWithdrawalDestination",
-          "isMut": true,
+          "name": "vaultProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "treasuryYieldConfig",
           "isMut": true,
           "isSigner": false
         },
@@ -2060,62 +2396,58 @@ export const IDL: M2 = {
       ],
       "args": [
         {
-          "name": "sellerFeeBasisPoints",
+          "name": "args",
           "type": {
-            "option": "u16"
+            "defined": "SetTreasuryYieldConfigArgs"
           }
-        },
+        }
+      ]
+    },
+    {
+      "name": "sweepTreasury",
+      "accounts": [
         {
-          "name": "buyerReferralBp",
-          "type": {
-            "option": "u16"
-          }
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "sellerReferralBp",
-          "type": {
-            "option": "u16"
-          }
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "requiresNotary",
-          "type": {
-            "option": "bool"
-          }
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "nprob",
-          "type": {
-            "option": "u8"
-          }
-        }
-      ]
-    },
-    {
-      "name": "withdraw",
-      "accounts": [
-        {
-          "name": "wallet",
+          "name": "treasuryYieldConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "vaultProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowPaymentAccount",
+          "name": "vaultState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "shareMint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "treasuryShareAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2127,33 +2459,26 @@ export const IDL: M2 = {
       ],
       "args": [
         {
-          "name": "escrowPaymentBump",
-          "type": "u8"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
+          "name": "args",
+          "type": {
+            "defined": "SweepTreasuryArgs"
+          }
         }
       ]
     },
     {
-      "name": "deposit",
+      "name": "sellBundle",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "seller",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "notary",
+          "name": "sellerReferral",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "escrowPaymentAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "authority",
           "isMut": false,
@@ -2164,6 +2489,11 @@ export const IDL: M2 = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -2172,46 +2502,98 @@ export const IDL: M2 = {
       ],
       "args": [
         {
-          "name": "escrowPaymentBump",
-          "type": "u8"
+          "name": "args",
+          "type": {
+            "defined": "SellBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "appendBundleItems",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AppendBundleItemsArgs"
+          }
         }
       ]
     },
     {
-      "name": "sell",
+      "name": "cancelBundle",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "seller",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "notary",
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeBundleSale",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenAta",
+          "name": "seller",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "notary",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
-          "isMut": false,
+          "name": "escrowPaymentAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -2225,27 +2607,32 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleSellerState",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "sellerReferral",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "sellerStatus",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ataProgram",
+          "name": "buyerStatus",
           "isMut": false,
           "isSigner": false
         },
@@ -2255,61 +2642,60 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "sellerStateBump",
-          "type": "u8"
-        },
-        {
-          "name": "programAsSignerBump",
-          "type": "u8"
         },
         {
-          "name": "buyerPrice",
-          "type": "u64"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "tokenSize",
-          "type": "u64"
-        },
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
-          "name": "sellerStateExpiry",
-          "type": "i64"
+          "name": "args",
+          "type": {
+            "defined": "ExecuteBundleSaleArgs"
+          }
         }
       ]
     },
     {
-      "name": "cancelSell",
+      "name": "setAllowedBuyer",
       "accounts": [
         {
           "name": "wallet",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "notary",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenAccount",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tokenMint",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "allowedBuyer",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "returnAllAssets",
+      "accounts": [
         {
           "name": "authority",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "auctionHouse",
@@ -2317,12 +2703,7 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellerReferral",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
@@ -2330,36 +2711,28 @@ export const IDL: M2 = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "buyerPrice",
-          "type": "u64"
         },
         {
-          "name": "tokenSize",
-          "type": "u64"
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "sellerStateExpiry",
-          "type": "i64"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "buy",
+      "name": "buyDelegated",
       "accounts": [
         {
           "name": "wallet",
           "isMut": true,
           "isSigner": true
         },
-        {
-          "name": "notary",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "tokenMint",
           "isMut": false,
@@ -2370,11 +2743,6 @@ export const IDL: M2 = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "escrowPaymentAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "authority",
           "isMut": false,
@@ -2396,46 +2764,62 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "paymentMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "paymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "buyerStateBump",
-          "type": "u8"
+          "name": "orderLimitConfig",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "escrowPaymentBump",
-          "type": "u8"
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "buyerPrice",
-          "type": "u64"
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tokenSize",
-          "type": "u64"
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "buyerStateExpiry",
-          "type": "i64"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyDelegatedArgs"
+          }
         }
       ]
     },
     {
-      "name": "buyV2",
+      "name": "sellBatch",
       "accounts": [
         {
           "name": "wallet",
@@ -2448,43 +2832,33 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowPaymentAccount",
-          "isMut": true,
+          "name": "sellerReferral",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buyerReferral",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2495,29 +2869,15 @@ export const IDL: M2 = {
       ],
       "args": [
         {
-          "name": "buyerPrice",
-          "type": "u64"
-        },
-        {
-          "name": "tokenSize",
-          "type": "u64"
-        },
-        {
-          "name": "buyerStateExpiry",
-          "type": "i64"
-        },
-        {
-          "name": "buyerCreatorRoyaltyBp",
-          "type": "u16"
-        },
-        {
-          "name": "extraArgs",
-          "type": "bytes"
+          "name": "args",
+          "type": {
+            "defined": "SellBatchArgs"
+          }
         }
       ]
     },
     {
-      "name": "cancelBuy",
+      "name": "notaryCancelBid",
       "accounts": [
         {
           "name": "wallet",
@@ -2526,12 +2886,12 @@ export const IDL: M2 = {
         },
         {
           "name": "notary",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "tokenMint",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2550,101 +2910,98 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "buyerReferral",
-          "isMut": false,
+          "name": "escrowPaymentAccount",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "buyerPrice",
-          "type": "u64"
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tokenSize",
-          "type": "u64"
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "buyerStateExpiry",
-          "type": "i64"
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "ocpSell",
+      "name": "proposeSwap",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "notary",
+          "name": "offeredMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "offeredMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAta",
+          "name": "offeredTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "requestedMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "requestedMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellerReferral",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpMintState",
+          "name": "swapOffer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpPolicy",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ocpFreezeAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ocpProgram",
+          "name": "escrowPaymentAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cmtProgram",
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
@@ -2657,92 +3014,82 @@ export const IDL: M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "OCPSellArgs"
+            "defined": "ProposeSwapArgs"
           }
         }
       ]
     },
     {
-      "name": "ocpCancelSell",
+      "name": "acceptSwap",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "counterparty",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "notary",
-          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "programAsSigner",
-          "isMut": false,
+          "name": "proposer",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAta",
-          "isMut": true,
+          "name": "offeredMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": false,
+          "name": "offeredTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metadata",
-          "isMut": false,
+          "name": "counterpartyReceiptTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "requestedMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "requestedTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpMintState",
+          "name": "proposerReceiptTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpPolicy",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpFreezeAuthority",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpProgram",
-          "isMut": false,
+          "name": "swapOffer",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "cmtProgram",
-          "isMut": false,
+          "name": "escrowPaymentAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
@@ -2757,7 +3104,7 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "ataProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -2765,120 +3112,147 @@ export const IDL: M2 = {
       "args": []
     },
     {
-      "name": "ocpExecuteSaleV2",
+      "name": "cancelSwap",
       "accounts": [
         {
-          "name": "payer",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "buyer",
+          "name": "offeredTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "seller",
+          "name": "swapOffer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPriceTick",
+      "accounts": [
         {
-          "name": "sellerTokenAta",
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "buyerTokenAta",
-          "isMut": true,
+          "name": "auctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tickSize",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "listRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "auctionHouse",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouseTreasury",
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "tokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
-          "isMut": true,
+          "name": "edition",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerEscrowPaymentAccount",
+          "name": "tokenRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerReferral",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
-          "isMut": true,
+          "name": "auctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpMintState",
+          "name": "rentalListing",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ocpPolicy",
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpFreezeAuthority",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ocpProgram",
+          "name": "tokenMetadataProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "cmtProgram",
+          "name": "authorizationRulesProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "authorizationRules",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "instructions",
           "isMut": false,
           "isSigner": false
         },
@@ -2891,98 +3265,83 @@ export const IDL: M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "OCPExecuteSaleV2Args"
+            "defined": "ListRentalArgs"
           }
         }
       ]
     },
     {
-      "name": "executeSaleV2",
+      "name": "rentNft",
       "accounts": [
         {
-          "name": "buyer",
+          "name": "renter",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "seller",
+          "name": "owner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": false,
+          "name": "tokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "edition",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "escrowPaymentAccount",
+          "name": "tokenRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerReceiptTokenAccount",
+          "name": "rentalListing",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "tokenMetadataProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouseTreasury",
-          "isMut": true,
+          "name": "authorizationRulesProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
-          "isMut": true,
+          "name": "authorizationRules",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerReferral",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellerTradeState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellerReferral",
-          "isMut": true,
+          "name": "instructions",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2994,131 +3353,58 @@ export const IDL: M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "ataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "programAsSigner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "escrowPaymentBump",
-          "type": "u8"
-        },
-        {
-          "name": "programAsSignerBump",
-          "type": "u8"
-        },
-        {
-          "name": "buyerPrice",
-          "type": "u64"
-        },
-        {
-          "name": "tokenSize",
-          "type": "u64"
-        },
-        {
-          "name": "buyerStateExpiry",
-          "type": "i64"
-        },
-        {
-          "name": "sellerStateExpiry",
-          "type": "i64"
-        },
-        {
-          "name": "makerFeeBp",
-          "type": "i16"
-        },
-        {
-          "name": "takerFeeBp",
-          "type": "u16"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "mip1Sell",
+      "name": "reclaimRental",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "notary",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "programAsSigner",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "metadata",
+          "name": "tokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "edition",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "tokenRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "migrationSellerTradeState",
+          "name": "rentalListing",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellerReferral",
+          "name": "programAsSigner",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "tokenAta",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "escrow mode for init sell:        we transfer from token_account to token_ata",
-            "escrow mode for change price:     token_account is the same as token_ata",
-            "migration mode for change price:  token_ata is not used, because we only need token_account which is owned by program_as_signer"
-          ]
-        },
         {
           "name": "tokenMetadataProgram",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "authorizationRulesProgram",
           "isMut": false,
@@ -3134,21 +3420,6 @@ export const IDL: M2 = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destinationTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -3158,182 +3429,257 @@ export const IDL: M2 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "MIP1SellArgs"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "mip1ExecuteSaleV2",
+      "name": "closeExpiredBuyerState",
       "accounts": [
         {
-          "name": "payer",
+          "name": "cranker",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "buyer",
+          "name": "crankBond",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "seller",
+          "name": "wallet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "notary",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAsSigner",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
+          "name": "buyerTradeState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerReceiptTokenAccount",
+          "name": "escrowPaymentAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": false,
+          "name": "walletOrderCounter",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "countedOrder",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionHouse",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionHouseTreasury",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setHoldPeriod",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
-          "isMut": true,
+          "name": "collection",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buyerTradeState",
+          "name": "collectionConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "buyerEscrowPaymentAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "holdPeriod",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setAutoRenew",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "buyerReferral",
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "period",
+          "type": "i64"
         },
         {
-          "name": "sellerReferral",
+          "name": "bumpBp",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "renewListing",
+      "accounts": [
+        {
+          "name": "sellerTradeState",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "provisionCreatorAtas",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "metadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "paymentMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
-          "isMut": true,
+          "name": "associatedTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setEscrowDepositor",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "instructions",
+          "name": "depositor",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "escrowDepositor",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "args",
-          "type": {
-            "defined": "MIP1ExecuteSaleV2Args"
-          }
+          "name": "enabled",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "mip1CancelSell",
+      "name": "depositorDeposit",
       "accounts": [
         {
-          "name": "wallet",
+          "name": "depositor",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "notary",
+          "name": "wallet",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "escrowDepositor",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "programAsSigner",
@@ -3341,91 +3687,212 @@ export const IDL: M2 = {
           "isSigner": false
         },
         {
-          "name": "tokenAta",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setRoyaltySubsidy",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "collection",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "royaltySubsidy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRoyaltySubsidyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "portfolioBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "escrowPaymentAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellerTradeState",
+          "name": "portfolioBidState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAccount",
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PortfolioBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellIntoPortfolioBid",
+      "accounts": [
+        {
+          "name": "seller",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "should always be ATA of (mint, wallet)"
-          ]
+          "isSigner": true
         },
         {
-          "name": "tokenAccountTemp",
+          "name": "buyer",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "should always be ATA of (mint, program_as_signer)"
-          ]
+          "isSigner": false
         },
         {
-          "name": "tempTokenRecord",
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "edition",
+          "name": "metadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "auctionHouse",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
+          "name": "auctionHouseTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
+          "name": "portfolioBidState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -3437,86 +3904,18082 @@ export const IDL: M2 = {
           "isSigner": false
         }
       ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellIntoPortfolioBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelPortfolioBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "portfolioBidState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "setSecondaryPrice",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setNotBefore",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "notBefore",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setOfferTarget",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetSeller",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AcceptOfferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptOffersBatch",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AcceptOffersBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "gift",
+      "accounts": [
+        {
+          "name": "sender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "t22Sell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderLimitConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateApproval",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "T22SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "t22CancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "t22ExecuteSaleV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "frontendConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerFeeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltySubsidy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fillProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "T22ExecuteSaleV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellCompressed",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousLeafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellCompressedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelSellCompressed",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelSellCompressedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyCompressed",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyCompressedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeCompressedSale",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecuteCompressedSaleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "getStateLayout",
+      "accounts": [],
+      "args": [
+        {
+          "name": "discriminator",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        }
+      ],
+      "returns": {
+        "defined": "StateLayoutData"
+      }
+    },
+    {
+      "name": "setRebateCampaign",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRebateCampaignArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "commitRebateDraw",
+      "accounts": [
+        {
+          "name": "randomnessAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CommitRebateDrawArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "drawRebate",
+      "accounts": [
+        {
+          "name": "randomnessAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRebate",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "randomnessAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "coreSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CoreSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "coreCancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "coreExecuteSale",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CoreExecuteSaleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCustodialPlatform",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platform",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodialPlatform",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "custodyWallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "placeOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderBook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderBookLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bookVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PlaceOrderArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderBook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderBookLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bookVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelOrderArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "matchOrders",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderBook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "askLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bookVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeFillProof",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fillProof",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sellEditions",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "masterTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "editionSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellEditionsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyEdition",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionMarkPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "updateAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelEditionSale",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "editionSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setInsuranceFund",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInsuranceFundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueInsurancePayout",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeInsurancePayout",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listStaked",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakedListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakingProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ListStakedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyStaked",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakedListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakingProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelStakedListing",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakedListing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPermanentDelegateApproval",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateApproval",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approved",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setOrderLimitConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderLimitConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxOpenListings",
+          "type": "u32"
+        },
+        {
+          "name": "maxOpenBids",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setPermanentDelegateConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "flagOnly",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "publishCancellationRoot",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cancellationSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "orderCount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "cancelWithProof",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cancellationSnapshot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSaleAuto",
+      "accounts": [
+        {
+          "name": "asset",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecuteSaleAutoArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "depositCrankBond",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawCrankBond",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "ocpSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "OCPSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ocpCancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "ocpExecuteSaleV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "OCPExecuteSaleV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSaleV2",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowPaymentBump",
+          "type": "u8"
+        },
+        {
+          "name": "programAsSignerBump",
+          "type": "u8"
+        },
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "buyerStateExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "sellerStateExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "makerFeeBp",
+          "type": "i16"
+        },
+        {
+          "name": "takerFeeBp",
+          "type": "u16"
+        },
+        {
+          "name": "maxPrice",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "minNetProceeds",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "custodyUserTag",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1Sell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "migrationSellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "escrow mode for init sell:        we transfer from token_account to token_ata",
+            "escrow mode for change price:     token_account is the same as token_ata",
+            "migration mode for change price:  token_ata is not used, because we only need token_account which is owned by program_as_signer"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MIP1SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1MigrateAndList",
+      "accounts": [
+        {
+          "name": "sell",
+          "accounts": [
+            {
+              "name": "wallet",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "notary",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "programAsSigner",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "metadata",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "auctionHouse",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sellerTradeState",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "migrationSellerTradeState",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellerReferral",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAta",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "escrow mode for init sell:        we transfer from token_account to token_ata",
+                "escrow mode for change price:     token_account is the same as token_ata",
+                "migration mode for change price:  token_ata is not used, because we only need token_account which is owned by program_as_signer"
+              ]
+            },
+            {
+              "name": "tokenMetadataProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "edition",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authorizationRulesProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authorizationRules",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "ownerTokenRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destinationTokenRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "collectionConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lastSale",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentalListing",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MIP1SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1ExecuteSaleV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MIP1ExecuteSaleV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1CancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "should always be ATA of (mint, wallet)"
+          ]
+        },
+        {
+          "name": "tokenAccountTemp",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "should always be ATA of (mint, program_as_signer)"
+          ]
+        },
+        {
+          "name": "tempTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "buyerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellerTradeStateV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "floorPremiumBp",
+            "type": {
+              "option": "i16"
+            }
+          },
+          {
+            "name": "creatorListing",
+            "type": "bool"
+          },
+          {
+            "name": "dutchEndPrice",
+            "type": "u64"
+          },
+          {
+            "name": "dutchStartTime",
+            "type": "i64"
+          },
+          {
+            "name": "dutchDecayInterval",
+            "type": "i64"
+          },
+          {
+            "name": "allowedBuyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "autoRenewPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "autoRenewBumpBp",
+            "type": "u16"
+          },
+          {
+            "name": "secondaryPaymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "secondaryPrice",
+            "type": "u64"
+          },
+          {
+            "name": "notBefore",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionHouse",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryWithdrawalDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "notary",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuryBump",
+            "type": "u8"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "buyerReferralBp",
+            "type": "u16"
+          },
+          {
+            "name": "sellerReferralBp",
+            "type": "u16"
+          },
+          {
+            "name": "requiresNotary",
+            "type": "bool"
+          },
+          {
+            "name": "nprob",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyerTradeStateV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "escrowless",
+            "type": "bool"
+          },
+          {
+            "name": "takeProfitPrice",
+            "type": "u64"
+          },
+          {
+            "name": "targetSeller",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintListingIndex",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "listings",
+            "type": {
+              "vec": {
+                "defined": "MintListingEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "tickSize",
+            "type": "u64"
+          },
+          {
+            "name": "holdPeriod",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "saleCount",
+            "type": "u64"
+          },
+          {
+            "name": "volume",
+            "type": "u128"
+          },
+          {
+            "name": "lastSalePrice",
+            "type": "u64"
+          },
+          {
+            "name": "lastSaleAt",
+            "type": "i64"
+          },
+          {
+            "name": "floorWindowStart",
+            "type": "i64"
+          },
+          {
+            "name": "floorPrice",
+            "type": "u64"
+          },
+          {
+            "name": "twapPrices",
+            "type": {
+              "array": [
+                "u64",
+                6
+              ]
+            }
+          },
+          {
+            "name": "twapSaleTimes",
+            "type": {
+              "array": [
+                "i64",
+                6
+              ]
+            }
+          },
+          {
+            "name": "twapHead",
+            "type": "u8"
+          },
+          {
+            "name": "twapPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lastSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "soldAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tradeSeq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tradeSequence",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seq",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellerStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "suspended",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyerStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "suspended",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "frontendConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "frontend",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "saleCount",
+            "type": "u64"
+          },
+          {
+            "name": "volume",
+            "type": "u128"
+          },
+          {
+            "name": "feesEarned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listingOperator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "operator",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionBidState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "filled",
+            "type": "u16"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "royaltyInclusive",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "traitHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "allowedAuctionHouses",
+            "type": {
+              "array": [
+                "publicKey",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyFallback",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "highestBid",
+            "type": "u64"
+          },
+          {
+            "name": "highestBidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidCount",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "type": "u8"
+          },
+          {
+            "name": "extensionWindow",
+            "type": "i64"
+          },
+          {
+            "name": "extensionSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "revealEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "commitCount",
+            "type": "u32"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tickSize",
+            "type": "u64"
+          },
+          {
+            "name": "highestMaxBid",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "takerFeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceFeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "flatFeeMicroUsd",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "prevFlatFeeMicroUsd",
+            "type": "u64"
+          },
+          {
+            "name": "prevMaxFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "rampSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buybackConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "burned",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidExpiryAlarm",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "bucket",
+            "type": "i64"
+          },
+          {
+            "name": "page",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "BidExpiryAlarmEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sealedBid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionState",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "revealEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceBandConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "maxAge",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceAttestation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "attestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryYieldConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaultProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaultState",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "float",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleSellerState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u64"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "sealed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "BundleItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapOffer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "offeredMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "offeredTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "counterparty",
+            "type": "publicKey"
+          },
+          {
+            "name": "requestedMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "sweetener",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentalListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "term",
+            "type": "i64"
+          },
+          {
+            "name": "renter",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "escrowDepositor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltySubsidy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "subsidyBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "portfolioBidState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidId",
+            "type": "u64"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "compressedSellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "coreSellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "asset",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodialPlatform",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "platform",
+            "type": "publicKey"
+          },
+          {
+            "name": "custodyWallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "editionSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "masterMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrints",
+            "type": "u64"
+          },
+          {
+            "name": "printed",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fillProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "tradeSeq",
+            "type": "u64"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "insuranceFund",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "takerFeeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaidOut",
+            "type": "u64"
+          },
+          {
+            "name": "payoutRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "payoutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "payoutAvailableAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderBook",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextSeq",
+            "type": "u64"
+          },
+          {
+            "name": "bidPrices",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "askPrices",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderBookLevel",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderBook",
+            "type": "publicKey"
+          },
+          {
+            "name": "isBid",
+            "type": "bool"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "orders",
+            "type": {
+              "vec": {
+                "defined": "BookOrder"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebateCampaign",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "randomnessAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebateEpoch",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "entriesHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "ticketCount",
+            "type": "u32"
+          },
+          {
+            "name": "winner",
+            "type": "publicKey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          },
+          {
+            "name": "drawn",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tickets",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakedListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "unstakeData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "permanentDelegateApproval",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "permanentDelegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "approved",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderLimitConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxOpenListings",
+            "type": "u32"
+          },
+          {
+            "name": "maxOpenBids",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "walletOrderCounter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "openListings",
+            "type": "u32"
+          },
+          {
+            "name": "openBids",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "countedOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tradeState",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "permanentDelegateConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "flagOnly",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cancellationSnapshot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "orderCount",
+            "type": "u32"
+          },
+          {
+            "name": "publishedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "crankBond",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cranker",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BuyCompressedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSellCompressedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecuteCompressedSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CompressedCreator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CompressedCreator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecuteSaleAutoArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "escrowPaymentBump",
+            "type": "u8"
+          },
+          {
+            "name": "programAsSignerBump",
+            "type": "u8"
+          },
+          {
+            "name": "compressed",
+            "type": {
+              "option": {
+                "defined": "CompressedSaleArgs"
+              }
+            }
+          },
+          {
+            "name": "maxPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "custodyUserTag",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellCompressedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "leaf",
+            "type": {
+              "defined": "CompressedLeafArgs"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreExecuteSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptOfferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptOffersBatchItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptOffersBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "AcceptOffersBatchItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AppendBundleItemsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seal",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AttestPriceArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyDelegatedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "buyerStateExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "delegatedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "takeProfitPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelOrderArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "royaltyInclusive",
+            "type": "bool"
+          },
+          {
+            "name": "traitHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "allowedAuctionHouses",
+            "type": {
+              "array": [
+                "publicKey",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommitBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommitRebateDrawArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateAuctionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "extensionWindow",
+            "type": "i64"
+          },
+          {
+            "name": "extensionSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "revealEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CustodySellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecuteBundleSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FieldLayoutData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "offset",
+            "type": "u16"
+          },
+          {
+            "name": "size",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StateLayoutData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "discriminator",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "len",
+            "type": "u16"
+          },
+          {
+            "name": "fields",
+            "type": {
+              "vec": {
+                "defined": "FieldLayoutData"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListRentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "term",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListStakedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "unstakeData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OperatorRepriceListingArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlaceOrderArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isBid",
+            "type": "bool"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "size",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellEditionsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrints",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInsuranceFundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerFeeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PortfolioBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidId",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposeSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "counterparty",
+            "type": "publicKey"
+          },
+          {
+            "name": "sweetener",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevealBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "salt",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBatchItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "SellBatchItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundleId",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellDutchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "decayInterval",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellIntoCollectionBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellIntoPortfolioBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetBuybackConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetFrontendConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPriceBandConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "maxAge",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRebateCampaignArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "randomnessAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRoyaltySubsidyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subsidyBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTakerFeeConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flatFeeMicroUsd",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "rampHours",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTreasuryYieldConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "float",
+            "type": "u64"
+          },
+          {
+            "name": "vaultState",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettleAuctionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapIntoEscrowArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmountIn",
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "swapData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SweepTreasuryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposit",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MIP1ExecuteSaleV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MIP1SellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OCPExecuteSaleV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OCPSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintListingEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sellerTradeState",
+            "type": "publicKey"
+          },
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidExpiryAlarmEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerTradeState",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BookOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "type": "u64"
+          },
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "T22ExecuteSaleV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "T22SellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedCreator",
+      "docs": [
+        "Creator of a compressed NFT as attested by the notary, the leaf only has a hash of these"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreRoyaltyCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "percentage",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreRoyalties",
+      "docs": [
+        "Royalties plugin of an mpl core asset or collection, without its rule set"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CoreRoyaltyCreator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedLeafArgs",
+      "docs": [
+        "Leaf of a compressed NFT and the root of the tree it is proven against, laid out the way",
+        "Bubblegum's delegate and transfer instructions take them"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreAuthority",
+      "docs": [
+        "Authority of a plugin of an mpl core asset"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Owner"
+          },
+          {
+            "name": "UpdateAuthority"
+          },
+          {
+            "name": "Address",
+            "fields": [
+              {
+                "name": "address",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DestinationSpecifier",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Key",
+            "fields": [
+              {
+                "defined": "&'refsPubkey"
+              }
+            ]
+          },
+          {
+            "name": "Ai",
+            "fields": [
+              {
+                "defined": "&'refsAccountInfo<'a>"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "PublicKeyMismatch",
+      "msg": "PublicKeyMismatch"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6002,
+      "name": "UninitializedAccount",
+      "msg": "UninitializedAccount"
+    },
+    {
+      "code": 6003,
+      "name": "IncorrectOwner",
+      "msg": "IncorrectOwner"
+    },
+    {
+      "code": 6004,
+      "name": "PublicKeysShouldBeUnique",
+      "msg": "PublicKeysShouldBeUnique"
+    },
+    {
+      "code": 6005,
+      "name": "StatementFalse",
+      "msg": "StatementFalse"
+    },
+    {
+      "code": 6006,
+      "name": "NotRentExempt",
+      "msg": "NotRentExempt"
+    },
+    {
+      "code": 6007,
+      "name": "NumericalOverflow",
+      "msg": "NumericalOverflow"
+    },
+    {
+      "code": 6008,
+      "name": "ExpectedSolAccount",
+      "msg": "Expected a sol account but got an spl token account instead"
+    },
+    {
+      "code": 6009,
+      "name": "CannotExchangeSOLForSol",
+      "msg": "Cannot exchange sol for sol"
+    },
+    {
+      "code": 6010,
+      "name": "SOLWalletMustSign",
+      "msg": "If paying with sol, sol wallet must be signer"
+    },
+    {
+      "code": 6011,
+      "name": "CannotTakeThisActionWithoutAuctionHouseSignOff",
+      "msg": "Cannot take this action without auction house signing too"
+    },
+    {
+      "code": 6012,
+      "name": "NoPayerPresent",
+      "msg": "No payer present on this txn"
+    },
+    {
+      "code": 6013,
+      "name": "DerivedKeyInvalid",
+      "msg": "Derived key invalid"
+    },
+    {
+      "code": 6014,
+      "name": "MetadataDoesntExist",
+      "msg": "Metadata doesn't exist"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidTokenAmount",
+      "msg": "Invalid token amount"
+    },
+    {
+      "code": 6016,
+      "name": "BothPartiesNeedToAgreeToSale",
+      "msg": "Both parties need to agree to this sale"
+    },
+    {
+      "code": 6017,
+      "name": "CannotMatchFreeSalesWithoutAuctionHouseOrSellerSignoff",
+      "msg": "Cannot match free sales unless the auction house or seller signs off"
+    },
+    {
+      "code": 6018,
+      "name": "SaleRequiresSigner",
+      "msg": "This sale requires a signer"
+    },
+    {
+      "code": 6019,
+      "name": "OldSellerNotInitialized",
+      "msg": "Old seller not initialized"
+    },
+    {
+      "code": 6020,
+      "name": "SellerATACannotHaveDelegate",
+      "msg": "Seller ata cannot have a delegate set"
+    },
+    {
+      "code": 6021,
+      "name": "BuyerATACannotHaveDelegate",
+      "msg": "Buyer ata cannot have a delegate set"
+    },
+    {
+      "code": 6022,
+      "name": "NoValidSignerPresent",
+      "msg": "No valid signer present"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidBasisPoints",
+      "msg": "Invalid BP"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidNotary",
+      "msg": "Invalid notary"
+    },
+    {
+      "code": 6025,
+      "name": "EmptyTradeState",
+      "msg": "Empty trade state"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidExpiry",
+      "msg": "Invalid expiry"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidPrice",
+      "msg": "Invalid price"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidRemainingAccountsWithoutProgramAsSigner",
+      "msg": "Invalid remainning accounts without program_as_signer"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidBump",
+      "msg": "Invalid bump"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidCreateAuctionHouseNonce",
+      "msg": "Invalid create auction house nonce"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidAccountState",
+      "msg": "Invalid account state"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidDiscriminator",
+      "msg": "Invalid discriminator"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidPlatformFeeBp",
+      "msg": "Invalid platform fee bp"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidTokenMint",
+      "msg": "Invalid token mint"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidTokenStandard",
+      "msg": "Invalid token standard"
+    },
+    {
+      "code": 6036,
+      "name": "Deprecated",
+      "msg": "Deprecated"
+    },
+    {
+      "code": 6037,
+      "name": "MissingRemainingAccount",
+      "msg": "Missing remaining account"
+    },
+    {
+      "code": 6038,
+      "name": "CollectionPaused",
+      "msg": "Trading is paused for this collection"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidSwapProgram",
+      "msg": "Swap program is not whitelisted"
+    },
+    {
+      "code": 6040,
+      "name": "SlippageExceeded",
+      "msg": "Swap output is below the minimum amount out"
+    },
+    {
+      "code": 6041,
+      "name": "FloorPriceUnavailable",
+      "msg": "Floor price is not available for this collection"
+    },
+    {
+      "code": 6042,
+      "name": "SellerSuspended",
+      "msg": "Seller has suspended all listings"
+    },
+    {
+      "code": 6043,
+      "name": "BuyerSuspended",
+      "msg": "Buyer has suspended all bids"
+    },
+    {
+      "code": 6044,
+      "name": "PriceBelowMintMinimum",
+      "msg": "Price is below the minimum price for the payment mint"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidPricePrecision",
+      "msg": "Price has more precision than the payment mint supports"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidReferral",
+      "msg": "Referral account does not match the trade state"
+    },
+    {
+      "code": 6047,
+      "name": "InsufficientComputeBudget",
+      "msg": "Not enough compute units requested, increase the compute unit limit"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidCollection",
+      "msg": "Token is not in the verified collection of the bid"
+    },
+    {
+      "code": 6049,
+      "name": "TraitAttestationRequired",
+      "msg": "Trait bids need the notary to attest the token's traits"
+    },
+    {
+      "code": 6050,
+      "name": "AuctionNotActive",
+      "msg": "Auction is not accepting bids"
+    },
+    {
+      "code": 6051,
+      "name": "AuctionBidTooLow",
+      "msg": "Auction bid is lower than the minimum bid"
+    },
+    {
+      "code": 6052,
+      "name": "AuctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidPriceFeed",
+      "msg": "Price feed is invalid or stale"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidSealedBid",
+      "msg": "Sealed bid doesn't match its commitment"
+    },
+    {
+      "code": 6055,
+      "name": "PriceOutsideAttestedBand",
+      "msg": "Price is outside of the attested fair price band"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidYieldVaultProgram",
+      "msg": "Invalid yield vault program"
+    },
+    {
+      "code": 6057,
+      "name": "BuyerNotAllowed",
+      "msg": "Listing is reserved for another buyer"
+    },
+    {
+      "code": 6058,
+      "name": "InsufficientDelegatedAmount",
+      "msg": "Delegated payment doesn't cover the bid"
+    },
+    {
+      "code": 6059,
+      "name": "PriceOffTick",
+      "msg": "Price is not a multiple of the collection's tick size"
+    },
+    {
+      "code": 6060,
+      "name": "RentalNotExpired",
+      "msg": "Rental term hasn't ended yet"
+    },
+    {
+      "code": 6061,
+      "name": "TradeStateNotExpired",
+      "msg": "Trade state hasn't expired yet"
+    },
+    {
+      "code": 6062,
+      "name": "HoldPeriodNotElapsed",
+      "msg": "Token is still within the collection's hold period"
+    },
+    {
+      "code": 6063,
+      "name": "MaxPriceExceeded",
+      "msg": "Sale costs more than the taker's max price"
+    },
+    {
+      "code": 6064,
+      "name": "MinNetProceedsNotMet",
+      "msg": "Seller's net proceeds are below the seller's min net proceeds"
+    },
+    {
+      "code": 6065,
+      "name": "SecondaryLegNeedsBuyerTaker",
+      "msg": "Listings with a secondary payment leg have to be taken by the buyer"
+    },
+    {
+      "code": 6066,
+      "name": "ListingNotYetActive",
+      "msg": "Listing can't be filled before its not_before time"
+    },
+    {
+      "code": 6067,
+      "name": "SellerNotTargeted",
+      "msg": "Offer is targeted at another seller"
+    },
+    {
+      "code": 6068,
+      "name": "MissingTransferHookAccounts",
+      "msg": "Accounts of the Token-2022 mint's transfer hook are missing"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidCompressedCreators",
+      "msg": "Creators don't match the creator hash of the compressed NFT"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidRebateSeed",
+      "msg": "Seed doesn't match the commitment of the rebate draw"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidCustodialPlatform",
+      "msg": "Buyer isn't an enabled custodial platform of the auction house"
+    },
+    {
+      "code": 6072,
+      "name": "CoreRuleSetViolation",
+      "msg": "Fill violates the rule set of the core royalties plugin"
+    },
+    {
+      "code": 6073,
+      "name": "AuctionReserveMet",
+      "msg": "Auction reached its reserve price and has to be settled"
+    },
+    {
+      "code": 6074,
+      "name": "OrderBookNotCrossed",
+      "msg": "Best bid and ask of the order book don't cross"
+    },
+    {
+      "code": 6075,
+      "name": "FillProofNotExpired",
+      "msg": "Fill proof can't be closed before its lifetime passed"
+    },
+    {
+      "code": 6076,
+      "name": "EditionSaleSoldOut",
+      "msg": "Edition sale has no editions left to print"
+    },
+    {
+      "code": 6077,
+      "name": "InsurancePayoutLocked",
+      "msg": "Insurance fund payout is still timelocked"
+    },
+    {
+      "code": 6078,
+      "name": "NonTransferableAsset",
+      "msg": "Asset is non-transferable and can't be listed"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidAuthorizationRules",
+      "msg": "Authorization rules accounts don't match the rule set of the pNFT"
+    },
+    {
+      "code": 6080,
+      "name": "InvalidStakingProgram",
+      "msg": "Staking program is not whitelisted"
+    },
+    {
+      "code": 6081,
+      "name": "StakedTokenNotReleased",
+      "msg": "Staking program didn't release the token to the buyer"
+    },
+    {
+      "code": 6082,
+      "name": "PermanentDelegateNotApproved",
+      "msg": "Mint has a permanent delegate the auction house hasn't approved"
+    },
+    {
+      "code": 6083,
+      "name": "OpenOrderLimitReached",
+      "msg": "Wallet has reached the open order limit of the auction house"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidCancellationProof",
+      "msg": "Order isn't in the published cancellation snapshot"
+    },
+    {
+      "code": 6085,
+      "name": "UnsupportedAssetStandard",
+      "msg": "Asset standard can't be routed"
+    },
+    {
+      "code": 6086,
+      "name": "InsufficientCrankBond",
+      "msg": "Crank bond doesn't cover the slash or the withdrawal"
+    }
+  ]
+};
+
+export const IDL: M2 = {
+  "version": "0.1.0",
+  "name": "m2",
+  "instructions": [
+    {
+      "name": "withdrawFromTreasury",
+      "accounts": [
+        {
+          "name": "treasuryWithdrawalDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateAuctionHouse",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryWithdrawalDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sellerFeeBasisPoints",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "buyerReferralBp",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "sellerReferralBp",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "requiresNotary",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "nprob",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowPaymentBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowPaymentBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sellerStateBump",
+          "type": "u8"
+        },
+        {
+          "name": "programAsSignerBump",
+          "type": "u8"
+        },
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "sellerStateExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "sellerStateExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "buy",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buyerStateBump",
+          "type": "u8"
+        },
+        {
+          "name": "escrowPaymentBump",
+          "type": "u8"
+        },
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "buyerStateExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "buyV2",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "buyerStateExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "buyerCreatorRoyaltyBp",
+          "type": "u16"
+        },
+        {
+          "name": "extraArgs",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "cancelBuy",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "buyerStateExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "migrateBuyerTradeState",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newAuctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newBuyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newOrderLimitConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newWalletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncMintListingIndex",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintListingIndex",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setCollectionPause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "migrateCollectionPause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionPause",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapIntoEscrow",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapIntoEscrowArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFloorPricing",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "floorPremiumBp",
+          "type": {
+            "option": "i16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "suspendAllListings",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resumeAllListings",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "suspendAllBids",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resumeAllBids",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStaleListing",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "custodySell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "token_account that holds the token, can be the custody_vault when changing price"
+          ]
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CustodySellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "custodyCancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFrontendConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frontend",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frontendConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetFrontendConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimFrontendFees",
+      "accounts": [
+        {
+          "name": "frontend",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "frontendConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "markCreatorListing",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setListingOperator",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "listingOperator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "operatorRepriceListing",
+      "accounts": [
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "listingOperator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "OperatorRepriceListingArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "collectionBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionBidState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CollectionBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellIntoCollectionBid",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidAuctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionBidState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateApproval",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellIntoCollectionBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelCollectionBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionBidState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRoyaltyFallback",
+      "accounts": [
+        {
+          "name": "updateAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltyFallback",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sellDutch",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellDutchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createAuction",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateAuctionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "placeAuctionBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "placeAuctionProxyBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxBid",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "highestBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SettleAuctionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTakerFeeConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "takerFeeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTakerFeeConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setBuybackConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buybackConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetBuybackConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buybackListing",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buybackConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncBidExpiryAlarm",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidExpiryAlarm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bucket",
+          "type": "i64"
+        },
+        {
+          "name": "page",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "commitBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CommitBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revealBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RevealBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reclaimSealedBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reclaimUnsold",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "highestBidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPriceBandConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPriceBandConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "attestPrice",
+      "accounts": [
+        {
+          "name": "notary",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AttestPriceArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTreasuryYieldConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryYieldConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTreasuryYieldConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepTreasury",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryYieldConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "shareMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryShareAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SweepTreasuryArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellBundle",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellBundleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "appendBundleItems",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AppendBundleItemsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelBundle",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeBundleSale",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bundleSellerState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecuteBundleSaleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAllowedBuyer",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "allowedBuyer",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "returnAllAssets",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buyDelegated",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderLimitConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyDelegatedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellBatch",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "notaryCancelBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "proposeSwap",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offeredMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "offeredMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "offeredTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "requestedMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "requestedMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ProposeSwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptSwap",
+      "accounts": [
+        {
+          "name": "counterparty",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offeredMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "offeredTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "counterpartyReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "requestedMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "requestedTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSwap",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offeredTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapOffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPriceTick",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tickSize",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "listRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ListRentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rentNft",
+      "accounts": [
+        {
+          "name": "renter",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reclaimRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentalListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredBuyerState",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setHoldPeriod",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "holdPeriod",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setAutoRenew",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "period",
+          "type": "i64"
+        },
+        {
+          "name": "bumpBp",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "renewListing",
+      "accounts": [
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "provisionCreatorAtas",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setEscrowDepositor",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowDepositor",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "depositorDeposit",
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowDepositor",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setRoyaltySubsidy",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltySubsidy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRoyaltySubsidyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "portfolioBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "portfolioBidState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PortfolioBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellIntoPortfolioBid",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "portfolioBidState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellIntoPortfolioBidArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelPortfolioBid",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "portfolioBidState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setSecondaryPrice",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setNotBefore",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "notBefore",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setOfferTarget",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetSeller",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptOffer",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AcceptOfferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptOffersBatch",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AcceptOffersBatchArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "gift",
+      "accounts": [
+        {
+          "name": "sender",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "t22Sell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderLimitConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateApproval",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "T22SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "t22CancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "t22ExecuteSaleV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceBandConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceAttestation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "frontendConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerFeeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "royaltySubsidy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fillProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerCountedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "T22ExecuteSaleV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellCompressed",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousLeafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellCompressedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelSellCompressed",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelSellCompressedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyCompressed",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyCompressedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeCompressedSale",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecuteCompressedSaleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "getStateLayout",
+      "accounts": [],
+      "args": [
+        {
+          "name": "discriminator",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        }
+      ],
+      "returns": {
+        "defined": "StateLayoutData"
+      }
+    },
+    {
+      "name": "setRebateCampaign",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRebateCampaignArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "commitRebateDraw",
+      "accounts": [
+        {
+          "name": "randomnessAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CommitRebateDrawArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "drawRebate",
+      "accounts": [
+        {
+          "name": "randomnessAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRebate",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "randomnessAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebateCampaign",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebateEpoch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "coreSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CoreSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "coreCancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "coreExecuteSale",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coreProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tradeSequence",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CoreExecuteSaleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCustodialPlatform",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platform",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodialPlatform",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "custodyWallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "placeOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderBook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderBookLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bookVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PlaceOrderArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderBook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderBookLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bookVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelOrderArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "matchOrders",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "orderBook",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "askLevel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bookVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeFillProof",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "fillProof",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sellEditions",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "masterTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "editionSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellEditionsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyEdition",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionMarkPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "updateAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelEditionSale",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "editionSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setInsuranceFund",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInsuranceFundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueInsurancePayout",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeInsurancePayout",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceFund",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listStaked",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakedListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakingProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ListStakedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyStaked",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakedListing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakingProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerStatus",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelStakedListing",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakedListing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPermanentDelegateApproval",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateApproval",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "approved",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setOrderLimitConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "orderLimitConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxOpenListings",
+          "type": "u32"
+        },
+        {
+          "name": "maxOpenBids",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setPermanentDelegateConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "permanentDelegateConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "flagOnly",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "publishCancellationRoot",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cancellationSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "orderCount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "cancelWithProof",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cancellationSnapshot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletOrderCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "countedOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSaleAuto",
+      "accounts": [
+        {
+          "name": "asset",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExecuteSaleAutoArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "depositCrankBond",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawCrankBond",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "crankBond",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "ocpSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "OCPSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ocpCancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "ocpExecuteSaleV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "OCPExecuteSaleV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeSaleV2",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowPaymentBump",
+          "type": "u8"
+        },
+        {
+          "name": "programAsSignerBump",
+          "type": "u8"
+        },
+        {
+          "name": "buyerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "tokenSize",
+          "type": "u64"
+        },
+        {
+          "name": "buyerStateExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "sellerStateExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "makerFeeBp",
+          "type": "i16"
+        },
+        {
+          "name": "takerFeeBp",
+          "type": "u16"
+        },
+        {
+          "name": "maxPrice",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "minNetProceeds",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "custodyUserTag",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1Sell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "migrationSellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "escrow mode for init sell:        we transfer from token_account to token_ata",
+            "escrow mode for change price:     token_account is the same as token_ata",
+            "migration mode for change price:  token_ata is not used, because we only need token_account which is owned by program_as_signer"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MIP1SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1MigrateAndList",
+      "accounts": [
+        {
+          "name": "sell",
+          "accounts": [
+            {
+              "name": "wallet",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "notary",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "programAsSigner",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "metadata",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "auctionHouse",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "sellerTradeState",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "migrationSellerTradeState",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellerReferral",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAta",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "escrow mode for init sell:        we transfer from token_account to token_ata",
+                "escrow mode for change price:     token_account is the same as token_ata",
+                "migration mode for change price:  token_ata is not used, because we only need token_account which is owned by program_as_signer"
+              ]
+            },
+            {
+              "name": "tokenMetadataProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "edition",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authorizationRulesProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authorizationRules",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "ownerTokenRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destinationTokenRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "collectionConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lastSale",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "associatedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "rentalListing",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MIP1SellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1ExecuteSaleV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReceiptTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouseTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerEscrowPaymentAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellerReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MIP1ExecuteSaleV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1CancelSell",
+      "accounts": [
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "notary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAsSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionHouse",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellerTradeState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "should always be ATA of (mint, wallet)"
+          ]
+        },
+        {
+          "name": "tokenAccountTemp",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "should always be ATA of (mint, program_as_signer)"
+          ]
+        },
+        {
+          "name": "tempTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "buyerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellerTradeStateV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "floorPremiumBp",
+            "type": {
+              "option": "i16"
+            }
+          },
+          {
+            "name": "creatorListing",
+            "type": "bool"
+          },
+          {
+            "name": "dutchEndPrice",
+            "type": "u64"
+          },
+          {
+            "name": "dutchStartTime",
+            "type": "i64"
+          },
+          {
+            "name": "dutchDecayInterval",
+            "type": "i64"
+          },
+          {
+            "name": "allowedBuyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "autoRenewPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "autoRenewBumpBp",
+            "type": "u16"
+          },
+          {
+            "name": "secondaryPaymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "secondaryPrice",
+            "type": "u64"
+          },
+          {
+            "name": "notBefore",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionHouse",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryWithdrawalDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "notary",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "treasuryBump",
+            "type": "u8"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "buyerReferralBp",
+            "type": "u16"
+          },
+          {
+            "name": "sellerReferralBp",
+            "type": "u16"
+          },
+          {
+            "name": "requiresNotary",
+            "type": "bool"
+          },
+          {
+            "name": "nprob",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyerTradeStateV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "escrowless",
+            "type": "bool"
+          },
+          {
+            "name": "takeProfitPrice",
+            "type": "u64"
+          },
+          {
+            "name": "targetSeller",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintListingIndex",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "listings",
+            "type": {
+              "vec": {
+                "defined": "MintListingEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "tickSize",
+            "type": "u64"
+          },
+          {
+            "name": "holdPeriod",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "saleCount",
+            "type": "u64"
+          },
+          {
+            "name": "volume",
+            "type": "u128"
+          },
+          {
+            "name": "lastSalePrice",
+            "type": "u64"
+          },
+          {
+            "name": "lastSaleAt",
+            "type": "i64"
+          },
+          {
+            "name": "floorWindowStart",
+            "type": "i64"
+          },
+          {
+            "name": "floorPrice",
+            "type": "u64"
+          },
+          {
+            "name": "twapPrices",
+            "type": {
+              "array": [
+                "u64",
+                6
+              ]
+            }
+          },
+          {
+            "name": "twapSaleTimes",
+            "type": {
+              "array": [
+                "i64",
+                6
+              ]
+            }
+          },
+          {
+            "name": "twapHead",
+            "type": "u8"
+          },
+          {
+            "name": "twapPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lastSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "soldAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tradeSeq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tradeSequence",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seq",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellerStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "suspended",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buyerStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "suspended",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "frontendConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "frontend",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "saleCount",
+            "type": "u64"
+          },
+          {
+            "name": "volume",
+            "type": "u128"
+          },
+          {
+            "name": "feesEarned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listingOperator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "operator",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionBidState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "filled",
+            "type": "u16"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "royaltyInclusive",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "traitHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "allowedAuctionHouses",
+            "type": {
+              "array": [
+                "publicKey",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltyFallback",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "highestBid",
+            "type": "u64"
+          },
+          {
+            "name": "highestBidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidCount",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "type": "u8"
+          },
+          {
+            "name": "extensionWindow",
+            "type": "i64"
+          },
+          {
+            "name": "extensionSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "revealEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "commitCount",
+            "type": "u32"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tickSize",
+            "type": "u64"
+          },
+          {
+            "name": "highestMaxBid",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "takerFeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceFeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "flatFeeMicroUsd",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "prevFlatFeeMicroUsd",
+            "type": "u64"
+          },
+          {
+            "name": "prevMaxFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "rampSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "buybackConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "burned",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidExpiryAlarm",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "bucket",
+            "type": "i64"
+          },
+          {
+            "name": "page",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "BidExpiryAlarmEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sealedBid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionState",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "revealEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceBandConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "maxAge",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceAttestation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "attestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryYieldConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaultProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaultState",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "float",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleSellerState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u64"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "sealed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "BundleItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapOffer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "offeredMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "offeredTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "counterparty",
+            "type": "publicKey"
+          },
+          {
+            "name": "requestedMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "sweetener",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentalListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "term",
+            "type": "i64"
+          },
+          {
+            "name": "renter",
+            "type": "publicKey"
+          },
+          {
+            "name": "rentedUntil",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "escrowDepositor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "royaltySubsidy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "type": "publicKey"
+          },
+          {
+            "name": "subsidyBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "portfolioBidState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidId",
+            "type": "u64"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "compressedSellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleTree",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "coreSellerTradeState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerReferral",
+            "type": "publicKey"
+          },
+          {
+            "name": "asset",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodialPlatform",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "platform",
+            "type": "publicKey"
+          },
+          {
+            "name": "custodyWallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "editionSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "masterMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrints",
+            "type": "u64"
+          },
+          {
+            "name": "printed",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fillProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "tradeSeq",
+            "type": "u64"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "insuranceFund",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "takerFeeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaidOut",
+            "type": "u64"
+          },
+          {
+            "name": "payoutRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "payoutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "payoutAvailableAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderBook",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextSeq",
+            "type": "u64"
+          },
+          {
+            "name": "bidPrices",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "askPrices",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderBookLevel",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderBook",
+            "type": "publicKey"
+          },
+          {
+            "name": "isBid",
+            "type": "bool"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "orders",
+            "type": {
+              "vec": {
+                "defined": "BookOrder"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebateCampaign",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "randomnessAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebateEpoch",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "entriesHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "ticketCount",
+            "type": "u32"
+          },
+          {
+            "name": "winner",
+            "type": "publicKey"
+          },
+          {
+            "name": "prize",
+            "type": "u64"
+          },
+          {
+            "name": "drawn",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tickets",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakedListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "unstakeData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "permanentDelegateApproval",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "permanentDelegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "approved",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderLimitConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxOpenListings",
+            "type": "u32"
+          },
+          {
+            "name": "maxOpenBids",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "walletOrderCounter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "openListings",
+            "type": "u32"
+          },
+          {
+            "name": "openBids",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "countedOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tradeState",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "permanentDelegateConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "flagOnly",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cancellationSnapshot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionHouseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "orderCount",
+            "type": "u32"
+          },
+          {
+            "name": "publishedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "crankBond",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cranker",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BuyCompressedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetId",
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelSellCompressedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecuteCompressedSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CompressedCreator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "proofLen",
+            "type": "u8"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CompressedCreator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecuteSaleAutoArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "escrowPaymentBump",
+            "type": "u8"
+          },
+          {
+            "name": "programAsSignerBump",
+            "type": "u8"
+          },
+          {
+            "name": "compressed",
+            "type": {
+              "option": {
+                "defined": "CompressedSaleArgs"
+              }
+            }
+          },
+          {
+            "name": "maxPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "custodyUserTag",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellCompressedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "leaf",
+            "type": {
+              "defined": "CompressedLeafArgs"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreExecuteSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CoreSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptOfferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptOffersBatchItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptOffersBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "AcceptOffersBatchItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AppendBundleItemsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seal",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AttestPriceArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyDelegatedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          },
+          {
+            "name": "buyerStateExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "delegatedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "takeProfitPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelOrderArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "royaltyInclusive",
+            "type": "bool"
+          },
+          {
+            "name": "traitHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "allowedAuctionHouses",
+            "type": {
+              "array": [
+                "publicKey",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommitBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommitRebateDrawArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateAuctionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementBp",
+            "type": "u16"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "extensionWindow",
+            "type": "i64"
+          },
+          {
+            "name": "extensionSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "revealEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CustodySellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecuteBundleSaleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FieldLayoutData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "offset",
+            "type": "u16"
+          },
+          {
+            "name": "size",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StateLayoutData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "discriminator",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "len",
+            "type": "u16"
+          },
+          {
+            "name": "fields",
+            "type": {
+              "vec": {
+                "defined": "FieldLayoutData"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListRentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "term",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListStakedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "unstakeData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OperatorRepriceListingArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlaceOrderArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isBid",
+            "type": "bool"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "size",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellEditionsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrints",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInsuranceFundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerFeeShareBp",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PortfolioBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidId",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "buyerCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposeSwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "counterparty",
+            "type": "publicKey"
+          },
+          {
+            "name": "sweetener",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevealBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "salt",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBatchItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSize",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": "SellBatchItem"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellBundleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bundleId",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellDutchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "decayInterval",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
-      "name": "buyerTradeState",
+      "name": "SellIntoCollectionBidArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
+            "name": "price",
+            "type": "u64"
           },
           {
-            "name": "buyer",
-            "type": "publicKey"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "buyerReferral",
-            "type": "publicKey"
+            "name": "takerFeeBp",
+            "type": "u16"
           },
           {
-            "name": "buyerPrice",
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellIntoPortfolioBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "tokenMint",
-            "type": "publicKey"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "tokenSize",
+            "name": "takerFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "minNetProceeds",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetBuybackConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPrice",
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "budget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetFrontendConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeShareBp",
+            "type": "u16"
           },
           {
-            "name": "expiry",
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPriceBandConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "maxAge",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "sellerTradeState",
+      "name": "SetRebateCampaignArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auctionHouseKey",
+            "name": "randomnessAuthority",
             "type": "publicKey"
           },
           {
-            "name": "seller",
-            "type": "publicKey"
+            "name": "prize",
+            "type": "u64"
           },
           {
-            "name": "sellerReferral",
-            "type": "publicKey"
+            "name": "enabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRoyaltySubsidyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subsidyBp",
+            "type": "u16"
           },
           {
-            "name": "buyerPrice",
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "budget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTakerFeeConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "flatFeeMicroUsd",
             "type": "u64"
           },
           {
-            "name": "tokenMint",
+            "name": "maxFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "rampHours",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTreasuryYieldConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "float",
+            "type": "u64"
+          },
+          {
+            "name": "vaultState",
             "type": "publicKey"
           },
           {
-            "name": "tokenAccount",
+            "name": "shareMint",
             "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettleAuctionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "tokenSize",
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapIntoEscrowArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmountIn",
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "minAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "swapData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SweepTreasuryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposit",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MIP1ExecuteSaleV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MIP1SellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
           },
           {
             "name": "expiry",
@@ -3526,41 +21989,61 @@ export const IDL: M2 = {
       }
     },
     {
-      "name": "sellerTradeStateV2",
+      "name": "OCPExecuteSaleV2Args",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
+            "name": "price",
+            "type": "u64"
           },
           {
-            "name": "seller",
-            "type": "publicKey"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "sellerReferral",
-            "type": "publicKey"
-          },
+            "name": "takerFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OCPSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "buyerPrice",
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "tokenMint",
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintListingEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sellerTradeState",
             "type": "publicKey"
           },
           {
-            "name": "tokenAccount",
+            "name": "auctionHouseKey",
             "type": "publicKey"
           },
           {
-            "name": "tokenSize",
-            "type": "u64"
+            "name": "seller",
+            "type": "publicKey"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "buyerPrice",
+            "type": "u64"
           },
           {
             "name": "expiry",
@@ -3574,179 +22057,243 @@ export const IDL: M2 = {
       }
     },
     {
-      "name": "auctionHouse",
+      "name": "BidExpiryAlarmEntry",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auctionHouseTreasury",
+            "name": "buyerTradeState",
             "type": "publicKey"
           },
           {
-            "name": "treasuryWithdrawalDestination",
+            "name": "buyer",
             "type": "publicKey"
           },
           {
-            "name": "authority",
+            "name": "tokenMint",
             "type": "publicKey"
           },
           {
-            "name": "creator",
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BundleItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
             "type": "publicKey"
           },
           {
-            "name": "notary",
+            "name": "tokenAccount",
             "type": "publicKey"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "BookOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "owner",
+            "type": "publicKey"
           },
           {
-            "name": "treasuryBump",
-            "type": "u8"
+            "name": "size",
+            "type": "u64"
           },
           {
-            "name": "sellerFeeBasisPoints",
-            "type": "u16"
-          },
+            "name": "seq",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "T22ExecuteSaleV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "buyerReferralBp",
-            "type": "u16"
+            "name": "price",
+            "type": "u64"
           },
           {
-            "name": "sellerReferralBp",
-            "type": "u16"
+            "name": "tokenSize",
+            "type": "u64"
           },
           {
-            "name": "requiresNotary",
-            "type": "bool"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "nprob",
-            "type": "u8"
+            "name": "takerFeeBp",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "buyerTradeStateV2",
+      "name": "T22SellArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auctionHouseKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyer",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerReferral",
-            "type": "publicKey"
-          },
-          {
-            "name": "buyerPrice",
+            "name": "price",
             "type": "u64"
           },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
           {
             "name": "tokenSize",
             "type": "u64"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
           {
             "name": "expiry",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedCreator",
+      "docs": [
+        "Creator of a compressed NFT as attested by the notary, the leaf only has a hash of these"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
           },
           {
-            "name": "buyerCreatorRoyaltyBp",
-            "type": "u16"
+            "name": "verified",
+            "type": "bool"
           },
           {
-            "name": "paymentMint",
-            "type": "publicKey"
+            "name": "share",
+            "type": "u8"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "MIP1ExecuteSaleV2Args",
+      "name": "CoreRoyaltyCreator",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "makerFeeBp",
-            "type": "i16"
+            "name": "address",
+            "type": "publicKey"
           },
           {
-            "name": "takerFeeBp",
-            "type": "u16"
+            "name": "percentage",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "MIP1SellArgs",
+      "name": "CoreRoyalties",
+      "docs": [
+        "Royalties plugin of an mpl core asset or collection, without its rule set"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "price",
-            "type": "u64"
+            "name": "basisPoints",
+            "type": "u16"
           },
           {
-            "name": "expiry",
-            "type": "i64"
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CoreRoyaltyCreator"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "OCPExecuteSaleV2Args",
+      "name": "CompressedLeafArgs",
+      "docs": [
+        "Leaf of a compressed NFT and the root of the tree it is proven against, laid out the way",
+        "Bubblegum's delegate and transfer instructions take them"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "price",
-            "type": "u64"
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "makerFeeBp",
-            "type": "i16"
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "takerFeeBp",
-            "type": "u16"
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "OCPSellArgs",
+      "name": "CoreAuthority",
+      "docs": [
+        "Authority of a plugin of an mpl core asset"
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "price",
-            "type": "u64"
+            "name": "None"
           },
           {
-            "name": "expiry",
-            "type": "i64"
+            "name": "Owner"
+          },
+          {
+            "name": "UpdateAuthority"
+          },
+          {
+            "name": "Address",
+            "fields": [
+              {
+                "name": "address",
+                "type": "publicKey"
+              }
+            ]
           }
         ]
       }
@@ -3966,6 +22513,251 @@ export const IDL: M2 = {
       "code": 6037,
       "name": "MissingRemainingAccount",
       "msg": "Missing remaining account"
+    },
+    {
+      "code": 6038,
+      "name": "CollectionPaused",
+      "msg": "Trading is paused for this collection"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidSwapProgram",
+      "msg": "Swap program is not whitelisted"
+    },
+    {
+      "code": 6040,
+      "name": "SlippageExceeded",
+      "msg": "Swap output is below the minimum amount out"
+    },
+    {
+      "code": 6041,
+      "name": "FloorPriceUnavailable",
+      "msg": "Floor price is not available for this collection"
+    },
+    {
+      "code": 6042,
+      "name": "SellerSuspended",
+      "msg": "Seller has suspended all listings"
+    },
+    {
+      "code": 6043,
+      "name": "BuyerSuspended",
+      "msg": "Buyer has suspended all bids"
+    },
+    {
+      "code": 6044,
+      "name": "PriceBelowMintMinimum",
+      "msg": "Price is below the minimum price for the payment mint"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidPricePrecision",
+      "msg": "Price has more precision than the payment mint supports"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidReferral",
+      "msg": "Referral account does not match the trade state"
+    },
+    {
+      "code": 6047,
+      "name": "InsufficientComputeBudget",
+      "msg": "Not enough compute units requested, increase the compute unit limit"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidCollection",
+      "msg": "Token is not in the verified collection of the bid"
+    },
+    {
+      "code": 6049,
+      "name": "TraitAttestationRequired",
+      "msg": "Trait bids need the notary to attest the token's traits"
+    },
+    {
+      "code": 6050,
+      "name": "AuctionNotActive",
+      "msg": "Auction is not accepting bids"
+    },
+    {
+      "code": 6051,
+      "name": "AuctionBidTooLow",
+      "msg": "Auction bid is lower than the minimum bid"
+    },
+    {
+      "code": 6052,
+      "name": "AuctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidPriceFeed",
+      "msg": "Price feed is invalid or stale"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidSealedBid",
+      "msg": "Sealed bid doesn't match its commitment"
+    },
+    {
+      "code": 6055,
+      "name": "PriceOutsideAttestedBand",
+      "msg": "Price is outside of the attested fair price band"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidYieldVaultProgram",
+      "msg": "Invalid yield vault program"
+    },
+    {
+      "code": 6057,
+      "name": "BuyerNotAllowed",
+      "msg": "Listing is reserved for another buyer"
+    },
+    {
+      "code": 6058,
+      "name": "InsufficientDelegatedAmount",
+      "msg": "Delegated payment doesn't cover the bid"
+    },
+    {
+      "code": 6059,
+      "name": "PriceOffTick",
+      "msg": "Price is not a multiple of the collection's tick size"
+    },
+    {
+      "code": 6060,
+      "name": "RentalNotExpired",
+      "msg": "Rental term hasn't ended yet"
+    },
+    {
+      "code": 6061,
+      "name": "TradeStateNotExpired",
+      "msg": "Trade state hasn't expired yet"
+    },
+    {
+      "code": 6062,
+      "name": "HoldPeriodNotElapsed",
+      "msg": "Token is still within the collection's hold period"
+    },
+    {
+      "code": 6063,
+      "name": "MaxPriceExceeded",
+      "msg": "Sale costs more than the taker's max price"
+    },
+    {
+      "code": 6064,
+      "name": "MinNetProceedsNotMet",
+      "msg": "Seller's net proceeds are below the seller's min net proceeds"
+    },
+    {
+      "code": 6065,
+      "name": "SecondaryLegNeedsBuyerTaker",
+      "msg": "Listings with a secondary payment leg have to be taken by the buyer"
+    },
+    {
+      "code": 6066,
+      "name": "ListingNotYetActive",
+      "msg": "Listing can't be filled before its not_before time"
+    },
+    {
+      "code": 6067,
+      "name": "SellerNotTargeted",
+      "msg": "Offer is targeted at another seller"
+    },
+    {
+      "code": 6068,
+      "name": "MissingTransferHookAccounts",
+      "msg": "Accounts of the Token-2022 mint's transfer hook are missing"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidCompressedCreators",
+      "msg": "Creators don't match the creator hash of the compressed NFT"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidRebateSeed",
+      "msg": "Seed doesn't match the commitment of the rebate draw"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidCustodialPlatform",
+      "msg": "Buyer isn't an enabled custodial platform of the auction house"
+    },
+    {
+      "code": 6072,
+      "name": "CoreRuleSetViolation",
+      "msg": "Fill violates the rule set of the core royalties plugin"
+    },
+    {
+      "code": 6073,
+      "name": "AuctionReserveMet",
+      "msg": "Auction reached its reserve price and has to be settled"
+    },
+    {
+      "code": 6074,
+      "name": "OrderBookNotCrossed",
+      "msg": "Best bid and ask of the order book don't cross"
+    },
+    {
+      "code": 6075,
+      "name": "FillProofNotExpired",
+      "msg": "Fill proof can't be closed before its lifetime passed"
+    },
+    {
+      "code": 6076,
+      "name": "EditionSaleSoldOut",
+      "msg": "Edition sale has no editions left to print"
+    },
+    {
+      "code": 6077,
+      "name": "InsurancePayoutLocked",
+      "msg": "Insurance fund payout is still timelocked"
+    },
+    {
+      "code": 6078,
+      "name": "NonTransferableAsset",
+      "msg": "Asset is non-transferable and can't be listed"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidAuthorizationRules",
+      "msg": "Authorization rules accounts don't match the rule set of the pNFT"
+    },
+    {
+      "code": 6080,
+      "name": "InvalidStakingProgram",
+      "msg": "Staking program is not whitelisted"
+    },
+    {
+      "code": 6081,
+      "name": "StakedTokenNotReleased",
+      "msg": "Staking program didn't release the token to the buyer"
+    },
+    {
+      "code": 6082,
+      "name": "PermanentDelegateNotApproved",
+      "msg": "Mint has a permanent delegate the auction house hasn't approved"
+    },
+    {
+      "code": 6083,
+      "name": "OpenOrderLimitReached",
+      "msg": "Wallet has reached the open order limit of the auction house"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidCancellationProof",
+      "msg": "Order isn't in the published cancellation snapshot"
+    },
+    {
+      "code": 6085,
+      "name": "UnsupportedAssetStandard",
+      "msg": "Asset standard can't be routed"
+    },
+    {
+      "code": 6086,
+      "name": "InsufficientCrankBond",
+      "msg": "Crank bond doesn't cover the slash or the withdrawal"
     }
   ]
 };